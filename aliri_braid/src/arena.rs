@@ -0,0 +1,27 @@
+/// An arena into which borrowed braids can be bulk-allocated
+///
+/// Braids generated with the `arena` parameter provide an `alloc_in()`
+/// constructor that checks a value and then copies it into an arena, so
+/// parsers that produce many short-lived values can avoid a separate heap
+/// allocation for each one. With the `bumpalo` feature enabled, this trait
+/// is implemented for `bumpalo::Bump`.
+pub trait Arena {
+    /// Copies a string slice into the arena, returning a reference to the
+    /// copy that lives as long as the arena
+    fn alloc_str<'a>(&'a self, raw: &str) -> &'a str;
+}
+
+impl<T: Arena + ?Sized> Arena for &T {
+    #[inline]
+    fn alloc_str<'a>(&'a self, raw: &str) -> &'a str {
+        (**self).alloc_str(raw)
+    }
+}
+
+#[cfg(feature = "bumpalo")]
+impl Arena for bumpalo::Bump {
+    #[inline]
+    fn alloc_str<'a>(&'a self, raw: &str) -> &'a str {
+        bumpalo::Bump::alloc_str(self, raw)
+    }
+}
@@ -0,0 +1,61 @@
+use alloc::string::ToString;
+use core::fmt;
+
+use axum_core::response::{IntoResponse, Response};
+use http::{header, HeaderValue, StatusCode};
+
+/// A rejection produced when a request value fails to construct a braid
+///
+/// Converts into a `400 Bad Request` response whose plain-text body is the
+/// error message produced by the braid's validator or normalizer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rejection<E>(E);
+
+impl<E> Rejection<E> {
+    /// Wraps an error produced while constructing a braid
+    pub fn new(error: E) -> Self {
+        Self(error)
+    }
+
+    /// The error produced while constructing the braid
+    pub fn error(&self) -> &E {
+        &self.0
+    }
+
+    /// Unwraps the error produced while constructing the braid
+    pub fn into_inner(self) -> E {
+        self.0
+    }
+}
+
+impl<E> From<E> for Rejection<E> {
+    fn from(error: E) -> Self {
+        Self(error)
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for Rejection<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<E: core::error::Error + 'static> core::error::Error for Rejection<E> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+impl<E: fmt::Display> IntoResponse for Rejection<E> {
+    fn into_response(self) -> Response {
+        (
+            StatusCode::BAD_REQUEST,
+            [(
+                header::CONTENT_TYPE,
+                HeaderValue::from_static("text/plain; charset=utf-8"),
+            )],
+            self.0.to_string(),
+        )
+            .into_response()
+    }
+}
@@ -0,0 +1,34 @@
+use core::fmt;
+
+use ::bson::spec::ElementType;
+
+/// An error produced when converting a BSON value into a braid
+///
+/// Returned by the `TryFrom<Bson>` implementation generated for braids with the
+/// `bson` parameter.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BsonError<E> {
+    /// The value is not a BSON string
+    UnexpectedType(ElementType),
+    /// The value is a BSON string, but was rejected by the braid's validator
+    Invalid(E),
+}
+
+impl<E: fmt::Display> fmt::Display for BsonError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::UnexpectedType(ty) => write!(f, "expected a BSON string, found {:?}", ty),
+            Self::Invalid(err) => fmt::Display::fmt(err, f),
+        }
+    }
+}
+
+impl<E: core::error::Error + 'static> core::error::Error for BsonError<E> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::UnexpectedType(_) => None,
+            Self::Invalid(err) => Some(err),
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use core::{fmt, str::Utf8Error};
+
+/// An error produced when a braid cannot be reinterpreted from raw bytes
+///
+/// Returned by the `from_bytes_validated` and `from_utf8` constructors, and the
+/// `TryFrom<&[u8]>` implementations, generated when the `from_bytes` parameter
+/// is given to the `braid` macro.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FromBytesError<E> {
+    /// The bytes are not valid UTF-8
+    Utf8(Utf8Error),
+    /// The bytes are valid UTF-8, but the value was rejected by the braid's
+    /// validator or normalizer
+    Invalid(E),
+}
+
+impl<E: fmt::Display> fmt::Display for FromBytesError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Utf8(err) => fmt::Display::fmt(err, f),
+            Self::Invalid(err) => fmt::Display::fmt(err, f),
+        }
+    }
+}
+
+impl<E: core::error::Error + 'static> core::error::Error for FromBytesError<E> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Utf8(err) => Some(err),
+            Self::Invalid(err) => Some(err),
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use core::fmt;
+
+/// A case convention that a braid value can be normalized into
+///
+/// Words are delimited by `_`, `-`, or whitespace, and by transitions from a
+/// lowercase letter or digit to an uppercase letter. A run of uppercase
+/// letters is treated as an acronym, so `HTTPServer` is split into `HTTP`
+/// and `Server`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Case {
+    /// Lowercase words separated by underscores, as in `snake_case`
+    Snake,
+    /// Lowercase words separated by hyphens, as in `kebab-case`
+    Kebab,
+    /// Capitalized words after an initial lowercase word, as in `camelCase`
+    Camel,
+}
+
+impl fmt::Display for Case {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::Snake => "snake_case",
+            Self::Kebab => "kebab-case",
+            Self::Camel => "camelCase",
+        })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Case {
+    /// Converts a value into this case convention, borrowing the value if it
+    /// already conforms
+    pub fn convert(self, raw: &str) -> alloc::borrow::Cow<'_, str> {
+        use alloc::borrow::Cow;
+
+        let mut converted = match self.convert_once(raw) {
+            Cow::Borrowed(raw) => return Cow::Borrowed(raw),
+            Cow::Owned(converted) => converted,
+        };
+
+        // Some word boundaries produced by a conversion are not detected when the result is
+        // converted again, such as between single-letter words in `camelCase`. Each further
+        // conversion can only remove word boundaries, so repeating it until the value is stable
+        // ensures that converted values conform.
+        while let Cow::Owned(next) = self.convert_once(&converted) {
+            converted = next;
+        }
+
+        Cow::Owned(converted)
+    }
+
+    fn convert_once(self, raw: &str) -> alloc::borrow::Cow<'_, str> {
+        use alloc::{borrow::Cow, string::String};
+
+        let mut converted = String::with_capacity(raw.len());
+        let mut chars = raw.chars().peekable();
+        let mut prev: Option<char> = None;
+        let mut pending_boundary = false;
+
+        while let Some(c) = chars.next() {
+            if c == '_' || c == '-' || c.is_whitespace() {
+                pending_boundary = true;
+                prev = None;
+                continue;
+            }
+
+            let starts_word = pending_boundary
+                || prev.is_some_and(|p| {
+                    is_upper(c)
+                        && (p.is_lowercase()
+                            || p.is_numeric()
+                            || (is_upper(p) && chars.peek().is_some_and(|n| n.is_lowercase())))
+                });
+            pending_boundary = false;
+
+            let is_first_word = converted.is_empty();
+            if starts_word && !is_first_word {
+                match self {
+                    Self::Snake => converted.push('_'),
+                    Self::Kebab => converted.push('-'),
+                    Self::Camel => {}
+                }
+            }
+
+            if self == Self::Camel && starts_word && !is_first_word {
+                converted.extend(c.to_uppercase());
+            } else {
+                converted.extend(c.to_lowercase());
+            }
+
+            prev = Some(c);
+        }
+
+        if converted == raw {
+            Cow::Borrowed(raw)
+        } else {
+            Cow::Owned(converted)
+        }
+    }
+
+    fn validate(self, raw: &str) -> Result<(), crate::ConstraintError> {
+        let converted = self.convert(raw);
+        if converted == raw {
+            return Ok(());
+        }
+
+        let position = raw
+            .char_indices()
+            .zip(converted.chars())
+            .find(|((_, a), b)| a != b)
+            .map_or_else(
+                || raw.len().min(converted.len()),
+                |((position, _), _)| position,
+            );
+
+        Err(crate::ConstraintError::new(
+            raw,
+            crate::Constraint::Case(self),
+            position,
+        ))
+    }
+}
+
+/// Whether `c` is an uppercase letter with a distinct lowercase form
+///
+/// Uppercase letters without a lowercase form, such as `'ℝ'`, are left as they
+/// are by conversion, so they cannot start a word without making conversion
+/// inconsistent when applied to its own output.
+#[cfg(feature = "alloc")]
+fn is_upper(c: char) -> bool {
+    c.is_uppercase() && c.to_lowercase().ne(core::iter::once(c))
+}
+
+macro_rules! case_normalizer {
+    ($(#[$meta:meta])* $name:ident => $case:ident) => {
+        $(#[$meta])*
+        #[cfg(feature = "alloc")]
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+        pub struct $name;
+
+        #[cfg(feature = "alloc")]
+        impl crate::Validator for $name {
+            type Error = crate::ConstraintError;
+
+            #[inline]
+            fn validate(raw: &str) -> Result<(), Self::Error> {
+                Case::$case.validate(raw)
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl crate::Normalizer for $name {
+            #[inline]
+            fn normalize(raw: &str) -> Result<alloc::borrow::Cow<'_, str>, Self::Error> {
+                Ok(Case::$case.convert(raw))
+            }
+        }
+    };
+}
+
+case_normalizer! {
+    /// A normalizer that converts values into `snake_case`
+    ///
+    /// Used by braids declared with `convert_case = "snake"`.
+    SnakeCase => Snake
+}
+
+case_normalizer! {
+    /// A normalizer that converts values into `kebab-case`
+    ///
+    /// Used by braids declared with `convert_case = "kebab"`.
+    KebabCase => Kebab
+}
+
+case_normalizer! {
+    /// A normalizer that converts values into `camelCase`
+    ///
+    /// Used by braids declared with `convert_case = "camel"`.
+    CamelCase => Camel
+}
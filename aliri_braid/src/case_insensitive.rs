@@ -0,0 +1,73 @@
+use core::{cmp::Ordering, hash::Hasher};
+
+/// A case-insensitive comparison of string slices, used by braids declared
+/// with `eq = "..."`
+///
+/// Implementations must be consistent with one another, so that values that
+/// are equal hash identically and compare as equal when ordered.
+pub trait CaseInsensitive {
+    /// Determines whether two values are equal, ignoring case
+    fn eq(a: &str, b: &str) -> bool;
+
+    /// Hashes a value, ignoring case
+    fn hash<H: Hasher>(s: &str, state: &mut H);
+
+    /// Orders two values, ignoring case
+    fn cmp(a: &str, b: &str) -> Ordering;
+}
+
+/// Ignores differences in the case of ASCII letters
+#[derive(Debug)]
+pub enum AsciiCaseInsensitive {}
+
+impl CaseInsensitive for AsciiCaseInsensitive {
+    #[inline]
+    fn eq(a: &str, b: &str) -> bool {
+        a.eq_ignore_ascii_case(b)
+    }
+
+    fn hash<H: Hasher>(s: &str, state: &mut H) {
+        for b in s.bytes() {
+            state.write_u8(b.to_ascii_lowercase());
+        }
+        state.write_u8(0xff);
+    }
+
+    fn cmp(a: &str, b: &str) -> Ordering {
+        let a = a.bytes().map(|b| b.to_ascii_lowercase());
+        let b = b.bytes().map(|b| b.to_ascii_lowercase());
+        a.cmp(b)
+    }
+}
+
+/// Ignores differences in case by comparing the lowercase mapping of each
+/// `char`
+#[cfg(feature = "unicode")]
+#[derive(Debug)]
+pub enum UnicodeCaseInsensitive {}
+
+#[cfg(feature = "unicode")]
+impl UnicodeCaseInsensitive {
+    fn fold(s: &str) -> impl Iterator<Item = char> + '_ {
+        s.chars().flat_map(char::to_lowercase)
+    }
+}
+
+#[cfg(feature = "unicode")]
+impl CaseInsensitive for UnicodeCaseInsensitive {
+    #[inline]
+    fn eq(a: &str, b: &str) -> bool {
+        Self::fold(a).eq(Self::fold(b))
+    }
+
+    fn hash<H: Hasher>(s: &str, state: &mut H) {
+        for c in Self::fold(s) {
+            state.write_u32(u32::from(c));
+        }
+        state.write_u8(0xff);
+    }
+
+    fn cmp(a: &str, b: &str) -> Ordering {
+        Self::fold(a).cmp(Self::fold(b))
+    }
+}
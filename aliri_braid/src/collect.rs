@@ -0,0 +1,675 @@
+use alloc::{
+    borrow::ToOwned,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{cell::RefCell, fmt, marker::PhantomData, ops::Deref};
+
+use serde::de::{
+    self, DeserializeSeed, Deserializer, EnumAccess, IntoDeserializer, MapAccess, SeqAccess,
+    VariantAccess, Visitor,
+};
+
+/// The newtype name used by [`Checked`] to recognize that it is being deserialized by
+/// [`deserialize_collecting()`]
+const MARKER: &str = "$aliri_braid::Checked";
+
+/// A field whose validation failure is collected rather than ending deserialization
+///
+/// Wrap the braid fields of a container in `Checked` and deserialize the container with
+/// [`deserialize_collecting()`] to report every invalid field at once. When deserialized
+/// by any other means, `Checked<T>` behaves exactly like `T`, failing on the first
+/// invalid value.
+///
+/// The wrapped value must be represented as a string, as braids are.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Checked<T>(Option<T>);
+
+impl<T> Checked<T> {
+    /// Wraps a value that has already been checked
+    pub fn new(value: T) -> Self {
+        Self(Some(value))
+    }
+
+    /// Unwraps the checked value
+    pub fn into_inner(self) -> T {
+        self.0.expect(INVALID_ESCAPED)
+    }
+}
+
+/// A `Checked` value is only left empty while a failing collection is in progress, and
+/// `deserialize_collecting()` never returns such a container
+const INVALID_ESCAPED: &str = "invalid checked value escaped a failed collection";
+
+impl<T> From<T> for Checked<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> Deref for Checked<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.0.as_ref().expect(INVALID_ESCAPED)
+    }
+}
+
+impl<T> AsRef<T> for Checked<T> {
+    fn as_ref(&self) -> &T {
+        self
+    }
+}
+
+impl<T: serde::Serialize> serde::Serialize for Checked<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        T::serialize(self, serializer)
+    }
+}
+
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Checked<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_newtype_struct(MARKER, CheckedVisitor(PhantomData))
+    }
+}
+
+struct CheckedVisitor<T>(PhantomData<T>);
+
+impl<'de, T: serde::Deserialize<'de>> Visitor<'de> for CheckedVisitor<T> {
+    type Value = Checked<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a string")
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        T::deserialize(deserializer).map(Checked::new)
+    }
+
+    /// Only called by the collecting deserializer, which offers the value under the marker key
+    /// and records any failure to deserialize it that is reported back
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        if map.next_key::<MarkerKey>()?.is_none() {
+            return Err(de::Error::invalid_length(0, &self));
+        }
+
+        match map.next_value_seed(Lenient(PhantomData))? {
+            Ok(value) => Ok(Checked::new(value)),
+            Err(message) => {
+                map.next_key_seed(Report(message))?;
+                Ok(Checked(None))
+            }
+        }
+    }
+}
+
+struct MarkerKey;
+
+impl<'de> serde::Deserialize<'de> for MarkerKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct MarkerKeyVisitor;
+
+        impl Visitor<'_> for MarkerKeyVisitor {
+            type Value = MarkerKey;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a checked value")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                if v == MARKER {
+                    Ok(MarkerKey)
+                } else {
+                    Err(E::invalid_value(de::Unexpected::Str(v), &self))
+                }
+            }
+        }
+
+        deserializer.deserialize_identifier(MarkerKeyVisitor)
+    }
+}
+
+/// Deserializes a value, capturing any failure so that deserialization can continue
+struct Lenient<T>(PhantomData<T>);
+
+impl<'de, T: serde::Deserialize<'de>> DeserializeSeed<'de> for Lenient<T> {
+    type Value = Result<T, String>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        Ok(T::deserialize(deserializer).map_err(|err| err.to_string()))
+    }
+}
+
+/// Reports a failure back to the collecting deserializer by failing with its message
+struct Report(String);
+
+impl<'de> DeserializeSeed<'de> for Report {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, _: D) -> Result<Self::Value, D::Error> {
+        Err(de::Error::custom(self.0))
+    }
+}
+
+/// A failure to deserialize a single [`Checked`] field
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldError {
+    path: String,
+    message: String,
+}
+
+impl FieldError {
+    /// The path to the field within the deserialized value, such as `users[1].name`
+    ///
+    /// The path is empty if the deserialized value is itself a `Checked` value.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// The error produced when deserializing the field
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.path.is_empty() {
+            f.write_str(&self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
+}
+
+impl core::error::Error for FieldError {}
+
+/// An error produced by [`deserialize_collecting()`]
+#[derive(Debug)]
+pub enum CollectError<E> {
+    /// The input could not be deserialized, regardless of the validity of any fields
+    Deserialize(E),
+    /// The input was well-formed, but one or more [`Checked`] fields were invalid
+    Invalid(Vec<FieldError>),
+}
+
+impl<E: fmt::Display> fmt::Display for CollectError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Deserialize(err) => fmt::Display::fmt(err, f),
+            Self::Invalid(errors) => {
+                f.write_str("invalid fields")?;
+                for (i, err) in errors.iter().enumerate() {
+                    f.write_str(if i == 0 { ": " } else { "; " })?;
+                    fmt::Display::fmt(err, f)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<E: core::error::Error + 'static> core::error::Error for CollectError<E> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Deserialize(err) => Some(err),
+            Self::Invalid(_) => None,
+        }
+    }
+}
+
+/// Deserializes a value, collecting the failures of all invalid [`Checked`] fields
+///
+/// Rather than stopping at the first invalid field, every `Checked` field that fails to
+/// deserialize is recorded along with its path, and all of the failures are returned
+/// together. Failures that are not attributable to a `Checked` field, such as malformed
+/// input, are returned immediately.
+///
+/// # Errors
+///
+/// Returns [`CollectError::Invalid`] if any `Checked` fields were invalid, or
+/// [`CollectError::Deserialize`] if the input could not otherwise be deserialized.
+///
+/// # Example
+///
+/// ```
+/// use aliri_braid::{braid, deserialize_collecting, Checked, CollectError};
+///
+/// #[braid(serde, min_len = 1)]
+/// pub struct Username;
+///
+/// #[braid(serde, max_len = 8)]
+/// pub struct Nickname;
+///
+/// #[derive(serde::Deserialize)]
+/// struct Signup {
+///     username: Checked<Username>,
+///     nickname: Checked<Nickname>,
+/// }
+///
+/// let json = r#"{"username": "", "nickname": "much too long"}"#;
+/// let mut deserializer = serde_json::Deserializer::from_str(json);
+/// match deserialize_collecting::<Signup, _>(&mut deserializer) {
+///     Err(CollectError::Invalid(errors)) => {
+///         assert_eq!(2, errors.len());
+///         assert_eq!("username", errors[0].path());
+///         assert_eq!("nickname", errors[1].path());
+///     }
+///     _ => panic!("expected both fields to be invalid"),
+/// }
+/// ```
+pub fn deserialize_collecting<'de, T, D>(deserializer: D) -> Result<T, CollectError<D::Error>>
+where
+    T: serde::Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    let state = RefCell::new(State::default());
+    let value = T::deserialize(Collecting::new(deserializer, &state, false))
+        .map_err(CollectError::Deserialize)?;
+
+    let errors = state.into_inner().errors;
+    if errors.is_empty() {
+        Ok(value)
+    } else {
+        Err(CollectError::Invalid(errors))
+    }
+}
+
+enum Segment {
+    Key(String),
+    Index(usize),
+}
+
+#[derive(Default)]
+struct State {
+    path: Vec<Segment>,
+    key: Option<String>,
+    errors: Vec<FieldError>,
+}
+
+impl State {
+    fn record(&mut self, message: String) {
+        let mut path = String::new();
+        for segment in &self.path {
+            match segment {
+                Segment::Key(key) if path.is_empty() => path.push_str(key),
+                Segment::Key(key) => {
+                    path.push('.');
+                    path.push_str(key);
+                }
+                Segment::Index(index) => path.push_str(&format!("[{}]", index)),
+            }
+        }
+
+        self.errors.push(FieldError { path, message });
+    }
+}
+
+/// A deserializer that tracks the path to the value being deserialized
+///
+/// When deserializing a map key, the key is captured so that it can be used as a path
+/// segment for the corresponding value.
+struct Collecting<'a, D> {
+    inner: D,
+    state: &'a RefCell<State>,
+    key: bool,
+}
+
+impl<'a, D> Collecting<'a, D> {
+    fn new(inner: D, state: &'a RefCell<State>, key: bool) -> Self {
+        Self { inner, state, key }
+    }
+
+    fn wrap<T>(&self, inner: T) -> Collecting<'a, T> {
+        Collecting::new(inner, self.state, self.key)
+    }
+}
+
+macro_rules! forward_deserialize {
+    ($($method:ident($($arg:ident: $ty:ty),*);)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, $($arg: $ty,)* visitor: V) -> Result<V::Value, Self::Error> {
+                let visitor = self.wrap(visitor);
+                self.inner.$method($($arg,)* visitor)
+            }
+        )*
+    };
+}
+
+impl<'de, D: Deserializer<'de>> Deserializer<'de> for Collecting<'_, D> {
+    type Error = D::Error;
+
+    forward_deserialize! {
+        deserialize_any();
+        deserialize_bool();
+        deserialize_i8();
+        deserialize_i16();
+        deserialize_i32();
+        deserialize_i64();
+        deserialize_i128();
+        deserialize_u8();
+        deserialize_u16();
+        deserialize_u32();
+        deserialize_u64();
+        deserialize_u128();
+        deserialize_f32();
+        deserialize_f64();
+        deserialize_char();
+        deserialize_str();
+        deserialize_string();
+        deserialize_bytes();
+        deserialize_byte_buf();
+        deserialize_option();
+        deserialize_unit();
+        deserialize_unit_struct(name: &'static str);
+        deserialize_seq();
+        deserialize_tuple(len: usize);
+        deserialize_tuple_struct(name: &'static str, len: usize);
+        deserialize_map();
+        deserialize_struct(name: &'static str, fields: &'static [&'static str]);
+        deserialize_enum(name: &'static str, variants: &'static [&'static str]);
+        deserialize_identifier();
+        deserialize_ignored_any();
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        if name == MARKER && !self.key {
+            let raw = self.inner.deserialize_string(RawString)?;
+            return visitor.visit_map(CheckedAccess {
+                raw: Some(raw),
+                state: self.state,
+                error: PhantomData,
+            });
+        }
+
+        let visitor = self.wrap(visitor);
+        self.inner.deserialize_newtype_struct(name, visitor)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+macro_rules! forward_visit {
+    ($($method:ident($ty:ty);)*) => {
+        $(
+            fn $method<E: de::Error>(self, v: $ty) -> Result<Self::Value, E> {
+                self.inner.$method(v)
+            }
+        )*
+    };
+}
+
+macro_rules! forward_visit_key {
+    ($($method:ident($ty:ty);)*) => {
+        $(
+            fn $method<E: de::Error>(self, v: $ty) -> Result<Self::Value, E> {
+                if self.key {
+                    self.state.borrow_mut().key = Some(v.to_string());
+                }
+                self.inner.$method(v)
+            }
+        )*
+    };
+}
+
+impl<'de, V: Visitor<'de>> Visitor<'de> for Collecting<'_, V> {
+    type Value = V::Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.inner.expecting(f)
+    }
+
+    forward_visit! {
+        visit_bool(bool);
+        visit_i8(i8);
+        visit_i16(i16);
+        visit_i32(i32);
+        visit_i128(i128);
+        visit_u8(u8);
+        visit_u16(u16);
+        visit_u32(u32);
+        visit_u128(u128);
+        visit_f32(f32);
+        visit_f64(f64);
+        visit_char(char);
+        visit_bytes(&[u8]);
+        visit_borrowed_bytes(&'de [u8]);
+        visit_byte_buf(Vec<u8>);
+    }
+
+    forward_visit_key! {
+        visit_i64(i64);
+        visit_u64(u64);
+        visit_str(&str);
+        visit_borrowed_str(&'de str);
+        visit_string(String);
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        self.inner.visit_none()
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        let deserializer = self.wrap(deserializer);
+        self.inner.visit_some(deserializer)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        self.inner.visit_unit()
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        let deserializer = self.wrap(deserializer);
+        self.inner.visit_newtype_struct(deserializer)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        self.inner.visit_seq(CollectingSeq {
+            inner: seq,
+            state: self.state,
+            index: 0,
+        })
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        self.inner.visit_map(CollectingMap {
+            inner: map,
+            state: self.state,
+        })
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        let data = self.wrap(data);
+        self.inner.visit_enum(data)
+    }
+}
+
+impl<'de, S: DeserializeSeed<'de>> DeserializeSeed<'de> for Collecting<'_, S> {
+    type Value = S::Value;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        let deserializer = self.wrap(deserializer);
+        self.inner.deserialize(deserializer)
+    }
+}
+
+impl<'a, 'de, A: EnumAccess<'de>> EnumAccess<'de> for Collecting<'a, A> {
+    type Error = A::Error;
+    type Variant = Collecting<'a, A::Variant>;
+
+    fn variant_seed<S: DeserializeSeed<'de>>(
+        self,
+        seed: S,
+    ) -> Result<(S::Value, Self::Variant), Self::Error> {
+        let state = self.state;
+        let (value, variant) = self.inner.variant_seed(seed)?;
+        Ok((value, Collecting::new(variant, state, false)))
+    }
+}
+
+impl<'de, A: VariantAccess<'de>> VariantAccess<'de> for Collecting<'_, A> {
+    type Error = A::Error;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        self.inner.unit_variant()
+    }
+
+    fn newtype_variant_seed<S: DeserializeSeed<'de>>(
+        self,
+        seed: S,
+    ) -> Result<S::Value, Self::Error> {
+        let seed = self.wrap(seed);
+        self.inner.newtype_variant_seed(seed)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let visitor = self.wrap(visitor);
+        self.inner.tuple_variant(len, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let visitor = self.wrap(visitor);
+        self.inner.struct_variant(fields, visitor)
+    }
+}
+
+struct CollectingSeq<'a, A> {
+    inner: A,
+    state: &'a RefCell<State>,
+    index: usize,
+}
+
+impl<'de, A: SeqAccess<'de>> SeqAccess<'de> for CollectingSeq<'_, A> {
+    type Error = A::Error;
+
+    fn next_element_seed<S: DeserializeSeed<'de>>(
+        &mut self,
+        seed: S,
+    ) -> Result<Option<S::Value>, Self::Error> {
+        self.state
+            .borrow_mut()
+            .path
+            .push(Segment::Index(self.index));
+        let element = self
+            .inner
+            .next_element_seed(Collecting::new(seed, self.state, false));
+        self.state.borrow_mut().path.pop();
+        self.index += 1;
+        element
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+struct CollectingMap<'a, A> {
+    inner: A,
+    state: &'a RefCell<State>,
+}
+
+impl<'de, A: MapAccess<'de>> MapAccess<'de> for CollectingMap<'_, A> {
+    type Error = A::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        self.inner
+            .next_key_seed(Collecting::new(seed, self.state, true))
+    }
+
+    fn next_value_seed<S: DeserializeSeed<'de>>(
+        &mut self,
+        seed: S,
+    ) -> Result<S::Value, Self::Error> {
+        let key = self
+            .state
+            .borrow_mut()
+            .key
+            .take()
+            .unwrap_or_else(|| "?".to_owned());
+        self.state.borrow_mut().path.push(Segment::Key(key));
+        let value = self
+            .inner
+            .next_value_seed(Collecting::new(seed, self.state, false));
+        self.state.borrow_mut().path.pop();
+        value
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+/// Captures the raw string behind a [`Checked`] value
+struct RawString;
+
+impl Visitor<'_> for RawString {
+    type Value = String;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a string")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(v.to_owned())
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+        Ok(v)
+    }
+}
+
+/// Offers the raw string behind a [`Checked`] value to its visitor under the marker key
+///
+/// If the value cannot be deserialized from the string, the visitor reports the failure by
+/// requesting another key with a seed that fails with the message, which is then recorded.
+struct CheckedAccess<'a, E> {
+    raw: Option<String>,
+    state: &'a RefCell<State>,
+    error: PhantomData<E>,
+}
+
+impl<'de, E: de::Error> MapAccess<'de> for CheckedAccess<'_, E> {
+    type Error = E;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, E> {
+        if self.raw.is_some() {
+            return seed.deserialize(MARKER.into_deserializer()).map(Some);
+        }
+
+        let key: de::value::StrDeserializer<'_, E> = MARKER.into_deserializer();
+        if let Err(err) = seed.deserialize(key) {
+            self.state.borrow_mut().record(err.to_string());
+        }
+
+        Ok(None)
+    }
+
+    fn next_value_seed<S: DeserializeSeed<'de>>(&mut self, seed: S) -> Result<S::Value, E> {
+        let raw = self.raw.take().unwrap_or_default();
+        seed.deserialize(raw.into_deserializer())
+    }
+}
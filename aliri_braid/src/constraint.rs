@@ -0,0 +1,196 @@
+use core::fmt;
+
+/// The maximum number of bytes of the offending value retained by a [`ConstraintError`]
+const MAX_RETAINED_LEN: usize = 32;
+
+/// A declarative constraint that a braid value must satisfy
+///
+/// Constraints are declared as parameters to the `braid` macro, which
+/// synthesizes a [`Validator`][crate::Validator] that checks each one in turn.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Constraint {
+    /// The value must not be empty
+    NonEmpty,
+    /// The value must be at least this many bytes long
+    MinLength(usize),
+    /// The value must be at most this many bytes long
+    MaxLength(usize),
+    /// The value must be at least this many `char`s long
+    MinChars(usize),
+    /// The value must be at most this many `char`s long
+    MaxChars(usize),
+    /// The value must start with this prefix
+    Prefix(&'static str),
+    /// The value must end with this suffix
+    Suffix(&'static str),
+    /// The entire value must match this regular expression
+    Pattern(&'static str),
+    /// The value must follow this case convention
+    Case(crate::Case),
+    /// The value must only contain characters from this set
+    Charset(Charset),
+    /// The value must follow this textual format
+    Format(crate::Format),
+}
+
+impl fmt::Display for Constraint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NonEmpty => f.write_str("must not be empty"),
+            Self::MinLength(min) => write!(f, "must be at least {} bytes long", min),
+            Self::MaxLength(max) => write!(f, "must be at most {} bytes long", max),
+            Self::MinChars(min) => write!(f, "must be at least {} characters long", min),
+            Self::MaxChars(max) => write!(f, "must be at most {} characters long", max),
+            Self::Prefix(prefix) => write!(f, "must start with {:?}", prefix),
+            Self::Suffix(suffix) => write!(f, "must end with {:?}", suffix),
+            Self::Pattern(pattern) => write!(f, "must match the pattern {:?}", pattern),
+            Self::Case(case) => write!(f, "must be in {}", case),
+            Self::Charset(charset) => write!(f, "must only contain {}", charset),
+            Self::Format(format) => write!(f, "must be {}", format),
+        }
+    }
+}
+
+/// A set of characters to which a braid value can be restricted
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Charset {
+    /// ASCII characters
+    Ascii,
+    /// ASCII letters and digits
+    AsciiAlphanumeric,
+    /// Any characters other than control characters
+    NoControl,
+    /// Printable ASCII characters, from `' '` through `'~'`
+    Printable,
+}
+
+impl Charset {
+    /// Finds the byte position of the first character in `raw` that is not in
+    /// this set
+    ///
+    /// This can be used during constant evaluation.
+    #[must_use]
+    pub const fn find_invalid(self, raw: &str) -> Option<usize> {
+        let bytes = raw.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            let b = bytes[i];
+            let allowed = match self {
+                Self::Ascii => b.is_ascii(),
+                Self::AsciiAlphanumeric => b.is_ascii_alphanumeric(),
+                // C1 control characters are encoded as `0xC2` followed by `0x80..=0x9F`
+                Self::NoControl => {
+                    !(b.is_ascii_control()
+                        || (b == 0xC2 && i + 1 < bytes.len() && bytes[i + 1] <= 0x9F))
+                }
+                Self::Printable => b.is_ascii_graphic() || b == b' ',
+            };
+
+            if !allowed {
+                return Some(i);
+            }
+            i += 1;
+        }
+
+        None
+    }
+}
+
+impl fmt::Display for Charset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::Ascii => "ASCII characters",
+            Self::AsciiAlphanumeric => "ASCII letters and digits",
+            Self::NoControl => "characters other than control characters",
+            Self::Printable => "printable ASCII characters",
+        })
+    }
+}
+
+/// An error produced when a value fails a declarative [`Constraint`]
+///
+/// The error retains a truncated copy of the offending value, the constraint
+/// that was violated, and the byte position at which checking failed.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ConstraintError {
+    value: [u8; MAX_RETAINED_LEN],
+    value_len: u8,
+    truncated: bool,
+    constraint: Constraint,
+    position: usize,
+}
+
+impl ConstraintError {
+    /// Constructs a new error for a value that violates `constraint` at the
+    /// given byte `position`
+    ///
+    /// Only a prefix of the value is retained. The value is truncated on a
+    /// `char` boundary, so the retained portion is always valid UTF-8.
+    pub fn new(value: &str, constraint: Constraint, position: usize) -> Self {
+        let mut len = value.len().min(MAX_RETAINED_LEN);
+        while !value.is_char_boundary(len) {
+            len -= 1;
+        }
+
+        let mut retained = [0; MAX_RETAINED_LEN];
+        retained[..len].copy_from_slice(&value.as_bytes()[..len]);
+
+        Self {
+            value: retained,
+            value_len: len as u8,
+            truncated: len < value.len(),
+            constraint,
+            position,
+        }
+    }
+
+    /// The offending value, possibly truncated
+    pub fn value(&self) -> &str {
+        core::str::from_utf8(&self.value[..usize::from(self.value_len)]).unwrap_or_default()
+    }
+
+    /// Whether the value returned by [`value()`][Self::value] was truncated
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// The constraint that was violated
+    pub fn constraint(&self) -> Constraint {
+        self.constraint
+    }
+
+    /// The byte position in the original value at which checking failed
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl fmt::Debug for ConstraintError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ConstraintError")
+            .field("value", &self.value())
+            .field("truncated", &self.truncated)
+            .field("constraint", &self.constraint)
+            .field("position", &self.position)
+            .finish()
+    }
+}
+
+impl fmt::Display for ConstraintError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "invalid value {:?}{}: {} (at byte {})",
+            self.value(),
+            if self.truncated { "…" } else { "" },
+            self.constraint,
+            self.position,
+        )
+    }
+}
+
+impl core::error::Error for ConstraintError {}
+
+crate::from_infallible!(ConstraintError);
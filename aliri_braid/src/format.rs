@@ -0,0 +1,380 @@
+use core::fmt;
+
+use crate::{Constraint, ConstraintError};
+
+/// A well-known textual format that a braid value must follow
+///
+/// Braids declared with the `format` parameter are checked by one of the
+/// validators or normalizers in this crate, each of which reports a
+/// [`ConstraintError`] with [`Constraint::Format`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Format {
+    /// A UUID in its canonical hyphenated, lowercase form
+    Uuid,
+    /// A hostname, as described in RFC 1123
+    Hostname,
+    /// An email address with a dot-atom local part and a hostname domain
+    Email,
+    /// A URI, as described in RFC 3986
+    Uri,
+    /// A domain name in its lowercase ASCII form, as described by IDNA
+    Idna,
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::Uuid => "a canonical UUID",
+            Self::Hostname => "a valid hostname",
+            Self::Email => "a valid email address",
+            Self::Uri => "a valid URI",
+            Self::Idna => "a domain name in its IDNA ASCII form",
+        })
+    }
+}
+
+/// The maximum length of a hostname, excluding any trailing dot
+const MAX_HOSTNAME_LEN: usize = 253;
+/// The maximum length of a single label of a hostname
+const MAX_LABEL_LEN: usize = 63;
+/// The maximum length of the local part of an email address
+const MAX_LOCAL_PART_LEN: usize = 64;
+/// The maximum length of an email address
+const MAX_EMAIL_LEN: usize = 254;
+
+/// Finds the byte position at which `raw` stops being a valid hostname
+fn find_invalid_hostname(raw: &str) -> Option<usize> {
+    let name = raw.strip_suffix('.').unwrap_or(raw);
+    if name.is_empty() {
+        return Some(0);
+    }
+
+    let mut start = 0;
+    for label in name.split('.') {
+        let bytes = label.as_bytes();
+        if bytes.is_empty() || bytes[0] == b'-' {
+            return Some(start);
+        }
+
+        if let Some(i) = bytes
+            .iter()
+            .position(|&b| !(b.is_ascii_alphanumeric() || b == b'-'))
+        {
+            return Some(start + i);
+        }
+
+        if bytes.len() > MAX_LABEL_LEN {
+            return Some(start + MAX_LABEL_LEN);
+        }
+
+        if bytes[bytes.len() - 1] == b'-' {
+            return Some(start + bytes.len() - 1);
+        }
+
+        start += bytes.len() + 1;
+    }
+
+    (name.len() > MAX_HOSTNAME_LEN).then_some(MAX_HOSTNAME_LEN)
+}
+
+/// Finds the byte position at which `raw` stops being a valid email address
+fn find_invalid_email(raw: &str) -> Option<usize> {
+    let at = match raw.rfind('@') {
+        Some(at) => at,
+        None => return Some(raw.len()),
+    };
+
+    let local = &raw[..at];
+    let is_atext = |b: u8| b.is_ascii_alphanumeric() || b"!#$%&'*+/=?^_`{|}~-".contains(&b);
+    let mut prev_dot = true;
+    for (i, b) in local.bytes().enumerate() {
+        let valid = if b == b'.' { !prev_dot } else { is_atext(b) };
+        if !valid {
+            return Some(i);
+        }
+        prev_dot = b == b'.';
+    }
+
+    if local.is_empty() || prev_dot {
+        return Some(at);
+    }
+
+    if local.len() > MAX_LOCAL_PART_LEN {
+        return Some(MAX_LOCAL_PART_LEN);
+    }
+
+    let domain = &raw[at + 1..];
+    if domain.ends_with('.') {
+        return Some(raw.len() - 1);
+    }
+
+    if let Some(i) = find_invalid_hostname(domain) {
+        return Some(at + 1 + i);
+    }
+
+    (raw.len() > MAX_EMAIL_LEN).then_some(MAX_EMAIL_LEN)
+}
+
+/// Finds the byte position at which `raw` stops being a valid URI
+///
+/// The value is split into its scheme, authority, path, query, and fragment,
+/// and the position reported is that of the first character that is invalid
+/// within its component. Where no single character is at fault, such as an
+/// authority with a malformed port, the start of the component is reported.
+#[cfg(feature = "uri")]
+fn find_invalid_uri(raw: &str) -> Option<usize> {
+    use iri_string::{spec::UriSpec, validate};
+
+    type ComponentValidator = fn(&str) -> Result<(), validate::Error>;
+
+    /// Finds the first character, or percent-encoded triplet, that is not
+    /// valid on its own within a component
+    fn find_invalid_char(component: &str, validate: ComponentValidator) -> usize {
+        let mut chars = component.char_indices();
+        while let Some((i, c)) = chars.next() {
+            let end = if c == '%' {
+                chars
+                    .nth(1)
+                    .map_or(component.len(), |(j, c)| j + c.len_utf8())
+            } else {
+                i + c.len_utf8()
+            };
+            if validate(&component[i..end]).is_err() {
+                return i;
+            }
+        }
+        0
+    }
+
+    validate::iri::<UriSpec>(raw).err()?;
+
+    let scheme_len = raw.find(':').unwrap_or(raw.len());
+    if let Some(i) = raw[..scheme_len].bytes().enumerate().position(|(i, b)| {
+        !(b.is_ascii_alphabetic() || i > 0 && (b.is_ascii_digit() || b"+-.".contains(&b)))
+    }) {
+        return Some(i);
+    }
+
+    if scheme_len == 0 || scheme_len == raw.len() {
+        return Some(scheme_len);
+    }
+
+    let rest_start = scheme_len + 1;
+    let rest = &raw[rest_start..];
+    let (rest, fragment) = rest.split_once('#').unwrap_or((rest, ""));
+    let (hier_part, query) = rest.split_once('?').unwrap_or((rest, ""));
+    let (authority, path) = match hier_part.strip_prefix("//") {
+        Some(hier_part) => hier_part.split_at(hier_part.find('/').unwrap_or(hier_part.len())),
+        None => ("", hier_part),
+    };
+
+    let path_start = rest_start + hier_part.len() - path.len();
+    let query_start = rest_start + hier_part.len() + 1;
+    let fragment_start = rest_start + rest.len() + 1;
+    let components: [(usize, &str, ComponentValidator); 4] = [
+        (rest_start + 2, authority, validate::authority::<UriSpec>),
+        (path_start, path, validate::path::<UriSpec>),
+        (query_start, query, validate::query::<UriSpec>),
+        (fragment_start, fragment, validate::fragment::<UriSpec>),
+    ];
+
+    let position = components
+        .iter()
+        .find(|(_, component, validate)| validate(component).is_err())
+        .map_or(rest_start, |&(start, component, validate)| {
+            start + find_invalid_char(component, validate)
+        });
+    Some(position)
+}
+
+macro_rules! format_validator {
+    ($(#[$meta:meta])* $name:ident => $format:ident, $find_invalid:expr) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+        pub struct $name;
+
+        impl crate::Validator for $name {
+            type Error = ConstraintError;
+
+            #[inline]
+            fn validate(raw: &str) -> Result<(), Self::Error> {
+                let find_invalid: fn(&str) -> Option<usize> = $find_invalid;
+                match find_invalid(raw) {
+                    None => Ok(()),
+                    Some(position) => Err(ConstraintError::new(
+                        raw,
+                        Constraint::Format(Format::$format),
+                        position,
+                    )),
+                }
+            }
+        }
+    };
+}
+
+format_validator! {
+    /// A validator for hostnames
+    ///
+    /// Each dot-separated label must be between 1 and 63 ASCII letters,
+    /// digits, or hyphens, and must not start or end with a hyphen. The
+    /// hostname may be at most 253 bytes long, and may end with a dot.
+    ///
+    /// Used by braids declared with `format = "hostname"`.
+    HostnameFormat => Hostname, find_invalid_hostname
+}
+
+format_validator! {
+    /// A validator for email addresses
+    ///
+    /// The local part must be a dot-separated sequence of the characters
+    /// permitted by RFC 5322, at most 64 bytes long, and the domain must be a
+    /// valid hostname without a trailing dot. Quoted local parts and address
+    /// literals are not accepted.
+    ///
+    /// Used by braids declared with `format = "email"`.
+    EmailFormat => Email, find_invalid_email
+}
+
+#[cfg(feature = "uri")]
+format_validator! {
+    /// A validator for URIs
+    ///
+    /// The value must be an absolute URI, with a scheme, as parsed by the
+    /// `iri-string` crate.
+    ///
+    /// Used by braids declared with `format = "uri"`.
+    UriFormat => Uri, find_invalid_uri
+}
+
+#[cfg(feature = "uuid")]
+mod uuid_format {
+    use alloc::borrow::{Cow, ToOwned};
+
+    use uuid::Uuid;
+
+    use super::Format;
+    use crate::{Constraint, ConstraintError};
+
+    /// A normalizer that converts UUIDs into their canonical text form
+    ///
+    /// Any form accepted by [`Uuid::try_parse()`] is normalized into the
+    /// hyphenated, lowercase form, such as
+    /// `67e55044-10b1-426f-9247-bb680e5fe0c8`.
+    ///
+    /// Used by braids declared with `format = "uuid"`.
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    pub struct UuidFormat;
+
+    const CONSTRAINT: Constraint = Constraint::Format(Format::Uuid);
+
+    impl UuidFormat {
+        fn canonicalize(raw: &str) -> Result<Cow<'_, str>, ConstraintError> {
+            let uuid =
+                Uuid::try_parse(raw).map_err(|_| ConstraintError::new(raw, CONSTRAINT, 0))?;
+            let mut buffer = Uuid::encode_buffer();
+            let canonical = uuid.hyphenated().encode_lower(&mut buffer);
+
+            if canonical == raw {
+                Ok(Cow::Borrowed(raw))
+            } else {
+                Ok(Cow::Owned((*canonical).to_owned()))
+            }
+        }
+    }
+
+    impl crate::Validator for UuidFormat {
+        type Error = ConstraintError;
+
+        #[inline]
+        fn validate(raw: &str) -> Result<(), Self::Error> {
+            match Self::canonicalize(raw)? {
+                Cow::Borrowed(_) => Ok(()),
+                Cow::Owned(canonical) => {
+                    let position = raw
+                        .bytes()
+                        .zip(canonical.bytes())
+                        .position(|(a, b)| a != b)
+                        .unwrap_or_else(|| raw.len().min(canonical.len()));
+                    Err(ConstraintError::new(raw, CONSTRAINT, position))
+                }
+            }
+        }
+    }
+
+    impl crate::Normalizer for UuidFormat {
+        #[inline]
+        fn normalize(raw: &str) -> Result<Cow<'_, str>, Self::Error> {
+            Self::canonicalize(raw)
+        }
+    }
+}
+
+#[cfg(feature = "uuid")]
+pub use uuid_format::UuidFormat;
+
+#[cfg(feature = "idna")]
+mod idna_format {
+    use alloc::borrow::Cow;
+
+    use idna::uts46::{AsciiDenyList, DnsLength, Hyphens, Uts46};
+
+    use super::Format;
+    use crate::{Constraint, ConstraintError};
+
+    /// A normalizer that converts domain names into their IDNA ASCII form
+    ///
+    /// Domain names are mapped with UTS #46, which lowercases them, and
+    /// labels with non-ASCII characters are encoded with Punycode, so that
+    /// `Bücher.Example` is normalized into `xn--bcher-kva.example`. The STD3
+    /// rules are applied, and the length of the name and each label is
+    /// checked, allowing a trailing dot.
+    ///
+    /// Used by braids declared with `format = "idna"`.
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    pub struct IdnaFormat;
+
+    const CONSTRAINT: Constraint = Constraint::Format(Format::Idna);
+
+    impl IdnaFormat {
+        fn to_ascii(raw: &str) -> Result<Cow<'_, str>, ConstraintError> {
+            Uts46::new()
+                .to_ascii(
+                    raw.as_bytes(),
+                    AsciiDenyList::STD3,
+                    Hyphens::Check,
+                    DnsLength::VerifyAllowRootDot,
+                )
+                .map_err(|_| ConstraintError::new(raw, CONSTRAINT, 0))
+        }
+    }
+
+    impl crate::Validator for IdnaFormat {
+        type Error = ConstraintError;
+
+        #[inline]
+        fn validate(raw: &str) -> Result<(), Self::Error> {
+            match Self::to_ascii(raw)? {
+                Cow::Borrowed(_) => Ok(()),
+                Cow::Owned(ascii) => {
+                    let position = raw
+                        .bytes()
+                        .zip(ascii.bytes())
+                        .position(|(a, b)| a != b)
+                        .unwrap_or_else(|| raw.len().min(ascii.len()));
+                    Err(ConstraintError::new(raw, CONSTRAINT, position))
+                }
+            }
+        }
+    }
+
+    impl crate::Normalizer for IdnaFormat {
+        #[inline]
+        fn normalize(raw: &str) -> Result<Cow<'_, str>, Self::Error> {
+            Self::to_ascii(raw)
+        }
+    }
+}
+
+#[cfg(feature = "idna")]
+pub use idna_format::IdnaFormat;
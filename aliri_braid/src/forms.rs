@@ -0,0 +1,9 @@
+/// Compares two forms of a braid, such as the owned type and a `Box` of the borrowed type,
+/// through the borrowed type
+///
+/// Each form dereferences to the borrowed type, so the comparisons generated between every pair
+/// of forms share its equality, whether exact or case-insensitive, rather than repeating it.
+#[inline]
+pub fn eq<R: ?Sized + PartialEq>(lhs: &R, rhs: &R) -> bool {
+    lhs == rhs
+}
@@ -0,0 +1,80 @@
+use core::fmt;
+
+/// A validator for braids that wrap a borrowed type other than `str`
+///
+/// Braids generated with the `ref_inner = "Type"` parameter wrap `Type` in
+/// their borrowed form, rather than `str`, and are checked by this trait in
+/// place of [`Validator`][crate::Validator]. The borrowed braid is
+/// `#[repr(transparent)]` around the inner type, so a reference to a value
+/// that has passed validation is reinterpreted in place.
+pub trait InnerValidator {
+    /// The borrowed type that is wrapped by the braid
+    type Inner: ?Sized;
+
+    /// The error produced when the value is invalid
+    type Error;
+
+    /// Validates a value according to a predetermined set of rules
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is invalid.
+    fn validate(inner: &Self::Inner) -> Result<(), Self::Error>;
+}
+
+/// An error produced by the validators synthesized for path braids
+///
+/// Braids generated with the `path` parameter and either the `relative` or
+/// `absolute` parameter reject values with this error.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PathError {
+    /// The path must be relative, but was absolute
+    NotRelative,
+    /// The path must be absolute, but was relative
+    NotAbsolute,
+}
+
+impl fmt::Display for PathError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NotRelative => f.write_str("path must be relative"),
+            Self::NotAbsolute => f.write_str("path must be absolute"),
+        }
+    }
+}
+
+impl core::error::Error for PathError {}
+
+/// An error produced when a C string braid cannot be created from a `str`
+///
+/// Returned by the `FromStr` and `TryFrom<&str>` implementations generated for
+/// braids with the `c_str` parameter.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CStringError<E> {
+    /// The string contains an interior nul byte
+    Nul(alloc::ffi::NulError),
+    /// The string is a valid C string, but was rejected by the braid's validator
+    Invalid(E),
+}
+
+#[cfg(feature = "alloc")]
+impl<E: fmt::Display> fmt::Display for CStringError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Nul(err) => fmt::Display::fmt(err, f),
+            Self::Invalid(err) => fmt::Display::fmt(err, f),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E: core::error::Error + 'static> core::error::Error for CStringError<E> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Nul(err) => Some(err),
+            Self::Invalid(err) => Some(err),
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use alloc::{collections::BTreeSet, sync::Arc};
+use core::{borrow::Borrow, cmp::Ordering, fmt};
+
+use crate::BraidRef;
+
+/// A borrowed braid that can be checked and then interned
+///
+/// Implemented for braids generated with the `intern` parameter. Values already
+/// present in the interner are returned without being checked again, as only
+/// checked values are ever interned.
+pub trait Intern: BraidRef {
+    /// The error produced when the value is invalid
+    type Error;
+
+    /// Checks a string slice as a strongly-typed value, returning the shared copy
+    /// held by the interner
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is not already interned and fails the braid's
+    /// checks.
+    fn intern_in(interner: &mut Interner<Self>, raw: &str) -> Result<Arc<Self>, Self::Error>;
+}
+
+/// A pool of borrowed braids in which equal values share a single allocation
+///
+/// Large documents often repeat the same identifiers many times. Interning each
+/// value as it is parsed produces an [`Arc`] that shares storage with every other
+/// copy of that value. With the `serde` feature enabled, `&mut Interner<T>`
+/// implements `DeserializeSeed`, deserializing a string directly into the pool.
+///
+/// Values are compared by their exact string contents, regardless of how the
+/// braid itself compares values.
+pub struct Interner<T: ?Sized> {
+    values: BTreeSet<Entry<T>>,
+}
+
+impl<T: ?Sized> Interner<T> {
+    /// Creates an empty interner
+    pub fn new() -> Self {
+        Self {
+            values: BTreeSet::new(),
+        }
+    }
+
+    /// The number of distinct values interned
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Whether no values have been interned
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Removes all values from the interner
+    ///
+    /// Values already handed out remain valid, but are no longer shared with
+    /// values interned afterwards.
+    pub fn clear(&mut self) {
+        self.values.clear();
+    }
+}
+
+impl<T: BraidRef + ?Sized> Interner<T> {
+    /// Returns the shared copy of a value, if it has been interned
+    pub fn get(&self, raw: &str) -> Option<Arc<T>> {
+        self.values.get(raw).map(|entry| Arc::clone(&entry.0))
+    }
+
+    /// Interns a value that has already been checked, returning the shared copy
+    pub fn insert(&mut self, value: &T) -> Arc<T>
+    where
+        for<'a> Arc<T>: From<&'a T>,
+    {
+        if let Some(interned) = self.get(value.as_str()) {
+            return interned;
+        }
+
+        let interned = Arc::from(value);
+        self.values.insert(Entry(Arc::clone(&interned)));
+        interned
+    }
+
+    /// Checks a string slice as a strongly-typed value, returning the shared copy
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is not already interned and fails the braid's
+    /// checks.
+    pub fn intern(&mut self, raw: &str) -> Result<Arc<T>, T::Error>
+    where
+        T: Intern,
+    {
+        T::intern_in(self, raw)
+    }
+}
+
+impl<T: ?Sized> Default for Interner<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: BraidRef + ?Sized> fmt::Debug for Interner<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set()
+            .entries(self.values.iter().map(|entry| entry.0.as_str()))
+            .finish()
+    }
+}
+
+/// An interned value, ordered by its string contents so that it can be found by
+/// a string slice
+struct Entry<T: ?Sized>(Arc<T>);
+
+impl<T: BraidRef + ?Sized> Borrow<str> for Entry<T> {
+    fn borrow(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl<T: BraidRef + ?Sized> PartialEq for Entry<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_str() == other.0.as_str()
+    }
+}
+
+impl<T: BraidRef + ?Sized> Eq for Entry<T> {}
+
+impl<T: BraidRef + ?Sized> PartialOrd for Entry<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: BraidRef + ?Sized> Ord for Entry<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.as_str().cmp(other.0.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> serde::de::DeserializeSeed<'de> for &mut Interner<T>
+where
+    T: Intern + ?Sized,
+    T::Error: fmt::Display,
+{
+    type Value = Arc<T>;
+
+    fn deserialize<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<Arc<T>, D::Error> {
+        deserializer.deserialize_str(InternVisitor(self))
+    }
+}
+
+#[cfg(feature = "serde")]
+struct InternVisitor<'i, T: ?Sized>(&'i mut Interner<T>);
+
+#[cfg(feature = "serde")]
+impl<'de, T> serde::de::Visitor<'de> for InternVisitor<'_, T>
+where
+    T: Intern + ?Sized,
+    T::Error: fmt::Display,
+{
+    type Value = Arc<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a string")
+    }
+
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        T::intern_in(self.0, v).map_err(E::custom)
+    }
+
+    fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        match core::str::from_utf8(v) {
+            Ok(s) => self.visit_str(s),
+            Err(_) => Err(E::invalid_value(serde::de::Unexpected::Bytes(v), &self)),
+        }
+    }
+}
@@ -68,6 +68,71 @@
 //! take_raw_string(owned.take());
 //! ```
 //!
+//! With the `iter` parameter, braids that are neither validated nor normalized can also be
+//! collected from an iterator of `char`s or string slices, and extended with further string
+//! slices.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! #
+//! # #[braid(iter)]
+//! # pub struct DatabaseName;
+//! #
+//! let mut owned: DatabaseName = ["mongo", "-", "prod"].iter().copied().collect();
+//! owned.extend(["-", "east"]);
+//! assert_eq!("mongo-prod-east", owned.as_str());
+//! ```
+//!
+//! With the `add` parameter, they can be concatenated with the borrowed form using `+` and `+=`.
+//! The `add_str` parameter allows concatenating plain string slices as well. Validated and
+//! normalized braids given `add` instead provide a fallible `try_concat()` method, which checks
+//! the concatenated value.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! #
+//! #[braid(add_str)]
+//! pub struct Urn;
+//!
+//! let mut urn = Urn::from_static("urn:isbn") + UrnRef::from_static(":");
+//! urn += "0451450523";
+//! assert_eq!("urn:isbn:0451450523", urn.as_str());
+//! ```
+//!
+//! Values assembled from several pieces can also be written directly with `build()`, generated
+//! by the `build` parameter, which passes a fresh `String` to the given function. As `String`
+//! implements [`fmt::Write`], the `write!` macro can be used to format into it. Validated and
+//! normalized braids check the value once, after the function returns, and so return a `Result`.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! use std::fmt::Write;
+//!
+//! #[braid(build)]
+//! pub struct ResourceName;
+//!
+//! let name = ResourceName::build(|s| write!(s, "projects/{}/zones/{}", 42, "east").unwrap());
+//! assert_eq!("projects/42/zones/east", name.as_str());
+//! ```
+//!
+//! [`fmt::Write`]: core::fmt::Write
+//!
+//! Such braids can also be given a mutation API with the `mutable` parameter, which generates
+//! `push_str()`, `push()`, `truncate()`, `clear()`, and `reserve()` on the owned form. Each
+//! delegates to the method of the same name on the wrapped field.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! #
+//! #[braid(mutable)]
+//! pub struct ResourcePath;
+//!
+//! let mut path = ResourcePath::from_static("/users");
+//! path.push('/');
+//! path.push_str("alice");
+//! assert_eq!("/users/alice", path.as_str());
+//! ```
+//!
 //! By default, the name of the borrowed form will be the same as the owned form
 //! with `Ref` appended to the end.
 //!
@@ -144,10 +209,38 @@
 //! # assert_eq!(owned, borrowed);
 //! ```
 //!
+//! The doc comment of the owned form can be overridden in the same way with `owned_doc`.
+//! Within either override, `{owned}` and `{ref}` are replaced with the names of the owned
+//! and borrowed forms, so that each can link to its counterpart.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! #
+//! #[braid(
+//!     owned_doc = "An owned database name, borrowed as [`{ref}`]",
+//!     ref_doc = "A borrowed database name, owned as [`{owned}`]"
+//! )]
+//! pub struct DatabaseName;
+//! ```
+//!
+//! When a value is known by another name, such as the name of a field on the wire, the `alias`
+//! parameter places a `#[doc(alias = "...")]` attribute on both forms so that searching the
+//! documentation for that name finds them. It may be given more than once.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! #
+//! #[braid(alias = "user_id", alias = "uid")]
+//! pub struct UserId;
+//! ```
+//!
 //! Attributes added to the braid will be applied to both the owned and borrowed forms
 //! with the exception of `///` and `#[doc = ""]` attributes. To add an attribute to
 //! only the owned form, use the `owned_attr` parameter. Similarly, use `ref_attr` to
-//! add an attribute to only the borrowed form.
+//! add an attribute to only the borrowed form. The contents of each are forwarded verbatim
+//! as an attribute, so conditional attributes like `cfg_attr(...)` can be used as well.
+//! Attributes on the wrapped field are likewise applied to the fields of both forms, and
+//! `owned_field_attr` and `ref_field_attr` add an attribute to the field of only one form.
 //!
 //! ```
 //! use aliri_braid::braid;
@@ -155,11 +248,33 @@
 //! #[braid(
 //!    owned_attr(must_use = "database name should always be used"),
 //!    ref_attr(must_use = "created a reference, but never used it"),
+//!    ref_attr(cfg_attr(feature = "nightly", doc = "Only documented on nightly")),
 //! )]
 //! #[cfg(not(feature = "nightly"))]
 //! pub struct DatabaseName;
 //! ```
 //!
+//! Services that define many simple identifiers can declare them together with `braid_many!`,
+//! which takes the parameters shared by every braid, followed by a semicolon and the braids to
+//! declare. Each braid may have its own doc comment, attributes, and visibility, and expands to
+//! the same code as a unit struct given `#[braid(...)]` with the shared parameters.
+//!
+//! ```
+//! use aliri_braid::braid_many;
+//!
+//! braid_many! {
+//!     serde, non_empty;
+//!     /// Identifies a user
+//!     pub UserId,
+//!     pub OrderId,
+//!     pub(crate) TenantId,
+//! }
+//!
+//! let user = UserId::from_static("alice");
+//! assert_eq!("alice", user.as_str());
+//! assert!(OrderIdRef::from_str("").is_err());
+//! ```
+//!
 //! # Extensibility
 //!
 //! The types created by the `braid` macro are placed in the same module where declared.
@@ -195,6 +310,156 @@
 //! }
 //! ```
 //!
+//! ## Renaming the constructors
+//!
+//! The checked constructors are named `new()` on the owned type and `from_str()` on the
+//! borrowed type. Where a fallible `new()` or an inherent `from_str()` that sits alongside
+//! `FromStr::from_str()` reads poorly, both can be renamed with `constructor = "name"`. The
+//! generated trait implementations call the renamed constructors.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! #
+//! #[braid(constructor = "parse", max_len = 8)]
+//! pub struct Hostname;
+//!
+//! let owned = Hostname::parse("example".to_owned()).unwrap();
+//! let borrowed = HostnameRef::parse("example").unwrap();
+//! assert_eq!(owned, borrowed);
+//! assert!(HostnameRef::parse("much too long").is_err());
+//! ```
+//!
+//! Alternatively, `naming = "v2"` opts into names that say what each method does. `take()`
+//! becomes `into_inner()`, and `from_str()` becomes `try_from_str()` if it can fail and
+//! `parse_ref()` if it cannot. The former names remain as deprecated aliases, so existing
+//! callers keep compiling while they migrate.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! #
+//! #[braid(naming = "v2")]
+//! pub struct Hostname;
+//!
+//! let borrowed = HostnameRef::parse_ref("example");
+//! let owned = borrowed.to_owned();
+//! assert_eq!("example", owned.into_inner());
+//! ```
+//!
+//! ## Conversions between braids
+//!
+//! Related braids often share a representation, such as a `Username` that can be promoted into
+//! an `AuditSubject`. The `convert_from = "OtherBraid"` parameter, which may be repeated,
+//! generates conversions from another braid and from references to its borrowed form. Owned
+//! values are converted by reusing their backing string, so no copy is made when both braids
+//! wrap a `String`. If the target braid has a validator or normalizer, `TryFrom` is implemented
+//! instead of `From`, as the source braid's invariants may not imply those of the target.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! # use std::convert::TryFrom;
+//! #
+//! #[braid]
+//! pub struct Username;
+//!
+//! #[braid(convert_from = "Username")]
+//! pub struct AuditSubject;
+//!
+//! #[braid(max_len = 8, convert_from = "Username")]
+//! pub struct ShortName;
+//!
+//! let subject = AuditSubject::from(Username::from_static("nobody"));
+//! assert_eq!("nobody", subject.as_str());
+//!
+//! assert!(ShortName::try_from(UsernameRef::from_static("nobody")).is_ok());
+//! assert!(ShortName::try_from(UsernameRef::from_static("much too long")).is_err());
+//! ```
+//!
+//! When two braids are known to have the same invariants, such as a `TenantId` and an `OrgId`
+//! while one domain is being split from another, `same_repr_as = "OtherBraid"` generates
+//! infallible conversions that skip revalidation, including zero-copy conversions between
+//! references to the borrowed forms. Both braids must declare `same_repr_as` naming the other.
+//! The braids are checked at compile time to wrap the same type, to be checked by the same
+//! validator or normalizer, and to declare the same constraints.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! #
+//! #[braid(max_len = 16, same_repr_as = "OrgId")]
+//! pub struct TenantId;
+//!
+//! #[braid(max_len = 16, same_repr_as = "TenantId")]
+//! pub struct OrgId;
+//!
+//! let tenant = TenantId::new("acme".to_owned()).unwrap();
+//! let org_ref = <&OrgIdRef>::from(&*tenant);
+//! assert_eq!("acme", org_ref.as_str());
+//!
+//! let org = OrgId::from(tenant);
+//! assert_eq!("acme", org.as_str());
+//! ```
+//!
+//! ## Writing code generic over braids
+//!
+//! Every braid implements the [`Braid`] trait for its owned type and the [`BraidRef`] trait for
+//! its borrowed type, so helpers can accept any braid without being written once per type. Both
+//! traits are sealed and are only implemented by the macros. The standard library traits, such
+//! as `PartialEq` and `From`, are still implemented on each braid directly, as the coherence
+//! rules do not allow a crate to implement foreign traits for every braid at once.
+//!
+//! ```
+//! use aliri_braid::{braid, Braid, BraidRef};
+//!
+//! #[braid]
+//! pub struct Username;
+//!
+//! fn shout<B: Braid>(value: &B) -> String {
+//!     value.as_braid_ref().as_str().to_uppercase()
+//! }
+//!
+//! assert_eq!("NOBODY", shout(&Username::from_static("nobody")));
+//! ```
+//!
+//! ## Parsing into structured types
+//!
+//! When a braid has a richer, structured interpretation, the `parse_as` parameter records that
+//! relationship alongside the braid. Each adapter generates an `as_*` method on the borrowed type
+//! along with a `TryFrom` conversion, both of which parse the value on demand using the target
+//! type's [`FromStr`][core::str::FromStr] implementation.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! # use std::convert::TryFrom;
+//! #
+//! #[braid(parse_as(IpAddr = "std::net::IpAddr"))]
+//! pub struct Host;
+//!
+//! let host = Host::from_static("127.0.0.1");
+//! assert!(host.as_ip_addr().unwrap().is_loopback());
+//! assert!(std::net::IpAddr::try_from(&*Host::from_static("localhost")).is_err());
+//! ```
+//!
+//! ## Delimited segments
+//!
+//! Identifiers such as ARNs and URNs are made up of segments separated by a delimiter. The
+//! `segments` parameter generates a `segments()` iterator and an `nth_segment(n)` accessor on the
+//! borrowed type that split the value on the given character. Naming another braid with
+//! `segment` produces each segment as a reference to that braid instead. As each segment is
+//! checked as it is produced, the items are the result of the `TryFrom` conversion.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! #
+//! #[braid(min_len = 1)]
+//! pub struct ArnPart;
+//!
+//! #[braid(segments = ':', segment = "ArnPartRef")]
+//! pub struct Arn;
+//!
+//! let arn = Arn::from_static("arn:aws:s3:::bucket");
+//! assert_eq!("s3", arn.nth_segment(2).unwrap().unwrap().as_str());
+//! assert!(arn.nth_segment(3).unwrap().is_err());
+//! ```
+//!
 //! # Encapsulation
 //!
 //! Because code within the same module where the braid is defined are allowed to
@@ -253,6 +518,19 @@
 //! println!("{}", ex_ref);
 //! ```
 //!
+//! Conversions between the owned and borrowed types and the types they wrap rely on both being
+//! `#[repr(transparent)]` over their field. Braids that forward attributes to the generated types
+//! with `ref_attr`, `owned_attr`, or field attributes can opt into compile-time checks of that
+//! layout with the `assert_layout` parameter, which fails to compile if `&Ref` differs in size or
+//! alignment from `&str`, or if the owned type differs from the type it wraps.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! #
+//! #[braid(assert_layout, ref_attr(must_use))]
+//! pub struct DatabaseName;
+//! ```
+//!
 //! # Validation
 //!
 //! Types can be configured to only contain certain values. This can be used to strongly
@@ -279,7 +557,10 @@
 //! As a convenience, `from_static` functions are provided that accept `&'static str`. For fallible
 //! braids and the owned form of normalized braids, this function will panic if the value is not
 //! valid. For borrowed form of normalized braids, the function will panic if the value is not
-//! normalized.
+//! normalized. Where a panic is undesirable, such as when the static value comes from generated
+//! code or configuration embedded at build time, the corresponding `try_from_static` functions
+//! return the error instead of panicking. For braids without a validator, `from_static` can
+//! never fail and is a `const fn`, so no `try_from_static` is generated.
 //!
 //! ```
 //! # use aliri_braid::braid;
@@ -320,6 +601,9 @@
 //! assert!(NonRootUsernameRef::from_str("nobody").is_ok());
 //!
 //! NonRootUsernameRef::from_static("nobody");
+//!
+//! assert_eq!(Err(InvalidUsername), NonRootUsername::try_from_static("root"));
+//! assert!(NonRootUsernameRef::try_from_static("nobody").is_ok());
 //! ```
 //!
 //! Foreign validators can also be used by specifying the name of the type that
@@ -369,6 +653,23 @@
 //! NonRootUsernameRef::from_static("nobody");
 //! ```
 //!
+//! Whole batches of raw strings, such as the rows of an ingested file, can be converted at once
+//! with `try_from_iter()`. The first invalid string stops the conversion, and its index is
+//! returned alongside the error.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! #
+//! #[braid(min_len = 1)]
+//! pub struct Department;
+//!
+//! let rows = vec!["sales".to_owned(), "support".to_owned()];
+//! assert_eq!(2, Department::try_from_iter(rows).unwrap().len());
+//!
+//! let rows = vec!["sales".to_owned(), String::new(), "hr".to_owned()];
+//! assert_eq!(1, Department::try_from_iter(rows).unwrap_err().0);
+//! ```
+//!
 //! Note: `Validator::Error` is expected to implement `From<Infallible>`. If
 //! you haven't implemented this trait, you'll receive an error of the
 //! following form:
@@ -399,6 +700,127 @@
 //! }
 //! ```
 //!
+//! ## Declarative constraints
+//!
+//! Simple constraints can be declared directly as parameters to the macro, in which case
+//! a [`Validator`] is synthesized for the braid. Values that fail a constraint produce a
+//! [`ConstraintError`], which describes the [`Constraint`] that was violated, the byte
+//! position at which checking failed, and a (possibly truncated) copy of the offending
+//! value.
+//!
+//! The following constraints are available:
+//!
+//! * `non_empty`: the value must not be empty
+//! * `charset = "..."`: the value must only contain characters from the named set, one of `ascii`,
+//!   `ascii_alphanumeric`, `no_control`, or `printable` (see [`Charset`])
+//! * `min_len = N` or `min_bytes = N`: the value must be at least `N` bytes long
+//! * `max_len = N` or `max_bytes = N`: the value must be at most `N` bytes long
+//! * `min_chars = N`: the value must be at least `N` `char`s long
+//! * `max_chars = N`: the value must be at most `N` `char`s long
+//! * `prefix = "..."`: the value must start with the given prefix
+//! * `suffix = "..."`: the value must end with the given suffix
+//! * `pattern = "..."`: the entire value must match the given regular expression, which requires
+//!   the `regex` feature of this crate
+//!
+//! Byte lengths suit limits imposed by storage, such as database columns, while `char` lengths
+//! are closer to what a user would count. The two can be combined on the same braid.
+//!
+//! When a value does not match a `pattern`, the reported position is the end of the longest
+//! prefix of the value that could still be extended into a match.
+//!
+//! Braids with a declared `prefix` or `suffix` also get `without_prefix()` and
+//! `without_suffix()` accessors on the borrowed form, which return the remainder of the
+//! value. If the remainder is itself a braid, naming its borrowed form with the `rest`
+//! parameter makes these accessors return the typed reference instead, validated through
+//! its `TryFrom<&str>` implementation.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! #
+//! #[braid]
+//! pub struct AccountId;
+//!
+//! #[braid(prefix = "acct:", rest = "AccountIdRef")]
+//! pub struct AccountUri;
+//!
+//! let uri = AccountUri::from_static("acct:12345");
+//! let account: &AccountIdRef = uri.without_prefix().unwrap();
+//! assert_eq!("12345", account.as_str());
+//! assert!(AccountUriRef::from_str("user:12345").is_err());
+//! ```
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! #
+//! #[braid(min_len = 3, max_len = 16)]
+//! pub struct Username;
+//!
+//! assert!(Username::new("nobody".to_string()).is_ok());
+//!
+//! let err = UsernameRef::from_str("me").unwrap_err();
+//! assert_eq!(aliri_braid::Constraint::MinLength(3), err.constraint());
+//! assert_eq!("me", err.value());
+//! assert_eq!(2, err.position());
+//! ```
+//!
+//! Declarative constraints can be combined with a named validator or normalizer, in which
+//! case the constraints are checked first. The error type of that validator must implement
+//! `From<ConstraintError>`. When combined with a normalizer, the constraints are also
+//! checked against the normalized value.
+//!
+//! ### Checked literals
+//!
+//! The `from_static()` constructors of a constrained braid check their value at runtime and
+//! panic if it is invalid. For braids whose only checks are declarative constraints, the
+//! [`braid_lit!`] macro instead checks a string literal during constant evaluation, so an
+//! invalid literal is a compile error rather than a runtime panic. It works with either the
+//! owned or the borrowed type.
+//!
+//! ```
+//! # use aliri_braid::{braid, braid_lit};
+//! #
+//! #[braid(min_len = 3, max_len = 16)]
+//! pub struct Username;
+//!
+//! let owned: Username = braid_lit!(Username, "nobody");
+//! let borrowed: &UsernameRef = braid_lit!(UsernameRef, "nobody");
+//! assert_eq!(owned, borrowed);
+//! ```
+//!
+//! No `From<&'static str>` implementation is provided for this purpose, as a trait
+//! implementation cannot restrict its argument to literals that can be checked at compile
+//! time. Braids with a named validator or normalizer, or with a `pattern`, do not support
+//! `braid_lit!`, as their checks cannot be run during constant evaluation.
+//!
+//! ## Custom error types
+//!
+//! By default, fallible constructors and conversions return the error type of the validator.
+//! To expose a single error type across many braids, the `error` parameter names an error
+//! type to be returned instead. The validator's error is converted into that type using its
+//! [`From`][core::convert::From] implementation.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! #
+//! #[derive(Debug)]
+//! pub enum DomainError {
+//!     InvalidName(aliri_braid::ConstraintError),
+//! }
+//!
+//! impl From<aliri_braid::ConstraintError> for DomainError {
+//!     fn from(err: aliri_braid::ConstraintError) -> Self {
+//!         Self::InvalidName(err)
+//!     }
+//! }
+//! # aliri_braid::from_infallible!(DomainError);
+//!
+//! #[braid(max_len = 8, error = "DomainError")]
+//! pub struct ShortName;
+//!
+//! let result: Result<ShortName, DomainError> = "a very long name".parse();
+//! assert!(matches!(result, Err(DomainError::InvalidName(_))));
+//! ```
+//!
 //! ## Normalization
 //!
 //! Braided strings can also have enforced normalization, which is carried out at the creation
@@ -449,7 +871,7 @@
 //! }
 //!
 //! impl aliri_braid::Normalizer for HeaderName {
-//!     fn normalize(s: &str) -> Result<Cow<str>, Self::Error> {
+//!     fn normalize(s: &str) -> Result<Cow<'_, str>, Self::Error> {
 //!         if s.is_empty() || !s.is_ascii() {
 //!             Err(InvalidHeaderName)
 //!         } else if s.as_bytes().iter().any(|&b| b'A' <= b && b <= b'Z') {
@@ -478,6 +900,151 @@
 //! assert_eq!("lowercase", HeaderNameRef::from_static("lowercase").as_str());
 //! ```
 //!
+//! Normalized braids also get helpers for values that may have arrived through an unchecked path.
+//! `Borrowed::is_normalized()` checks whether a string slice is already in normalized form
+//! without constructing a value, `Borrowed::normalized()` returns a
+//! [`Cow<Borrowed>`][alloc::borrow::Cow] that only allocates if a change is needed, and
+//! `Owned::normalize()` consumes and repairs an owned value. Values that cannot be normalized
+//! are returned unchanged by the latter two.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! # use std::borrow::Cow;
+//! #
+//! # #[derive(Debug, PartialEq, Eq)]
+//! # pub struct InvalidHeaderName;
+//! # impl std::fmt::Display for InvalidHeaderName {
+//! #     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+//! #         f.write_str("invalid header name")
+//! #     }
+//! # }
+//! # aliri_braid::from_infallible!(InvalidHeaderName);
+//! # impl std::error::Error for InvalidHeaderName {}
+//! #
+//! # #[braid(normalizer)]
+//! # pub struct HeaderName;
+//! #
+//! # impl aliri_braid::Validator for HeaderName {
+//! #     type Error = InvalidHeaderName;
+//! #     fn validate(s: &str) -> Result<(), Self::Error> {
+//! #         if s.is_empty() || !s.is_ascii() || s.as_bytes().iter().any(|&b| b'A' <= b && b <= b'Z') {
+//! #             Err(InvalidHeaderName)
+//! #         } else {
+//! #             Ok(())
+//! #         }
+//! #     }
+//! # }
+//! #
+//! # impl aliri_braid::Normalizer for HeaderName {
+//! #     fn normalize(s: &str) -> Result<Cow<'_, str>, Self::Error> {
+//! #         if s.is_empty() || !s.is_ascii() {
+//! #             Err(InvalidHeaderName)
+//! #         } else if s.as_bytes().iter().any(|&b| b'A' <= b && b <= b'Z') {
+//! #             Ok(Cow::Owned(s.to_ascii_lowercase()))
+//! #         } else {
+//! #             Ok(Cow::Borrowed(s))
+//! #         }
+//! #     }
+//! # }
+//! #
+//! assert!(HeaderNameRef::is_normalized("content-type"));
+//! assert!(!HeaderNameRef::is_normalized("Content-Type"));
+//!
+//! let header = HeaderName::from_static("content-type");
+//! assert!(matches!(header.normalized(), Cow::Borrowed(_)));
+//! assert_eq!("content-type", header.normalize().as_str());
+//! ```
+//!
+//! ### Case conventions
+//!
+//! Braids for configuration keys or similar identifiers often only need to normalize values
+//! into a particular case convention. The `convert_case` parameter synthesizes such a normalizer,
+//! accepting `snake`, `kebab`, or `camel`. Words are split on `_`, `-`, whitespace, and changes
+//! in case, and a run of capitals is kept together as an acronym. Values that are not already
+//! normalized are rejected by `from_normalized_str()` with a [`ConstraintError`], and
+//! `convert_case` can be combined with other declarative constraints, which are checked after
+//! normalization.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! #
+//! #[braid(convert_case = "snake")]
+//! pub struct ConfigKey;
+//!
+//! assert_eq!("max_connections", ConfigKey::from_static("maxConnections").as_str());
+//! assert_eq!("http_proxy", ConfigKey::from_static("HTTP-Proxy").as_str());
+//! assert!(ConfigKeyRef::from_normalized_str("maxConnections").is_err());
+//! ```
+//!
+//! ### UUIDs
+//!
+//! With the `uuid` feature of this crate enabled, the `format = "uuid"` parameter normalizes
+//! values into canonical UUID text using `UuidFormat`. Any form that `uuid::Uuid` can parse
+//! is accepted, and stored in its hyphenated, lowercase form. The owned type can be created
+//! from a `uuid::Uuid`, and references to either type can be converted back with `TryFrom`.
+//!
+//! ```
+//! # #[cfg(feature = "uuid")]
+//! # {
+//! # use aliri_braid::braid;
+//! use std::convert::TryFrom;
+//! use uuid::Uuid;
+//!
+//! #[braid(format = "uuid")]
+//! pub struct AccountId;
+//!
+//! let id = AccountId::from_static("67E55044-10B1-426F-9247-BB680E5FE0C8");
+//! assert_eq!("67e55044-10b1-426f-9247-bb680e5fe0c8", id.as_str());
+//!
+//! let uuid = Uuid::try_from(&id).unwrap();
+//! assert_eq!(id, AccountId::from(uuid));
+//! # }
+//! ```
+//!
+//! ### Well-known formats
+//!
+//! The `format` parameter also accepts `hostname`, `email`, and `uri`, which synthesize a
+//! validator for the given format, as implemented by [`HostnameFormat`], [`EmailFormat`], and
+//! `UriFormat`. Invalid values are rejected with a [`ConstraintError`] describing the format
+//! and the position at which the value stopped conforming, and these formats can be combined
+//! with other declarative constraints. Checking URIs requires the `uri` feature of this crate,
+//! which uses the `iri-string` crate.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! #
+//! #[braid(format = "email", max_len = 128)]
+//! pub struct EmailAddress;
+//!
+//! assert!(EmailAddressRef::from_str("jane.doe@example.com").is_ok());
+//!
+//! let err = EmailAddressRef::from_str("jane.doe@example..com").unwrap_err();
+//! assert_eq!(
+//!     "invalid value \"jane.doe@example..com\": must be a valid email address (at byte 17)",
+//!     err.to_string(),
+//! );
+//! ```
+//!
+//! With the `idna` feature of this crate enabled, `format = "idna"` normalizes domain names
+//! with `IdnaFormat`, lowercasing them and encoding internationalized labels with Punycode.
+//! Domain names thus compare and hash consistently regardless of the form in which they were
+//! given.
+//!
+//! ```
+//! # #[cfg(feature = "idna")]
+//! # {
+//! # use aliri_braid::braid;
+//! #
+//! #[braid(format = "idna")]
+//! pub struct DomainName;
+//!
+//! let unicode = DomainName::from_static("Bücher.Example");
+//! let ascii = DomainName::from_static("xn--bcher-kva.example");
+//! assert_eq!(unicode, ascii);
+//! assert_eq!("xn--bcher-kva.example", unicode.as_str());
+//! # }
+//! ```
+//!
 //! ## Unchecked creation
 //!
 //! Where necessary for efficiency, it is possible to bypass the validations on creation through
@@ -520,8 +1087,14 @@
 //! ```
 //!
 //! If you find violations of your guarantees, you can look specifically for uses of `unsafe`.
-//!
-//! ```
+//! The unchecked constructors are `const`, so they can also be used to build values for `const`
+//! and `static` items, and they perform no checks at all. To help catch violations early, each
+//! has a non-`const` counterpart, `new_unchecked_checked()` or `from_str_unchecked_checked()`,
+//! which runs the validator in a `debug_assert!`, so invalid values will panic in debug and test
+//! builds. Owned values are checked in the same way when they are borrowed. Release builds skip
+//! these checks entirely.
+//!
+//! ```should_panic
 //! # use aliri_braid::braid;
 //! #
 //! # #[derive(Debug, PartialEq, Eq)]
@@ -549,27 +1122,329 @@
 //! #     }
 //! # }
 //! #
+//! // Panics in debug builds: "" does not conform to the validator
 //! unsafe {
-//!     NonRootUsername::new_unchecked(String::from(""));
-//!     NonRootUsernameRef::from_str_unchecked("root");
+//!     NonRootUsername::new_unchecked_checked(String::from(""));
+//!     NonRootUsernameRef::from_str_unchecked_checked("root");
 //! }
 //! ```
 //!
-//! # Provided trait impls
+//! ### Omitting or renaming unchecked constructors
 //!
-//! By default, the following traits will be automatically implemented.
+//! For security-sensitive braids, the `unchecked` parameter controls how these constructors
+//! are exposed. With `unchecked = "omit"`, they are kept private to the module declaring the
+//! braid, so code outside that module has no way to bypass validation. With
+//! `unchecked = "rename:suffix"`, they are instead named `new_suffix()` and
+//! `from_str_suffix()`, along with `new_suffix_checked()` and `from_str_suffix_checked()`,
+//! which can make their uses easier to find when auditing. Finally,
+//! `unchecked = "safe_hidden"` generates them as safe functions hidden from the documentation,
+//! relying on the `_unchecked` naming convention rather than `unsafe` to signal the contract.
 //!
-//! For the `Owned` type
-//! * [`core::clone::Clone`]
-//! * [`core::fmt::Debug`]
-//! * [`core::fmt::Display`]
-//! * [`core::hash::Hash`]
-//! * [`core::cmp::Eq`]
-//! * [`core::cmp::Ord`]
-//! * [`core::cmp::PartialEq<Owned>`]
-//! * [`core::cmp::PartialEq<Borrowed>`]
+//! ```
+//! # use aliri_braid::braid;
+//! #
+//! #[braid(max_len = 16, unchecked = "rename:trusted")]
+//! pub struct SessionToken;
+//!
+//! #[allow(unsafe_code)]
+//! let token = unsafe { SessionTokenRef::from_str_trusted("abc123") };
+//! # assert_eq!("abc123", token.as_str());
+//! ```
+//!
+//! ```compile_fail
+//! mod token {
+//!     #[aliri_braid::braid(max_len = 16, unchecked = "omit")]
+//!     pub struct SessionToken;
+//! }
+//!
+//! // The unchecked constructors are private to the `token` module
+//! let token = unsafe { token::SessionTokenRef::from_str_unchecked("abc123") };
+//! ```
+//!
+//! ### Test-only constructors
+//!
+//! Test code that builds fixtures through the unchecked constructors needs an `unsafe` block for
+//! every value. The `test_ctor` parameter instead generates a safe `test()` constructor on the
+//! owned type that skips validation and normalization. The constructor is gated behind
+//! `#[cfg(test)]` in the crate declaring the braid, so production builds contain no way around
+//! the braid's invariants. As with the unchecked constructors, values are still expected to
+//! conform, and debug assertions will catch values that do not when they are borrowed.
+//!
+//! ```ignore
+//! #[braid(max_len = 16, test_ctor)]
+//! pub struct SessionToken;
+//!
+//! #[cfg(test)]
+//! mod tests {
+//!     #[test]
+//!     fn looks_up_sessions() {
+//!         let token = super::SessionToken::test("abc123");
+//!         // ...
+//!     }
+//! }
+//! ```
+//!
+//! To audit where unchecked values come from, such as during integration testing, the
+//! `provenance` parameter records on each owned value whether it was created through a checked
+//! or an unchecked constructor. In debug builds, `provenance()` returns a [`Provenance`],
+//! which includes the location that called the unchecked constructor. Values converted from
+//! the borrowed type or derived from other owned values are recorded as checked, as the
+//! borrowed type does not carry a provenance of its own. In release builds, nothing is recorded,
+//! `provenance()` returns `None`, and the owned type is laid out as before.
+//!
+//! ```
+//! # use aliri_braid::{braid, Provenance};
+//! #
+//! #[braid(provenance, max_len = 16)]
+//! pub struct RequestId;
+//!
+//! let checked = RequestId::from_static("req-1");
+//! #[allow(unsafe_code)]
+//! let unchecked = unsafe { RequestId::new_unchecked("req-2".to_owned()) };
+//!
+//! if cfg!(debug_assertions) {
+//!     assert_eq!(Some(Provenance::Checked), checked.provenance());
+//!     assert!(matches!(unchecked.provenance(), Some(Provenance::Unchecked(_))));
+//! }
+//! ```
+//!
+//! ## Slicing borrowed values
+//!
+//! With the `slice` parameter, the borrowed type provides `slice(range)` and `split_at(mid)`,
+//! which borrow parts of a value as the same braid without going back through a string slice. Both
+//! take byte offsets and panic on the same out-of-bounds or non-`char`-boundary offsets as indexing
+//! a `str`. Validated braids check each part again and return a `Result`, while normalized braids
+//! require each part to already be in normalized form.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! #
+//! #[braid(slice, min_len = 3)]
+//! pub struct Label;
+//!
+//! let label = LabelRef::from_static("frontend");
+//! assert_eq!("front", label.slice(..5).unwrap().as_str());
+//! assert!(label.slice(..2).is_err());
+//!
+//! let (head, tail) = label.split_at(5).unwrap();
+//! assert_eq!(("front", "end"), (head.as_str(), tail.as_str()));
+//! ```
+//!
+//! ## Converting vectors
+//!
+//! With the `vec` parameter, collections of values can be converted without copying each
+//! element. Owned braids without a validator or normalizer provide `from_vec()`, which reuses
+//! the allocation of a vector of the wrapped type, and their borrowed types provide
+//! `from_str_vec()` to borrow a vector of string slices in place. Validated and normalized
+//! braids instead provide `try_from_vec()`, which checks each value and reports the index of the
+//! first one that fails. All owned braids provide `into_inner_vec()` to unwrap a vector of
+//! values.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! #
+//! #[braid(vec)]
+//! pub struct Tag;
+//!
+//! #[braid(vec, max_len = 4)]
+//! pub struct Code;
+//!
+//! let tags = Tag::from_vec(vec!["blue".to_owned(), "green".to_owned()]);
+//! assert_eq!("green", tags[1].as_str());
+//! assert_eq!(vec!["blue", "green"], Tag::into_inner_vec(tags));
+//!
+//! let tags = TagRef::from_str_vec(vec!["red"]);
+//! assert_eq!("red", tags[0].as_str());
+//!
+//! let err = Code::try_from_vec(vec!["ok".to_owned(), "too long".to_owned()]).unwrap_err();
+//! assert_eq!(1, err.0);
+//! ```
+//!
+//! With the `view_slices` parameter, unvalidated braids that wrap a `String` can also view slices
+//! in place: `from_slice()` views a `&[String]` as a slice of the owned type, `as_inner_slice()`
+//! does the reverse, and `from_str_slice()` on the borrowed type views a `&[&str]` as a slice of
+//! borrowed values.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! #
+//! #[braid(view_slices)]
+//! pub struct Tag;
+//!
+//! let raw = ["blue".to_owned(), "green".to_owned()];
+//! let tags: &[Tag] = Tag::from_slice(&raw);
+//! assert_eq!("green", tags[1].as_str());
+//!
+//! let tags: &[&TagRef] = TagRef::from_str_slice(&["red", "yellow"]);
+//! assert_eq!("yellow", tags[1].as_str());
+//! ```
+//!
+//! ## Zero-copy creation from bytes
+//!
+//! When values are read out of memory-mapped files or other byte buffers, the `from_bytes`
+//! parameter generates a `from_bytes_validated()` constructor on the borrowed type. It checks
+//! that the bytes are valid UTF-8 and that they conform to the braid's validator, or are
+//! already normalized, and then reinterprets them in place without copying. Failures are
+//! reported through a [`FromBytesError`], which distinguishes invalid UTF-8 from values rejected
+//! by the braid.
+//!
+//! Because the value is fully checked before it is reinterpreted, this constructor is safe to
+//! call on untrusted input, and the returned reference borrows from the provided buffer.
+//!
+//! ```
+//! # use aliri_braid::{braid, FromBytesError};
+//! #
+//! #[braid(from_bytes, max_len = 8)]
+//! pub struct Label;
+//!
+//! let mapped: &[u8] = b"mapped\xff";
+//!
+//! let label = LabelRef::from_bytes_validated(&mapped[..6]).unwrap();
+//! assert_eq!("mapped", label.as_str());
+//! assert_eq!(mapped.as_ptr(), label.as_str().as_ptr());
+//!
+//! assert!(matches!(LabelRef::from_bytes_validated(mapped), Err(FromBytesError::Utf8(_))));
+//! assert!(matches!(
+//!     LabelRef::from_bytes_validated(b"much too long"),
+//!     Err(FromBytesError::Invalid(_))
+//! ));
+//! ```
+//!
+//! Network code that holds owned byte buffers can instead use the `from_utf8()` constructor
+//! generated on the owned type, which reuses the buffer's allocation, or its `TryFrom<&[u8]>`
+//! implementation. Braids without a validator, and normalized braids, also provide a
+//! `from_utf8_lossy()` constructor, which replaces invalid UTF-8 sequences with
+//! `U+FFFD REPLACEMENT CHARACTER` before normalizing the value.
+//!
+//! ```
+//! # use aliri_braid::{braid, FromBytesError};
+//! #
+//! #[braid(from_bytes)]
+//! pub struct Payload;
+//!
+//! let payload = Payload::from_utf8(b"hello".to_vec()).unwrap();
+//! assert_eq!("hello", payload.as_str());
+//!
+//! assert!(matches!(Payload::from_utf8(b"\xff".to_vec()), Err(FromBytesError::Utf8(_))));
+//! assert_eq!("hi\u{fffd}", Payload::from_utf8_lossy(b"hi\xff").as_str());
+//! ```
+//!
+//! ## Arena allocation
+//!
+//! Parsers that produce many short-lived values can avoid a separate heap allocation for each
+//! one by copying them into an arena. The `arena` parameter generates an `alloc_in()`
+//! constructor on the borrowed type that checks a value, copies it into any type implementing
+//! [`Arena`], and returns a reference that lives as long as the arena. For normalized braids,
+//! only the normalized form is copied. Enabling the `bumpalo` feature of this crate implements
+//! [`Arena`] for `bumpalo::Bump`.
+//!
+//! ```
+//! use aliri_braid::{braid, Arena};
+//!
+//! /// An arena that never frees its values
+//! struct Leak;
+//!
+//! impl Arena for Leak {
+//!     fn alloc_str<'a>(&'a self, raw: &str) -> &'a str {
+//!         Box::leak(raw.into())
+//!     }
+//! }
+//!
+//! #[braid(arena, max_len = 8)]
+//! pub struct Symbol;
+//!
+//! let symbol: &SymbolRef = SymbolRef::alloc_in(&Leak, "ident").unwrap();
+//! assert_eq!("ident", symbol.as_str());
+//! assert!(SymbolRef::alloc_in(&Leak, "much too long").is_err());
+//! ```
+//!
+//! ## Interning
+//!
+//! Documents that repeat the same identifiers many times can share a single allocation for each
+//! distinct value by interning them. The `intern` parameter implements [`Intern`] for the
+//! borrowed type, so that an [`Interner`] can check a string slice and return an `Arc` of the
+//! borrowed type shared with every equal value. Values that are already interned are returned
+//! without being checked again. For normalized braids, only the normalized form is interned.
+//!
+//! With the `serde` feature of this crate enabled, `&mut Interner<T>` implements
+//! `DeserializeSeed`, so values can be deserialized directly into the interner, including from
+//! the `DeserializeSeed` of an enclosing container.
+//!
+//! ```
+//! use std::sync::Arc;
+//!
+//! use aliri_braid::{braid, Interner};
+//!
+//! #[braid(intern, max_len = 8)]
+//! pub struct EventId;
+//!
+//! let mut interner = Interner::<EventIdRef>::new();
+//! let first = interner.intern("login").unwrap();
+//! let second = interner.intern("login").unwrap();
+//! assert!(Arc::ptr_eq(&first, &second));
+//! assert!(interner.intern("much too long").is_err());
+//!
+//! # #[cfg(feature = "serde")]
+//! # {
+//! use serde::de::DeserializeSeed;
+//!
+//! let mut de = serde_json::Deserializer::from_str(r#""login""#);
+//! let third = (&mut interner).deserialize(&mut de).unwrap();
+//! assert!(Arc::ptr_eq(&first, &third));
+//! # }
+//! ```
+//!
+//! ## Grapheme-aware truncation
+//!
+//! Braids holding human-visible text, such as display names, often need to be shortened for
+//! presentation. With the `unicode` feature of this crate enabled, the `unicode` parameter
+//! generates `grapheme_len()` and `truncate_graphemes(n)` on the borrowed type. Both count
+//! extended grapheme clusters rather than bytes or `char`s, so truncation never splits a
+//! user-perceived character, such as a letter with a combining accent or a multi-part emoji.
+//!
+//! `truncate_graphemes(n)` returns a `Cow` of the borrowed type, borrowing from the original
+//! value where possible. For validated braids, the truncated value is validated again and an
+//! error is returned if it no longer upholds the braid's invariants. For normalized braids, the
+//! truncated value is normalized again, which may produce an owned value.
+//!
+//! ## Testing invariants
+//!
+//! The [`braid_tests!()`] macro generates a module of unit tests for a braid, checking the
+//! properties that the rest of the ecosystem relies on: that references to the borrowed type are
+//! laid out like `&str`, that valid values parse, reparse, and borrow consistently, that owned
+//! and borrowed values hash the same, that invalid values are rejected, and, optionally, that
+//! values survive a serde roundtrip.
+//!
+//! ```
+//! use aliri_braid::{braid, braid_tests};
+//!
+//! #[braid(min_len = 1, max_len = 16)]
+//! pub struct Username;
+//!
+//! braid_tests! {
+//!     mod username_tests for Username => UsernameRef;
+//!     valid = ["alice", "bob"];
+//!     invalid = ["", "a much too long username"];
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! # Provided trait impls
+//!
+//! By default, the following traits will be automatically implemented.
+//!
+//! For the `Owned` type
+//! * [`core::clone::Clone`]
+//! * [`core::fmt::Debug`]
+//! * [`core::fmt::Display`]
+//! * [`core::hash::Hash`]
+//! * [`core::cmp::Eq`]
+//! * [`core::cmp::Ord`]
+//! * [`core::cmp::PartialEq<Owned>`]
+//! * [`core::cmp::PartialEq<Borrowed>`]
 //! * [`core::cmp::PartialEq<&Borrowed>`]
 //! * [`core::cmp::PartialEq<Box<Borrowed>>`]
+//! * [`core::cmp::PartialEq<Cow<Borrowed>>`]
 //! * [`core::cmp::PartialOrd`]
 //! * [`core::convert::AsRef<Borrowed>`]
 //! * [`core::convert::AsRef<str>`]
@@ -600,6 +1475,7 @@
 //! * [`core::cmp::PartialEq<Borrowed>`]
 //! * [`core::cmp::PartialEq<&Borrowed>`]
 //! * [`core::cmp::PartialEq<Box<Borrowed>>`]
+//! * [`core::cmp::PartialEq<Cow<Borrowed>>`]
 //! * [`core::cmp::PartialOrd`]
 //! * [`core::convert::From<&Cow<Borrowed>>`]
 //! * [`alloc::borrow::ToOwned`] where `Owned = Owned`
@@ -610,6 +1486,12 @@
 //! Validated and normalize borrowed types will instead implement
 //! * [`core::convert::TryFrom<&str>`]
 //!
+//! For `&Borrowed`, `Box<Borrowed>`, and `Cow<Borrowed>`
+//! * [`core::cmp::PartialEq`] with each of `Owned`, `Borrowed`, `&Borrowed`, `Box<Borrowed>`, and
+//!   `Cow<Borrowed>`
+//!
+//! Equality is thus symmetric between every pair of these ownership forms.
+//!
 //! For `Cow<'static, Borrowed>`
 //! * [`core::convert::From<Owned>`]
 //!
@@ -619,6 +1501,9 @@
 //! For `Box<Borrowed>`
 //! * [`core::convert::From<Owned>`]
 //!
+//! For `Box<Borrowed>`, `Rc<Borrowed>`, and `Arc<Borrowed>`
+//! * [`core::convert::From<&Borrowed>`]
+//!
 //! The above conversion will fail if the value is not already normalized.
 //!
 //! Types that are not normalized will additionally implement
@@ -628,10 +1513,102 @@
 //! of equivalent braid values will have differing results for equality, which violates the
 //! contract implied by the `Borrow` trait.
 //!
+//! Maps such as those from `indexmap` and `hashbrown` look up keys through the `Equivalent`
+//! trait from the [`equivalent`] crate, which is implemented for every type that a key can be
+//! borrowed as. A map keyed by the owned type can thus be queried with `&Borrowed`, and with
+//! `&str` for types that are not normalized, without constructing an owned value. The macros
+//! do not generate `Equivalent` impls of their own, as those would conflict with that blanket
+//! implementation.
+//!
+//! [`equivalent`]: https://docs.rs/equivalent
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! use indexmap::IndexMap;
+//!
+//! #[braid]
+//! pub struct HeaderName;
+//!
+//! let mut headers = IndexMap::new();
+//! headers.insert(HeaderName::from_static("accept"), "text/plain");
+//!
+//! assert_eq!(Some(&"text/plain"), headers.get(HeaderNameRef::from_static("accept")));
+//! assert_eq!(Some(&"text/plain"), headers.get("accept"));
+//! ```
+//!
 //! `Deref` to a `str` is explicitly not implemented. This means that an explicit call is
 //! required to treat a value as an untyped string, whether `.as_str()`, `.to_string()`, or
 //! `.into_string()`
 //!
+//! Because the owned type dereferences to the borrowed type, optional values can be borrowed
+//! with [`Option::as_deref()`]. The same applies to `Option<Box<Borrowed>>` and
+//! `Option<Cow<Borrowed>>`, each of which produces an `Option<&Borrowed>`.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! #
+//! #[braid]
+//! pub struct Nickname;
+//!
+//! fn greet(nickname: Option<&NicknameRef>) -> &str {
+//!     nickname.map_or("friend", NicknameRef::as_str)
+//! }
+//!
+//! let nickname = Some(Nickname::from_static("Ace"));
+//! assert_eq!("Ace", greet(nickname.as_deref()));
+//! assert_eq!("friend", greet(None));
+//! ```
+//!
+//! ## Filesystem paths
+//!
+//! Braids that hold file names or paths can opt into `AsRef<Path>` and `AsRef<OsStr>` for
+//! both types with the `as_path` parameter, so that values can be passed directly to `std::fs`
+//! and `std::process` APIs. As these expose the value as a string slice, the parameter cannot
+//! be combined with `no_std` or `no_expose = "opaque"`. Braids that need to hold non-Unicode
+//! paths can wrap `PathBuf` instead, as described in [Paths](#paths).
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! use std::path::Path;
+//!
+//! #[braid(as_path)]
+//! pub struct FileName;
+//!
+//! let name = FileName::from_static("Cargo.toml");
+//! assert_eq!(Path::new("crates/Cargo.toml"), Path::new("crates").join(&name));
+//! assert_eq!(Some("toml".as_ref()), Path::new(&name).extension());
+//! ```
+//!
+//! ## Default values
+//!
+//! The `default = "value"` parameter implements [`Default`] for the owned type, which is
+//! useful for configuration structs using `#[serde(default)]`. The value is constructed with
+//! `from_static()`, so validated braids will panic if the value is invalid and normalized
+//! braids will normalize it. Declarative constraints are checked against the value when the
+//! macro is expanded. For braids without a validator or normalizer, the bare `default` flag
+//! uses an empty string as the default value.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! #
+//! #[braid(default = "localhost", max_len = 253)]
+//! pub struct Hostname;
+//!
+//! #[braid(default)]
+//! pub struct Comment;
+//!
+//! assert_eq!("localhost", Hostname::default().as_str());
+//! assert_eq!("", Comment::default().as_str());
+//! ```
+//!
+//! ```compile_fail
+//! # use aliri_braid::braid;
+//! #
+//! // error: invalid value "": must be at least 1 bytes long
+//! #[braid(default = "", min_len = 1)]
+//! pub struct Hostname;
+//! ```
+//!
 //! ## Omitting `Clone`
 //!
 //! For some types, it may be desirable to prevent arbitrary cloning of a type. In that case,
@@ -661,7 +1638,9 @@
 //! The modes have the following effects:
 //!
 //! * `impl`: Format the owned and reference type transparently as the underlying string (slice)
-//!   type.
+//!   type. Formatting flags behave as they do for a `str`: `Display` honors width, fill, alignment,
+//!   and precision, so `format!("{:>10}", id)` pads the value, while `Debug` quotes and escapes the
+//!   value as-is.
 //! * `owned`: Automatically provide an owned implementation that transparently delegates to the
 //!   implementation of the borrowed form. The consumer must provide their custom implementation on
 //!   the borrowed form.
@@ -703,6 +1682,29 @@
 //! assert_eq!("secret value", borrowed.as_str());
 //! ```
 //!
+//! The `ord` parameter also accepts `case_insensitive` and `natural`, which order values ignoring
+//! case, or with runs of ASCII digits compared by their numeric value, respectively. Values that
+//! these orderings consider the same, such as `Alpha` and `alpha`, or `item02` and `item2`, are
+//! then ordered by their exact contents, so that the ordering remains consistent with equality.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! use std::collections::BTreeSet;
+//!
+//! #[braid(ord = "natural")]
+//! pub struct FileName;
+//!
+//! let names: BTreeSet<_> = ["item10", "item2", "item1"]
+//!     .iter()
+//!     .copied()
+//!     .map(FileName::from_static)
+//!     .collect();
+//! assert_eq!(
+//!     vec!["item1", "item2", "item10"],
+//!     names.iter().map(|n| n.as_str()).collect::<Vec<_>>(),
+//! );
+//! ```
+//!
 //! # Serde
 //!
 //! [`Serialize`] and [`Deserialize`] implementations from the [`serde`] crate
@@ -724,6 +1726,30 @@
 //! # assert_eq!(username, new_username);
 //! ```
 //!
+//! By default, the owned type serializes through the `Serialize` implementation of the type it
+//! wraps. Some backing types, such as byte-oriented strings, may serialize as bytes rather than
+//! as a string in some formats. Specifying `serde = "str"` instead serializes the owned type as a
+//! string slice, so the output does not depend on the choice of backing type.
+//!
+//! Specifying `serde = "bytes"` serializes both types as string slices in formats that are
+//! human-readable, such as JSON, and as bytes in formats that are not, as reported by
+//! `is_human_readable()`. Values read as bytes are still checked for UTF-8 before being
+//! validated, and borrowed values can be read directly from the input where the format allows.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! #
+//! #[braid(serde = "bytes")]
+//! pub struct Username;
+//!
+//! let username = Username::from_static("root");
+//! assert_eq!(serde_json::to_string(&username).unwrap(), r#""root""#);
+//!
+//! let encoded = bincode::serialize(&username).unwrap();
+//! let borrowed: &UsernameRef = bincode::deserialize(&encoded).unwrap();
+//! assert_eq!(borrowed, username);
+//! ```
+//!
 //! Such automatic implementations will also properly handle string values that require
 //! validation. This automatic validation has the benefit of easing use with _Serde_ while
 //! still protecting the integrity of the type.
@@ -765,98 +1791,969 @@
 //! assert!(serde_json::from_str::<&UsernameRef>("\"nobody\"").is_ok());
 //! ```
 //!
-//! # Custom string types
-//!
-//! The `braid` macro can be used to define a custom string type that wraps types
-//! other than the standard `String`. This allows defining a braid that is backed
-//! by a type that offers small-string optimizations, such as [`SmartString`] or
-//! [`CompactString`].
-//!
-//! Functions that expose the inner wrapped type can be made private by adding the
-//! `no_expose` parameter to avoid leaking the type in the public interface.
-//!
-//! [`SmartString`]: https://docs.rs/smartstring/*/smartstring/struct.SmartString.html
-//! [`CompactString`]: https://docs.rs/compact_str/*/compact_str/struct.CompactString.html
+//! Braids nested inside containers, such as `Vec<Cow<Borrowed>>`, `HashMap<&Borrowed, Owned>`,
+//! or `Option<Box<Borrowed>>`, are supported through these implementations. Note that the
+//! `Deserialize` implementation that `serde` provides for [`Cow`][alloc::borrow::Cow] always
+//! produces an owned value. To borrow from the input where possible, the borrowed form of a
+//! braid provides a `deserialize_cow()` function that can be used with `deserialize_with`.
 //!
 //! ```
 //! # use aliri_braid::braid;
-//! use compact_str::CompactString;
-//! use smartstring::{SmartString, LazyCompact};
+//! use std::borrow::Cow;
 //!
-//! #[braid(no_expose)]
-//! pub struct UserId(CompactString);
+//! #[braid(serde)]
+//! pub struct Username;
 //!
-//! #[braid(no_expose)]
-//! pub struct AltUserId(SmartString<LazyCompact>);
+//! #[derive(serde::Deserialize)]
+//! struct Login<'a> {
+//!     #[serde(borrow, deserialize_with = "UsernameRef::deserialize_cow")]
+//!     username: Cow<'a, UsernameRef>,
+//! }
+//!
+//! let login: Login = serde_json::from_str(r#"{"username":"nobody"}"#).unwrap();
+//! assert!(matches!(login.username, Cow::Borrowed(_)));
 //! ```
 //!
-//! It can also be used to wrap a [`ByteString`], which is a string backed by
-//! [`Bytes`], which may be useful if the type is primarily used in contexts
-//! where a zero-copy implementation is preferred.
+//! Smart pointers to the borrowed form serialize exactly as the owned and borrowed forms do,
+//! through the blanket implementations that `serde` provides for `Box<T>`, `Cow<T>`, and, with
+//! its `rc` feature, `Rc<T>` and `Arc<T>`. On the way back in, `Box<Borrowed>` is deserialized by
+//! the braid itself, and `serde` builds `Rc<Borrowed>` and `Arc<Borrowed>` from that box, so
+//! every form is validated and normalized in the same way.
 //!
-//! [`ByteString`]: https://docs.rs/bytestring/*/bytestring/struct.ByteString.html
-//! [`Bytes`]: https://docs.rs/bytes/*/bytes/struct.Bytes.html
+//! Braids can also be used as map keys, which are serialized as strings. Keys deserialized into
+//! the owned form, a boxed borrowed form, or a `Cow` are validated and normalized just like any
+//! other value, so keys of a normalized braid that differ only before normalization are merged
+//! into a single entry, with the last value winning. Keys borrowed as `&Borrowed` must already
+//! be in normalized form, and, as with any borrowed string, cannot borrow from JSON input that
+//! contains escape sequences.
 //!
 //! ```
 //! # use aliri_braid::braid;
-//! use bytestring::ByteString;
+//! use std::collections::HashMap;
 //!
-//! #[braid]
-//! pub struct ZeroCopyIdentifier(ByteString);
+//! #[braid(serde, normalizer)]
+//! pub struct Tag;
+//! # impl aliri_braid::Validator for Tag {
+//! #     type Error = std::convert::Infallible;
+//! #     fn validate(s: &str) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # impl aliri_braid::Normalizer for Tag {
+//! #     fn normalize(s: &str) -> Result<std::borrow::Cow<str>, Self::Error> {
+//! #         Ok(s.to_lowercase().into())
+//! #     }
+//! # }
+//!
+//! let counts: HashMap<Tag, u32> = serde_json::from_str(r#"{"RUST": 1, "rust": 2}"#).unwrap();
+//! assert_eq!(1, counts.len());
+//! assert_eq!(2, counts[TagRef::from_static("rust")]);
 //! ```
 //!
-//! ## Requirements
+//! The owned type also implements `Deserialize::deserialize_in_place`, which containers such as
+//! `Vec` use when deserializing into an existing value. For braids wrapping a `String`, the
+//! incoming value is checked first and then copied into the existing allocation, so a buffer of
+//! braids can be refreshed without reallocating each element. If the value is rejected, the
+//! existing value is left untouched.
 //!
-//! In order to be used as a custom string type, the type must implement the
-//! following traits:
+//! ## Case-insensitive equality
 //!
-//! * [`core::clone::Clone`] (unless `clone` is `omit`)
-//! * [`core::fmt::Debug`] (unless `debug` is `omit`)
-//! * [`core::fmt::Display`] (unless `display` is `omit`)
-//! * [`core::cmp::Eq`]
-//! * [`core::cmp::PartialEq`]
-//! * [`core::hash::Hash`]
-//! * [`core::cmp::Ord`] (unless `ord` is `omit`)
-//! * [`core::cmp::PartialOrd`] (unless `ord` is `omit`)
-//! * [`serde::Serialize`] (unless `serde` is `omit`)
-//! * [`serde::Deserialize`] (unless `serde` is `omit`)
-//! * [`core::convert::From<&str>`]
-//! * [`core::convert::From<Box<str>>`]
-//! * [`core::convert::AsRef<str>`]
-//! * [`core::convert::Into<String>`]
+//! Some identifiers, such as host names and HTTP header names, compare equal regardless of
+//! case. The `eq = "ascii_case_insensitive"` parameter replaces the derived `PartialEq`, `Eq`,
+//! and `Hash` implementations, as well as the generated `Ord` implementations, with ones that
+//! ignore differences in the case of ASCII letters. The value itself keeps the case it was
+//! created with.
 //!
-//! [`serde::Serialize`]: https://docs.rs/serde/*/serde/trait.Serialize.html
-//! [`serde::Deserialize`]: https://docs.rs/serde/*/serde/trait.Deserialize.html
+//! ```
+//! # use aliri_braid::braid;
+//! use std::collections::HashSet;
 //!
-//! # `no_std` support
+//! #[braid(eq = "ascii_case_insensitive")]
+//! pub struct HostName;
 //!
-//! Braids can be implemented in `no_std` environments with `alloc`. By adding the
-//! `no_std` parameter to the macro, all impls will reference the `core` or `alloc`
-//! crates instead of the `std` crate, as appropriate.
+//! let host = HostName::from_static("Example.COM");
+//! assert_eq!(host, HostNameRef::from_static("example.com"));
+//! assert_eq!("Example.COM", host.as_str());
 //!
+//! let mut hosts = HashSet::new();
+//! hosts.insert(host);
+//! assert!(hosts.contains(HostNameRef::from_static("EXAMPLE.com")));
 //! ```
-//! extern crate alloc;
 //!
-//! use aliri_braid::braid;
-//! use alloc::string::String;
+//! With the `unicode` feature enabled, `eq = "unicode_case_insensitive"` instead compares
+//! values after Unicode lowercasing. In either case, `Borrow<str>` is not implemented, as a
+//! `str` hashes and compares by its exact contents, and so cannot be used to look up a
+//! case-insensitive braid.
 //!
-//! #[braid(no_std)]
-//! pub struct NoStdLibWrapper;
-//! #
-//! # fn main() {}
-//! ```
+//! Equality can also be left to the consumer with `eq = "omit"`, for example to compare only
+//! part of the value. No `PartialEq` or `Eq` implementations are then generated, including the
+//! comparisons between the owned and borrowed forms. As the generated `Hash` and `Ord`
+//! implementations would disagree with such an equality, `hash` must be `omit` or `custom`,
+//! and `ord` must be `omit`.
 //!
-//! In environments without an allocator, `braid_ref` can be used to create a
-//! reference-only braid. In order to remove the `alloc` dependency in `aliri_braid`,
-//! specify `default-features = "false"` in the `Cargo.toml` file.
+//! ## Custom `Hash` implementations
 //!
-//! ```
-//! use aliri_braid::braid_ref;
+//! The `hash` parameter controls the [`Hash`][core::hash::Hash] implementations independently
+//! of equality. With `hash = "omit"`, neither form implements `Hash`. With `hash = "custom"`,
+//! the consumer implements `Hash` for both forms, for example to hash a canonical form of the
+//! value. Such an implementation must still agree with `Eq`, and an owned value must hash the
+//! same as its borrowed form so that maps keyed by the owned type can be searched with the
+//! borrowed type. As the values may no longer hash as a `str` would, `Borrow<str>` is not
+//! implemented.
 //!
-//! #[braid_ref(no_std)]
-//! pub struct NoStdValue;
-//! #
-//! # fn main() {}
+//! ```
+//! # use aliri_braid::braid;
+//! use std::{
+//!     collections::HashSet,
+//!     hash::{Hash, Hasher},
+//! };
+//!
+//! #[braid(hash = "custom")]
+//! pub struct Email;
+//!
+//! impl Hash for Email {
+//!     fn hash<H: Hasher>(&self, state: &mut H) {
+//!         (**self).hash(state)
+//!     }
+//! }
+//!
+//! impl Hash for EmailRef {
+//!     fn hash<H: Hasher>(&self, state: &mut H) {
+//!         for b in self.as_str().bytes() {
+//!             state.write_u8(b.to_ascii_lowercase());
+//!         }
+//!     }
+//! }
+//!
+//! let mut emails = HashSet::new();
+//! emails.insert(Email::from_static("user@example.com"));
+//! assert!(emails.contains(EmailRef::from_static("user@example.com")));
+//! ```
+//!
+//! ## Reporting every invalid field
+//!
+//! Deserialization normally stops at the first braid that fails validation. API surfaces that
+//! must report every invalid field at once can enable the `serde` feature of this crate, wrap
+//! braid fields in `Checked`, and deserialize with `deserialize_collecting()`. Each invalid
+//! `Checked` field is recorded with its path, such as `members[1].name`, and all of them are
+//! returned together. Outside of `deserialize_collecting()`, a `Checked` field behaves exactly
+//! like the braid it wraps.
+//!
+//! ## Typed route parameters with `axum`
+//!
+//! The `axum` parameter makes a braid usable as a typed route parameter with [`axum`]. It
+//! implies `serde`, so the owned type can be extracted with `Path<T>` or as a field of a
+//! `Query<T>`. When a path or query segment fails validation, `axum` responds with
+//! `400 Bad Request`, and the response body carries the message of the validator's error.
+//!
+//! For handlers that construct braids themselves, enabling the `axum` feature of this crate
+//! provides a `Rejection` type. Any braid error converts into a `Rejection` with `?`, and a
+//! `Rejection` responds with `400 Bad Request` and the error message as a plain-text body.
+//!
+//! [`axum`]: https://docs.rs/axum/*/axum/
+//!
+//! ```ignore
+//! use aliri_braid::{braid, Rejection};
+//! use axum::extract::Path;
+//!
+//! #[braid(axum, max_len = 16)]
+//! pub struct Username;
+//!
+//! async fn profile(Path(username): Path<Username>) -> String {
+//!     format!("profile of {}", username)
+//! }
+//!
+//! async fn greet(raw: String) -> Result<String, Rejection<aliri_braid::ConstraintError>> {
+//!     let username = Username::new(raw)?;
+//!     Ok(format!("hello, {}", username))
+//! }
+//! ```
+//!
+//! # Command-line arguments with `clap`
+//!
+//! The `clap` parameter implements [`clap`]'s `ValueParserFactory` for the owned type, so that
+//! command-line arguments can be parsed directly into a braid with `value_parser!()` or the
+//! `clap` derive macros. When an argument fails validation, the validator's error message is
+//! included in the error reported by `clap`. The owned type's `FromStr` error must implement
+//! `std::error::Error + Send + Sync`.
+//!
+//! [`clap`]: https://docs.rs/clap/*/clap/
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! use clap::{value_parser, Arg, Command};
+//!
+//! #[braid(clap, max_len = 8)]
+//! pub struct Username;
+//!
+//! let command = Command::new("login")
+//!     .arg(Arg::new("username").value_parser(value_parser!(Username)));
+//!
+//! let matches = command.clone().try_get_matches_from(["login", "nobody"]).unwrap();
+//! assert_eq!("nobody", matches.get_one::<Username>("username").unwrap().as_str());
+//!
+//! assert!(command.try_get_matches_from(["login", "much too long"]).is_err());
+//! ```
+//!
+//! # Structured logging with `valuable`
+//!
+//! The `valuable` parameter implements [`valuable`]'s `Valuable` trait for both the owned and
+//! borrowed types, recording the value as a `Value::String` that borrows from the braid. With
+//! `tracing`'s `valuable` support enabled, a braid can then be recorded as a structured field
+//! using `tracing::field::valuable(&value)`, without formatting it into a new `String` at each
+//! log site.
+//!
+//! [`valuable`]: https://docs.rs/valuable/*/valuable/
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! use valuable::{Valuable, Value};
+//!
+//! #[braid(valuable)]
+//! pub struct RequestId;
+//!
+//! let id = RequestId::from_static("req-1234");
+//! assert!(matches!(id.as_value(), Value::String("req-1234")));
+//!
+//! let borrowed: &RequestIdRef = &id;
+//! assert!(matches!(borrowed.as_value(), Value::String("req-1234")));
+//! ```
+//!
+//! # Embedded logging with `defmt`
+//!
+//! The `defmt` parameter implements [`defmt`]'s `Format` trait for the borrowed type, and for
+//! the owned type when one is generated, writing the value as a `{=str}` parameter. This allows
+//! `no_std` braids to be logged over a `defmt` transport, such as RTT, without pulling in
+//! `core::fmt`.
+//!
+//! [`defmt`]: https://docs.rs/defmt/*/defmt/
+//!
+//! ```
+//! # extern crate alloc;
+//! # use aliri_braid::braid;
+//! #[braid(defmt, no_std)]
+//! pub struct DeviceId;
+//!
+//! fn report(device: &DeviceIdRef) {
+//!     defmt::info!("device {} connected", device);
+//! }
+//! ```
+//!
+//! # TypeScript bindings with `ts-rs`
+//!
+//! The `ts_rs` parameter implements [`ts-rs`]'s `TS` trait for both the owned and borrowed
+//! types. The braid is exported as an alias of `string`, named after the owned type and
+//! documented with the name of the Rust type, so that structs deriving `TS` can contain braids
+//! without overriding each field's type.
+//!
+//! [`ts-rs`]: https://docs.rs/ts-rs/*/ts_rs/
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! use ts_rs::TS;
+//!
+//! #[braid(ts_rs)]
+//! pub struct Username;
+//!
+//! #[derive(TS)]
+//! pub struct Account {
+//!     pub username: Username,
+//! }
+//!
+//! assert_eq!("type Username = string;", Username::decl());
+//! assert_eq!("type Account = { username: Username, };", Account::decl());
+//! ```
+//!
+//! # Redis commands with `redis`
+//!
+//! The `redis` parameter implements [`redis`]'s `ToRedisArgs` for the owned type and for
+//! references to the borrowed type, so that braids can be passed directly as keys and values in
+//! `redis` commands. `FromRedisValue` is implemented for the owned type, and values read back
+//! from the server are parsed through `FromStr`, so that a value failing validation is reported
+//! as a parsing error. These implementations target `redis` 1.x.
+//!
+//! [`redis`]: https://docs.rs/redis/*/redis/
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! use redis::{FromRedisValue, ToRedisArgs, Value};
+//!
+//! #[braid(redis, max_len = 8)]
+//! pub struct SessionId;
+//!
+//! let id = SessionId::from_static("s-1234");
+//! assert_eq!(vec![b"s-1234".to_vec()], id.to_redis_args());
+//!
+//! let read = SessionId::from_redis_value(Value::BulkString(b"s-1234".to_vec())).unwrap();
+//! assert_eq!(id, read);
+//!
+//! assert!(SessionId::from_redis_value(Value::BulkString(b"much too long".to_vec())).is_err());
+//! ```
+//!
+//! # BSON values with `bson`
+//!
+//! With the `bson` feature enabled, the `bson` parameter generates conversions between a braid
+//! and `bson::Bson`, so that braids can be used directly in MongoDB filters and documents.
+//! The owned type and references to the borrowed type convert into a `Bson::String`, and the
+//! owned type implements `TryFrom<Bson>`, producing a `BsonError` when the value is not a
+//! string or fails validation. When combined with `serde`, the owned type is always serialized
+//! as a string, even if the wrapped type would otherwise serialize differently.
+//!
+//! ```
+//! # #[cfg(feature = "bson")]
+//! # {
+//! # use std::convert::TryFrom;
+//! # use aliri_braid::braid;
+//! use bson::{doc, Bson};
+//!
+//! #[braid(bson, max_len = 8)]
+//! pub struct Sku;
+//!
+//! let sku = Sku::from_static("w-1234");
+//! let filter = doc! { "sku": &*sku };
+//! assert_eq!(Ok("w-1234"), filter.get_str("sku"));
+//!
+//! assert_eq!(sku, Sku::try_from(Bson::String("w-1234".to_owned())).unwrap());
+//! assert!(Sku::try_from(Bson::Int32(1234)).is_err());
+//! # }
+//! ```
+//!
+//! # Entity columns with `sea-orm`
+//!
+//! The `sea_orm` parameter implements the [`sea-orm`] value traits for the owned type, so that it
+//! can be used directly as the type of an entity's column, including as an optional column or a
+//! primary key. Values are stored as strings, and values read back from the database are parsed
+//! through `FromStr`, so that a row failing validation produces a `DbErr::Type` error.
+//!
+//! [`sea-orm`]: https://docs.rs/sea-orm/*/sea_orm/
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! #[braid(sea_orm, max_len = 32)]
+//! pub struct Username;
+//!
+//! mod user {
+//!     # use std::convert::TryInto;
+//!     use sea_orm::entity::prelude::*;
+//!
+//!     #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+//!     #[sea_orm(table_name = "users")]
+//!     pub struct Model {
+//!         #[sea_orm(primary_key, auto_increment = false)]
+//!         pub name: super::Username,
+//!         pub display_name: Option<super::Username>,
+//!     }
+//!
+//!     #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+//!     pub enum Relation {}
+//!
+//!     impl ActiveModelBehavior for ActiveModel {}
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! # Property testing with `quickcheck`
+//!
+//! The `quickcheck` parameter implements [`quickcheck::Arbitrary`] for the owned type. Values
+//! are generated from arbitrary strings: for validated and normalized braids, a generated string
+//! that fails the braid's checks is shrunk until a valid value is found, and shrinking only ever
+//! produces values that pass the checks. Normalized braids are always generated in their
+//! normalized form.
+//!
+//! [`quickcheck::Arbitrary`]: https://docs.rs/quickcheck/1/quickcheck/trait.Arbitrary.html
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! #[braid(quickcheck, max_len = 8)]
+//! pub struct Username;
+//!
+//! fn roundtrips(name: Username) -> bool {
+//!     UsernameRef::from_str(name.as_str()).is_ok()
+//! }
+//!
+//! quickcheck::quickcheck(roundtrips as fn(Username) -> bool);
+//! ```
+//!
+//! Constraints that arbitrary strings rarely satisfy, such as a required prefix or a well-known
+//! format, may fail to produce a value at all. Implement `Arbitrary` by hand for those braids.
+//!
+//! # Protobuf messages with `prost`
+//!
+//! Braids already convert to and from the `String` fields of [`prost`]'s generated structs
+//! through `Into<String>` and `TryFrom<String>`. With the `prost` feature enabled, a few helpers
+//! cover the remaining field shapes: `from_proto_optional()` and `into_proto_optional()` treat
+//! proto3's empty string as an absent value, and `from_proto_repeated()` and
+//! `into_proto_repeated()` convert repeated fields. `BraidMessage<T>` is a `prost::Message`
+//! carrying a single braid with the encoding of `google.protobuf.StringValue`, which is checked
+//! by the braid when decoded.
+//!
+//! [`prost`]: https://docs.rs/prost/*/prost/
+//!
+//! ```
+//! # #[cfg(feature = "prost")]
+//! # {
+//! # use std::convert::{TryFrom, TryInto};
+//! # use aliri_braid::braid;
+//! use aliri_braid::{from_proto_optional, ConstraintError};
+//!
+//! #[braid(max_len = 8)]
+//! pub struct Username;
+//!
+//! #[derive(Clone, PartialEq, prost::Message)]
+//! pub struct UserDto {
+//!     #[prost(string, tag = "1")]
+//!     pub name: String,
+//!     #[prost(string, tag = "2")]
+//!     pub manager: String,
+//! }
+//!
+//! pub struct User {
+//!     pub name: Username,
+//!     pub manager: Option<Username>,
+//! }
+//!
+//! impl TryFrom<UserDto> for User {
+//!     type Error = ConstraintError;
+//!
+//!     fn try_from(dto: UserDto) -> Result<Self, Self::Error> {
+//!         Ok(Self {
+//!             name: dto.name.try_into()?,
+//!             manager: from_proto_optional(dto.manager)?,
+//!         })
+//!     }
+//! }
+//!
+//! let dto = UserDto { name: "alice".to_owned(), manager: String::new() };
+//! let user = User::try_from(dto).unwrap();
+//! assert_eq!("alice", user.name.as_str());
+//! assert!(user.manager.is_none());
+//! # }
+//! ```
+//!
+//! # Adapters for `serde_with`
+//!
+//! With the `serde_with` feature enabled, `BraidAs` can be used in [`serde_with`]'s
+//! `#[serde_as]` annotations to serialize owned braids as strings in any position that accepts
+//! an adapter, including the elements of collections and the keys of maps. Values are
+//! deserialized through the braid's `FromStr` implementation, so the braid does not need the
+//! `serde` parameter, and values are validated or normalized as they are read.
+//!
+//! [`serde_with`]: https://docs.rs/serde_with/*/serde_with/
+//!
+//! ```
+//! # #[cfg(feature = "serde_with")]
+//! # {
+//! use std::collections::BTreeMap;
+//! # use aliri_braid::braid;
+//! use aliri_braid::BraidAs;
+//! use serde::{Deserialize, Serialize};
+//! use serde_with::serde_as;
+//!
+//! #[braid(max_len = 8)]
+//! pub struct Username;
+//!
+//! #[serde_as]
+//! #[derive(Serialize, Deserialize)]
+//! pub struct Roles {
+//!     #[serde_as(as = "BTreeMap<BraidAs, _>")]
+//!     pub levels: BTreeMap<Username, u32>,
+//! }
+//!
+//! let roles: Roles = serde_json::from_str(r#"{"levels":{"alice":2}}"#).unwrap();
+//! assert_eq!(Some(&2), roles.levels.get(UsernameRef::from_static("alice")));
+//!
+//! assert!(serde_json::from_str::<Roles>(r#"{"levels":{"much too long":1}}"#).is_err());
+//! # }
+//! ```
+//!
+//! # Optional integrations in libraries
+//!
+//! A library can offer its braids' integrations behind optional features of its own, rather
+//! than requiring every dependent to pull in `serde` or `clap`. The `feature_gate(...)`
+//! parameter names a feature of the declaring crate for each integration, and places all of the
+//! impls generated for that integration behind `#[cfg(feature = "...")]`. The rest of the braid
+//! is unaffected.
+//!
+//! ```
+//! use aliri_braid::braid;
+//!
+//! /// Serializable only when the `serde` feature of this crate is enabled
+//! #[braid(serde, clap, feature_gate(serde = "serde", clap = "cli"))]
+//! pub struct Username;
+//! ```
+//!
+//! The integrations that can be gated are `serde`, `clap`, `valuable`, `defmt`, `ts_rs`, `redis`,
+//! `bson`, `sea_orm`, and `quickcheck`.
+//!
+//! # Generic braids
+//!
+//! A family of related identifiers can share a single definition by adding type parameters
+//! that are carried in `PhantomData` fields. Each instantiation, such as `Id<User>` or
+//! `Id<Order>`, is a distinct type, so identifiers for one kind of entity cannot be passed
+//! where another is expected. The borrowed form carries the same phantom fields, and so
+//! is generic over the same parameters.
+//!
+//! ```
+//! use std::marker::PhantomData;
+//! # use aliri_braid::braid;
+//!
+//! pub struct User;
+//! pub struct Order;
+//!
+//! #[braid]
+//! pub struct Id<T>(String, PhantomData<T>);
+//!
+//! let user: Id<User> = Id::from_static("u-1234");
+//! let borrowed: &IdRef<User> = &user;
+//! assert_eq!(borrowed, IdRef::<User>::from_static("u-1234"));
+//! ```
+//!
+//! The generated implementations place no bounds on the type parameters, so `Id<User>`
+//! implements `Clone`, `Eq`, `Hash`, and so on even though `User` does not. Constructors that
+//! deal in `'static` references, like `from_static()`, require the parameters to be `'static`.
+//! Lifetime parameters are not supported, and `braid_ref` does not accept generic parameters.
+//!
+//! # Custom string types
+//!
+//! The `braid` macro can be used to define a custom string type that wraps types
+//! other than the standard `String`. This allows defining a braid that is backed
+//! by a type that offers small-string optimizations, such as [`SmartString`] or
+//! [`CompactString`].
+//!
+//! Functions that expose the inner wrapped type can be made private by adding the
+//! `no_expose` parameter to avoid leaking the type in the public interface.
+//!
+//! [`SmartString`]: https://docs.rs/smartstring/*/smartstring/struct.SmartString.html
+//! [`CompactString`]: https://docs.rs/compact_str/*/compact_str/struct.CompactString.html
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! use compact_str::CompactString;
+//! use smartstring::{SmartString, LazyCompact};
+//!
+//! #[braid(no_expose)]
+//! pub struct UserId(CompactString);
+//!
+//! #[braid(no_expose)]
+//! pub struct AltUserId(SmartString<LazyCompact>);
+//! ```
+//!
+//! To keep a braid fully opaque outside of its module, use `no_expose = "opaque"`. In addition
+//! to hiding the inner type, `as_str()` is kept private, and the `AsRef<str>`, `Borrow<str>`,
+//! `From<Owned> for String`, [`Braid`], and [`BraidRef`] impls are omitted. Other impls that
+//! render the value, such as `Debug`, `Display`, and `Serialize`, are still generated unless
+//! omitted with their own parameters.
+//!
+//! ```
+//! mod credentials {
+//!     use aliri_braid::braid;
+//!
+//!     #[braid(no_expose = "opaque", display = "omit", debug = "omit")]
+//!     pub struct ApiKey;
+//!
+//!     impl ApiKeyRef {
+//!         pub fn matches(&self, presented: &str) -> bool {
+//!             self.as_str() == presented
+//!         }
+//!     }
+//! }
+//!
+//! let key = credentials::ApiKey::from_static("s3cr3t");
+//! assert!(key.matches("s3cr3t"));
+//! ```
+//!
+//! The generated inherent methods, such as the constructors and `as_str()`, are `pub` by
+//! default. The `expose = "pub(crate)"` parameter gives them another visibility instead, so that
+//! a public braid can only be constructed through its inherent methods within the defining
+//! crate. Generated trait impls, such as `From<&str>` and `FromStr`, remain public, as trait
+//! impls cannot be restricted.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! #
+//! #[braid(expose = "pub(crate)")]
+//! pub struct InternalId;
+//!
+//! let id = InternalId::from_static("internal");
+//! assert_eq!("internal", id.as_str());
+//! ```
+//!
+//! Generated inherent methods that return a value, such as `from_static()`, `take()`, and
+//! `as_str()`, are marked `#[must_use]`, so that a freshly constructed value is not silently
+//! dropped. Methods returning a `Result` are already covered by `Result` itself. Trait impls,
+//! such as `From` and `TryFrom`, cannot be marked. The `no_must_use` parameter omits the
+//! attribute.
+//!
+//! It can also be used to wrap a [`ByteString`], which is a string backed by
+//! [`Bytes`], which may be useful if the type is primarily used in contexts
+//! where a zero-copy implementation is preferred.
+//!
+//! [`ByteString`]: https://docs.rs/bytestring/*/bytestring/struct.ByteString.html
+//! [`Bytes`]: https://docs.rs/bytes/*/bytes/struct.Bytes.html
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! use bytestring::ByteString;
+//!
+//! #[braid]
+//! pub struct ZeroCopyIdentifier(ByteString);
+//! ```
+//!
+//! Braids wrapping a `Cow<'static, str>` borrow static string slices rather than copying them,
+//! so that tables of mostly static identifiers do not allocate for each entry. `from_static()`
+//! and `try_from_static()` store the borrowed variant after checking the value, and normalized
+//! braids only allocate when the value was not already normalized. For braids without a
+//! validator or normalizer, `from_static()` is a `const fn`, so that values can be declared as
+//! constants or statics without initializing them lazily. Values created from non-static
+//! strings are copied into an owned `String`.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! use std::borrow::Cow;
+//!
+//! #[braid]
+//! pub struct Label(Cow<'static, str>);
+//!
+//! const DEFAULT_LABEL: Label = Label::from_static("default");
+//! assert_eq!("default", DEFAULT_LABEL.as_str());
+//! ```
+//!
+//! A braid can also wrap another braid, such as an identifier that must be a valid user
+//! identifier but is further scoped to a tenant. Declaring the braid with `nested` checks
+//! each value against the inner braid first, and then against the outer braid's own validator
+//! or constraints, if any. Without its own validator, the outer braid reports the inner
+//! braid's errors. Otherwise, its validator's error must implement `From` the inner braid's
+//! error. The inner braid remains available through `take()`, while the borrowed form wraps a
+//! `str` like any other braid. Inner braids that normalize their values only accept values
+//! that are already normalized. Braids without any checks of their own can already be wrapped
+//! without `nested`, as they convert from strings infallibly.
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! use std::convert::TryFrom;
+//!
+//! #[braid(non_empty)]
+//! pub struct UserId;
+//!
+//! #[braid(nested, max_len = 16)]
+//! pub struct TenantUserId(UserId);
+//!
+//! assert!(TenantUserId::try_from("").is_err());
+//! assert!(TenantUserId::try_from("a very long user identifier").is_err());
+//!
+//! let id = TenantUserId::try_from("acme/alice").unwrap();
+//! let user: UserId = id.take();
+//! assert_eq!("acme/alice", user.as_str());
+//! ```
+//!
+//! With the `bytes` feature of this crate enabled, braids wrapping a [`ByteString`] also get a
+//! `from_bytes()` constructor and a `TryFrom<Bytes>` implementation, which check the value
+//! without copying it out of the provided buffer, along with an `into_bytes()` method to
+//! unwrap the buffer again. Failures are reported through a [`FromBytesError`].
+//!
+//! ```
+//! # #[cfg(feature = "bytes")]
+//! # {
+//! # use aliri_braid::braid;
+//! use bytes::Bytes;
+//! use bytestring::ByteString;
+//!
+//! #[braid(max_len = 16)]
+//! pub struct Frame(ByteString);
+//!
+//! let buffer = Bytes::from_static(b"frame");
+//! let frame = Frame::from_bytes(buffer.clone()).unwrap();
+//! assert_eq!(buffer.as_ptr(), frame.as_str().as_ptr());
+//! assert_eq!(buffer, frame.into_bytes());
+//! # }
+//! ```
+//!
+//! ## Requirements
+//!
+//! In order to be used as a custom string type, the type must implement the
+//! following traits:
+//!
+//! * [`core::clone::Clone`] (unless `clone` is `omit`)
+//! * [`core::fmt::Debug`] (unless `debug` is `omit`)
+//! * [`core::fmt::Display`] (unless `display` is `omit`)
+//! * [`core::cmp::Eq`]
+//! * [`core::cmp::PartialEq`]
+//! * [`core::hash::Hash`]
+//! * [`core::cmp::Ord`] (unless `ord` is `omit`)
+//! * [`core::cmp::PartialOrd`] (unless `ord` is `omit`)
+//! * [`serde::Serialize`] (unless `serde` is `omit`)
+//! * [`serde::Deserialize`] (unless `serde` is `omit`)
+//! * [`core::convert::From<&str>`] (except for `Cow<'static, str>` and `nested` braids)
+//! * [`core::convert::From<Box<str>>`] (except for `Cow<'static, str>` and `nested` braids, and
+//!   unless `box_conversions` are omitted)
+//! * [`core::convert::AsRef<str>`]
+//! * [`core::convert::Into<String>`]
+//!
+//! Some string types, such as `smol_str::SmolStr`, cannot be created from a `Box<str>`. Such
+//! types can still be wrapped by passing `omit(box_conversions)`, which drops the borrowed
+//! type's `into_owned()` method along with the owned type's conversions from `Box<str>` and
+//! from boxed borrowed values.
+//!
+//! [`serde::Serialize`]: https://docs.rs/serde/*/serde/trait.Serialize.html
+//! [`serde::Deserialize`]: https://docs.rs/serde/*/serde/trait.Deserialize.html
+//!
+//! ## Borrowed types other than `str`
+//!
+//! The `ref_inner` parameter makes the borrowed form wrap another unsized type, such as `[u8]`
+//! or a path type, in place of `str`. The owned field must be declared explicitly, must
+//! implement `Borrow` of the inner type, and must be convertible from the inner type's
+//! `ToOwned::Owned`. Validated braids implement [`InnerValidator`], which checks a reference to
+//! the inner type, in place of [`Validator`].
+//!
+//! Only a reduced set of items is generated: `new()` and `into_inner()` on the owned type,
+//! `from_inner()` and `as_inner()` on the borrowed type, `Deref`, `Borrow`, `ToOwned`, and
+//! `AsRef` between the two, along with `Debug`, equality, hashing, and ordering that delegate
+//! to the inner type.
+//!
+//! ```
+//! use aliri_braid::{braid, InnerValidator};
+//!
+//! #[braid(ref_inner = "[u8]", validator)]
+//! pub struct Frame(Vec<u8>);
+//!
+//! impl InnerValidator for Frame {
+//!     type Inner = [u8];
+//!     type Error = &'static str;
+//!
+//!     fn validate(inner: &[u8]) -> Result<(), Self::Error> {
+//!         if inner.len() <= 4 { Ok(()) } else { Err("frame too long") }
+//!     }
+//! }
+//!
+//! let frame = Frame::new(vec![1, 2, 3]).unwrap();
+//! let borrowed = FrameRef::from_inner(&[1, 2, 3]).unwrap();
+//! assert_eq!(frame, borrowed);
+//! assert!(FrameRef::from_inner(&[0; 8]).is_err());
+//! ```
+//!
+//! ### Paths
+//!
+//! The `path` parameter is shorthand for `ref_inner = "std::path::Path"`. When the struct
+//! declares no field, the owned type wraps a `PathBuf`. Path braids also get `join()` and
+//! `display()` on the borrowed type and `AsRef<OsStr>` on both types. Adding `relative` or
+//! `absolute` rejects other paths with a [`PathError`], without writing a validator.
+//!
+//! ```
+//! use std::path::{Path, PathBuf};
+//! use aliri_braid::{braid, PathError};
+//!
+//! #[braid(path, relative)]
+//! pub struct AssetPathBuf;
+//!
+//! let asset = AssetPathBuf::new(PathBuf::from("images/logo.png")).unwrap();
+//! assert_eq!(Path::new("static/images/logo.png"), Path::new("static").join(&*asset));
+//! assert_eq!(PathBuf::from("images/logo.png/2x"), asset.join("2x"));
+//! assert_eq!(
+//!     Err(PathError::NotRelative),
+//!     AssetPath::from_inner(Path::new("/etc/passwd")).map(drop),
+//! );
+//! ```
+//!
+//! ### C strings
+//!
+//! The `c_str` parameter is shorthand for `ref_inner = "core::ffi::CStr"`, for identifiers that
+//! cross an FFI boundary. When the struct declares no field, the owned type wraps a `CString`.
+//! The borrowed type gets `as_ptr()`, `to_bytes()`, and `to_str()`, and the owned type can be
+//! parsed from a `str`, failing with a [`CStringError`] if the string has an interior nul byte.
+//!
+//! ```
+//! use aliri_braid::braid;
+//!
+//! #[braid(c_str)]
+//! pub struct SymbolName;
+//!
+//! let symbol: SymbolName = "sqlite3_open".parse().unwrap();
+//! assert_eq!(b"sqlite3_open", symbol.to_bytes());
+//! assert!(!symbol.as_ptr().is_null());
+//! assert!("sqlite3\0open".parse::<SymbolName>().is_err());
+//! ```
+//!
+//! # `no_std` support
+//!
+//! Braids can be implemented in `no_std` environments with `alloc`. By adding the
+//! `no_std` parameter to the macro, all impls will reference the `core` or `alloc`
+//! crates instead of the `std` crate, as appropriate.
+//!
+//! ```
+//! extern crate alloc;
+//!
+//! use aliri_braid::braid;
+//! use alloc::string::String;
+//!
+//! #[braid(no_std)]
+//! pub struct NoStdLibWrapper;
+//! #
+//! # fn main() {}
+//! ```
+//!
+//! Some targets provide `alloc` without atomics, and so lack `alloc::sync::Arc`. The conversion
+//! from the borrowed type into `Arc` can be dropped on such targets with `omit(arc)`, and the
+//! conversion into `Rc` with `omit(rc)`.
+//!
+//! ```
+//! extern crate alloc;
+//!
+//! use aliri_braid::braid;
+//! use alloc::string::String;
+//!
+//! #[braid(no_std, omit(arc))]
+//! pub struct NoAtomicsWrapper;
+//! #
+//! # fn main() {}
+//! ```
+//!
+//! In environments without an allocator, `braid_ref` can be used to create a
+//! reference-only braid. In order to remove the `alloc` dependency in `aliri_braid`,
+//! specify `default-features = "false"` in the `Cargo.toml` file. Reference-only braids
+//! provide the same `from_static()` constructor as the borrowed form of a full braid, which
+//! is `const` for braids without a validator and panics on invalid values otherwise.
+//!
+//! ```
+//! use aliri_braid::braid_ref;
+//!
+//! #[braid_ref(no_std)]
+//! pub struct NoStdValue;
+//!
+//! const DEFAULT_VALUE: &NoStdValue = NoStdValue::from_static("default");
+//! #
+//! # fn main() {}
+//! ```
+//!
+//! A reference-only braid can also be paired with an owned type defined elsewhere, such as
+//! in another module or a dependency. The `owned = "Type"` parameter generates the
+//! `ToOwned` implementation, comparisons, and `serde` glue for that type without defining
+//! it. The owned type is only expected to provide a `new()` constructor (or the unchecked
+//! constructor, for validated braids) and an `as_str()` accessor. Because the owned type may
+//! be foreign to the crate, comparisons between it and `Cow` are not generated, and neither is
+//! the `Deserialize` implementation for `Box`.
+//!
+//! ```
+//! use aliri_braid::braid_ref;
+//!
+//! #[derive(Debug, PartialEq, Eq)]
+//! pub struct Label(String);
+//!
+//! impl Label {
+//!     pub fn new(raw: String) -> Self {
+//!         Self(raw)
+//!     }
+//!
+//!     pub fn as_str(&self) -> &str {
+//!         &self.0
+//!     }
+//! }
+//!
+//! #[braid_ref(owned = "Label")]
+//! pub struct LabelRef;
+//!
+//! let label: Label = LabelRef::from_static("urgent").to_owned();
+//! assert_eq!(label, LabelRef::from_static("urgent"));
+//! ```
+//!
+//! An owned type that cannot be changed, such as an identifier type from another crate, may
+//! not offer those methods. The `to_owned = "path"` and `as_str = "path"` parameters name
+//! functions to use in their place, allowing a braid to be introduced alongside such a type
+//! during a migration. As the foreign type makes no promises about its contents, borrowing
+//! a validated braid from it checks the value and panics if it is invalid.
+//!
+//! ```
+//! use std::borrow::Borrow;
+//! use aliri_braid::braid_ref;
+//!
+//! #[braid_ref(
+//!     owned = "String",
+//!     to_owned = "account_to_string",
+//!     as_str = "String::as_str",
+//!     non_empty,
+//! )]
+//! pub struct AccountRef;
+//!
+//! fn account_to_string(value: &AccountRef) -> String {
+//!     value.as_str().to_owned()
+//! }
+//!
+//! let legacy = String::from("acct-42");
+//! let account: &AccountRef = legacy.borrow();
+//! assert_eq!(legacy, account);
+//!
+//! let owned: String = AccountRef::from_static("acct-42").to_owned();
+//! assert_eq!(owned, legacy);
+//! ```
+//!
+//! Without any owned counterpart, the `alloc` parameter still provides conversions from the
+//! reference-only braid into `Box`, `Rc`, and `Arc`, along with a `Deserialize` implementation
+//! for `Box` that checks the deserialized string in place. When combined with `no_std`, the
+//! crate must declare `extern crate alloc`.
+//!
+//! ```
+//! use std::sync::Arc;
+//! use aliri_braid::braid_ref;
+//!
+//! #[braid_ref(alloc)]
+//! pub struct Topic;
+//!
+//! let shared: Arc<Topic> = Topic::from_static("updates").into();
+//! assert_eq!(shared.as_str(), "updates");
+//! ```
+//!
+//! ## Standard library facades
+//!
+//! Some build environments alias the standard library through a facade crate. The
+//! `std_path = "my_std"` parameter redirects every generated `::std`, `::core`, or `::alloc`
+//! path through `::my_std` instead. The facade must be available in the extern prelude and
+//! re-export the standard library's API under the usual module names. This parameter cannot
+//! be combined with `no_std`.
+//!
+//! ```
+//! extern crate std as my_std;
+//!
+//! use aliri_braid::braid;
+//!
+//! #[braid(std_path = "my_std", serde)]
+//! pub struct FacadeWrapper;
+//! #
+//! # fn main() {}
+//! ```
+//!
+//! Generated code refers to this crate as `::aliri_braid`. When the crate is renamed in
+//! `Cargo.toml` or re-exported through another crate, the `crate = "..."` parameter names the
+//! path to use instead, in the same manner as `serde`'s parameter of the same name.
+//!
+//! ```
+//! mod facade {
+//!     pub(crate) use aliri_braid as braid;
+//! }
+//!
+//! #[facade::braid::braid(crate = "facade::braid", max_len = 8)]
+//! pub struct ReexportedWrapper;
+//! #
+//! # fn main() {}
+//! ```
+//!
+//! # Runtime registry
+//!
+//! With the `registry` feature enabled, every braid registers a `BraidInfo` describing
+//! its type names, the module in which it was declared, how it is checked, and any
+//! [declarative constraints](#declarative-constraints). Registered braids can be enumerated
+//! at runtime with `braids()`, which is useful for generating documentation or validation
+//! catalogs without maintaining a manual list. Registration is handled by the [`inventory`]
+//! crate and only works on the platforms that it supports.
+//!
+//! [`inventory`]: https://docs.rs/inventory/*/inventory/
+//!
+//! ```
+//! # use aliri_braid::braid;
+//! #
+//! #[braid(max_len = 64)]
+//! pub struct DatabaseName;
+//!
+//! # #[cfg(feature = "registry")]
+//! for info in aliri_braid::braids() {
+//!     println!("{}: {:?}", info.ref_name(), info.constraints());
+//! }
 //! ```
 //!
 //! # Safety
@@ -892,6 +2789,76 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+mod arena;
+#[cfg(feature = "axum")]
+mod axum;
+#[cfg(feature = "bson")]
+mod bson;
+mod bytes;
+mod case;
+mod case_insensitive;
+#[cfg(feature = "serde")]
+mod collect;
+mod constraint;
+mod format;
+mod forms;
+mod inner;
+#[cfg(feature = "alloc")]
+mod interner;
+mod literal;
+#[cfg(feature = "alloc")]
+mod nested;
+mod ordering;
+#[cfg(feature = "regex")]
+mod pattern;
+#[cfg(feature = "prost")]
+mod prost;
+mod provenance;
+#[cfg(feature = "registry")]
+mod registry;
+mod repr;
+#[cfg(feature = "serde_with")]
+mod serde_with;
+mod testing;
+#[cfg(feature = "unicode")]
+mod unicode;
+
+pub use arena::Arena;
+#[cfg(feature = "axum")]
+pub use axum::Rejection;
+pub use bytes::FromBytesError;
+pub use case::Case;
+#[cfg(feature = "alloc")]
+pub use case::{CamelCase, KebabCase, SnakeCase};
+#[cfg(feature = "serde")]
+pub use collect::{deserialize_collecting, Checked, CollectError, FieldError};
+pub use constraint::{Charset, Constraint, ConstraintError};
+#[cfg(feature = "idna")]
+pub use format::IdnaFormat;
+#[cfg(feature = "uri")]
+pub use format::UriFormat;
+#[cfg(feature = "uuid")]
+pub use format::UuidFormat;
+pub use format::{EmailFormat, Format, HostnameFormat};
+#[cfg(feature = "alloc")]
+pub use inner::CStringError;
+pub use inner::{InnerValidator, PathError};
+#[cfg(feature = "alloc")]
+pub use interner::{Intern, Interner};
+pub use provenance::Provenance;
+#[cfg(feature = "registry")]
+pub use registry::{braids, BraidInfo, CheckMode};
+
+#[cfg(feature = "bson")]
+pub use crate::bson::BsonError;
+#[cfg(feature = "prost")]
+pub use crate::prost::{
+    from_proto_optional, from_proto_repeated, into_proto_optional, into_proto_repeated,
+    BraidMessage,
+};
+#[cfg(feature = "serde_with")]
+pub use crate::serde_with::BraidAs;
+
 /// A validator that can verify a given input is valid given certain preconditions
 ///
 /// If the type can be normalized, this implementation should also validate that
@@ -922,7 +2889,58 @@ pub trait Normalizer: Validator {
     /// # Errors
     ///
     /// Returns an error if the string is invalid and cannot be normalized.
-    fn normalize(raw: &str) -> Result<::alloc::borrow::Cow<str>, Self::Error>;
+    fn normalize(raw: &str) -> Result<::alloc::borrow::Cow<'_, str>, Self::Error>;
+
+    /// Validates and normalizes an owned input, reusing its allocation where
+    /// possible
+    ///
+    /// The owned constructors of braids wrapping a `String` use this method.
+    /// The default implementation delegates to [`normalize()`][Self::normalize],
+    /// returning the input unchanged if it is already normalized. Normalizers
+    /// that can modify a value in place should override it to avoid allocating
+    /// a second time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is invalid and cannot be normalized.
+    fn normalize_owned(
+        raw: ::alloc::string::String,
+    ) -> Result<::alloc::string::String, Self::Error> {
+        let normalized = match Self::normalize(&raw)? {
+            ::alloc::borrow::Cow::Borrowed(_) => None,
+            ::alloc::borrow::Cow::Owned(normalized) => Some(normalized),
+        };
+
+        Ok(normalized.unwrap_or(raw))
+    }
+}
+
+/// The owned form of a braid
+///
+/// Implemented for the owned type of every braid generated with `braid`, this
+/// trait allows code to be written generically over braids, such as helpers
+/// that accept any braid and work with its borrowed form. The trait is sealed,
+/// and is only implemented by the macros.
+pub trait Braid: __private::Sealed {
+    /// The borrowed form of the braid
+    type Ref: ?Sized + BraidRef;
+
+    /// Borrows the value as its borrowed form
+    fn as_braid_ref(&self) -> &Self::Ref;
+
+    /// Provides access to the underlying value as a string slice
+    fn as_str(&self) -> &str {
+        self.as_braid_ref().as_str()
+    }
+}
+
+/// The borrowed form of a braid
+///
+/// Implemented for the borrowed type of every braid, including those generated
+/// with `braid_ref`. The trait is sealed, and is only implemented by the macros.
+pub trait BraidRef: __private::Sealed {
+    /// Provides access to the underlying value as a string slice
+    fn as_str(&self) -> &str;
 }
 
 /// Utility macro for easily defining `From<Infallible>` for a given type.
@@ -954,4 +2972,45 @@ macro_rules! from_infallible {
     };
 }
 
-pub use aliri_braid_impl::{braid, braid_ref};
+#[cfg(not(feature = "registry"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __register_braid {
+    ($info:expr) => {};
+}
+
+#[doc(hidden)]
+pub mod __private {
+    /// Restricts implementations of [`Braid`][crate::Braid] and
+    /// [`BraidRef`][crate::BraidRef] to types generated by the macros
+    pub trait Sealed {}
+
+    #[cfg(feature = "bson")]
+    pub use ::bson::Bson;
+    #[cfg(feature = "bytes")]
+    pub use bytes::Bytes;
+    #[cfg(feature = "registry")]
+    pub use inventory;
+    #[cfg(feature = "uuid")]
+    pub use uuid::{Error as UuidError, Uuid};
+
+    #[cfg(feature = "unicode")]
+    pub use crate::case_insensitive::UnicodeCaseInsensitive;
+    #[cfg(feature = "alloc")]
+    pub use crate::nested::{nested_field, Nested};
+    #[cfg(feature = "regex")]
+    pub use crate::pattern::Pattern;
+    #[cfg(feature = "unicode")]
+    pub use crate::unicode::{grapheme_len, grapheme_prefix};
+    pub use crate::{
+        case_insensitive::{AsciiCaseInsensitive, CaseInsensitive},
+        forms::eq,
+        literal::{char_count, ends_with, starts_with},
+        ordering::{case_insensitive_cmp, natural_cmp},
+        provenance::Tracked,
+        repr::{assert_same_repr, Repr},
+        testing::hash_of,
+    };
+}
+
+pub use aliri_braid_impl::{braid, braid_many, braid_ref};
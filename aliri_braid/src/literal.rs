@@ -0,0 +1,107 @@
+/// Constructs a braid from a string literal that is checked at compile time
+///
+/// The literal is checked against the braid's declarative constraints during
+/// constant evaluation, so an invalid literal fails to compile rather than
+/// panicking at runtime. The macro accepts either the owned or the borrowed
+/// type of any braid that has no validator or normalizer of its own, and
+/// evaluates to the same value as calling `from_static()` on that type.
+///
+/// # Example
+///
+/// ```
+/// use aliri_braid::{braid, braid_lit};
+///
+/// #[braid(min_len = 1, max_len = 16, prefix = "usr_")]
+/// pub struct UserId;
+///
+/// let owned: UserId = braid_lit!(UserId, "usr_1234");
+/// let borrowed: &UserIdRef = braid_lit!(UserIdRef, "usr_1234");
+/// assert_eq!(owned, borrowed);
+/// ```
+///
+/// ```compile_fail
+/// # use aliri_braid::{braid, braid_lit};
+/// #
+/// # #[braid(min_len = 1, max_len = 16, prefix = "usr_")]
+/// # pub struct UserId;
+/// #
+/// // This literal is missing the required prefix
+/// let owned: UserId = braid_lit!(UserId, "1234");
+/// ```
+#[macro_export]
+macro_rules! braid_lit {
+    ($ty:ty, $lit:literal) => {{
+        const LITERAL: &'static str = {
+            if !<$ty>::__check_literal($lit) {
+                ::core::panic!(
+                    "{}",
+                    ::core::concat!(
+                        "invalid literal ",
+                        ::core::stringify!($lit),
+                        " for `",
+                        ::core::stringify!($ty),
+                        "`",
+                    )
+                );
+            }
+            $lit
+        };
+        <$ty>::from_static(LITERAL)
+    }};
+}
+
+/// Determines whether `raw` starts with `prefix` during constant evaluation
+#[must_use]
+pub const fn starts_with(raw: &str, prefix: &str) -> bool {
+    let (raw, prefix) = (raw.as_bytes(), prefix.as_bytes());
+    if raw.len() < prefix.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < prefix.len() {
+        if raw[i] != prefix[i] {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+/// Counts the `char`s in `raw` during constant evaluation
+#[must_use]
+pub const fn char_count(raw: &str) -> usize {
+    let raw = raw.as_bytes();
+    let mut count = 0;
+    let mut i = 0;
+    while i < raw.len() {
+        // Every `char` has exactly one byte that is not a continuation byte
+        if raw[i] & 0xC0 != 0x80 {
+            count += 1;
+        }
+        i += 1;
+    }
+
+    count
+}
+
+/// Determines whether `raw` ends with `suffix` during constant evaluation
+#[must_use]
+pub const fn ends_with(raw: &str, suffix: &str) -> bool {
+    let (raw, suffix) = (raw.as_bytes(), suffix.as_bytes());
+    if raw.len() < suffix.len() {
+        return false;
+    }
+
+    let offset = raw.len() - suffix.len();
+    let mut i = 0;
+    while i < suffix.len() {
+        if raw[offset + i] != suffix[i] {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
@@ -0,0 +1,53 @@
+use alloc::string::String;
+use core::{convert::TryFrom, marker::PhantomData, str::FromStr};
+
+use crate::{Braid, Validator};
+
+/// The validator of a braid declared with `nested`, which wraps another braid
+///
+/// A value is checked by the inner braid `T` first, and then by the validator
+/// `V` named on the outer braid, or `()` if none. Inner braids that normalize
+/// their values only accept values already in normalized form.
+#[derive(Debug)]
+pub struct Nested<T, V = ()>(PhantomData<fn() -> (T, V)>);
+
+impl<T> Validator for Nested<T>
+where
+    T: Braid + FromStr,
+    for<'a> &'a T::Ref: TryFrom<&'a str, Error = T::Err>,
+{
+    type Error = T::Err;
+
+    #[inline]
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        <&T::Ref>::try_from(raw)?;
+        Ok(())
+    }
+}
+
+impl<T, V> Validator for Nested<T, V>
+where
+    T: Braid + FromStr,
+    for<'a> &'a T::Ref: TryFrom<&'a str, Error = T::Err>,
+    V: Validator,
+    V::Error: From<T::Err>,
+{
+    type Error = V::Error;
+
+    #[inline]
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        <&T::Ref>::try_from(raw)?;
+        V::validate(raw)
+    }
+}
+
+/// Constructs the inner braid of a nested braid from a value that has already
+/// been checked by [`Nested`]
+#[inline]
+#[track_caller]
+pub fn nested_field<T: TryFrom<String>>(raw: impl Into<String>) -> T {
+    match T::try_from(raw.into()) {
+        Ok(value) => value,
+        Err(_) => unreachable!("the value was checked by the inner braid"),
+    }
+}
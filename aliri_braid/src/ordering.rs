@@ -0,0 +1,67 @@
+use core::cmp::Ordering;
+
+/// Orders two values ignoring case, used by braids declared with
+/// `ord = "case_insensitive"`
+///
+/// Values differing only in case are ordered by their exact contents, so that
+/// the ordering remains consistent with the derived `Eq`.
+pub fn case_insensitive_cmp(a: &str, b: &str) -> Ordering {
+    let folded_a = a.chars().flat_map(char::to_lowercase);
+    let folded_b = b.chars().flat_map(char::to_lowercase);
+    folded_a.cmp(folded_b).then_with(|| a.cmp(b))
+}
+
+/// Orders two values treating runs of ASCII digits as numbers, used by braids
+/// declared with `ord = "natural"`
+///
+/// `item2` is thus ordered before `item10`. Values that differ only in
+/// leading zeros are ordered by their exact contents, so that the ordering
+/// remains consistent with the derived `Eq`.
+pub fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let mut rest_a = a.as_bytes();
+    let mut rest_b = b.as_bytes();
+
+    loop {
+        match (rest_a.first(), rest_b.first()) {
+            (None, None) => return a.cmp(b),
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let (digits_a, tail_a) = split_digits(rest_a);
+                let (digits_b, tail_b) = split_digits(rest_b);
+                let ordering = cmp_digits(digits_a, digits_b);
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+                rest_a = tail_a;
+                rest_b = tail_b;
+            }
+            (Some(x), Some(y)) => {
+                if x != y {
+                    // Comparing bytes orders UTF-8 encoded text by code point
+                    return x.cmp(y);
+                }
+                rest_a = &rest_a[1..];
+                rest_b = &rest_b[1..];
+            }
+        }
+    }
+}
+
+/// Splits a leading run of ASCII digits from the rest of the value
+fn split_digits(s: &[u8]) -> (&[u8], &[u8]) {
+    let end = s
+        .iter()
+        .position(|b| !b.is_ascii_digit())
+        .unwrap_or(s.len());
+    s.split_at(end)
+}
+
+/// Compares two runs of ASCII digits by their numeric value, regardless of how
+/// many digits they have
+fn cmp_digits(a: &[u8], b: &[u8]) -> Ordering {
+    let trim = |s: &[u8]| -> usize { s.iter().position(|&b| b != b'0').unwrap_or(s.len()) };
+    let a = &a[trim(a)..];
+    let b = &b[trim(b)..];
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
@@ -0,0 +1,91 @@
+use alloc::boxed::Box;
+use core::fmt;
+
+use once_cell::race::OnceBox;
+use regex_automata::{
+    dfa::{dense, Automaton},
+    util::primitives::StateID,
+    Anchored, Input, MatchKind,
+};
+
+/// A regular expression checked by a synthesized `pattern` constraint
+///
+/// The expression is compiled into a DFA on first use, so that it can be held
+/// in a `static`. It has already been checked to compile during macro
+/// expansion.
+pub struct Pattern {
+    source: &'static str,
+    dfa: OnceBox<dense::DFA<alloc::vec::Vec<u32>>>,
+}
+
+impl Pattern {
+    /// Constructs a pattern from the source of a regular expression
+    #[must_use]
+    pub const fn new(source: &'static str) -> Self {
+        Self {
+            source,
+            dfa: OnceBox::new(),
+        }
+    }
+
+    /// Checks that the entire value of `raw` matches the pattern
+    ///
+    /// On failure, returns the byte position at which matching failed: the
+    /// end of the longest prefix of `raw` that could still be extended into a
+    /// match, rounded down to a `char` boundary.
+    pub fn check(&self, raw: &str) -> Result<(), usize> {
+        let dfa = self.dfa.get_or_init(|| {
+            // Every match is tracked, so that a higher-priority alternative matching a prefix of
+            // the value does not hide a lower-priority alternative that matches all of it
+            let dfa = dense::Builder::new()
+                .configure(dense::Config::new().match_kind(MatchKind::All))
+                .build(self.source)
+                .expect("pattern is checked during expansion");
+            Box::new(dfa)
+        });
+
+        let input = Input::new(raw).anchored(Anchored::Yes);
+        let mut state = dfa.start_state_forward(&input).map_err(|_| 0_usize)?;
+        for (i, &b) in raw.as_bytes().iter().enumerate() {
+            let previous = state;
+            state = dfa.next_state(state, b);
+            if dfa.is_dead_state(state) || dfa.is_quit_state(state) {
+                return Err(failure_position(dfa, raw, i, previous));
+            }
+        }
+
+        if dfa.is_match_state(dfa.next_eoi_state(state)) {
+            Ok(())
+        } else {
+            Err(failure_position(dfa, raw, raw.len(), state))
+        }
+    }
+}
+
+/// Determines the position at which matching failed, given that no match
+/// can be found after consuming the byte at `position`
+///
+/// Matches are reported by the DFA one byte late, so the byte before
+/// `position` may already have ruled out a match while leading into a match
+/// state for a shorter prefix. In that case, matching failed at that byte.
+fn failure_position<A: Automaton>(dfa: &A, raw: &str, position: usize, before: StateID) -> usize {
+    let doomed = position > 0
+        && dfa.is_match_state(before)
+        && !dfa.is_match_state(dfa.next_eoi_state(before))
+        && (0..=u8::MAX).all(|b| dfa.is_dead_state(dfa.next_state(before, b)));
+
+    floor_char_boundary(raw, if doomed { position - 1 } else { position })
+}
+
+fn floor_char_boundary(raw: &str, mut position: usize) -> usize {
+    while !raw.is_char_boundary(position) {
+        position -= 1;
+    }
+    position
+}
+
+impl fmt::Debug for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Pattern").field(&self.source).finish()
+    }
+}
@@ -0,0 +1,150 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::convert::TryFrom;
+
+use ::prost::{
+    bytes::{Buf, BufMut},
+    encoding::{self, DecodeContext, WireType},
+    DecodeError, Message,
+};
+
+use crate::Braid;
+
+/// The field number of the value within a `google.protobuf.StringValue`
+const VALUE_TAG: u32 = 1;
+
+/// Converts an optional `prost` string field into an optional braid
+///
+/// Proto3 does not distinguish an unset string field from an empty one, so an
+/// empty string is treated as absent rather than being checked by the braid.
+pub fn from_proto_optional<T: TryFrom<String>>(raw: String) -> Result<Option<T>, T::Error> {
+    if raw.is_empty() {
+        Ok(None)
+    } else {
+        T::try_from(raw).map(Some)
+    }
+}
+
+/// Converts an optional braid into a `prost` string field
+///
+/// An absent value is represented by the empty string.
+pub fn into_proto_optional<T: Into<String>>(value: Option<T>) -> String {
+    value.map(Into::into).unwrap_or_default()
+}
+
+/// Converts a repeated `prost` string field into braids, failing on the first invalid value
+pub fn from_proto_repeated<T: TryFrom<String>>(raw: Vec<String>) -> Result<Vec<T>, T::Error> {
+    raw.into_iter().map(T::try_from).collect()
+}
+
+/// Converts braids into a repeated `prost` string field
+pub fn into_proto_repeated<T: Into<String>>(values: Vec<T>) -> Vec<String> {
+    values.into_iter().map(Into::into).collect()
+}
+
+/// A `prost` message carrying a single braid
+///
+/// `BraidMessage<T>` has the same encoding as `google.protobuf.StringValue`, so it
+/// can be used as the type of a message field where the schema declares a
+/// `StringValue`. Decoding checks the value with the braid's `TryFrom<String>`
+/// implementation, and fails with the braid's error message if it is invalid.
+///
+/// As with `StringValue`, an empty string is not encoded, and decodes as an absent
+/// value.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BraidMessage<T>(Option<T>);
+
+impl<T> BraidMessage<T> {
+    /// Wraps a braid in a message
+    pub fn new(value: T) -> Self {
+        Self(Some(value))
+    }
+
+    /// The braid carried by the message, if any
+    pub fn get(&self) -> Option<&T> {
+        self.0.as_ref()
+    }
+
+    /// Unwraps the braid carried by the message, if any
+    pub fn into_inner(self) -> Option<T> {
+        self.0
+    }
+}
+
+impl<T> Default for BraidMessage<T> {
+    fn default() -> Self {
+        Self(None)
+    }
+}
+
+impl<T> From<T> for BraidMessage<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> From<Option<T>> for BraidMessage<T> {
+    fn from(value: Option<T>) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> BraidMessage<T>
+where
+    T: Braid,
+{
+    fn value(&self) -> Option<&str> {
+        self.0.as_ref().map(Braid::as_str).filter(|s| !s.is_empty())
+    }
+}
+
+impl<T> Message for BraidMessage<T>
+where
+    T: Braid + TryFrom<String> + Send + Sync,
+    T::Error: ToString,
+{
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        if let Some(value) = self.value() {
+            encoding::encode_key(VALUE_TAG, WireType::LengthDelimited, buf);
+            encoding::encode_varint(value.len() as u64, buf);
+            buf.put_slice(value.as_bytes());
+        }
+    }
+
+    fn merge_field(
+        &mut self,
+        tag: u32,
+        wire_type: WireType,
+        buf: &mut impl Buf,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError> {
+        if tag != VALUE_TAG {
+            return encoding::skip_field(wire_type, tag, buf, ctx);
+        }
+
+        let mut raw = String::new();
+        encoding::string::merge(wire_type, &mut raw, buf, ctx)?;
+        self.0 = from_proto_optional::<T>(raw).map_err(|err| invalid(err.to_string()))?;
+        Ok(())
+    }
+
+    fn encoded_len(&self) -> usize {
+        self.value().map_or(0, |value| {
+            encoding::key_len(VALUE_TAG)
+                + encoding::encoded_len_varint(value.len() as u64)
+                + value.len()
+        })
+    }
+
+    fn clear(&mut self) {
+        self.0 = None;
+    }
+}
+
+/// `prost` offers no other way to report a custom decoding failure
+#[allow(deprecated)]
+fn invalid(description: String) -> DecodeError {
+    DecodeError::new(description)
+}
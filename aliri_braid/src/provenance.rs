@@ -0,0 +1,52 @@
+use core::{
+    hash::{Hash, Hasher},
+    panic::Location,
+};
+
+/// How an owned value was created, as recorded in debug builds by braids declared with
+/// `provenance`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Provenance {
+    /// Created through a checked constructor, or derived from a value that was already checked
+    Checked,
+    /// Created through an unchecked constructor, called at the given location
+    Unchecked(&'static Location<'static>),
+}
+
+impl Provenance {
+    /// Whether the value was created through a checked constructor
+    #[inline]
+    #[must_use]
+    pub const fn is_checked(self) -> bool {
+        matches!(self, Self::Checked)
+    }
+}
+
+/// The provenance carried by an owned value, which is ignored when comparing or hashing values
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug)]
+pub struct Tracked(pub Provenance);
+
+impl Tracked {
+    /// Records the location from which an unchecked constructor was called
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub const fn unchecked() -> Self {
+        Self(Provenance::Unchecked(Location::caller()))
+    }
+}
+
+impl PartialEq for Tracked {
+    #[inline]
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for Tracked {}
+
+impl Hash for Tracked {
+    #[inline]
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
@@ -0,0 +1,90 @@
+use crate::Constraint;
+
+/// How values of a registered braid are checked on construction
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CheckMode {
+    /// Any string value is accepted
+    None,
+    /// Values must conform to a [`Validator`][crate::Validator]
+    Validate,
+    /// Values are normalized by a [`Normalizer`][crate::Normalizer]
+    Normalize,
+}
+
+/// Metadata describing a braid, collected into a global registry
+///
+/// Every braid registers an entry when the `registry` feature is enabled.
+/// Registered braids can be enumerated at runtime with [`braids()`].
+#[derive(Debug)]
+pub struct BraidInfo {
+    owned_name: Option<&'static str>,
+    ref_name: &'static str,
+    module_path: &'static str,
+    check_mode: CheckMode,
+    constraints: &'static [Constraint],
+}
+
+impl BraidInfo {
+    #[doc(hidden)]
+    pub const fn new(
+        owned_name: Option<&'static str>,
+        ref_name: &'static str,
+        module_path: &'static str,
+        check_mode: CheckMode,
+        constraints: &'static [Constraint],
+    ) -> Self {
+        Self {
+            owned_name,
+            ref_name,
+            module_path,
+            check_mode,
+            constraints,
+        }
+    }
+
+    /// The name of the owned type, if one was generated
+    pub fn owned_name(&self) -> Option<&'static str> {
+        self.owned_name
+    }
+
+    /// The name of the borrowed type
+    pub fn ref_name(&self) -> &'static str {
+        self.ref_name
+    }
+
+    /// The path of the module in which the braid was declared
+    pub fn module_path(&self) -> &'static str {
+        self.module_path
+    }
+
+    /// How values of the braid are checked on construction
+    pub fn check_mode(&self) -> CheckMode {
+        self.check_mode
+    }
+
+    /// The declarative constraints placed on the braid
+    pub fn constraints(&self) -> &'static [Constraint] {
+        self.constraints
+    }
+}
+
+inventory::collect!(BraidInfo);
+
+/// Iterates over all braids registered in the current program
+///
+/// The order in which braids are yielded is unspecified.
+pub fn braids() -> impl Iterator<Item = &'static BraidInfo> {
+    inventory::iter::<BraidInfo>.into_iter()
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __register_braid {
+    ($info:expr) => {
+        #[allow(unsafe_code)]
+        const _: () = {
+            $crate::__private::inventory::submit! { $info }
+        };
+    };
+}
@@ -0,0 +1,78 @@
+use crate::Constraint;
+
+/// The representation of an owned braid that declares `same_repr_as`
+///
+/// Two braids share a representation when they wrap the same field type,
+/// are checked by the same validator or normalizer, and declare the same
+/// constraints. A value of one is then always a valid value of the other.
+///
+/// Patterns are compared by their source, so two patterns that match the
+/// same values but are written differently are not considered the same.
+pub trait Repr: Sized {
+    /// The type of the wrapped field
+    type Field;
+
+    /// The validator or normalizer named on the braid, or `()` if none
+    type Check;
+
+    /// The constraints declared on the braid
+    const CONSTRAINTS: &'static [Constraint];
+
+    /// Unwraps the field of the braid
+    fn into_repr(self) -> Self::Field;
+}
+
+/// Asserts during constant evaluation that two braids share a representation
+pub const fn assert_same_repr<A, B>()
+where
+    A: Repr,
+    B: Repr<Field = A::Field, Check = A::Check>,
+{
+    assert!(
+        same_constraints(A::CONSTRAINTS, B::CONSTRAINTS),
+        "braids declared with `same_repr_as` must declare the same constraints"
+    );
+}
+
+/// Determines whether two sets of constraints are the same, regardless of
+/// the order in which they were declared
+const fn same_constraints(a: &[Constraint], b: &[Constraint]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < a.len() {
+        let mut found = false;
+        let mut j = 0;
+        while j < b.len() {
+            found |= same_constraint(&a[i], &b[j]);
+            j += 1;
+        }
+
+        if !found {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+const fn same_constraint(a: &Constraint, b: &Constraint) -> bool {
+    match (a, b) {
+        (Constraint::MinLength(a), Constraint::MinLength(b))
+        | (Constraint::MaxLength(a), Constraint::MaxLength(b))
+        | (Constraint::MinChars(a), Constraint::MinChars(b))
+        | (Constraint::MaxChars(a), Constraint::MaxChars(b)) => *a == *b,
+        (Constraint::Prefix(a), Constraint::Prefix(b))
+        | (Constraint::Suffix(a), Constraint::Suffix(b))
+        | (Constraint::Pattern(a), Constraint::Pattern(b)) => {
+            a.len() == b.len() && crate::literal::starts_with(a, b)
+        }
+        (Constraint::Case(a), Constraint::Case(b)) => *a as u8 == *b as u8,
+        (Constraint::NonEmpty, Constraint::NonEmpty) => true,
+        (Constraint::Charset(a), Constraint::Charset(b)) => *a as u8 == *b as u8,
+        _ => false,
+    }
+}
@@ -0,0 +1,51 @@
+use core::{fmt, marker::PhantomData, str::FromStr};
+
+use ::serde_with::{DeserializeAs, SerializeAs};
+use serde::{de, Deserializer, Serializer};
+
+use crate::Braid;
+
+/// A `serde_with` adapter that serializes a braid as a string
+///
+/// Use `BraidAs` in a `#[serde_as]` annotation to serialize owned braids wherever
+/// `serde_with` accepts an adapter, such as the elements of a `Vec<_>` or the keys
+/// of a `HashMap<_, _>`. The braid is serialized through its string value and
+/// deserialized through its `FromStr` implementation, so that braids without the
+/// `serde` parameter can be used, and invalid values are rejected with the braid's
+/// error message.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BraidAs;
+
+impl<T: Braid> SerializeAs<T> for BraidAs {
+    fn serialize_as<S: Serializer>(source: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(source.as_str())
+    }
+}
+
+impl<'de, T> DeserializeAs<'de, T> for BraidAs
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        deserializer.deserialize_str(BraidVisitor(PhantomData))
+    }
+}
+
+struct BraidVisitor<T>(PhantomData<T>);
+
+impl<T> de::Visitor<'_> for BraidVisitor<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a string")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        v.parse().map_err(E::custom)
+    }
+}
@@ -0,0 +1,201 @@
+use core::hash::{Hash, Hasher};
+
+/// Generates a module of unit tests checking the invariants every braid should uphold
+///
+/// The macro expands to a `#[cfg(test)]` module with the given name, containing
+/// tests that:
+///
+/// * check that a reference to the borrowed type has the same size and alignment as `&str`, and
+///   that `Option` can use its niche;
+/// * parse each `valid` value with `FromStr`, then check that the result parses again to an equal
+///   value, borrows as an equal value of the borrowed type, and hashes the same as its borrowed
+///   form, as required by `Borrow`;
+/// * check that each `invalid` value is rejected by both the owned and the borrowed type;
+/// * when a `serde` format is given, check that each valid value serializes to the same output from
+///   the owned and borrowed types and deserializes back to an equal value. The format is named by a
+///   path to a module providing `to_string` and `from_str` functions, such as `serde_json`.
+///
+/// The `invalid` and `serde` lines are optional. The braid must expose its
+/// underlying string, as the tests are written against the [`Braid`][crate::Braid]
+/// and [`BraidRef`][crate::BraidRef] traits. The module imports everything from
+/// its parent module.
+///
+/// # Example
+///
+/// ```
+/// use aliri_braid::{braid, braid_tests};
+///
+/// #[braid(serde, min_len = 1, max_len = 16)]
+/// pub struct Username;
+///
+/// braid_tests! {
+///     mod username_tests for Username => UsernameRef;
+///     valid = ["alice", "bob", "\"quoted\""];
+///     invalid = ["", "a much too long username"];
+///     serde = serde_json;
+/// }
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! braid_tests {
+    (
+        mod $name:ident for $owned:ty => $borrowed:ty;
+        valid = [$($valid:literal),* $(,)?];
+        $(invalid = [$($invalid:literal),* $(,)?];)?
+        $(serde = $($format:ident)::+;)?
+    ) => {
+        #[cfg(test)]
+        mod $name {
+            #[allow(unused_imports)]
+            use super::*;
+
+            const VALID: &[&str] = &[$($valid),*];
+
+            #[test]
+            fn layout() {
+                ::core::assert_eq!(
+                    ::core::mem::size_of::<&$borrowed>(),
+                    ::core::mem::size_of::<&str>(),
+                    "`&{}` should be the same size as `&str`",
+                    ::core::stringify!($borrowed),
+                );
+                ::core::assert_eq!(
+                    ::core::mem::align_of::<&$borrowed>(),
+                    ::core::mem::align_of::<&str>(),
+                    "`&{}` should have the same alignment as `&str`",
+                    ::core::stringify!($borrowed),
+                );
+                ::core::assert_eq!(
+                    ::core::mem::size_of::<::core::option::Option<&$borrowed>>(),
+                    ::core::mem::size_of::<&$borrowed>(),
+                    "`Option<&{}>` should use the niche of the reference",
+                    ::core::stringify!($borrowed),
+                );
+            }
+
+            fn parse_valid(raw: &str) -> $owned {
+                match <$owned as ::core::str::FromStr>::from_str(raw) {
+                    ::core::result::Result::Ok(value) => value,
+                    ::core::result::Result::Err(_) => ::core::panic!(
+                        "{:?} should be a valid `{}`",
+                        raw,
+                        ::core::stringify!($owned),
+                    ),
+                }
+            }
+
+            #[test]
+            fn valid_values() {
+                for &raw in VALID {
+                    let owned = parse_valid(raw);
+                    let value = $crate::Braid::as_str(&owned);
+
+                    let reparsed = parse_valid(value);
+                    ::core::assert!(
+                        owned == reparsed,
+                        "{:?} should parse to an equal value when parsed again",
+                        raw,
+                    );
+
+                    let borrowed =
+                        match <&$borrowed as ::core::convert::TryFrom<&str>>::try_from(value) {
+                            ::core::result::Result::Ok(borrowed) => borrowed,
+                            ::core::result::Result::Err(_) => ::core::panic!(
+                                "{:?} should be a valid `{}`",
+                                value,
+                                ::core::stringify!($borrowed),
+                            ),
+                        };
+                    ::core::assert_eq!($crate::BraidRef::as_str(borrowed), value);
+                    ::core::assert!(owned == borrowed, "{:?} should equal its borrowed form", raw);
+                    ::core::assert!(
+                        $crate::Braid::as_braid_ref(&owned) == borrowed,
+                        "{:?} should borrow as an equal value",
+                        raw,
+                    );
+                    ::core::assert_eq!(
+                        $crate::__private::hash_of(&owned),
+                        $crate::__private::hash_of(borrowed),
+                        "{:?} should hash the same as its borrowed form",
+                        raw,
+                    );
+                }
+            }
+
+            $(
+                const INVALID: &[&str] = &[$($invalid),*];
+
+                #[test]
+                fn invalid_values() {
+                    for &raw in INVALID {
+                        ::core::assert!(
+                            <$owned as ::core::str::FromStr>::from_str(raw).is_err(),
+                            "{:?} should not be a valid `{}`",
+                            raw,
+                            ::core::stringify!($owned),
+                        );
+                        ::core::assert!(
+                            <&$borrowed as ::core::convert::TryFrom<&str>>::try_from(raw).is_err(),
+                            "{:?} should not be a valid `{}`",
+                            raw,
+                            ::core::stringify!($borrowed),
+                        );
+                    }
+                }
+            )?
+
+            $(
+                #[test]
+                fn serde_roundtrip() {
+                    for &raw in VALID {
+                        let owned = parse_valid(raw);
+                        let borrowed = $crate::Braid::as_braid_ref(&owned);
+
+                        let serialized = $($format)::+::to_string(&owned)
+                            .expect("the owned value should serialize");
+                        let serialized_ref = $($format)::+::to_string(borrowed)
+                            .expect("the borrowed value should serialize");
+                        ::core::assert_eq!(
+                            serialized, serialized_ref,
+                            "{:?} should serialize the same from the owned and borrowed types",
+                            raw,
+                        );
+
+                        let deserialized: $owned = $($format)::+::from_str(&serialized)
+                            .expect("the serialized value should deserialize");
+                        ::core::assert!(
+                            owned == deserialized,
+                            "{:?} should deserialize to an equal value",
+                            raw,
+                        );
+                    }
+                }
+            )?
+        }
+    };
+}
+
+/// Hashes a value with a fixed hasher, so that the hashes of owned and borrowed
+/// forms can be compared without the standard library
+#[must_use]
+pub fn hash_of<T: Hash + ?Sized>(value: &T) -> u64 {
+    let mut hasher = Fnv(0xcbf2_9ce4_8422_2325);
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// The 64-bit FNV-1a hash
+struct Fnv(u64);
+
+impl Hasher for Fnv {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
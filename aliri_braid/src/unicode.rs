@@ -0,0 +1,20 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Counts the extended grapheme clusters in `raw`
+#[must_use]
+pub fn grapheme_len(raw: &str) -> usize {
+    raw.graphemes(true).count()
+}
+
+/// Returns the longest prefix of `raw` containing at most `n` extended
+/// grapheme clusters
+///
+/// The prefix always ends on a grapheme boundary, so combining marks,
+/// emoji modifiers, and other multi-scalar clusters are never split.
+#[must_use]
+pub fn grapheme_prefix(raw: &str, n: usize) -> &str {
+    match raw.grapheme_indices(true).nth(n) {
+        Some((end, _)) => &raw[..end],
+        None => raw,
+    }
+}
@@ -0,0 +1,83 @@
+use std::borrow::Cow;
+
+use aliri_braid::{braid, braid_ref, ConstraintError};
+use bumpalo::Bump;
+
+#[braid(arena)]
+pub struct Token;
+
+#[braid(arena, max_len = 4)]
+pub struct Short;
+
+#[braid(arena, normalizer)]
+pub struct Lower;
+
+impl aliri_braid::Validator for Lower {
+    type Error = ConstraintError;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        match raw.char_indices().find(|(_, c)| c.is_uppercase()) {
+            Some((idx, _)) => Err(ConstraintError::new(
+                raw,
+                aliri_braid::Constraint::MinLength(0),
+                idx,
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+impl aliri_braid::Normalizer for Lower {
+    fn normalize(raw: &str) -> Result<Cow<'_, str>, Self::Error> {
+        if raw.chars().any(char::is_uppercase) {
+            Ok(Cow::Owned(raw.to_lowercase()))
+        } else {
+            Ok(Cow::Borrowed(raw))
+        }
+    }
+}
+
+#[braid_ref(arena, min_len = 1)]
+pub struct Field;
+
+#[test]
+fn values_are_copied_into_the_arena() {
+    let arena = Bump::new();
+    let input = String::from("token");
+
+    let token = TokenRef::alloc_in(&arena, &input);
+    assert_eq!("token", token.as_str());
+    assert_ne!(input.as_ptr(), token.as_str().as_ptr());
+    assert!(arena.allocated_bytes() > 0);
+}
+
+#[test]
+fn values_are_checked_before_allocation() {
+    let arena = Bump::new();
+
+    assert_eq!("abc", ShortRef::alloc_in(&arena, "abc").unwrap().as_str());
+    assert!(ShortRef::alloc_in(&arena, "too long").is_err());
+
+    assert_eq!("x", Field::alloc_in(&arena, "x").unwrap().as_str());
+    assert!(Field::alloc_in(&arena, "").is_err());
+}
+
+#[test]
+fn normalized_values_are_copied_in_normal_form() {
+    let arena = Bump::new();
+    assert_eq!(
+        "mixed",
+        LowerRef::alloc_in(&arena, "MiXeD").unwrap().as_str()
+    );
+}
+
+#[test]
+fn many_values_share_the_arena() {
+    let arena = Bump::new();
+    let tokens: Vec<&TokenRef> = (0..100)
+        .map(|i| TokenRef::alloc_in(&arena, &i.to_string()))
+        .collect();
+
+    assert_eq!("42", tokens[42].as_str());
+    assert_eq!(Token::from_static("99"), tokens[99]);
+}
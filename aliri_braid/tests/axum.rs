@@ -0,0 +1,38 @@
+use aliri_braid::{braid, ConstraintError, Rejection};
+use axum_core::response::IntoResponse;
+use http::{header, StatusCode};
+
+#[braid(axum, max_len = 8)]
+pub struct Username;
+
+fn construct(raw: &str) -> Result<Username, Rejection<ConstraintError>> {
+    Ok(Username::new(raw.to_owned())?)
+}
+
+#[test]
+fn axum_braids_deserialize_like_route_parameters() {
+    let username: Username = serde_json::from_str("\"nobody\"").unwrap();
+    assert_eq!("nobody", username.as_str());
+
+    let err = serde_json::from_str::<Username>("\"much too long\"").unwrap_err();
+    assert!(err.to_string().contains("must be at most 8 bytes long"));
+}
+
+#[test]
+fn braid_errors_convert_into_rejections() {
+    assert!(construct("nobody").is_ok());
+
+    let rejection = construct("much too long").unwrap_err();
+    assert_eq!(8, rejection.error().position());
+    assert_eq!(rejection.error().to_string(), rejection.to_string());
+}
+
+#[test]
+fn rejections_respond_with_bad_request() {
+    let response = construct("much too long").unwrap_err().into_response();
+    assert_eq!(StatusCode::BAD_REQUEST, response.status());
+    assert_eq!(
+        "text/plain; charset=utf-8",
+        response.headers()[header::CONTENT_TYPE]
+    );
+}
@@ -3,6 +3,8 @@ use aliri_braid::braid;
 /// A basic example of a wrapper around a [`String`]
 #[braid(
     serde,
+    iter,
+    add,
     ref_doc = "A borrowed reference to a basic string slice wrapper"
 )]
 pub struct BasicExampleBuf;
@@ -91,4 +93,30 @@ mod tests {
         let owned = BasicExample::from_str("Testing the Buffer");
         let _reference: &str = owned.borrow();
     }
+
+    #[test]
+    fn owned_from_chars() {
+        let owned: BasicExampleBuf = "abc".chars().rev().collect();
+        assert_eq!(owned.as_str(), "cba");
+    }
+
+    #[test]
+    fn owned_from_str_segments() {
+        let owned: BasicExampleBuf = ["a", "b", "c"].iter().copied().collect();
+        assert_eq!(owned.as_str(), "abc");
+    }
+
+    #[test]
+    fn owned_extend() {
+        let mut owned = BasicExampleBuf::from_static("a");
+        owned.extend(["/", "b"]);
+        assert_eq!(owned.as_str(), "a/b");
+    }
+
+    #[test]
+    fn owned_add_borrowed() {
+        let mut owned = BasicExampleBuf::from_static("a") + BasicExample::from_static("b");
+        owned += BasicExample::from_static("c");
+        assert_eq!(owned.as_str(), "abc");
+    }
 }
@@ -0,0 +1,138 @@
+use std::borrow::Cow;
+
+use aliri_braid::braid;
+use serde::Deserialize;
+use serde_test::{
+    assert_de_tokens, assert_de_tokens_error, assert_ser_tokens, assert_tokens, Configure, Token,
+};
+
+#[braid(serde = "bytes")]
+pub struct Plain;
+
+#[braid(serde = "bytes", min_len = 1)]
+pub struct Validated;
+
+#[braid(serde = "bytes", convert_case = "snake")]
+pub struct Normalized;
+
+#[derive(Debug, Deserialize)]
+struct Borrowing<'a> {
+    #[serde(borrow, deserialize_with = "PlainRef::deserialize_cow")]
+    value: Cow<'a, PlainRef>,
+}
+
+#[test]
+fn serializes_as_str_when_human_readable() {
+    let owned = Plain::from_static("value");
+    let borrowed: &PlainRef = &owned;
+    assert_tokens(&owned.clone().readable(), &[Token::Str("value")]);
+    assert_ser_tokens(&borrowed.readable(), &[Token::Str("value")]);
+    assert_de_tokens(&borrowed.readable(), &[Token::BorrowedStr("value")]);
+    assert_eq!(serde_json::to_string(&owned).unwrap(), r#""value""#);
+    assert_eq!(serde_json::to_string(borrowed).unwrap(), r#""value""#);
+}
+
+#[test]
+fn serializes_as_bytes_when_not_human_readable() {
+    let owned = Plain::from_static("value");
+    let borrowed: &PlainRef = &owned;
+    assert_tokens(&owned.clone().compact(), &[Token::Bytes(b"value")]);
+    assert_ser_tokens(&borrowed.compact(), &[Token::Bytes(b"value")]);
+    assert_de_tokens(&borrowed.compact(), &[Token::BorrowedBytes(b"value")]);
+}
+
+#[test]
+fn owned_accepts_strings_when_not_human_readable() {
+    let owned = Plain::from_static("value");
+    assert_de_tokens(&owned.clone().compact(), &[Token::Str("value")]);
+    assert_de_tokens(&owned.compact(), &[Token::ByteBuf(b"value")]);
+}
+
+#[test]
+fn rejects_invalid_utf8_when_not_human_readable() {
+    assert_de_tokens_error::<serde_test::Compact<Plain>>(
+        &[Token::Bytes(b"\xff")],
+        "invalid value: byte array, expected a UTF-8 byte string",
+    );
+    assert_de_tokens_error::<serde_test::Compact<&PlainRef>>(
+        &[Token::BorrowedBytes(b"\xff")],
+        "invalid value: byte array, expected a UTF-8 byte string",
+    );
+}
+
+#[test]
+fn validates_bytes_when_not_human_readable() {
+    assert_de_tokens_error::<serde_test::Compact<Validated>>(
+        &[Token::Bytes(b"")],
+        "invalid value \"\": must be at least 1 bytes long (at byte 0)",
+    );
+    assert_de_tokens_error::<serde_test::Compact<&ValidatedRef>>(
+        &[Token::BorrowedBytes(b"")],
+        "invalid value \"\": must be at least 1 bytes long (at byte 0)",
+    );
+}
+
+#[test]
+fn normalizes_bytes_when_not_human_readable() {
+    assert_de_tokens(
+        &Normalized::from_static("some_value").compact(),
+        &[Token::Bytes(b"SomeValue")],
+    );
+}
+
+#[test]
+fn round_trips_through_bincode() {
+    let owned = Validated::from_static("value");
+    let encoded = bincode::serialize(&owned).unwrap();
+    assert_eq!(encoded, bincode::serialize(&b"value"[..]).unwrap());
+
+    let decoded: Validated = bincode::deserialize(&encoded).unwrap();
+    assert_eq!(decoded, owned);
+
+    let borrowed: &ValidatedRef = bincode::deserialize(&encoded).unwrap();
+    assert_eq!(borrowed, owned);
+
+    let boxed: Box<ValidatedRef> = bincode::deserialize(&encoded).unwrap();
+    assert_eq!(&*boxed, owned);
+}
+
+#[test]
+fn cow_borrows_bytes_from_bincode() {
+    let encoded = bincode::serialize(&Plain::from_static("value")).unwrap();
+    let decoded: Borrowing = bincode::deserialize(&encoded).unwrap();
+    assert!(matches!(decoded.value, Cow::Borrowed(_)));
+    assert_eq!(decoded.value.as_str(), "value");
+}
+
+#[test]
+fn json_is_unchanged() {
+    let owned: Validated = serde_json::from_str(r#""value""#).unwrap();
+    assert_eq!(owned.as_str(), "value");
+    assert!(serde_json::from_str::<Validated>(r#""""#).is_err());
+
+    let borrowed: &PlainRef = serde_json::from_str(r#""value""#).unwrap();
+    assert_eq!(borrowed.as_str(), "value");
+}
+
+#[test]
+fn deserializes_in_place_from_bincode() {
+    use bincode::Options;
+
+    let encoded = bincode::serialize(&Normalized::from_static("some_value")).unwrap();
+    let options = bincode::DefaultOptions::new().with_fixint_encoding();
+    let mut place = Normalized::from_static("original");
+    Normalized::deserialize_in_place(
+        &mut bincode::Deserializer::from_slice(&encoded, options),
+        &mut place,
+    )
+    .unwrap();
+    assert_eq!("some_value", place.as_str());
+
+    let encoded = bincode::serialize(&b"\xff"[..]).unwrap();
+    assert!(Normalized::deserialize_in_place(
+        &mut bincode::Deserializer::from_slice(&encoded, options),
+        &mut place,
+    )
+    .is_err());
+    assert_eq!("some_value", place.as_str());
+}
@@ -0,0 +1,37 @@
+use std::convert::TryFrom;
+
+use aliri_braid::{braid, braid_many, ConstraintError};
+
+braid_many! {
+    serde, max_len = 8;
+    /// Identifies a user
+    pub UserId,
+    pub OrderId,
+    pub(crate) TenantId,
+}
+
+braid_many!(clone = "omit"; Untagged, Unlabeled;);
+
+#[braid(serde, max_len = 8)]
+pub struct SingleId;
+
+#[test]
+fn each_braid_shares_the_parameters() {
+    assert!(UserId::try_from("alice").is_ok());
+    assert!(OrderId::try_from("much too long").is_err());
+    assert!(<&TenantIdRef>::try_from("much too long").is_err());
+
+    let id: OrderId = serde_json::from_str(r#""order-1""#).unwrap();
+    assert_eq!("order-1", id.as_str());
+}
+
+#[test]
+fn batch_declarations_match_individual_declarations() {
+    let batch: Result<UserId, ConstraintError> = UserId::try_from("much too long");
+    let single: Result<SingleId, ConstraintError> = SingleId::try_from("much too long");
+    assert_eq!(batch.unwrap_err(), single.unwrap_err());
+
+    static_assertions::assert_not_impl_any!(UserId: From<OrderId>, PartialEq<OrderId>);
+    static_assertions::assert_not_impl_any!(Untagged: Clone);
+    static_assertions::assert_not_impl_any!(Unlabeled: Clone);
+}
@@ -0,0 +1,49 @@
+use std::marker::PhantomData;
+
+use aliri_braid::{braid, braid_tests};
+
+#[braid(serde)]
+pub struct Plain;
+
+braid_tests! {
+    mod plain for Plain => PlainRef;
+    valid = ["", "plain", "with \"quotes\" and \\ slashes", "🏗"];
+    serde = serde_json;
+}
+
+#[braid(serde, min_len = 1, max_len = 8, charset = "ascii_alphanumeric")]
+pub struct Constrained;
+
+braid_tests! {
+    mod constrained for Constrained => ConstrainedRef;
+    valid = ["a", "abc123", "ABCDEFGH"];
+    invalid = ["", "has space", "abcdefghi", "🏗"];
+    serde = serde_json;
+}
+
+#[braid(serde, convert_case = "snake")]
+pub struct Snake;
+
+braid_tests! {
+    mod snake for Snake => SnakeRef;
+    valid = ["already_snake", "CamelCase", "kebab-case"];
+}
+
+#[braid(eq = "ascii_case_insensitive")]
+pub struct Header;
+
+braid_tests! {
+    mod header for Header => HeaderRef;
+    valid = ["Content-Type", "accept"];
+}
+
+#[braid(min_len = 1)]
+pub struct Id<T>(String, PhantomData<T>);
+
+pub struct User;
+
+braid_tests! {
+    mod generic for Id<User> => IdRef<User>;
+    valid = ["1", "user"];
+    invalid = [""];
+}
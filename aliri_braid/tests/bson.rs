@@ -0,0 +1,72 @@
+use std::convert::TryFrom;
+
+use aliri_braid::{braid, braid_ref, BsonError};
+use bson::{doc, spec::ElementType, Bson};
+
+#[braid(bson)]
+pub struct DatabaseName;
+
+#[braid(bson, max_len = 8)]
+pub struct ShortName;
+
+#[braid(bson, serde)]
+pub struct Tag;
+
+#[braid_ref(bson)]
+pub struct Collection;
+
+#[test]
+fn owned_converts_to_bson_string() {
+    let name = DatabaseName::from_static("inventory");
+    assert_eq!(Bson::String("inventory".to_owned()), Bson::from(name));
+}
+
+#[test]
+fn borrowed_converts_to_bson_string() {
+    let name = DatabaseNameRef::from_static("inventory");
+    assert_eq!(Bson::String("inventory".to_owned()), Bson::from(name));
+    assert_eq!(
+        Bson::String("items".to_owned()),
+        Bson::from(Collection::from_static("items"))
+    );
+}
+
+#[test]
+fn braids_can_be_used_in_filters() {
+    let name = ShortName::from_static("widget");
+    let filter = doc! { "name": &*name, "alias": name.clone() };
+    assert_eq!(Some("widget"), filter.get_str("name").ok());
+    assert_eq!(Some("widget"), filter.get_str("alias").ok());
+}
+
+#[test]
+fn owned_converts_from_bson_string() {
+    let name = ShortName::try_from(Bson::String("widget".to_owned())).unwrap();
+    assert_eq!("widget", name.as_str());
+}
+
+#[test]
+fn invalid_values_are_rejected() {
+    let err = ShortName::try_from(Bson::String("much too long".to_owned())).unwrap_err();
+    assert!(matches!(err, BsonError::Invalid(_)));
+    assert!(err.to_string().contains("must be at most 8 bytes long"));
+}
+
+#[test]
+fn other_types_are_rejected() {
+    let err = DatabaseName::try_from(Bson::Int32(7)).unwrap_err();
+    assert_eq!(BsonError::UnexpectedType(ElementType::Int32), err);
+}
+
+#[test]
+fn owned_serializes_as_bson_string() {
+    let value = Tag::from_static("packed");
+    assert_eq!(
+        Bson::String("packed".to_owned()),
+        bson::to_bson(&value).unwrap()
+    );
+    assert_eq!(
+        value,
+        bson::from_bson::<Tag>(Bson::String("packed".to_owned())).unwrap()
+    );
+}
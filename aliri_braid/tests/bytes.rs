@@ -0,0 +1,48 @@
+use std::convert::TryFrom;
+
+use aliri_braid::{braid, FromBytesError};
+use bytes::Bytes;
+use bytestring::ByteString;
+
+#[braid]
+pub struct Frame(ByteString);
+
+#[braid(max_len = 4)]
+pub struct Short(ByteString);
+
+#[test]
+fn from_bytes_shares_the_buffer() {
+    let buffer = Bytes::from_static(b"frame");
+    let frame = Frame::from_bytes(buffer.clone()).unwrap();
+    assert_eq!("frame", frame.as_str());
+    assert_eq!(buffer.as_ptr(), frame.as_str().as_ptr());
+
+    let bytes = frame.into_bytes();
+    assert_eq!(buffer.as_ptr(), bytes.as_ptr());
+}
+
+#[test]
+fn from_bytes_rejects_invalid_utf8() {
+    assert!(matches!(
+        Frame::from_bytes(Bytes::from_static(b"\xff")),
+        Err(FromBytesError::Utf8(_))
+    ));
+}
+
+#[test]
+fn from_bytes_validates_without_copying() {
+    let buffer = Bytes::from_static(b"ok");
+    let short = Short::try_from(buffer.clone()).unwrap();
+    assert_eq!(buffer.as_ptr(), short.as_str().as_ptr());
+
+    assert!(matches!(
+        Short::try_from(Bytes::from_static(b"too long")),
+        Err(FromBytesError::Invalid(_))
+    ));
+}
+
+#[test]
+fn into_bytes_through_from() {
+    let bytes: Bytes = Frame::from_static("frame").into();
+    assert_eq!(&b"frame"[..], &bytes[..]);
+}
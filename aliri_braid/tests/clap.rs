@@ -0,0 +1,81 @@
+use aliri_braid::braid;
+use clap::{value_parser, Arg, Command};
+
+#[braid(clap)]
+pub struct Plain;
+
+#[braid(clap, max_len = 8)]
+pub struct Username;
+
+#[braid(clap, normalizer = "Lowercase")]
+pub struct Tag;
+
+pub struct Lowercase;
+
+impl aliri_braid::Validator for Lowercase {
+    type Error = aliri_braid::ConstraintError;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        match raw.find(char::is_uppercase) {
+            Some(idx) => Err(aliri_braid::ConstraintError::new(
+                raw,
+                aliri_braid::Constraint::MaxLength(idx),
+                idx,
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+impl aliri_braid::Normalizer for Lowercase {
+    fn normalize(raw: &str) -> Result<std::borrow::Cow<'_, str>, Self::Error> {
+        Ok(raw.to_lowercase().into())
+    }
+}
+
+fn command() -> Command {
+    Command::new("braids")
+        .arg(
+            Arg::new("plain")
+                .long("plain")
+                .value_parser(value_parser!(Plain)),
+        )
+        .arg(
+            Arg::new("username")
+                .long("username")
+                .value_parser(value_parser!(Username)),
+        )
+        .arg(Arg::new("tag").long("tag").value_parser(value_parser!(Tag)))
+}
+
+#[test]
+fn arguments_parse_into_braids() {
+    let matches = command()
+        .try_get_matches_from([
+            "braids",
+            "--plain",
+            "any",
+            "--username",
+            "nobody",
+            "--tag",
+            "LOUD",
+        ])
+        .unwrap();
+
+    assert_eq!("any", matches.get_one::<Plain>("plain").unwrap().as_str());
+    assert_eq!(
+        "nobody",
+        matches.get_one::<Username>("username").unwrap().as_str()
+    );
+    assert_eq!("loud", matches.get_one::<Tag>("tag").unwrap().as_str());
+}
+
+#[test]
+fn validation_errors_are_reported_by_clap() {
+    let err = command()
+        .try_get_matches_from(["braids", "--username", "much too long"])
+        .unwrap_err();
+
+    assert_eq!(clap::error::ErrorKind::ValueValidation, err.kind());
+    assert!(err.to_string().contains("must be at most 8 bytes long"));
+}
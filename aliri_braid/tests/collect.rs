@@ -0,0 +1,130 @@
+use std::collections::BTreeMap;
+
+use aliri_braid::{braid, deserialize_collecting, Checked, CollectError};
+use serde::Deserialize;
+
+#[braid(serde, min_len = 1, max_len = 8)]
+pub struct Username;
+
+#[braid(serde, prefix = "#")]
+pub struct Channel;
+
+#[derive(Debug, Deserialize)]
+struct Member {
+    name: Checked<Username>,
+    nickname: Option<Checked<Username>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Team {
+    lead: Checked<Username>,
+    members: Vec<Member>,
+    channels: BTreeMap<String, Checked<Channel>>,
+    #[serde(default)]
+    note: Option<String>,
+}
+
+fn collect<T: for<'de> Deserialize<'de>>(json: &str) -> Result<T, CollectError<serde_json::Error>> {
+    deserialize_collecting(&mut serde_json::Deserializer::from_str(json))
+}
+
+fn paths(err: CollectError<serde_json::Error>) -> Vec<String> {
+    match err {
+        CollectError::Invalid(errors) => errors.iter().map(|e| e.path().to_owned()).collect(),
+        CollectError::Deserialize(err) => panic!("unexpected deserialization failure: {}", err),
+    }
+}
+
+#[test]
+fn valid_values_deserialize() {
+    let team: Team = collect(
+        r##"{
+            "lead": "alice",
+            "members": [{"name": "bob", "nickname": "bobby"}],
+            "channels": {"general": "#general"}
+        }"##,
+    )
+    .unwrap();
+
+    assert_eq!("alice", team.lead.as_str());
+    assert_eq!("bobby", team.members[0].nickname.as_ref().unwrap().as_str());
+    assert_eq!(
+        Username::from_static("bob"),
+        team.members[0].name.clone().into_inner()
+    );
+    assert_eq!("#general", team.channels["general"].as_str());
+    assert!(team.note.is_none());
+}
+
+#[test]
+fn all_invalid_fields_are_collected_with_paths() {
+    let err = collect::<Team>(
+        r##"{
+            "lead": "",
+            "members": [
+                {"name": "bob", "nickname": null},
+                {"name": "much too long", "nickname": ""}
+            ],
+            "channels": {"general": "#general", "random": "random"},
+            "note": "checked fields do not stop deserialization"
+        }"##,
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        vec![
+            "lead",
+            "members[1].name",
+            "members[1].nickname",
+            "channels.random",
+        ],
+        paths(err)
+    );
+}
+
+#[test]
+fn field_errors_describe_the_failure() {
+    let err = collect::<Team>(r#"{"lead": "", "members": [], "channels": {}}"#).unwrap_err();
+    assert_eq!(
+        "invalid fields: lead: invalid value \"\": must be at least 1 bytes long (at byte 0)",
+        err.to_string()
+    );
+
+    match err {
+        CollectError::Invalid(errors) => {
+            assert_eq!(
+                "invalid value \"\": must be at least 1 bytes long (at byte 0)",
+                errors[0].message()
+            );
+        }
+        CollectError::Deserialize(err) => panic!("unexpected deserialization failure: {}", err),
+    }
+}
+
+#[test]
+fn malformed_input_fails_immediately() {
+    let err = collect::<Team>(r#"{"lead": "", "members": 5, "channels": {}}"#).unwrap_err();
+    assert!(matches!(err, CollectError::Deserialize(_)));
+
+    let err = collect::<Team>(r#"{"lead": 5, "members": [], "channels": {}}"#).unwrap_err();
+    assert!(matches!(err, CollectError::Deserialize(_)));
+}
+
+#[test]
+fn checked_fields_fail_fast_outside_of_collection() {
+    let err = serde_json::from_str::<Team>(
+        r#"{"lead": "much too long", "members": [{"name": ""}], "channels": {}}"#,
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("must be at most 8 bytes long"));
+
+    let team: Team =
+        serde_json::from_str(r#"{"lead": "alice", "members": [], "channels": {}}"#).unwrap();
+    assert_eq!("alice", team.lead.as_str());
+}
+
+#[test]
+fn top_level_checked_value_has_empty_path() {
+    let err = collect::<Checked<Username>>(r#""""#).unwrap_err();
+    assert_eq!(vec![""], paths(err));
+}
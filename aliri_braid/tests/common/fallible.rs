@@ -8,6 +8,8 @@ use static_assertions::{assert_eq_align, assert_eq_size, assert_eq_size_ptr, ass
 
 use crate::{Validated, ValidatedBuf};
 
+assert_equality_matrix!(ValidatedBuf => Validated, "One", "Two");
+
 #[test]
 pub fn equality_tests() -> Result<(), Box<dyn std::error::Error>> {
     let x = ValidatedBuf::from_static("One");
@@ -57,6 +59,21 @@ pub fn try_from_owned_fails() {
     let _: ValidatedBuf = "Test 🏗".try_into().unwrap();
 }
 
+#[test]
+pub fn try_from_static_pass() -> Result<(), Box<dyn std::error::Error>> {
+    let x = ValidatedBuf::try_from_static("One")?;
+    let y = Validated::try_from_static("One")?;
+    assert_eq!(x, y);
+    assert_eq!("One", y.as_str());
+    Ok(())
+}
+
+#[test]
+pub fn try_from_static_fails() {
+    assert!(ValidatedBuf::try_from_static("Test 🏗").is_err());
+    assert!(Validated::try_from_static("Test 🏗").is_err());
+}
+
 #[test]
 pub fn try_from_borrowed_pass() -> Result<(), Box<dyn std::error::Error>> {
     let x: &Validated = "One".try_into()?;
@@ -70,6 +87,33 @@ pub fn try_from_borrowed_fails() {
     let _: &Validated = "Test 🏗".try_into().unwrap();
 }
 
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "new_unchecked_checked")]
+#[allow(unsafe_code)]
+pub fn new_unchecked_checked_asserts_in_debug() {
+    let _ = unsafe { ValidatedBuf::new_unchecked_checked("Test 🏗".to_owned()) };
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "from_str_unchecked_checked")]
+#[allow(unsafe_code)]
+pub fn from_str_unchecked_checked_asserts_in_debug() {
+    let _ = unsafe { Validated::from_str_unchecked_checked("Test 🏗") };
+}
+
+#[allow(unsafe_code)]
+const CONST_OWNED: ValidatedBuf = unsafe { ValidatedBuf::new_unchecked(String::new()) };
+#[allow(unsafe_code)]
+const CONST_BORROWED: &Validated = unsafe { Validated::from_str_unchecked("One") };
+
+#[test]
+pub fn unchecked_constructors_are_const() {
+    assert_eq!("", CONST_OWNED.as_str());
+    assert_eq!("One", CONST_BORROWED.as_str());
+}
+
 #[test]
 fn debug_and_display_tests() {
     let x = ValidatedBuf::from_static("One");
@@ -81,6 +125,20 @@ fn debug_and_display_tests() {
     assert_eq!("\"One\"", format!("{:?}", y));
 }
 
+#[test]
+fn formatter_flags_match_str() {
+    let x = ValidatedBuf::from_static("One");
+    let y = Validated::from_static("One");
+
+    assert_eq!(format!("{:>6}|", "One"), format!("{:>6}|", x));
+    assert_eq!(format!("{:<6}|", "One"), format!("{:<6}|", y));
+    assert_eq!(format!("{:*^7}", "One"), format!("{:*^7}", x));
+    assert_eq!(format!("{:.2}", "One"), format!("{:.2}", y));
+    assert_eq!(format!("{:>8.1}", "One"), format!("{:>8.1}", x));
+    assert_eq!(format!("{:>8?}", "One"), format!("{:>8?}", x));
+    assert_eq!(format!("{:#?}", "One"), format!("{:#?}", y));
+}
+
 #[cfg_attr(miri, ignore = "takes too long on miri")]
 #[quickcheck]
 fn owned_and_borrowed_hashes_are_equivalent(s: String) -> quickcheck::TestResult {
@@ -264,7 +322,7 @@ fn check_reference_size() {
 }
 
 #[test]
-#[allow(clippy::forget_ref, clippy::transmute_ptr_to_ptr)]
+#[allow(forgetting_references, clippy::transmute_ptr_to_ptr)]
 fn check_reference_size_ptr() {
     let s = "source";
     let y: &Validated = Validated::from_str(s).unwrap();
@@ -272,7 +330,7 @@ fn check_reference_size_ptr() {
 }
 
 #[test]
-#[allow(clippy::forget_ref, clippy::transmute_ptr_to_ptr)]
+#[allow(forgetting_references, clippy::transmute_ptr_to_ptr)]
 fn check_reference_size_val() {
     let s = "source";
     let y: &Validated = Validated::from_str(s).unwrap();
@@ -1,4 +1,5 @@
 use std::{
+    borrow::Cow,
     collections::{BTreeSet, HashSet},
     convert::TryInto,
 };
@@ -8,6 +9,8 @@ use static_assertions::{assert_eq_align, assert_eq_size, assert_eq_size_ptr, ass
 
 use crate::{Orange, OrangeRef};
 
+assert_equality_matrix!(Orange => OrangeRef, "One", "Two");
+
 #[test]
 pub fn equality_tests() {
     let x = Orange::from_static("One");
@@ -41,6 +44,20 @@ pub fn debug_and_display_tests() {
     assert_eq!("\"One\"", format!("{:?}", y));
 }
 
+#[test]
+fn formatter_flags_match_str() {
+    let x = Orange::from_static("One");
+    let y = OrangeRef::from_static("One");
+
+    assert_eq!(format!("{:>6}|", "One"), format!("{:>6}|", x));
+    assert_eq!(format!("{:<6}|", "One"), format!("{:<6}|", y));
+    assert_eq!(format!("{:*^7}", "One"), format!("{:*^7}", x));
+    assert_eq!(format!("{:.2}", "One"), format!("{:.2}", y));
+    assert_eq!(format!("{:>8.1}", "One"), format!("{:>8.1}", x));
+    assert_eq!(format!("{:>8?}", "One"), format!("{:>8?}", x));
+    assert_eq!(format!("{:#?}", "One"), format!("{:#?}", y));
+}
+
 #[cfg_attr(miri, ignore = "takes too long on miri")]
 #[quickcheck]
 fn owned_and_borrowed_hashes_are_equivalent(s: String) -> bool {
@@ -82,6 +99,7 @@ pub fn from_owned_pass() {
 }
 
 #[test]
+#[allow(clippy::unnecessary_fallible_conversions)]
 pub fn try_from_owned_pass() -> Result<(), Box<dyn std::error::Error>> {
     let x: Orange = "One".try_into()?;
     assert_eq!("One", x.as_str());
@@ -89,6 +107,7 @@ pub fn try_from_owned_pass() -> Result<(), Box<dyn std::error::Error>> {
 }
 
 #[test]
+#[allow(clippy::unnecessary_fallible_conversions)]
 pub fn try_from_borrowed_pass() -> Result<(), Box<dyn std::error::Error>> {
     let x: &OrangeRef = "One".try_into()?;
     assert_eq!("One", x.as_str());
@@ -218,7 +237,7 @@ fn check_reference_size() {
 }
 
 #[test]
-#[allow(clippy::forget_ref, clippy::transmute_ptr_to_ptr)]
+#[allow(forgetting_references, clippy::transmute_ptr_to_ptr)]
 fn check_reference_size_ptr() {
     let s = "source";
     let y: &OrangeRef = OrangeRef::from_str(s);
@@ -226,7 +245,7 @@ fn check_reference_size_ptr() {
 }
 
 #[test]
-#[allow(clippy::forget_ref, clippy::transmute_ptr_to_ptr)]
+#[allow(forgetting_references, clippy::transmute_ptr_to_ptr)]
 fn check_reference_size_val() {
     let s = "source";
     let y: &OrangeRef = OrangeRef::from_str(s);
@@ -298,3 +317,19 @@ fn check_owned_size_val() {
 }
 
 assert_core_impls!(Orange => OrangeRef);
+
+#[test]
+fn optional_values_deref_to_borrowed() {
+    let owned = Some(Orange::from_static("One"));
+    let borrowed: Option<&OrangeRef> = owned.as_deref();
+    assert_eq!(Some(OrangeRef::from_static("One")), borrowed);
+
+    let boxed: Option<Box<OrangeRef>> = owned.clone().map(Orange::into_boxed_ref);
+    assert_eq!(borrowed, boxed.as_deref());
+
+    let cow: Option<Cow<OrangeRef>> = owned.clone().map(Cow::Owned);
+    assert_eq!(borrowed, cow.as_deref());
+
+    let none: Option<Orange> = None;
+    assert_eq!(None, none.as_deref());
+}
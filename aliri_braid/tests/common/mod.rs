@@ -114,6 +114,57 @@ macro_rules! assert_core_impls {
     };
 }
 
+macro_rules! assert_equality_matrix {
+    ($owned:ty => $borrowed:ty, $value:literal, $other:literal) => {
+        #[test]
+        pub fn equality_matrix() {
+            use std::borrow::Cow;
+
+            let a_owned = <$owned>::from_static($value);
+            let a_ref: &$borrowed = &a_owned;
+            let a_box = a_owned.clone().into_boxed_ref();
+            let a_cow_borrowed = Cow::Borrowed(a_ref);
+            let a_cow_owned = Cow::<$borrowed>::Owned(a_owned.clone());
+
+            let b_owned = a_owned.clone();
+            let b_ref: &$borrowed = &b_owned;
+            let b_box = b_owned.clone().into_boxed_ref();
+            let b_cow_borrowed = Cow::Borrowed(b_ref);
+            let b_cow_owned = Cow::<$borrowed>::Owned(b_owned.clone());
+
+            let c_owned = <$owned>::from_static($other);
+            let c_ref: &$borrowed = &c_owned;
+            let c_box = c_owned.clone().into_boxed_ref();
+            let c_cow_borrowed = Cow::Borrowed(c_ref);
+            let c_cow_owned = Cow::<$borrowed>::Owned(c_owned.clone());
+
+            assert_equality_matrix!(
+                @matrix
+                [a_owned, *a_ref, a_ref, a_box, a_cow_borrowed, a_cow_owned]
+                [
+                    (b_owned, c_owned),
+                    (*b_ref, *c_ref),
+                    (b_ref, c_ref),
+                    (b_box, c_box),
+                    (b_cow_borrowed, c_cow_borrowed),
+                    (b_cow_owned, c_cow_owned)
+                ]
+            );
+        }
+    };
+    (@matrix [$($lhs:expr),*] $rhs:tt) => {
+        $(assert_equality_matrix!(@row $lhs, $rhs);)*
+    };
+    (@row $lhs:expr, [$(($equal:expr, $unequal:expr)),*]) => {
+        $(
+            assert!($lhs == $equal, "{} == {}", stringify!($lhs), stringify!($equal));
+            assert!($equal == $lhs, "{} == {}", stringify!($equal), stringify!($lhs));
+            assert!($lhs != $unequal, "{} != {}", stringify!($lhs), stringify!($unequal));
+            assert!($unequal != $lhs, "{} != {}", stringify!($unequal), stringify!($lhs));
+        )*
+    };
+}
+
 mod fallible;
 mod infallible;
 mod normalized;
@@ -8,6 +8,8 @@ use static_assertions::{assert_eq_align, assert_eq_size, assert_eq_size_ptr, ass
 
 use crate::{Normalized, NormalizedBuf};
 
+assert_equality_matrix!(NormalizedBuf => Normalized, "One", "Two");
+
 #[test]
 pub fn equality_tests() -> Result<(), Box<dyn std::error::Error>> {
     let x = NormalizedBuf::from_static("One Two");
@@ -71,6 +73,29 @@ pub fn try_from_owned_fails() {
     let _: NormalizedBuf = "Test 🏗".try_into().unwrap();
 }
 
+#[test]
+pub fn try_from_static_pass() -> Result<(), Box<dyn std::error::Error>> {
+    let x = NormalizedBuf::try_from_static("One")?;
+    let y = Normalized::try_from_static("One")?;
+    assert_eq!(x, y);
+    assert_eq!("One", y.as_str());
+    Ok(())
+}
+
+#[test]
+pub fn try_from_static_fails() {
+    assert!(NormalizedBuf::try_from_static("Test 🏗").is_err());
+    assert!(Normalized::try_from_static("Test 🏗").is_err());
+}
+
+#[test]
+pub fn try_from_static_non_normal() -> Result<(), Box<dyn std::error::Error>> {
+    let x = NormalizedBuf::try_from_static("One Two")?;
+    assert_eq!("OneTwo", x.as_str());
+    assert!(Normalized::try_from_static("One Two").is_err());
+    Ok(())
+}
+
 #[test]
 pub fn try_from_borrowed_pass() -> Result<(), Box<dyn std::error::Error>> {
     let x: &Normalized = "One".try_into()?;
@@ -90,6 +115,33 @@ pub fn try_from_borrowed_fails() {
     let _: &Normalized = "Test 🏗".try_into().unwrap();
 }
 
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "new_unchecked_checked")]
+#[allow(unsafe_code)]
+pub fn new_unchecked_checked_asserts_in_debug() {
+    let _ = unsafe { NormalizedBuf::new_unchecked_checked("One Two".to_owned()) };
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "from_str_unchecked_checked")]
+#[allow(unsafe_code)]
+pub fn from_str_unchecked_checked_asserts_in_debug() {
+    let _ = unsafe { Normalized::from_str_unchecked_checked("One Two") };
+}
+
+#[allow(unsafe_code)]
+const CONST_OWNED: NormalizedBuf = unsafe { NormalizedBuf::new_unchecked(String::new()) };
+#[allow(unsafe_code)]
+const CONST_BORROWED: &Normalized = unsafe { Normalized::from_str_unchecked("One") };
+
+#[test]
+pub fn unchecked_constructors_are_const() {
+    assert_eq!("", CONST_OWNED.as_str());
+    assert_eq!("One", CONST_BORROWED.as_str());
+}
+
 #[test]
 fn debug_and_display_tests() {
     let x = NormalizedBuf::from_static("One Two");
@@ -315,7 +367,7 @@ fn check_reference_size() {
 }
 
 #[test]
-#[allow(clippy::forget_ref, clippy::transmute_ptr_to_ptr)]
+#[allow(forgetting_references, clippy::transmute_ptr_to_ptr)]
 fn check_reference_size_ptr() {
     let s = "source";
     let y: &Normalized = &Normalized::from_str(s).unwrap();
@@ -323,7 +375,7 @@ fn check_reference_size_ptr() {
 }
 
 #[test]
-#[allow(clippy::forget_ref, clippy::transmute_ptr_to_ptr)]
+#[allow(forgetting_references, clippy::transmute_ptr_to_ptr)]
 fn check_reference_size_ptr_normalized() {
     let s = "source five";
     let y: &Normalized = &Normalized::from_str(s).unwrap();
@@ -331,7 +383,7 @@ fn check_reference_size_ptr_normalized() {
 }
 
 #[test]
-#[allow(clippy::forget_ref, clippy::transmute_ptr_to_ptr)]
+#[allow(forgetting_references, clippy::transmute_ptr_to_ptr)]
 fn check_reference_size_val() {
     let s = "source";
     let y: &Normalized = &Normalized::from_str(s).unwrap();
@@ -341,7 +393,7 @@ fn check_reference_size_val() {
 }
 
 #[test]
-#[allow(clippy::forget_ref, clippy::transmute_ptr_to_ptr)]
+#[allow(forgetting_references, clippy::transmute_ptr_to_ptr)]
 fn check_reference_size_val_normalized() {
     let s = "source five";
     let y: &Normalized = &Normalized::from_str(s).unwrap();
@@ -0,0 +1,441 @@
+use std::{borrow::Cow, convert::Infallible, fmt};
+
+use aliri_braid::{braid, braid_lit, braid_ref, Case, Charset, Constraint, ConstraintError};
+
+#[braid(min_len = 3, max_len = 8)]
+pub struct ShortName;
+
+#[braid_ref(max_len = 4)]
+pub struct Tiny;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum InvalidTag {
+    Constraint(ConstraintError),
+    Whitespace,
+}
+
+impl fmt::Display for InvalidTag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Constraint(err) => err.fmt(f),
+            Self::Whitespace => f.write_str("tag cannot contain whitespace"),
+        }
+    }
+}
+
+impl From<ConstraintError> for InvalidTag {
+    fn from(err: ConstraintError) -> Self {
+        Self::Constraint(err)
+    }
+}
+
+impl From<Infallible> for InvalidTag {
+    #[inline(always)]
+    fn from(x: Infallible) -> Self {
+        match x {}
+    }
+}
+
+impl std::error::Error for InvalidTag {}
+
+pub struct NoWhitespace;
+
+impl aliri_braid::Validator for NoWhitespace {
+    type Error = InvalidTag;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        if raw.contains(char::is_whitespace) {
+            Err(InvalidTag::Whitespace)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl aliri_braid::Normalizer for NoWhitespace {
+    fn normalize(raw: &str) -> Result<Cow<'_, str>, Self::Error> {
+        if raw.contains(char::is_whitespace) {
+            Ok(Cow::Owned(raw.replace(char::is_whitespace, "")))
+        } else {
+            Ok(Cow::Borrowed(raw))
+        }
+    }
+}
+
+#[braid(validator = "NoWhitespace", max_len = 5)]
+pub struct Tag;
+
+#[braid(prefix = "arn:")]
+pub struct Arn;
+
+#[braid(suffix = ".json")]
+pub struct JsonFileName;
+
+#[braid(prefix = "user:", rest = "TagRef")]
+pub struct UserTag;
+
+#[braid(normalizer = "NoWhitespace", max_len = 5)]
+pub struct CompactTag;
+
+#[braid(convert_case = "snake")]
+pub struct SnakeKey;
+
+#[braid(convert_case = "kebab", max_len = 16)]
+pub struct KebabKey;
+
+#[braid(convert_case = "camel")]
+pub struct CamelKey;
+
+#[braid(non_empty)]
+pub struct DisplayName;
+
+#[braid_ref(non_empty)]
+pub struct Label;
+
+#[braid(non_empty, validator = "NoWhitespace")]
+pub struct Handle;
+
+#[braid(charset = "ascii_alphanumeric", min_len = 1, max_len = 8)]
+pub struct Sku;
+
+#[braid(charset = "no_control")]
+pub struct Comment;
+
+#[braid_ref(charset = "printable")]
+pub struct Banner;
+
+#[braid(min_chars = 2, max_chars = 4, max_bytes = 8)]
+pub struct Nickname;
+
+#[braid_ref(max_chars = 3)]
+pub struct Initials;
+
+#[test]
+fn accepts_values_within_bounds() {
+    assert_eq!("abc", ShortName::new("abc".to_owned()).unwrap().as_str());
+    assert_eq!(
+        "abcdefgh",
+        ShortNameRef::from_str("abcdefgh").unwrap().as_str()
+    );
+    assert_eq!("abcd", Tiny::from_str("abcd").unwrap().as_str());
+}
+
+#[test]
+fn rejects_short_values() {
+    let err = ShortNameRef::from_str("ab").unwrap_err();
+    assert_eq!(Constraint::MinLength(3), err.constraint());
+    assert_eq!("ab", err.value());
+    assert_eq!(2, err.position());
+    assert!(!err.is_truncated());
+}
+
+#[test]
+fn rejects_long_values() {
+    let err = ShortName::new("abcdefghi".to_owned()).unwrap_err();
+    assert_eq!(Constraint::MaxLength(8), err.constraint());
+    assert_eq!("abcdefghi", err.value());
+    assert_eq!(8, err.position());
+
+    let err = Tiny::from_str("abcde").unwrap_err();
+    assert_eq!(Constraint::MaxLength(4), err.constraint());
+}
+
+#[test]
+fn error_retains_truncated_value_on_char_boundary() {
+    let value = "ü".repeat(20);
+    let err = ShortNameRef::from_str(&value).unwrap_err();
+    assert!(err.is_truncated());
+    assert_eq!("ü".repeat(16), err.value());
+}
+
+#[test]
+fn error_display_describes_violation() {
+    let err = ShortNameRef::from_str("ab").unwrap_err();
+    assert_eq!(
+        "invalid value \"ab\": must be at least 3 bytes long (at byte 2)",
+        err.to_string()
+    );
+
+    let err = ShortNameRef::from_str("abcdefghijklmnopqrstuvwxyz0123456789").unwrap_err();
+    assert_eq!(
+        "invalid value \"abcdefghijklmnopqrstuvwxyz012345\"…: must be at most 8 bytes long (at \
+         byte 8)",
+        err.to_string()
+    );
+}
+
+#[test]
+fn constraints_run_before_named_validator() {
+    assert!(TagRef::from_str("ab c").is_err());
+    assert_eq!(
+        InvalidTag::Whitespace,
+        TagRef::from_str("ab c").unwrap_err()
+    );
+    assert!(matches!(
+        Tag::new("abcdef".to_owned()),
+        Err(InvalidTag::Constraint(_))
+    ));
+    assert_eq!("abc", Tag::from_static("abc").as_str());
+}
+
+#[test]
+fn constraints_checked_against_normalized_value() {
+    assert_eq!(
+        "abcde",
+        CompactTag::new("a b c d e".to_owned()).unwrap().as_str()
+    );
+    assert!(matches!(
+        CompactTagRef::from_str("a b c d e f"),
+        Err(InvalidTag::Constraint(_))
+    ));
+    assert!(CompactTagRef::from_normalized_str("a b").is_err());
+}
+
+#[test]
+fn prefix_is_required_and_strippable() {
+    let arn = ArnRef::from_str("arn:aws:iam::123456789012:user/Development").unwrap();
+    assert_eq!(
+        "aws:iam::123456789012:user/Development",
+        arn.without_prefix()
+    );
+
+    let err = ArnRef::from_str("arm:aws").unwrap_err();
+    assert_eq!(Constraint::Prefix("arn:"), err.constraint());
+    assert_eq!(2, err.position());
+    assert_eq!(
+        "invalid value \"arm:aws\": must start with \"arn:\" (at byte 2)",
+        err.to_string()
+    );
+}
+
+#[test]
+fn suffix_is_required_and_strippable() {
+    let name = JsonFileName::from_static("config.json");
+    assert_eq!("config", name.without_suffix());
+
+    let err = JsonFileName::new("config.yaml".to_owned()).unwrap_err();
+    assert_eq!(Constraint::Suffix(".json"), err.constraint());
+    assert_eq!(6, err.position());
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "value is missing the prefix \"arn:\"")]
+#[allow(unsafe_code)]
+fn stripping_a_missing_prefix_asserts_in_debug() {
+    let arn = unsafe { ArnRef::from_str_unchecked("aws:s3:::bucket") };
+    let _ = arn.without_prefix();
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "value is missing the suffix \".json\"")]
+#[allow(unsafe_code)]
+fn stripping_a_missing_suffix_asserts_in_debug() {
+    let name = unsafe { JsonFileNameRef::from_str_unchecked("config.yaml") };
+    let _ = name.without_suffix();
+}
+
+#[test]
+fn rest_is_typed_as_other_braid() {
+    let tag = UserTagRef::from_str("user:admin").unwrap();
+    let rest: &TagRef = tag.without_prefix().unwrap();
+    assert_eq!("admin", rest.as_str());
+
+    let tag = UserTagRef::from_str("user:administrator").unwrap();
+    assert!(matches!(
+        tag.without_prefix(),
+        Err(InvalidTag::Constraint(_))
+    ));
+}
+
+#[test]
+fn convert_case_normalizes_words() {
+    for raw in [
+        "max_connections",
+        "maxConnections",
+        "Max-Connections",
+        "MAX CONNECTIONS",
+    ] {
+        assert_eq!(
+            "max_connections",
+            SnakeKey::new(raw.to_owned()).unwrap().as_str()
+        );
+        assert_eq!(
+            "max-connections",
+            KebabKey::new(raw.to_owned()).unwrap().as_str()
+        );
+        assert_eq!(
+            "maxConnections",
+            CamelKey::new(raw.to_owned()).unwrap().as_str()
+        );
+    }
+
+    assert_eq!(
+        "http_server_id",
+        SnakeKey::from_static("HTTPServerID").as_str()
+    );
+    assert_eq!("user-id2", KebabKey::from_static("__user__ID2").as_str());
+    assert_eq!("userId", CamelKey::from_static("user_id").as_str());
+}
+
+#[test]
+fn convert_case_output_conforms() {
+    for raw in ["a b c", "zCℝ", "x ŉ", "HTTP S"] {
+        let snake = SnakeKey::new(raw.to_owned()).unwrap();
+        assert!(
+            SnakeKeyRef::from_normalized_str(snake.as_str()).is_ok(),
+            "{:?}",
+            snake
+        );
+        let kebab = KebabKey::new(raw.to_owned()).unwrap();
+        assert!(
+            KebabKeyRef::from_normalized_str(kebab.as_str()).is_ok(),
+            "{:?}",
+            kebab
+        );
+        let camel = CamelKey::new(raw.to_owned()).unwrap();
+        assert!(
+            CamelKeyRef::from_normalized_str(camel.as_str()).is_ok(),
+            "{:?}",
+            camel
+        );
+    }
+
+    assert_eq!("z_cℝ", SnakeKey::from_static("zCℝ").as_str());
+    assert_eq!("aBc", CamelKey::from_static("a b c").as_str());
+}
+
+#[test]
+fn convert_case_borrows_conforming_values() {
+    assert!(matches!(
+        SnakeKeyRef::from_str("already_snake").unwrap(),
+        Cow::Borrowed(_)
+    ));
+    assert!(matches!(
+        CamelKeyRef::from_str("notCamel_yet").unwrap(),
+        Cow::Owned(_)
+    ));
+}
+
+#[test]
+fn convert_case_rejects_non_normalized_values() {
+    let err = SnakeKeyRef::from_normalized_str("max-connections").unwrap_err();
+    assert_eq!(Constraint::Case(Case::Snake), err.constraint());
+    assert_eq!(3, err.position());
+    assert_eq!(
+        "invalid value \"max-connections\": must be in snake_case (at byte 3)",
+        err.to_string()
+    );
+
+    let err = KebabKeyRef::from_str("a-very-long-configuration-key").unwrap_err();
+    assert_eq!(Constraint::MaxLength(16), err.constraint());
+}
+
+#[test]
+fn literals_are_checked_at_compile_time() {
+    let name: ShortName = braid_lit!(ShortName, "abcd");
+    assert_eq!("abcd", name.as_str());
+
+    let name: &ShortNameRef = braid_lit!(ShortNameRef, "abcdefgh");
+    assert_eq!("abcdefgh", name.as_str());
+
+    let tiny: &Tiny = braid_lit!(Tiny, "");
+    assert_eq!("", tiny.as_str());
+
+    let arn: Arn = braid_lit!(Arn, "arn:aws:s3:::bucket");
+    assert_eq!("aws:s3:::bucket", arn.without_prefix());
+
+    let name: &JsonFileNameRef = braid_lit!(JsonFileNameRef, "config.json");
+    assert_eq!("config", name.without_suffix());
+}
+
+#[test]
+fn non_empty_rejects_empty_values() {
+    assert_eq!("a", DisplayName::from_static("a").as_str());
+    assert_eq!(" ", Label::from_str(" ").unwrap().as_str());
+
+    let err = DisplayNameRef::from_str("").unwrap_err();
+    assert_eq!(Constraint::NonEmpty, err.constraint());
+    assert_eq!(0, err.position());
+    assert_eq!(
+        "invalid value \"\": must not be empty (at byte 0)",
+        err.to_string()
+    );
+    assert!(Label::from_str("").is_err());
+
+    let label: &Label = braid_lit!(Label, "x");
+    assert_eq!("x", label.as_str());
+}
+
+#[test]
+fn non_empty_runs_before_named_validator() {
+    assert_eq!(
+        InvalidTag::Constraint(ConstraintError::new("", Constraint::NonEmpty, 0)),
+        Handle::new(String::new()).unwrap_err()
+    );
+    assert_eq!(
+        InvalidTag::Whitespace,
+        Handle::new("a b".to_owned()).unwrap_err()
+    );
+}
+
+#[test]
+fn charset_rejects_characters_outside_the_set() {
+    assert_eq!("ABC123", Sku::from_static("ABC123").as_str());
+
+    let err = SkuRef::from_str("AB-12").unwrap_err();
+    assert_eq!(
+        Constraint::Charset(Charset::AsciiAlphanumeric),
+        err.constraint()
+    );
+    assert_eq!(2, err.position());
+    assert_eq!(
+        "invalid value \"AB-12\": must only contain ASCII letters and digits (at byte 2)",
+        err.to_string()
+    );
+    assert_eq!(
+        Constraint::MaxLength(8),
+        SkuRef::from_str("ABCDEFGHI").unwrap_err().constraint()
+    );
+
+    assert!(CommentRef::from_str("naïve café ☕").is_ok());
+    assert_eq!(2, CommentRef::from_str("ok\u{85}").unwrap_err().position());
+    assert_eq!(1, CommentRef::from_str("a\tb").unwrap_err().position());
+
+    assert!(Banner::from_str("Hello, world!").is_ok());
+    assert_eq!(5, Banner::from_str("Hello\n").unwrap_err().position());
+    assert_eq!(1, Banner::from_str("né").unwrap_err().position());
+
+    let sku: &SkuRef = braid_lit!(SkuRef, "X1");
+    assert_eq!("X1", sku.as_str());
+}
+
+#[test]
+fn char_lengths_are_distinct_from_byte_lengths() {
+    assert_eq!("ñoño", Nickname::from_static("ñoño").as_str());
+    assert_eq!("日本", NicknameRef::from_str("日本").unwrap().as_str());
+
+    let err = NicknameRef::from_str("é").unwrap_err();
+    assert_eq!(Constraint::MinChars(2), err.constraint());
+    assert_eq!(
+        "invalid value \"é\": must be at least 2 characters long (at byte 2)",
+        err.to_string()
+    );
+
+    let err = NicknameRef::from_str("äbcde").unwrap_err();
+    assert_eq!(Constraint::MaxChars(4), err.constraint());
+    assert_eq!(5, err.position());
+
+    let err = NicknameRef::from_str("日本語").unwrap_err();
+    assert_eq!(Constraint::MaxLength(8), err.constraint());
+
+    assert!(Initials::from_str("ÅÉÎ").is_ok());
+    assert_eq!(
+        Constraint::MaxChars(3),
+        Initials::from_str("ABCD").unwrap_err().constraint()
+    );
+
+    let initials: &Initials = braid_lit!(Initials, "ÅÉÎ");
+    assert_eq!("ÅÉÎ", initials.as_str());
+}
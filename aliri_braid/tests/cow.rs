@@ -0,0 +1,114 @@
+use std::{
+    borrow::Cow,
+    convert::{TryFrom, TryInto},
+};
+
+use aliri_braid::braid;
+
+#[braid(serde)]
+pub struct Label(Cow<'static, str>);
+
+#[braid(serde, max_len = 8)]
+pub struct ShortLabel(Cow<'static, str>);
+
+#[braid(convert_case = "snake")]
+pub struct SnakeLabel(Cow<'static, str>);
+
+const GREETING: Label = Label::from_static("hello");
+static FAREWELL: Label = Label::from_static("goodbye");
+
+fn is_borrowed(value: Label) -> bool {
+    matches!(value.take(), Cow::Borrowed(_))
+}
+
+#[test]
+fn from_static_is_const() {
+    assert_eq!("hello", GREETING.as_str());
+    assert_eq!("goodbye", FAREWELL.as_str());
+}
+
+#[test]
+fn from_static_borrows_without_allocating() {
+    let raw: &'static str = "borrowed";
+    let label = Label::from_static(raw);
+    assert_eq!(raw.as_ptr(), label.as_str().as_ptr());
+    assert!(is_borrowed(label));
+    assert!(is_borrowed(GREETING));
+}
+
+#[test]
+fn conversions_from_non_static_values_are_owned() {
+    let raw = String::from("owned");
+    assert!(!is_borrowed(Label::from(raw.as_str())));
+    assert!(!is_borrowed(Label::from(raw.clone())));
+    assert!(!is_borrowed(Label::from(raw.into_boxed_str())));
+    assert!(!is_borrowed(LabelRef::from_str("owned").to_owned()));
+}
+
+#[test]
+fn borrowed_forms_convert_to_owned() {
+    let boxed: Box<LabelRef> = Label::from_static("boxed").into_boxed_ref();
+    assert_eq!(Label::from_static("boxed"), boxed.into_owned());
+
+    let label: &LabelRef = &GREETING;
+    assert_eq!(GREETING, label.to_owned());
+}
+
+#[test]
+fn validated_values_are_checked() {
+    let short: ShortLabel = "short".try_into().unwrap();
+    assert_eq!("short", short.as_str());
+    assert!(ShortLabel::try_from("much too long").is_err());
+    assert!(ShortLabel::try_from(String::from("much too long")).is_err());
+    assert_eq!(short, ShortLabel::from_static("short"));
+}
+
+#[test]
+fn validated_static_values_are_borrowed() {
+    let raw: &'static str = "short";
+    let short = ShortLabel::from_static(raw);
+    assert_eq!(raw.as_ptr(), short.as_str().as_ptr());
+    assert!(matches!(short.take(), Cow::Borrowed(_)));
+    assert!(matches!(
+        ShortLabel::try_from_static(raw).unwrap().take(),
+        Cow::Borrowed(_)
+    ));
+    assert!(ShortLabel::try_from_static("much too long").is_err());
+}
+
+#[test]
+#[should_panic(expected = "invalid ShortLabelRef")]
+fn validated_static_values_are_checked() {
+    let _ = ShortLabel::from_static("much too long");
+}
+
+#[test]
+fn normalized_values_are_normalized() {
+    let snake: SnakeLabel = "NotSnake".parse().unwrap();
+    assert_eq!("not_snake", snake.as_str());
+    assert_eq!(snake, SnakeLabel::from_static("not_snake"));
+    assert_eq!(snake, SnakeLabel::new(Cow::Borrowed("NotSnake")).unwrap());
+}
+
+#[test]
+fn normalized_static_values_are_borrowed_when_already_normalized() {
+    let raw: &'static str = "already_snake";
+    let snake = SnakeLabel::from_static(raw);
+    assert_eq!(raw.as_ptr(), snake.as_str().as_ptr());
+    assert!(matches!(snake.take(), Cow::Borrowed(_)));
+
+    let snake = SnakeLabel::from_static("NotSnake");
+    assert_eq!("not_snake", snake.as_str());
+    assert!(matches!(snake.take(), Cow::Owned(_)));
+}
+
+#[test]
+fn serde_roundtrip() {
+    let json = serde_json::to_string(&GREETING).unwrap();
+    assert_eq!(r#""hello""#, json);
+    let label: Label = serde_json::from_str(&json).unwrap();
+    assert_eq!(GREETING, label);
+    let short: ShortLabel = serde_json::from_str(r#""short""#).unwrap();
+    assert_eq!("short", short.as_str());
+    assert!(serde_json::from_str::<ShortLabel>(r#""much too long""#).is_err());
+}
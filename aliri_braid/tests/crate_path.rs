@@ -0,0 +1,65 @@
+mod facade {
+    pub use aliri_braid as braid;
+}
+
+use std::borrow::Cow;
+
+use aliri_braid::{braid, braid_lit, braid_ref};
+
+#[braid(crate = "facade::braid", serde, max_len = 4, prefix = "#", from_bytes)]
+pub struct Tag;
+
+#[braid(crate = "facade::braid", convert_case = "snake")]
+pub struct Column;
+
+#[braid(crate = "facade::braid", normalizer = "Lowercase")]
+pub struct Lowered;
+
+#[braid_ref(crate = "facade::braid", min_len = 1)]
+pub struct TagRefOnly;
+
+pub struct Lowercase;
+
+impl facade::braid::Validator for Lowercase {
+    type Error = facade::braid::ConstraintError;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        match raw.char_indices().find(|(_, c)| c.is_uppercase()) {
+            Some((idx, _)) => Err(facade::braid::ConstraintError::new(
+                raw,
+                facade::braid::Constraint::MinLength(0),
+                idx,
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+impl facade::braid::Normalizer for Lowercase {
+    fn normalize(raw: &str) -> Result<Cow<'_, str>, Self::Error> {
+        if raw.chars().any(char::is_uppercase) {
+            Ok(Cow::Owned(raw.to_lowercase()))
+        } else {
+            Ok(Cow::Borrowed(raw))
+        }
+    }
+}
+
+#[test]
+fn braids_resolve_through_the_crate_path() {
+    assert_eq!("#ok", braid_lit!(Tag, "#ok").as_str());
+    assert!(Tag::new("#long".to_owned()).is_err());
+    assert!(Tag::new("ok".to_owned()).is_err());
+    assert_eq!(
+        "#ok",
+        TagRef::from_bytes_validated(b"#ok").unwrap().as_str()
+    );
+    assert_eq!(
+        "\"#ok\"",
+        serde_json::to_string(&Tag::from_static("#ok")).unwrap()
+    );
+
+    assert_eq!("user_id", Column::from_static("UserId").as_str());
+    assert_eq!("abc", Lowered::from_static("ABC").as_str());
+    assert!(TagRefOnly::from_str("").is_err());
+}
@@ -0,0 +1,23 @@
+extern crate alloc;
+
+use aliri_braid::{braid, braid_ref};
+
+#[braid(defmt, no_std)]
+pub struct DeviceId;
+
+#[braid(defmt, no_std, max_len = 16)]
+pub struct Channel;
+
+#[braid_ref(defmt, no_std)]
+pub struct Topic;
+
+#[braid]
+pub struct Unlogged;
+
+static_assertions::assert_impl_all!(DeviceId: defmt::Format);
+static_assertions::assert_impl_all!(DeviceIdRef: defmt::Format);
+static_assertions::assert_impl_all!(Channel: defmt::Format);
+static_assertions::assert_impl_all!(ChannelRef: defmt::Format);
+static_assertions::assert_impl_all!(Topic: defmt::Format);
+static_assertions::assert_not_impl_any!(Unlogged: defmt::Format);
+static_assertions::assert_not_impl_any!(UnloggedRef: defmt::Format);
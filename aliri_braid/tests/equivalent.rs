@@ -0,0 +1,27 @@
+use aliri_braid::braid;
+use indexmap::IndexMap;
+
+#[braid]
+pub struct Header;
+
+#[braid(min_len = 1)]
+pub struct Tenant;
+
+#[test]
+fn borrowed_keys_look_up_owned_keys() {
+    let mut map = IndexMap::new();
+    map.insert(Header::from_static("accept"), 1);
+
+    assert_eq!(Some(&1), map.get(HeaderRef::from_static("accept")));
+    assert_eq!(Some(&1), map.get("accept"));
+    assert_eq!(None, map.get("Accept"));
+}
+
+#[test]
+fn borrowed_keys_look_up_validated_owned_keys() {
+    let mut map = IndexMap::new();
+    map.insert(Tenant::from_static("acme"), 1);
+
+    assert_eq!(Some(&1), map.get(TenantRef::from_static("acme")));
+    assert_eq!(Some(&1), map.get("acme"));
+}
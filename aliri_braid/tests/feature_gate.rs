@@ -0,0 +1,40 @@
+use aliri_braid::{braid, braid_ref};
+
+/// Serializable only when the `serde` feature of the declaring crate is enabled
+#[braid(serde, feature_gate(serde = "serde"))]
+pub struct GatedName;
+
+#[braid_ref(serde, feature_gate(serde = "serde"))]
+pub struct GatedLabel;
+
+#[braid(serde)]
+pub struct UngatedName;
+
+static_assertions::assert_impl_all!(UngatedName: serde::Serialize, serde::de::DeserializeOwned);
+
+#[cfg(feature = "serde")]
+static_assertions::assert_impl_all!(
+    GatedName: serde::Serialize,
+    serde::de::DeserializeOwned
+);
+#[cfg(feature = "serde")]
+static_assertions::assert_impl_all!(GatedNameRef: serde::Serialize);
+#[cfg(feature = "serde")]
+static_assertions::assert_impl_all!(GatedLabel: serde::Serialize);
+
+#[cfg(not(feature = "serde"))]
+static_assertions::assert_not_impl_any!(
+    GatedName: serde::Serialize,
+    serde::de::DeserializeOwned
+);
+#[cfg(not(feature = "serde"))]
+static_assertions::assert_not_impl_any!(GatedNameRef: serde::Serialize);
+#[cfg(not(feature = "serde"))]
+static_assertions::assert_not_impl_any!(GatedLabel: serde::Serialize);
+
+#[test]
+fn gated_braids_keep_their_other_impls() {
+    let name = GatedName::from_static("alice");
+    assert_eq!("alice", name.as_str());
+    assert_eq!(name, *GatedNameRef::from_static("alice"));
+}
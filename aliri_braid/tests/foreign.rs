@@ -0,0 +1,102 @@
+use std::{
+    borrow::{Borrow, Cow},
+    convert::TryFrom,
+};
+
+use aliri_braid::braid_ref;
+use smartstring::alias::String as LegacyId;
+
+/// A borrowed form for an identifier type owned by another crate
+#[braid_ref(
+    owned = "LegacyId",
+    to_owned = "legacy_id_from_ref",
+    as_str = "legacy_id_as_str",
+    serde
+)]
+pub struct LegacyIdRef(str);
+
+fn legacy_id_from_ref(value: &LegacyIdRef) -> LegacyId {
+    LegacyId::from(value.as_str())
+}
+
+fn legacy_id_as_str(value: &LegacyId) -> &str {
+    value
+}
+
+/// A validated borrowed form for short codes held in a plain `String`
+#[braid_ref(
+    owned = "String",
+    to_owned = "short_code_to_string",
+    as_str = "String::as_str",
+    max_len = 4
+)]
+pub struct ShortCodeRef(str);
+
+fn short_code_to_string(value: &ShortCodeRef) -> String {
+    value.as_str().to_owned()
+}
+
+#[test]
+fn to_owned_uses_the_conversion_function() {
+    let borrowed = LegacyIdRef::from_static("user-1");
+    let owned: LegacyId = borrowed.to_owned();
+    assert_eq!("user-1", owned.as_str());
+
+    let code = ShortCodeRef::from_static("abcd");
+    let owned: String = code.to_owned();
+    assert_eq!("abcd", owned);
+}
+
+#[test]
+fn foreign_owned_values_compare_with_borrowed_forms() {
+    let owned = LegacyId::from("user-1");
+    let borrowed = LegacyIdRef::from_static("user-1");
+    assert_eq!(owned, *borrowed);
+    assert_eq!(owned, borrowed);
+    assert_eq!(borrowed, owned);
+    assert_eq!(*borrowed, owned);
+    assert_ne!(LegacyIdRef::from_static("user-2"), owned);
+
+    let boxed: Box<LegacyIdRef> = borrowed.into();
+    assert_eq!(owned, boxed);
+    assert_eq!(boxed, owned);
+}
+
+#[test]
+fn foreign_owned_values_borrow_as_the_borrowed_form() {
+    let owned = LegacyId::from("user-1");
+    let borrowed: &LegacyIdRef = owned.borrow();
+    assert_eq!("user-1", borrowed.as_str());
+
+    let owned = String::from("abcd");
+    let borrowed: &ShortCodeRef = owned.borrow();
+    assert_eq!("abcd", borrowed.as_str());
+}
+
+#[test]
+#[should_panic(expected = "`String` does not hold a valid `ShortCodeRef`")]
+fn borrowing_an_invalid_foreign_value_panics() {
+    let owned = String::from("too long");
+    let _: &ShortCodeRef = owned.borrow();
+}
+
+#[test]
+fn cow_holds_the_foreign_owned_type() {
+    let borrowed = <&ShortCodeRef>::try_from("abc").unwrap();
+    let cow: Cow<ShortCodeRef> = Cow::Borrowed(borrowed);
+    let owned: String = cow.into_owned();
+    assert_eq!("abc", owned);
+}
+
+#[test]
+fn deserialize_cow_borrows_or_converts() {
+    let mut de = serde_json::Deserializer::from_str(r#""user-1""#);
+    let cow = LegacyIdRef::deserialize_cow(&mut de).unwrap();
+    assert!(matches!(cow, Cow::Borrowed(_)));
+    assert_eq!("user-1", cow.as_str());
+
+    let mut de = serde_json::Deserializer::from_str(r#""user\u002d2""#);
+    let cow = LegacyIdRef::deserialize_cow(&mut de).unwrap();
+    assert!(matches!(cow, Cow::Owned(_)));
+    assert_eq!("user-2", cow.as_str());
+}
@@ -0,0 +1,73 @@
+use aliri_braid::{braid, Constraint, Format};
+
+#[braid(format = "hostname")]
+pub struct Hostname;
+
+#[braid(format = "hostname", max_len = 16)]
+pub struct ShortHostname;
+
+#[braid(format = "email")]
+pub struct EmailAddress;
+
+fn invalid_at<T>(result: Result<T, aliri_braid::ConstraintError>, format: Format) -> usize {
+    let err = result.err().expect("value should be invalid");
+    assert_eq!(Constraint::Format(format), err.constraint());
+    err.position()
+}
+
+#[test]
+fn hostnames() {
+    for valid in [
+        "example.com",
+        "example.com.",
+        "localhost",
+        "xn--bcher-kva.example",
+    ] {
+        assert!(HostnameRef::from_str(valid).is_ok(), "{}", valid);
+    }
+
+    let invalid = |raw| invalid_at(HostnameRef::from_str(raw), Format::Hostname);
+    assert_eq!(0, invalid(""));
+    assert_eq!(0, invalid("."));
+    assert_eq!(8, invalid("example..com"));
+    assert_eq!(8, invalid("example.-com"));
+    assert_eq!(10, invalid("example.co-"));
+    assert_eq!(3, invalid("exa_mple.com"));
+    assert_eq!(63, invalid(&"a".repeat(64)));
+}
+
+#[test]
+fn hostnames_with_constraints() {
+    assert!(ShortHostname::new("example.com".to_owned()).is_ok());
+    let err = ShortHostname::new("subdomain.example.com".to_owned()).unwrap_err();
+    assert_eq!(Constraint::MaxLength(16), err.constraint());
+}
+
+#[test]
+fn email_addresses() {
+    for valid in [
+        "user@example.com",
+        "first.last+tag@mail.example.org",
+        "o'brien@example.ie",
+    ] {
+        assert!(EmailAddressRef::from_str(valid).is_ok(), "{}", valid);
+    }
+
+    let invalid = |raw| invalid_at(EmailAddressRef::from_str(raw), Format::Email);
+    assert_eq!(10, invalid("no-at-sign"));
+    assert_eq!(0, invalid(".first@example.com"));
+    assert_eq!(6, invalid("first.@example.com"));
+    assert_eq!(6, invalid("first..last@example.com"));
+    assert_eq!(17, invalid("first@example.com."));
+    assert_eq!(6, invalid("first@"));
+    assert_eq!(6, invalid("first@-example.com"));
+}
+
+#[test]
+fn describes_the_format() {
+    let err = EmailAddress::new("nobody".to_owned()).unwrap_err();
+    assert_eq!(
+        "invalid value \"nobody\": must be a valid email address (at byte 6)",
+        err.to_string()
+    );
+}
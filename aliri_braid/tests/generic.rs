@@ -0,0 +1,111 @@
+use std::{
+    collections::{BTreeSet, HashSet},
+    marker::PhantomData,
+};
+
+use aliri_braid::braid;
+
+pub struct User;
+pub struct Order;
+
+#[braid(serde)]
+pub struct Id<T>(String, PhantomData<T>);
+
+#[braid(serde, max_len = 8)]
+pub struct ShortId<T> {
+    value: String,
+    marker: PhantomData<fn() -> T>,
+}
+
+#[braid(normalizer = "Lowercase")]
+pub struct Slug<T: ?Sized>(PhantomData<T>, String);
+
+pub struct Lowercase;
+
+impl aliri_braid::Validator for Lowercase {
+    type Error = aliri_braid::ConstraintError;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        assert!(!raw.chars().any(char::is_uppercase));
+        Ok(())
+    }
+}
+
+impl aliri_braid::Normalizer for Lowercase {
+    fn normalize(raw: &str) -> Result<std::borrow::Cow<'_, str>, Self::Error> {
+        Ok(raw.to_lowercase().into())
+    }
+}
+
+#[test]
+fn ids_are_distinct_types_sharing_one_definition() {
+    let user: Id<User> = Id::new("abc".to_owned());
+    let order: Id<Order> = Id::from_static("abc");
+    assert_eq!(user.as_str(), order.as_str());
+
+    let borrowed: &IdRef<User> = &user;
+    assert_eq!(borrowed, IdRef::<User>::from_static("abc"));
+    assert_eq!(user, borrowed.to_owned());
+
+    static_assertions::assert_not_impl_any!(Id<User>: PartialEq<Id<Order>>, From<Id<Order>>);
+}
+
+#[test]
+fn generic_braids_do_not_require_bounds_on_parameters() {
+    static_assertions::assert_impl_all!(
+        Id<User>: Clone,
+        std::hash::Hash,
+        Eq,
+        Ord,
+        std::fmt::Debug,
+        std::fmt::Display,
+        serde::Serialize,
+        serde::de::DeserializeOwned,
+    );
+    static_assertions::assert_impl_all!(IdRef<User>: std::hash::Hash, Eq, Ord, std::fmt::Debug);
+
+    let ids: HashSet<Id<User>> = ["a", "b", "a"]
+        .iter()
+        .copied()
+        .map(Id::from_static)
+        .collect();
+    assert_eq!(2, ids.len());
+    assert!(ids.contains(IdRef::from_static("a")));
+
+    let ordered: BTreeSet<&IdRef<User>> =
+        ["b", "a"].iter().copied().map(IdRef::from_static).collect();
+    assert_eq!(
+        vec!["a", "b"],
+        ordered.iter().map(|id| id.as_str()).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn generic_braids_box_and_round_trip() {
+    let id: Id<Order> = Id::from_static("order-1");
+    let boxed: Box<IdRef<Order>> = id.clone().into_boxed_ref();
+    assert_eq!(id, boxed.into_owned());
+
+    let json = serde_json::to_string(&id).unwrap();
+    let parsed: Id<Order> = serde_json::from_str(&json).unwrap();
+    assert_eq!(id, parsed);
+}
+
+#[test]
+fn validated_generic_braids_check_values() {
+    assert!(ShortId::<User>::new("short".to_owned()).is_ok());
+    assert!(ShortId::<User>::new("much too long".to_owned()).is_err());
+    assert!(ShortIdRef::<User>::from_str("much too long").is_err());
+
+    let id = ShortId::<Order>::from_static("abc");
+    assert_eq!("abc", id.value.as_str());
+    assert_eq!(id, ShortIdRef::<Order>::from_static("abc").to_owned());
+    assert_eq!(id, aliri_braid::braid_lit!(ShortId<Order>, "abc"));
+}
+
+#[test]
+fn normalized_generic_braids_normalize_values() {
+    let slug = Slug::<str>::new("Hello".to_owned()).unwrap();
+    assert_eq!("hello", slug.as_str());
+    assert_eq!("hello", SlugRef::<str>::from_str("HELLO").unwrap().as_str());
+}
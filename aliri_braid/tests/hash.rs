@@ -0,0 +1,71 @@
+use std::{
+    borrow::Borrow,
+    collections::HashSet,
+    hash::{Hash, Hasher},
+};
+
+use aliri_braid::{braid, braid_ref};
+use static_assertions::{assert_impl_all, assert_not_impl_any};
+
+/// An email address, hashed by its lowercased form
+#[braid(hash = "custom")]
+pub struct Email;
+
+impl Hash for Email {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state)
+    }
+}
+
+impl Hash for EmailRef {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for b in self.as_str().bytes() {
+            state.write_u8(b.to_ascii_lowercase());
+        }
+    }
+}
+
+#[braid(hash = "omit")]
+pub struct Opaque;
+
+#[braid(hash = "omit", eq = "ascii_case_insensitive")]
+pub struct Keyword;
+
+#[braid_ref(hash = "omit")]
+pub struct Fragment;
+
+assert_impl_all!(Email: Hash, Eq, Borrow<EmailRef>);
+assert_impl_all!(EmailRef: Hash, Eq);
+assert_not_impl_any!(Email: Borrow<str>);
+assert_not_impl_any!(EmailRef: Borrow<str>);
+
+assert_impl_all!(Opaque: Eq, Ord, Borrow<str>, Borrow<OpaqueRef>);
+assert_impl_all!(OpaqueRef: Eq, Ord, Borrow<str>);
+assert_not_impl_any!(Opaque: Hash);
+assert_not_impl_any!(OpaqueRef: Hash);
+
+assert_impl_all!(Keyword: Eq);
+assert_not_impl_any!(Keyword: Hash);
+assert_not_impl_any!(KeywordRef: Hash);
+
+assert_impl_all!(Fragment: Eq, Borrow<str>);
+assert_not_impl_any!(Fragment: Hash);
+
+#[test]
+fn custom_hash_is_used_for_both_forms() {
+    let mut set = HashSet::new();
+    set.insert(Email::from_static("user@example.com"));
+
+    assert!(set.contains(EmailRef::from_static("user@example.com")));
+    assert!(!set.contains(EmailRef::from_static("User@Example.com")));
+}
+
+#[test]
+fn omitted_hash_keeps_equality() {
+    assert_eq!(Opaque::from_static("a"), OpaqueRef::from_static("a"));
+    assert_eq!(
+        Keyword::from_static("SELECT"),
+        Keyword::from_static("select")
+    );
+    assert_eq!(Fragment::from_static("b"), Fragment::from_static("b"));
+}
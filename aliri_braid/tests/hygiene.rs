@@ -0,0 +1,106 @@
+#![deny(unused_qualifications, unused_imports)]
+
+//! Braids declared next to items that shadow the standard prelude
+
+#[allow(dead_code)]
+mod shadowed {
+    use std::borrow::Cow;
+
+    use aliri_braid::{braid, braid_ref};
+
+    pub struct Ok;
+    pub struct Err;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Option;
+    pub struct Box;
+    pub struct Vec;
+    pub struct ToOwned;
+    pub struct ToString;
+    pub struct Default;
+    pub struct From;
+    pub struct Into;
+    pub struct AsRef;
+    pub struct Clone;
+    pub struct Sized;
+    pub struct Drop;
+
+    pub fn drop() {}
+    pub fn concat() {}
+    pub fn stringify() {}
+
+    #[braid(serde, from_bytes, default, parse_as(Number = "u32"))]
+    pub struct Plain;
+
+    #[braid(
+        serde,
+        validator = "Checked",
+        max_len = 8,
+        from_bytes,
+        test_ctor,
+        same_repr_as = "SameAsValidated"
+    )]
+    pub struct Validated;
+
+    #[braid(serde, normalizer = "Lowercase", default = "x", from_bytes, test_ctor)]
+    pub struct Normalized;
+
+    #[braid(serde = "str", convert_from = "Plain")]
+    pub struct Promoted;
+
+    #[braid(validator = "Checked", max_len = 8, same_repr_as = "Validated")]
+    pub struct SameAsValidated;
+
+    #[braid_ref(serde, alloc, validator = "Checked", from_bytes)]
+    pub struct RefOnly;
+
+    pub struct Checked;
+
+    impl aliri_braid::Validator for Checked {
+        type Error = aliri_braid::ConstraintError;
+
+        fn validate(raw: &str) -> core::result::Result<(), Self::Error> {
+            match raw.find(' ') {
+                core::option::Option::Some(idx) => {
+                    core::result::Result::Err(aliri_braid::ConstraintError::new(
+                        raw,
+                        aliri_braid::Constraint::MinLength(0),
+                        idx,
+                    ))
+                }
+                core::option::Option::None => core::result::Result::Ok(()),
+            }
+        }
+    }
+
+    pub struct Lowercase;
+
+    impl aliri_braid::Validator for Lowercase {
+        type Error = aliri_braid::ConstraintError;
+
+        fn validate(raw: &str) -> core::result::Result<(), Self::Error> {
+            <Checked as aliri_braid::Validator>::validate(raw)
+        }
+    }
+
+    impl aliri_braid::Normalizer for Lowercase {
+        fn normalize(raw: &str) -> core::result::Result<Cow<'_, str>, Self::Error> {
+            core::result::Result::Ok(Cow::Owned(raw.to_lowercase()))
+        }
+    }
+}
+
+#[test]
+fn braids_ignore_shadowed_prelude_items() {
+    use shadowed::*;
+
+    assert_eq!("abc", Plain::from_static("abc").as_str());
+    assert!(Validated::new("a b".to_owned()).is_err());
+    assert_eq!("abc", Normalized::from_static("ABC").as_str());
+    assert_eq!(
+        "\"abc\"",
+        serde_json::to_string(&Promoted::from(Plain::from_static("abc"))).unwrap()
+    );
+    assert!(RefOnly::from_str("a b").is_err());
+}
@@ -0,0 +1,52 @@
+use std::collections::HashSet;
+
+use aliri_braid::{braid, Constraint, Format};
+
+#[braid(format = "idna")]
+pub struct DomainName;
+
+#[test]
+fn normalizes_into_ascii_form() {
+    assert_eq!(
+        "example.com",
+        DomainName::from_static("Example.COM").as_str()
+    );
+    assert_eq!(
+        "xn--bcher-kva.example",
+        DomainName::from_static("Bücher.Example").as_str()
+    );
+    assert_eq!(
+        "xn--bcher-kva.example",
+        DomainName::from_static("xn--bcher-kva.example").as_str()
+    );
+    assert_eq!(
+        "example.com.",
+        DomainName::from_static("example.com.").as_str()
+    );
+}
+
+#[test]
+fn equivalent_forms_compare_and_hash_alike() {
+    let names: HashSet<DomainName> = ["bücher.example", "BÜCHER.example", "xn--bcher-kva.EXAMPLE"]
+        .iter()
+        .map(|raw| DomainName::new((*raw).to_owned()).unwrap())
+        .collect();
+    assert_eq!(1, names.len());
+}
+
+#[test]
+fn rejects_invalid_and_non_normalized_names() {
+    for invalid in ["exa mple.com", "-example.com", "under_score.example", ""] {
+        let err = DomainName::new(invalid.to_owned()).unwrap_err();
+        assert_eq!(
+            Constraint::Format(Format::Idna),
+            err.constraint(),
+            "{:?}",
+            invalid
+        );
+    }
+
+    let err = DomainNameRef::from_normalized_str("example.COM").unwrap_err();
+    assert_eq!(8, err.position());
+    assert!(DomainNameRef::from_normalized_str("example.com").is_ok());
+}
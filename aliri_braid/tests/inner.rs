@@ -0,0 +1,155 @@
+use std::{
+    collections::HashSet,
+    convert::TryFrom,
+    ffi::{CStr, CString, OsStr},
+    path::{Path, PathBuf},
+};
+
+use aliri_braid::{braid, CStringError, InnerValidator, PathError};
+
+#[braid(ref_inner = "[u8]")]
+pub struct Payload(Vec<u8>);
+
+#[braid(ref_inner = "Path", ref_name = "RelPath", validator)]
+pub struct RelPathBuf(PathBuf);
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct AbsolutePath;
+
+impl InnerValidator for RelPathBuf {
+    type Inner = Path;
+    type Error = AbsolutePath;
+
+    fn validate(inner: &Path) -> Result<(), Self::Error> {
+        if inner.is_absolute() {
+            Err(AbsolutePath)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[test]
+fn unvalidated_round_trip() {
+    let payload = Payload::new(vec![1, 2, 3]);
+    let borrowed: &PayloadRef = &payload;
+    assert_eq!(&[1, 2, 3], borrowed.as_inner());
+    assert_eq!(payload, borrowed);
+    assert_eq!(payload, borrowed.to_owned());
+    assert_eq!(vec![1, 2, 3], payload.into_inner());
+}
+
+#[test]
+fn borrowed_reinterprets_in_place() {
+    let raw: &[u8] = &[4, 5];
+    let borrowed = PayloadRef::from_inner(raw);
+    assert_eq!(raw.as_ptr(), borrowed.as_inner().as_ptr());
+}
+
+#[test]
+fn validated_constructors() {
+    let rel = RelPathBuf::new(PathBuf::from("src/lib.rs")).unwrap();
+    assert_eq!(Path::new("src/lib.rs"), rel.as_inner());
+    assert_eq!(
+        Err(AbsolutePath),
+        RelPathBuf::try_from(PathBuf::from("/etc")).map(drop)
+    );
+
+    let borrowed = RelPath::from_inner(Path::new("Cargo.toml")).unwrap();
+    assert_eq!(Path::new("Cargo.toml"), AsRef::<Path>::as_ref(borrowed));
+    assert_eq!(
+        Err(AbsolutePath),
+        RelPath::from_inner(Path::new("/")).map(drop)
+    );
+}
+
+#[test]
+fn owned_and_borrowed_hash_alike() {
+    let mut set = HashSet::new();
+    set.insert(RelPathBuf::new(PathBuf::from("a")).unwrap());
+    assert!(set.contains(RelPath::from_inner(Path::new("a")).unwrap()));
+}
+
+#[test]
+fn debug_delegates_to_inner() {
+    let rel = RelPathBuf::new(PathBuf::from("a")).unwrap();
+    assert_eq!(format!("{:?}", Path::new("a")), format!("{:?}", rel));
+}
+
+#[braid(path)]
+pub struct AnyPathBuf;
+
+#[braid(path, absolute, ref_name = "RootedPath")]
+pub struct RootedPathBuf(PathBuf);
+
+#[test]
+fn path_defaults_to_path_buf() {
+    let any = AnyPathBuf::new(PathBuf::from("/usr"));
+    assert_eq!(PathBuf::from("/usr"), any.clone().into_inner());
+    assert_eq!(PathBuf::from("/usr/bin"), any.join("bin"));
+    assert_eq!("/usr", any.display().to_string());
+    assert_eq!(OsStr::new("/usr"), AsRef::<OsStr>::as_ref(&any));
+    assert_eq!(Path::new("/usr"), AsRef::<Path>::as_ref(&any));
+}
+
+#[test]
+fn path_checks() {
+    assert!(RootedPathBuf::new(PathBuf::from("/usr")).is_ok());
+    assert_eq!(
+        Err(PathError::NotAbsolute),
+        RootedPath::from_inner(Path::new("usr")).map(drop)
+    );
+    assert_eq!("path must be absolute", PathError::NotAbsolute.to_string());
+}
+
+#[braid(c_str)]
+pub struct Symbol;
+
+#[braid(c_str, validator)]
+pub struct LowerSymbol;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct NotLowercase;
+
+impl InnerValidator for LowerSymbol {
+    type Inner = CStr;
+    type Error = NotLowercase;
+
+    fn validate(inner: &CStr) -> Result<(), Self::Error> {
+        if inner.to_bytes().iter().all(u8::is_ascii_lowercase) {
+            Ok(())
+        } else {
+            Err(NotLowercase)
+        }
+    }
+}
+
+#[test]
+fn c_str_defaults_to_c_string() {
+    let symbol: Symbol = "init".parse().unwrap();
+    assert_eq!(b"init", symbol.to_bytes());
+    assert_eq!(Ok("init"), symbol.to_str());
+    assert_eq!(symbol.as_inner().as_ptr(), symbol.as_ptr());
+    assert_eq!(CString::new("init").unwrap(), symbol.into_inner());
+}
+
+#[test]
+fn c_str_rejects_interior_nul() {
+    assert!(matches!(
+        Symbol::try_from("in\0it"),
+        Err(CStringError::Nul(err)) if err.nul_position() == 2
+    ));
+}
+
+#[test]
+fn c_str_validates() {
+    assert!(LowerSymbol::try_from("init").is_ok());
+    assert_eq!(
+        Err(CStringError::Invalid(NotLowercase)),
+        "Init".parse::<LowerSymbol>().map(drop)
+    );
+    assert_eq!(
+        Err(NotLowercase),
+        LowerSymbolRef::from_inner(CStr::from_bytes_with_nul(b"X\0").unwrap()).map(drop)
+    );
+}
@@ -0,0 +1,175 @@
+use std::{borrow::Cow, fmt, marker::PhantomData, sync::Arc};
+
+use aliri_braid::{braid, braid_ref, ConstraintError, Interner};
+use serde::de::{DeserializeSeed, Deserializer, SeqAccess, Visitor};
+
+#[braid(intern)]
+pub struct Token;
+
+#[braid(intern, max_len = 4)]
+pub struct Short;
+
+#[braid(intern, normalizer)]
+pub struct Lower;
+
+impl aliri_braid::Validator for Lower {
+    type Error = ConstraintError;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        match raw.char_indices().find(|(_, c)| c.is_uppercase()) {
+            Some((idx, _)) => Err(ConstraintError::new(
+                raw,
+                aliri_braid::Constraint::MinLength(0),
+                idx,
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+impl aliri_braid::Normalizer for Lower {
+    fn normalize(raw: &str) -> Result<Cow<'_, str>, Self::Error> {
+        if raw.chars().any(char::is_uppercase) {
+            Ok(Cow::Owned(raw.to_lowercase()))
+        } else {
+            Ok(Cow::Borrowed(raw))
+        }
+    }
+}
+
+#[braid_ref(intern, alloc, min_len = 1)]
+pub struct Field;
+
+#[braid(intern)]
+pub struct Id<T>(String, PhantomData<T>);
+
+#[test]
+fn equal_values_share_storage() {
+    let mut interner = Interner::<TokenRef>::new();
+    let first = interner.intern("token").unwrap();
+    let second = interner.intern(&String::from("token")).unwrap();
+    let other = interner.intern("other").unwrap();
+
+    assert!(Arc::ptr_eq(&first, &second));
+    assert!(!Arc::ptr_eq(&first, &other));
+    assert_eq!(2, interner.len());
+    assert!(Arc::ptr_eq(&first, &interner.get("token").unwrap()));
+}
+
+#[test]
+fn values_are_checked_before_interning() {
+    let mut interner = Interner::<ShortRef>::new();
+    assert_eq!("abc", interner.intern("abc").unwrap().as_str());
+    assert!(interner.intern("too long").is_err());
+    assert_eq!(1, interner.len());
+
+    let mut interner = Interner::<Field>::new();
+    assert_eq!("x", interner.intern("x").unwrap().as_str());
+    assert!(interner.intern("").is_err());
+    assert!(interner.get("").is_none());
+}
+
+#[test]
+fn normalized_values_share_storage() {
+    let mut interner = Interner::<LowerRef>::new();
+    let loud = interner.intern("MiXeD").unwrap();
+    let quiet = interner.intern("mixed").unwrap();
+
+    assert_eq!("mixed", loud.as_str());
+    assert!(Arc::ptr_eq(&loud, &quiet));
+    assert_eq!(1, interner.len());
+}
+
+#[test]
+fn checked_values_can_be_inserted() {
+    let mut interner = Interner::<ShortRef>::new();
+    let short = Short::from_static("abc");
+    let inserted = interner.insert(&short);
+    assert!(Arc::ptr_eq(&inserted, &interner.intern("abc").unwrap()));
+
+    interner.clear();
+    assert!(interner.is_empty());
+    assert!(!Arc::ptr_eq(&inserted, &interner.intern("abc").unwrap()));
+}
+
+#[test]
+fn generic_braids_can_be_interned() {
+    struct User;
+
+    let mut interner = Interner::<IdRef<User>>::default();
+    let first = interner.intern("user").unwrap();
+    let second = interner.intern("user").unwrap();
+    assert!(Arc::ptr_eq(&first, &second));
+}
+
+#[test]
+fn interner_debug_lists_values() {
+    let mut interner = Interner::<TokenRef>::new();
+    interner.intern("b").unwrap();
+    interner.intern("a").unwrap();
+    assert_eq!(r#"{"a", "b"}"#, format!("{:?}", interner));
+}
+
+#[test]
+fn values_deserialize_into_the_interner() {
+    let mut interner = Interner::<ShortRef>::new();
+    let mut de = serde_json::Deserializer::from_str(r#""abc" "abc" "def""#);
+
+    let first = (&mut interner).deserialize(&mut de).unwrap();
+    let second = (&mut interner).deserialize(&mut de).unwrap();
+    let third = (&mut interner).deserialize(&mut de).unwrap();
+
+    assert!(Arc::ptr_eq(&first, &second));
+    assert_eq!("def", third.as_str());
+    assert_eq!(2, interner.len());
+}
+
+#[test]
+fn invalid_values_fail_to_deserialize() {
+    let mut interner = Interner::<ShortRef>::new();
+    let mut de = serde_json::Deserializer::from_str(r#""too long""#);
+    assert!((&mut interner).deserialize(&mut de).is_err());
+    assert!(interner.is_empty());
+}
+
+/// Deserializes a sequence of tokens, interning each one
+struct Tokens<'i>(&'i mut Interner<TokenRef>);
+
+impl<'de> DeserializeSeed<'de> for Tokens<'_> {
+    type Value = Vec<Arc<TokenRef>>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de> Visitor<'de> for Tokens<'_> {
+    type Value = Vec<Arc<TokenRef>>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a sequence of tokens")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut tokens = Vec::new();
+        while let Some(token) = seq.next_element_seed(&mut *self.0)? {
+            tokens.push(token);
+        }
+        Ok(tokens)
+    }
+}
+
+#[test]
+fn repeated_values_in_a_document_share_storage() {
+    let mut interner = Interner::<TokenRef>::new();
+    let json = r#"["a", "b", "a", "a", "b"]"#;
+    let tokens = Tokens(&mut interner)
+        .deserialize(&mut serde_json::Deserializer::from_str(json))
+        .unwrap();
+
+    assert_eq!(5, tokens.len());
+    assert!(Arc::ptr_eq(&tokens[0], &tokens[2]));
+    assert!(Arc::ptr_eq(&tokens[0], &tokens[3]));
+    assert!(Arc::ptr_eq(&tokens[1], &tokens[4]));
+    assert_eq!(2, interner.len());
+}
@@ -21,7 +21,7 @@ pub struct ValidatedWithCustomRefName;
 #[braid(serde)]
 pub struct Orange;
 
-#[braid]
+#[braid(iter)]
 pub struct OrangeWithNamedField {
     id: String,
 }
@@ -88,7 +88,7 @@ impl aliri_braid::Validator for NormalizedBuf {
 }
 
 impl aliri_braid::Normalizer for NormalizedBuf {
-    fn normalize(s: &str) -> Result<Cow<str>, Self::Error> {
+    fn normalize(s: &str) -> Result<Cow<'_, str>, Self::Error> {
         if s.chars().any(|c| c.len_utf8() > 3) {
             Err(InvalidData)
         } else if s.contains(' ') {
@@ -179,9 +179,737 @@ impl fmt::Display for SecretRef {
     }
 }
 
+#[derive(Debug)]
+pub enum DomainError {
+    InvalidData(InvalidData),
+    Constraint(aliri_braid::ConstraintError),
+}
+
+impl fmt::Display for DomainError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidData(err) => err.fmt(f),
+            Self::Constraint(err) => err.fmt(f),
+        }
+    }
+}
+
+impl From<InvalidData> for DomainError {
+    fn from(err: InvalidData) -> Self {
+        Self::InvalidData(err)
+    }
+}
+
+impl From<aliri_braid::ConstraintError> for DomainError {
+    fn from(err: aliri_braid::ConstraintError) -> Self {
+        Self::Constraint(err)
+    }
+}
+
+aliri_braid::from_infallible!(DomainError);
+
+impl std::error::Error for DomainError {}
+
+#[braid(serde, validator = "ValidatedBuf", error = "DomainError")]
+pub struct MappedErrorValidated;
+
+#[braid(serde, normalizer = "NormalizedBuf", error = "DomainError")]
+pub struct MappedErrorNormalized;
+
+#[braid(max_len = 4, error = "DomainError")]
+pub struct MappedErrorConstrained;
+
+#[aliri_braid::braid_ref(validator = "ValidatedBuf", error = "DomainError")]
+pub struct MappedErrorRefOnly;
+
+#[braid(parse_as(SocketAddr = "std::net::SocketAddr", Port = "u16"))]
+pub struct Endpoint;
+
+#[aliri_braid::braid_ref(parse_as(Number = "i64"))]
+pub struct NumericRefOnly;
+
+mod sealed {
+    #[aliri_braid::braid(validator = "super::ValidatedBuf", unchecked = "omit")]
+    pub struct Sealed;
+
+    #[aliri_braid::braid(normalizer = "super::NormalizedBuf", unchecked = "omit")]
+    pub struct SealedNormalized;
+}
+
+#[braid(validator = "ValidatedBuf", unchecked = "rename:trusted")]
+pub struct Trusted;
+
+#[aliri_braid::braid_ref(validator = "ValidatedBuf", unchecked = "rename:trusted")]
+pub struct TrustedRefOnly;
+
+#[braid(validator = "ValidatedBuf", unchecked = "safe_hidden")]
+pub struct SafeHidden;
+
+#[braid(normalizer = "NormalizedBuf", unchecked = "safe_hidden")]
+pub struct SafeHiddenNormalized;
+
+#[aliri_braid::braid_ref(validator = "ValidatedBuf", unchecked = "safe_hidden")]
+pub struct SafeHiddenRefOnly;
+
+#[braid(from_bytes)]
+pub struct Mapped;
+
+#[braid(from_bytes, validator = "ValidatedBuf")]
+pub struct MappedValidated;
+
+#[braid(from_bytes, normalizer = "NormalizedBuf")]
+pub struct MappedNormalized;
+
+#[aliri_braid::braid_ref(from_bytes, validator = "ValidatedBuf", error = "DomainError")]
+pub struct MappedRefOnly;
+
+#[braid(serde, default)]
+pub struct DefaultEmpty;
+
+#[braid(serde, default = "guest", max_len = 8)]
+pub struct DefaultUser;
+
+#[braid(normalizer = "NormalizedBuf", default = "no spaces")]
+pub struct DefaultNormalized;
+
+#[braid(convert_from = "Basic")]
+pub struct Promoted;
+
+#[braid(
+    validator = "ValidatedBuf",
+    error = "DomainError",
+    convert_from = "Basic",
+    convert_from = "Promoted"
+)]
+pub struct PromotedValidated;
+
+#[braid(validator = "ValidatedBuf", test_ctor)]
+pub struct TestValidated;
+
+#[braid(normalizer = "NormalizedBuf", test_ctor)]
+pub struct TestNormalized;
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+#[serde(transparent)]
+pub struct ExistingOwned(String);
+
+impl ExistingOwned {
+    pub fn new(raw: String) -> Self {
+        Self(raw)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for ExistingOwned {
+    fn from(raw: String) -> Self {
+        Self::new(raw)
+    }
+}
+
+#[aliri_braid::braid_ref(owned = "ExistingOwned", serde)]
+pub struct ExistingRef;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExistingValidated(String);
+
+impl ExistingValidated {
+    pub fn new_unchecked(raw: String) -> Self {
+        Self(raw)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+#[aliri_braid::braid_ref(
+    owned = "ExistingValidated",
+    validator = "ValidatedBuf",
+    unchecked = "safe_hidden"
+)]
+pub struct ExistingValidatedRef;
+
+/// A string backing that serializes itself as raw bytes
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ByteBacked(String);
+
+impl From<String> for ByteBacked {
+    fn from(raw: String) -> Self {
+        Self(raw)
+    }
+}
+
+impl From<&str> for ByteBacked {
+    fn from(raw: &str) -> Self {
+        Self(raw.to_owned())
+    }
+}
+
+impl From<Box<str>> for ByteBacked {
+    fn from(raw: Box<str>) -> Self {
+        Self(raw.into())
+    }
+}
+
+impl From<ByteBacked> for String {
+    fn from(backed: ByteBacked) -> Self {
+        backed.0
+    }
+}
+
+impl From<ByteBacked> for Box<str> {
+    fn from(backed: ByteBacked) -> Self {
+        backed.0.into_boxed_str()
+    }
+}
+
+impl AsRef<str> for ByteBacked {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::borrow::Borrow<str> for ByteBacked {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl serde::Serialize for ByteBacked {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.0.as_bytes())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ByteBacked {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self)
+    }
+}
+
+#[aliri_braid::braid(serde)]
+pub struct WrappedSerialized(ByteBacked);
+
+#[aliri_braid::braid(serde = "str")]
+pub struct StrSerialized(ByteBacked);
+
+/// A string backing that cannot be created from a `Box<str>`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Unboxable(String);
+
+impl From<String> for Unboxable {
+    fn from(raw: String) -> Self {
+        Self(raw)
+    }
+}
+
+impl From<&str> for Unboxable {
+    fn from(raw: &str) -> Self {
+        Self(raw.to_owned())
+    }
+}
+
+impl From<Unboxable> for String {
+    fn from(unboxable: Unboxable) -> Self {
+        unboxable.0
+    }
+}
+
+impl AsRef<str> for Unboxable {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Unboxable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[braid(omit(box_conversions))]
+pub struct Unboxed(Unboxable);
+
+#[braid(omit(box_conversions), validator = "ValidatedBuf")]
+pub struct UnboxedValidated(Unboxable);
+
+static_assertions::assert_not_impl_any!(Unboxed: From<Box<str>>, From<Box<UnboxedRef>>);
+static_assertions::assert_not_impl_any!(UnboxedValidated: From<Box<UnboxedValidatedRef>>);
+
+#[braid(omit(arc))]
+pub struct Unshared;
+
+#[aliri_braid::braid_ref(alloc, omit(rc, arc))]
+pub struct UncountedRefOnly;
+
+static_assertions::assert_impl_all!(std::rc::Rc<UnsharedRef>: From<&'static UnsharedRef>);
+static_assertions::assert_not_impl_any!(std::sync::Arc<UnsharedRef>: From<&'static UnsharedRef>);
+static_assertions::assert_not_impl_any!(
+    std::rc::Rc<UncountedRefOnly>: From<&'static UncountedRefOnly>
+);
+static_assertions::assert_not_impl_any!(
+    std::sync::Arc<UncountedRefOnly>: From<&'static UncountedRefOnly>
+);
+
+#[aliri_braid::braid_ref(alloc, serde)]
+pub struct AllocRefOnly;
+
+#[aliri_braid::braid_ref(alloc, serde, validator = "ValidatedBuf")]
+pub struct AllocValidatedRefOnly;
+
+#[braid(serde, validator = "ValidatedBuf", from_bytes, constructor = "parse")]
+pub struct Parsed;
+
+#[braid(serde, normalizer = "NormalizedBuf", constructor = "parse")]
+pub struct ParsedNormalized;
+
+#[aliri_braid::braid_ref(serde, validator = "ValidatedBuf", constructor = "parse")]
+pub struct ParsedRefOnly;
+
+#[braid(naming = "v2")]
+pub struct Renamed;
+
+#[braid(naming = "v2", validator = "ValidatedBuf")]
+pub struct RenamedValidated;
+
+#[braid(naming = "v2", normalizer = "NormalizedBuf")]
+pub struct RenamedNormalized;
+
+#[aliri_braid::braid_ref(naming = "v2", validator = "ValidatedBuf")]
+pub struct RenamedRefOnly;
+
+#[braid(naming = "v2", validator = "ValidatedBuf", constructor = "parse")]
+pub struct RenamedParsed;
+
+#[braid(
+    validator = "ValidatedBuf",
+    omit(from_static, try_from_static, take, into_boxed_ref, into_owned)
+)]
+pub struct Trimmed;
+
+impl Trimmed {
+    pub fn from_static(raw: &'static str) -> Self {
+        Self::new(raw.trim().to_owned()).unwrap()
+    }
+
+    pub fn take(&mut self) -> String {
+        std::mem::take(&mut self.0)
+    }
+}
+
+impl TrimmedRef {
+    pub fn from_static(raw: &'static str) -> &'static Self {
+        Self::from_str(raw.trim()).unwrap()
+    }
+}
+
+#[braid(naming = "v2", omit(take))]
+pub struct RenamedWithoutAlias;
+
+impl RenamedWithoutAlias {
+    pub fn take(&self) -> &str {
+        self.as_str()
+    }
+}
+
+#[aliri_braid::braid_ref(owned = "ExistingOwned", omit(from_static))]
+pub struct OmittedRefOnly;
+
+impl OmittedRefOnly {
+    pub fn from_static(raw: &'static str) -> &'static Self {
+        Self::from_str(raw.trim())
+    }
+}
+
+#[braid(same_repr_as = "OrgId")]
+pub struct TenantId;
+
+#[braid(same_repr_as = "TenantId")]
+pub struct OrgId;
+
+#[braid(validator = "ValidatedBuf", same_repr_as = "LegacyValidated")]
+pub struct MigratedValidated;
+
+#[braid(validator = "ValidatedBuf", same_repr_as = "MigratedValidated")]
+pub struct LegacyValidated;
+
+#[braid(prefix = "t_", max_len = 8, same_repr_as = "LegacyPrefixed")]
+pub struct MigratedPrefixed;
+
+#[braid(max_len = 8, prefix = "t_", same_repr_as = "MigratedPrefixed")]
+pub struct LegacyPrefixed;
+
+pub mod opaque {
+    use aliri_braid::braid;
+
+    #[braid(no_expose = "opaque", validator = "crate::ValidatedBuf")]
+    pub struct Secret;
+
+    impl SecretRef {
+        pub fn is_short(&self) -> bool {
+            self.as_str().len() < 8
+        }
+    }
+}
+
+static_assertions::assert_not_impl_any!(opaque::Secret: Into<String>, AsRef<str>);
+
+pub mod restricted {
+    use aliri_braid::{braid, braid_ref};
+
+    #[braid(expose = "pub(crate)", validator = "crate::ValidatedBuf")]
+    pub struct Internal;
+
+    #[braid_ref(expose = "pub(crate)")]
+    pub struct InternalRefOnly;
+}
+
+#[braid(
+    owned_attr(cfg_attr(all(), must_use = "conditionally must be used")),
+    ref_attr(cfg_attr(any(), derive(NotADerive))),
+    ref_attr(cfg_attr(all(), doc = "Conditionally documented"))
+)]
+pub struct ConditionalAttrs;
+
+#[braid(
+    owned_attr(derive(serde::Serialize)),
+    owned_field_attr(serde(serialize_with = "serialize_uppercase")),
+    ref_field_attr(doc = "The borrowed value")
+)]
+pub struct FieldAttrs;
+
+fn serialize_uppercase<S: serde::Serializer>(
+    value: &str,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&value.to_uppercase())
+}
+
+/// Replaced by `owned_doc`
+#[braid(
+    owned_doc = "An owned value, borrowed as [`{ref}`]",
+    owned_doc = "",
+    owned_doc = "See also [`{owned}`] and [`{ref}`]",
+    ref_doc = "A borrowed value, owned as [`{owned}`]"
+)]
+#[must_use]
+pub struct TemplatedDocs;
+
+/// Kept on the borrowed type
+#[braid(owned_doc = "An owned value, borrowed as [`{ref}`]")]
+pub struct OwnedDocOnly;
+
+#[braid(alias = "user_id", alias = "uid", ref_doc = "A borrowed [`{owned}`]")]
+pub struct AliasedUser;
+
+#[aliri_braid::braid_ref(alias = "user_name")]
+pub struct AliasedUserName;
+
+#[braid(no_must_use)]
+pub struct Discardable;
+
+#[braid(mutable)]
+pub struct ArnBuilder;
+
+#[braid(add_str, build)]
+pub struct ArnPrefix;
+
+#[braid(add, build, max_len = 6)]
+pub struct ShortCode;
+
+#[braid(segments = ':')]
+pub struct Urn;
+
+#[braid(min_len = 1)]
+pub struct ArnPart;
+
+#[braid(segments = ':', segment = "ArnPartRef")]
+pub struct Arn;
+
+#[aliri_braid::braid_ref(segments = '/')]
+pub struct KeyPath;
+
+#[braid(eq = "ascii_case_insensitive")]
+pub struct HostName;
+
+#[aliri_braid::braid_ref(eq = "ascii_case_insensitive")]
+pub struct HeaderName;
+
+#[braid(ord = "natural")]
+pub struct FileName;
+
+#[braid(ord = "case_insensitive")]
+pub struct Tag;
+
+#[aliri_braid::braid_ref(ord = "natural")]
+pub struct Version;
+
+#[braid(as_path, validator = "ValidatedBuf")]
+pub struct ConfigFile;
+
+#[braid(assert_layout)]
+pub struct LaidOut;
+
+#[braid(assert_layout, normalizer = "NormalizedBuf")]
+pub struct LaidOutNormalized {
+    value: String,
+}
+
+#[braid(assert_layout, ref_attr(must_use))]
+pub struct LaidOutByteBacked(ByteBacked);
+
 mod tests {
+    use std::convert::{TryFrom, TryInto};
+
     use super::*;
 
+    #[test]
+    fn check_mapped_error_types() {
+        static_assertions::assert_impl_all!(
+            MappedErrorValidated: TryFrom<String, Error = DomainError>,
+            TryFrom<&'static str, Error = DomainError>,
+            std::str::FromStr<Err = DomainError>,
+        );
+        static_assertions::assert_impl_all!(
+            &'static MappedErrorValidatedRef: TryFrom<&'static str, Error = DomainError>,
+        );
+        static_assertions::assert_impl_all!(
+            MappedErrorNormalized: TryFrom<String, Error = DomainError>,
+            std::str::FromStr<Err = DomainError>,
+        );
+        static_assertions::assert_impl_all!(
+            &'static MappedErrorRefOnly: TryFrom<&'static str, Error = DomainError>,
+        );
+    }
+
+    #[test]
+    fn check_mapped_errors_are_converted() {
+        assert!(matches!(
+            MappedErrorValidated::new("🏗".to_owned()),
+            Err(DomainError::InvalidData(_))
+        ));
+        assert!(matches!(
+            MappedErrorNormalizedRef::from_str("🏗"),
+            Err(DomainError::InvalidData(_))
+        ));
+        assert!(matches!(
+            MappedErrorConstrainedRef::from_str("too long"),
+            Err(DomainError::Constraint(_))
+        ));
+        assert!(matches!(
+            MappedErrorRefOnly::from_str("🏗"),
+            Err(DomainError::InvalidData(_))
+        ));
+        assert!(serde_json::from_str::<MappedErrorValidated>("\"🏗\"").is_err());
+    }
+
+    #[test]
+    fn check_parse_as_adapters() {
+        let endpoint = Endpoint::from_static("127.0.0.1:8080");
+        assert_eq!(8080, endpoint.as_socket_addr().unwrap().port());
+        assert!(endpoint.as_port().is_err());
+        assert_eq!(Ok(443), u16::try_from(&*Endpoint::from_static("443")));
+
+        assert_eq!(-7, NumericRefOnly::from_str("-7").as_number().unwrap());
+        assert!(i64::try_from(NumericRefOnly::from_str("seven")).is_err());
+
+        static_assertions::assert_impl_all!(
+            &'static EndpointRef: TryInto<std::net::SocketAddr, Error = std::net::AddrParseError>,
+        );
+    }
+
+    #[test]
+    fn check_omitted_unchecked_constructors_still_back_conversions() {
+        use sealed::{Sealed, SealedNormalized, SealedNormalizedRef, SealedRef};
+
+        let owned = Sealed::new("valid".to_owned()).unwrap();
+        let borrowed: &SealedRef = &owned;
+        assert_eq!("valid", borrowed.as_str());
+        assert!(SealedRef::from_str("🏗").is_err());
+        assert_eq!(owned, Box::<SealedRef>::from(owned.clone()).into_owned());
+
+        let normalized = SealedNormalizedRef::from_str("a b").unwrap();
+        assert_eq!("ab", normalized.as_str());
+        assert_eq!(
+            "ab",
+            SealedNormalized::from_static("ab")
+                .into_boxed_ref()
+                .into_owned()
+                .as_str()
+        );
+    }
+
+    #[test]
+    #[allow(unsafe_code)]
+    fn check_renamed_unchecked_constructors() {
+        let owned = unsafe { Trusted::new_trusted("valid".to_owned()) };
+        let borrowed = unsafe { TrustedRef::from_str_trusted("valid") };
+        assert_eq!(owned, borrowed);
+
+        let ref_only = unsafe { TrustedRefOnly::from_str_trusted("valid") };
+        assert_eq!("valid", ref_only.as_str());
+    }
+
+    #[test]
+    fn check_safe_hidden_unchecked_constructors() {
+        let owned = SafeHidden::new_unchecked("valid".to_owned());
+        let borrowed = SafeHiddenRef::from_str_unchecked("valid");
+        assert_eq!(owned, borrowed);
+        assert_eq!(
+            owned,
+            Box::<SafeHiddenRef>::from(owned.clone()).into_owned()
+        );
+
+        let normalized = SafeHiddenNormalizedRef::from_str("a b").unwrap();
+        assert_eq!("ab", normalized.as_str());
+        assert_eq!(
+            "ab",
+            SafeHiddenNormalized::new_unchecked("ab".to_owned()).as_str()
+        );
+
+        let ref_only = SafeHiddenRefOnly::from_str_unchecked("valid");
+        assert_eq!("valid", ref_only.as_str());
+    }
+
+    #[test]
+    fn check_from_bytes_validated() {
+        use aliri_braid::FromBytesError;
+
+        let buffer = b"plain\xff\xfevalid a b";
+        let plain = MappedRef::from_bytes_validated(&buffer[..5]).unwrap();
+        assert_eq!("plain", plain.as_str());
+        assert_eq!(buffer.as_ptr(), plain.as_str().as_ptr());
+        assert!(matches!(
+            MappedRef::from_bytes_validated(&buffer[5..7]),
+            Err(FromBytesError::Utf8(_))
+        ));
+
+        let validated = MappedValidatedRef::from_bytes_validated(&buffer[7..12]).unwrap();
+        assert_eq!("valid", validated.as_str());
+        assert!(matches!(
+            MappedValidatedRef::from_bytes_validated("🏗".as_bytes()),
+            Err(FromBytesError::Invalid(InvalidData))
+        ));
+
+        assert!(MappedNormalizedRef::from_bytes_validated(&buffer[7..12]).is_ok());
+        assert!(matches!(
+            MappedNormalizedRef::from_bytes_validated(&buffer[7..]),
+            Err(FromBytesError::Invalid(InvalidData))
+        ));
+
+        assert!(matches!(
+            MappedRefOnly::from_bytes_validated("🏗".as_bytes()),
+            Err(FromBytesError::Invalid(DomainError::InvalidData(_)))
+        ));
+    }
+
+    #[test]
+    fn check_from_utf8() {
+        use std::convert::TryFrom;
+
+        use aliri_braid::FromBytesError;
+
+        let bytes = b"plain".to_vec();
+        let ptr = bytes.as_ptr();
+        let plain = Mapped::from_utf8(bytes).unwrap();
+        assert_eq!("plain", plain.as_str());
+        assert_eq!(ptr, plain.as_str().as_ptr());
+        assert!(matches!(
+            Mapped::from_utf8(b"\xff".to_vec()),
+            Err(FromBytesError::Utf8(_))
+        ));
+        assert_eq!("a\u{fffd}b", Mapped::from_utf8_lossy(b"a\xffb").as_str());
+        assert_eq!("plain", Mapped::try_from(&b"plain"[..]).unwrap().as_str());
+
+        assert!(MappedValidated::from_utf8(b"valid".to_vec()).is_ok());
+        assert!(matches!(
+            MappedValidated::from_utf8("🏗".as_bytes().to_vec()),
+            Err(FromBytesError::Invalid(InvalidData))
+        ));
+        assert!(matches!(
+            MappedValidated::try_from(&b"\xfe"[..]),
+            Err(FromBytesError::Utf8(_))
+        ));
+
+        let normalized = MappedNormalized::from_utf8(b"a b".to_vec()).unwrap();
+        assert_eq!("ab", normalized.as_str());
+        assert_eq!(
+            "a\u{fffd}b",
+            MappedNormalized::from_utf8_lossy(b"a \xffb")
+                .unwrap()
+                .as_str()
+        );
+        assert!(matches!(
+            MappedNormalized::try_from("🏗".as_bytes()),
+            Err(FromBytesError::Invalid(InvalidData))
+        ));
+    }
+
+    #[test]
+    fn check_default_values() {
+        assert_eq!("", DefaultEmpty::default().as_str());
+        assert_eq!("guest", DefaultUser::default().as_str());
+        assert_eq!("nospaces", DefaultNormalized::default().as_str());
+
+        #[derive(serde::Deserialize)]
+        struct Config {
+            #[serde(default)]
+            user: DefaultUser,
+            #[serde(default)]
+            comment: DefaultEmpty,
+        }
+
+        let config: Config = serde_json::from_str("{}").unwrap();
+        assert_eq!("guest", config.user.as_str());
+        assert_eq!("", config.comment.as_str());
+
+        let config: Config = serde_json::from_str(r#"{"user": "root"}"#).unwrap();
+        assert_eq!("root", config.user.as_str());
+    }
+
+    #[test]
+    fn check_test_ctor_constructs_values() {
+        let validated = TestValidated::test("valid");
+        assert_eq!(TestValidated::from_static("valid"), validated);
+
+        let normalized = TestNormalized::test("normal");
+        assert_eq!(TestNormalized::from_static("normal"), normalized);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "from_str_unchecked")]
+    fn check_test_ctor_asserts_invariants_on_borrow() {
+        let _ = TestValidated::test("🏗").as_str();
+    }
+
+    #[test]
+    fn check_convert_from_other_braids() {
+        let basic = Basic::from_static("subject");
+        let ptr = basic.as_str().as_ptr();
+        let promoted = Promoted::from(basic);
+        assert_eq!("subject", promoted.as_str());
+        assert_eq!(ptr, promoted.as_str().as_ptr());
+        assert_eq!(promoted, Promoted::from(BasicRef::from_static("subject")));
+
+        let validated = PromotedValidated::try_from(promoted).unwrap();
+        assert_eq!("subject", validated.as_str());
+        assert!(PromotedValidated::try_from(BasicRef::from_static("subject")).is_ok());
+        assert!(matches!(
+            PromotedValidated::try_from(Basic::from_static("🏗")),
+            Err(DomainError::InvalidData(_))
+        ));
+
+        static_assertions::assert_impl_all!(
+            PromotedValidated: TryFrom<Basic, Error = DomainError>,
+            TryFrom<&'static PromotedRef, Error = DomainError>,
+        );
+        static_assertions::assert_not_impl_any!(PromotedValidated: From<Basic>);
+    }
+
     #[test]
     fn check_custom_no_impl_clone() {
         static_assertions::assert_not_impl_any!(CustomImpls: Clone);
@@ -257,4 +985,448 @@ mod tests {
         assert_eq!("***SECRET***", format!("{}", vref));
         assert_eq!("my secret is bananas", format!("{:#}", vref));
     }
+
+    #[test]
+    fn check_existing_owned_glue() {
+        let borrowed = ExistingRef::from_static("existing");
+        let owned: ExistingOwned = borrowed.to_owned();
+        assert_eq!("existing", owned.as_str());
+        assert_eq!(owned, borrowed);
+        assert_eq!(borrowed, owned);
+
+        let reborrowed: &ExistingRef = std::borrow::Borrow::borrow(&owned);
+        assert_eq!(borrowed, reborrowed);
+
+        let mut deserializer = serde_json::Deserializer::from_str(r#""with \"quotes\"""#);
+        let cow = ExistingRef::deserialize_cow(&mut deserializer).unwrap();
+        assert!(matches!(cow, Cow::Owned(_)));
+        assert_eq!("with \"quotes\"", cow.as_str());
+    }
+
+    #[test]
+    fn check_existing_validated_owned_glue() {
+        let borrowed = ExistingValidatedRef::from_str("One").unwrap();
+        let owned: ExistingValidated = borrowed.to_owned();
+        assert_eq!("One", owned.as_str());
+        assert_eq!(owned, borrowed);
+
+        let reborrowed: &ExistingValidatedRef = std::borrow::Borrow::borrow(&owned);
+        assert_eq!(borrowed, reborrowed);
+    }
+
+    const CONST_REF_ONLY: &NumericRefOnly = NumericRefOnly::from_static("42");
+
+    #[test]
+    fn check_ref_only_from_static() {
+        assert_eq!(42, CONST_REF_ONLY.as_number().unwrap());
+        assert_eq!("valid", MappedErrorRefOnly::from_static("valid").as_str());
+        assert!(matches!(
+            MappedErrorRefOnly::try_from_static("🏗"),
+            Err(DomainError::InvalidData(_))
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid MappedErrorRefOnly")]
+    fn check_ref_only_from_static_panics_on_invalid() {
+        let _ = MappedErrorRefOnly::from_static("🏗");
+    }
+
+    #[test]
+    fn check_ref_only_alloc_conversions() {
+        let borrowed = AllocRefOnly::from_str("shared");
+
+        let boxed: Box<AllocRefOnly> = borrowed.into();
+        let rc: std::rc::Rc<AllocRefOnly> = borrowed.into();
+        let arc: std::sync::Arc<AllocRefOnly> = borrowed.into();
+        assert_eq!(borrowed, &*boxed);
+        assert_eq!(borrowed, &*rc);
+        assert_eq!(borrowed, &*arc);
+    }
+
+    #[test]
+    fn check_ref_only_alloc_deserializes_boxed() {
+        let boxed: Box<AllocRefOnly> = serde_json::from_str(r#""shared""#).unwrap();
+        assert_eq!("shared", boxed.as_str());
+
+        let boxed: Box<AllocValidatedRefOnly> = serde_json::from_str(r#""valid""#).unwrap();
+        assert_eq!("valid", boxed.as_str());
+        assert!(serde_json::from_str::<Box<AllocValidatedRefOnly>>(r#""🏗""#).is_err());
+    }
+
+    fn generic_len<B: aliri_braid::Braid>(value: &B) -> usize {
+        aliri_braid::BraidRef::as_str(value.as_braid_ref()).len()
+    }
+
+    fn generic_ref_len<B: aliri_braid::BraidRef + ?Sized>(value: &B) -> usize {
+        value.as_str().len()
+    }
+
+    #[test]
+    fn check_support_traits() {
+        let owned = Basic::from_static("basic");
+        assert_eq!(5, generic_len(&owned));
+        assert_eq!("basic", aliri_braid::Braid::as_str(&owned));
+        assert_eq!(5, generic_ref_len(BasicRef::from_static("basic")));
+        assert_eq!(2, generic_ref_len(NumericRefOnly::from_str("42")));
+    }
+
+    #[test]
+    fn check_serialize_via_str() {
+        let wrapped = WrappedSerialized::from_static("abc");
+        assert_eq!("[97,98,99]", serde_json::to_string(&wrapped).unwrap());
+
+        let serialized = StrSerialized::from_static("abc");
+        assert_eq!(r#""abc""#, serde_json::to_string(&serialized).unwrap());
+        assert_eq!(
+            serialized,
+            serde_json::from_str::<StrSerialized>(r#""abc""#).unwrap()
+        );
+    }
+
+    #[test]
+    fn check_renamed_constructors() {
+        let owned = Parsed::parse("abc".to_owned()).unwrap();
+        assert_eq!(owned, ParsedRef::parse("abc").unwrap());
+        assert_eq!(owned, Parsed::from_static("abc"));
+        assert_eq!(owned, "abc".parse::<Parsed>().unwrap());
+        assert_eq!(owned, serde_json::from_str::<Parsed>(r#""abc""#).unwrap());
+        assert_eq!(&*owned, ParsedRef::from_bytes_validated(b"abc").unwrap());
+        assert!(Parsed::parse("🦀".to_owned()).is_err());
+        assert!(<&ParsedRef>::try_from("🦀").is_err());
+
+        let normalized = ParsedNormalized::parse("a b c".to_owned()).unwrap();
+        assert_eq!("abc", normalized.as_str());
+        assert_eq!(
+            Cow::Owned::<ParsedNormalizedRef>(normalized),
+            ParsedNormalizedRef::parse("a b c").unwrap()
+        );
+
+        let ref_only: &ParsedRefOnly = serde_json::from_str(r#""abc""#).unwrap();
+        assert_eq!(ref_only, ParsedRefOnly::parse("abc").unwrap());
+        assert!(ParsedRefOnly::parse("🦀").is_err());
+    }
+
+    #[test]
+    fn check_v2_naming() {
+        let owned = Renamed::from_static("abc");
+        assert_eq!(owned, RenamedRef::parse_ref("abc"));
+        assert_eq!("abc", owned.into_inner());
+
+        let validated = RenamedValidated::new("abc".to_owned()).unwrap();
+        assert_eq!(validated, RenamedValidatedRef::try_from_str("abc").unwrap());
+        assert!(RenamedValidatedRef::try_from_str("🦀").is_err());
+
+        let normalized = RenamedNormalizedRef::try_from_str("a b c").unwrap();
+        assert_eq!("abc", normalized.as_str());
+
+        assert!(RenamedRefOnly::try_from_str("🦀").is_err());
+        assert_eq!("abc", RenamedParsedRef::parse("abc").unwrap().as_str());
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn check_v2_naming_keeps_deprecated_aliases() {
+        assert_eq!("abc", Renamed::from_static("abc").take());
+        assert_eq!("abc", RenamedRef::from_str("abc").as_str());
+        assert_eq!(
+            "abc",
+            RenamedValidatedRef::from_str("abc").unwrap().as_str()
+        );
+        assert_eq!(
+            "abc",
+            RenamedNormalizedRef::from_str("a b c").unwrap().as_str()
+        );
+        assert_eq!("abc", RenamedRefOnly::from_str("abc").unwrap().as_str());
+    }
+
+    #[test]
+    fn check_omitted_methods_defer_to_user_impls() {
+        let mut owned = Trimmed::from_static("  abc  ");
+        assert_eq!("abc", owned.as_str());
+        assert_eq!("abc", TrimmedRef::from_static("  abc  ").as_str());
+
+        let boxed: Box<TrimmedRef> = owned.clone().into();
+        assert_eq!("abc", boxed.as_str());
+        assert_eq!(owned, Trimmed::from(boxed));
+
+        assert_eq!("abc", owned.take());
+        assert_eq!("", owned.as_str());
+
+        let renamed = RenamedWithoutAlias::from_static("abc");
+        assert_eq!("abc", renamed.take());
+        assert_eq!("abc", renamed.into_inner());
+
+        assert_eq!("abc", OmittedRefOnly::from_static(" abc ").as_str());
+    }
+
+    #[test]
+    fn check_same_repr_conversions() {
+        let tenant = TenantId::from_static("acme");
+        let org = OrgId::from(tenant.clone());
+        assert_eq!(tenant.as_str(), org.as_str());
+        assert_eq!(tenant, TenantId::from(org));
+
+        let org_ref = <&OrgIdRef>::from(&*tenant);
+        let tenant_ref = <&TenantIdRef>::from(org_ref);
+        assert_eq!("acme", org_ref.as_str());
+        assert_eq!(tenant, tenant_ref);
+
+        let legacy = LegacyValidated::new("abc".to_owned()).unwrap();
+        let migrated = MigratedValidated::from(legacy);
+        assert_eq!("abc", migrated.as_str());
+        let legacy_ref = <&LegacyValidatedRef>::from(&*migrated);
+        assert_eq!("abc", legacy_ref.as_str());
+
+        let prefixed = MigratedPrefixed::from_static("t_abc");
+        let legacy = LegacyPrefixed::from(prefixed);
+        assert_eq!("abc", legacy.without_prefix());
+        assert_eq!("t_abc", MigratedPrefixed::from(legacy).as_str());
+    }
+
+    #[test]
+    fn check_opaque_braid_is_usable_in_its_module() {
+        let secret = opaque::Secret::try_from("hunter2").unwrap();
+        assert!(secret.is_short());
+        assert_eq!(secret, opaque::SecretRef::from_str("hunter2").unwrap());
+        assert!(opaque::Secret::try_from("🦀").is_err());
+    }
+
+    #[test]
+    fn check_restricted_methods_are_usable_in_the_crate() {
+        let owned = restricted::Internal::new("abc".to_owned()).unwrap();
+        assert_eq!("abc", owned.as_str());
+        assert_eq!(owned, restricted::InternalRef::from_static("abc"));
+        assert_eq!("abc", owned.take());
+
+        let borrowed = restricted::InternalRefOnly::from_str("abc");
+        assert_eq!("abc", borrowed.as_str());
+    }
+
+    #[test]
+    fn check_forwarded_cfg_attrs() {
+        let owned = ConditionalAttrs::from_static("abc");
+        let borrowed: &ConditionalAttrsRef = &owned;
+        assert_eq!("abc", borrowed.as_str());
+    }
+
+    #[test]
+    fn check_field_attrs_target_one_side() {
+        let owned = FieldAttrs::from_static("abc");
+        assert_eq!("\"ABC\"", serde_json::to_string(&owned).unwrap());
+        assert_eq!("abc", FieldAttrsRef::from_static("abc").as_str());
+    }
+
+    #[test]
+    fn check_templated_docs() {
+        let owned = TemplatedDocs::from_static("abc");
+        let borrowed: &TemplatedDocsRef = &owned;
+        assert_eq!("abc", borrowed.as_str());
+        assert_eq!("abc", OwnedDocOnlyRef::from_static("abc").as_str());
+    }
+
+    #[test]
+    fn check_aliased_braids() {
+        let owned = AliasedUser::from_static("abc");
+        let borrowed: &AliasedUserRef = &owned;
+        assert_eq!("abc", borrowed.as_str());
+        assert_eq!("abc", AliasedUserName::from_str("abc").as_str());
+    }
+
+    #[test]
+    fn check_no_must_use_allows_discarding() {
+        Discardable::from_static("abc");
+        DiscardableRef::from_str("abc");
+        Discardable::from_static("abc").take();
+    }
+
+    #[test]
+    fn check_extending_named_field_braid() {
+        let mut owned: OrangeWithNamedField = ["a", "b"].iter().copied().collect();
+        owned.extend(["c"]);
+        assert_eq!("abc", owned.as_str());
+    }
+
+    #[test]
+    fn check_mutable_braid() {
+        let mut arn = ArnBuilder::from_static("arn:aws");
+        arn.reserve(16);
+        arn.push(':');
+        arn.push_str("s3");
+        assert_eq!("arn:aws:s3", arn.as_str());
+
+        arn.truncate(3);
+        assert_eq!("arn", arn.as_str());
+
+        arn.clear();
+        assert_eq!("", arn.as_str());
+    }
+
+    #[test]
+    fn check_add_str() {
+        let mut prefix = ArnPrefix::from_static("arn") + ":";
+        prefix += "aws";
+        prefix += ArnPrefixRef::from_static(":s3");
+        assert_eq!("arn:aws:s3", prefix.as_str());
+    }
+
+    #[test]
+    fn check_omitted_box_conversions() {
+        let unboxed = Unboxed::from("plain");
+        let boxed: Box<UnboxedRef> = unboxed.clone().into_boxed_ref();
+        assert_eq!(unboxed.as_str(), boxed.as_str());
+
+        let validated = UnboxedValidated::new(Unboxable::from("valid")).unwrap();
+        assert_eq!(&validated, UnboxedValidatedRef::from_static("valid"));
+        assert!(UnboxedValidated::new(Unboxable::from("🏗")).is_err());
+    }
+
+    #[test]
+    fn check_build() {
+        use std::fmt::Write;
+
+        let partition = "aws";
+        let prefix = ArnPrefix::build(|s| write!(s, "arn:{}:s3", partition).unwrap());
+        assert_eq!("arn:aws:s3", prefix.as_str());
+
+        let code = ShortCode::build(|s| s.push_str("abc")).unwrap();
+        assert_eq!("abc", code.as_str());
+        assert!(ShortCode::build(|s| s.push_str("abcdefghi")).is_err());
+    }
+
+    #[test]
+    fn check_try_concat() {
+        let code = ShortCode::from_static("abc");
+        let joined = code.try_concat(ShortCodeRef::from_static("def")).unwrap();
+        assert_eq!("abcdef", joined.as_str());
+        assert!(joined.try_concat(ShortCodeRef::from_static("g")).is_err());
+    }
+
+    #[test]
+    fn check_try_from_iter() {
+        let codes = ShortCode::try_from_iter(vec!["abc".to_owned(), "de".to_owned()]).unwrap();
+        assert_eq!(
+            vec!["abc", "de"],
+            codes.iter().map(|c| c.as_str()).collect::<Vec<_>>()
+        );
+
+        let (idx, _) = ShortCode::try_from_iter(vec![
+            "abc".to_owned(),
+            "defghijk".to_owned(),
+            "lmnopqrs".to_owned(),
+        ])
+        .unwrap_err();
+        assert_eq!(1, idx);
+
+        assert!(ShortCode::try_from_iter(Vec::new()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn check_segments() {
+        let urn = Urn::from_static("urn:isbn:0451450523");
+        assert_eq!(
+            vec!["urn", "isbn", "0451450523"],
+            urn.segments().collect::<Vec<_>>()
+        );
+        assert_eq!(Some("0451450523"), urn.segments().next_back());
+        assert_eq!(Some("isbn"), urn.nth_segment(1));
+        assert_eq!(None, urn.nth_segment(3));
+
+        let path = KeyPath::from_str("a/b");
+        assert_eq!(Some("b"), path.nth_segment(1));
+    }
+
+    #[test]
+    fn check_typed_segments() {
+        let arn = Arn::from_static("arn:aws::bucket");
+        let service: &ArnPartRef = arn.nth_segment(1).unwrap().unwrap();
+        assert_eq!("aws", service.as_str());
+        assert!(arn.nth_segment(2).unwrap().is_err());
+        assert_eq!(3, arn.segments().filter_map(Result::ok).count());
+    }
+
+    #[test]
+    fn check_ascii_case_insensitive() {
+        use std::collections::{BTreeSet, HashSet};
+
+        let host = HostName::from_static("Example.COM");
+        let lower = HostNameRef::from_static("example.com");
+        assert_eq!(host, HostName::from_static("example.com"));
+        assert_eq!(host, lower);
+        assert_eq!(lower, host);
+        assert_eq!(&*host, lower);
+        assert_ne!(host, HostNameRef::from_static("example.org"));
+        assert_eq!("Example.COM", host.as_str());
+
+        let hosts: HashSet<HostName> = ["a.example", "A.EXAMPLE", "b.example"]
+            .iter()
+            .copied()
+            .map(HostName::from_static)
+            .collect();
+        assert_eq!(2, hosts.len());
+        assert!(hosts.contains(HostNameRef::from_static("B.Example")));
+
+        let sorted: BTreeSet<&HostNameRef> = ["b", "A", "a", "C"]
+            .iter()
+            .copied()
+            .map(HostNameRef::from_static)
+            .collect();
+        assert_eq!(
+            vec!["a", "b", "c"],
+            sorted
+                .iter()
+                .map(|h| h.as_str().to_ascii_lowercase())
+                .collect::<Vec<_>>()
+        );
+
+        assert_eq!(
+            HeaderName::from_static("Content-Type"),
+            HeaderName::from_static("content-type")
+        );
+    }
+
+    #[test]
+    fn check_natural_ord() {
+        use std::collections::BTreeSet;
+
+        let names: BTreeSet<FileName> = ["item10", "item2", "item02", "item1", "Item3", "a"]
+            .iter()
+            .copied()
+            .map(FileName::from_static)
+            .collect();
+        assert_eq!(
+            vec!["Item3", "a", "item1", "item02", "item2", "item10"],
+            names.iter().map(|n| n.as_str()).collect::<Vec<_>>()
+        );
+        assert!(FileNameRef::from_static("x9") < FileNameRef::from_static("x10"));
+        assert!(Version::from_static("1.9.0") < Version::from_static("1.10.0"));
+    }
+
+    #[test]
+    fn check_case_insensitive_ord() {
+        let mut tags: Vec<Tag> = ["beta", "Alpha", "alpha", "Gamma"]
+            .iter()
+            .copied()
+            .map(Tag::from_static)
+            .collect();
+        tags.sort();
+        assert_eq!(
+            vec!["Alpha", "alpha", "beta", "Gamma"],
+            tags.iter().map(|t| t.as_str()).collect::<Vec<_>>()
+        );
+        assert_ne!(Tag::from_static("Alpha"), Tag::from_static("alpha"));
+    }
+
+    #[test]
+    fn check_as_path() {
+        use std::{ffi::OsStr, path::Path};
+
+        let file = ConfigFile::new("app.toml".to_owned()).unwrap();
+        assert_eq!(Path::new("/etc/app.toml"), Path::new("/etc").join(&file));
+        assert_eq!(OsStr::new("app.toml"), AsRef::<OsStr>::as_ref(&*file));
+        assert_eq!(
+            Some(OsStr::new("toml")),
+            AsRef::<Path>::as_ref(&*file).extension()
+        );
+    }
 }
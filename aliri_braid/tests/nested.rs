@@ -0,0 +1,130 @@
+use std::{borrow::Borrow, convert::TryFrom};
+
+use aliri_braid::{braid, Constraint, ConstraintError};
+
+fn violated<T>(result: Result<T, ConstraintError>) -> Option<Constraint> {
+    result.err().map(|err| err.constraint())
+}
+
+#[braid(serde, non_empty)]
+pub struct UserId;
+
+/// A user identifier scoped to a tenant, which must also be a valid `UserId`
+#[braid(serde, nested)]
+pub struct TenantScopedId(UserId);
+
+#[braid(nested, add, max_len = 8)]
+pub struct ShortScopedId(UserId);
+
+#[braid]
+pub struct Plain;
+
+/// Braids without checks of their own can be wrapped without `nested`
+#[braid]
+pub struct WrapsPlain(Plain);
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum InvalidTenantUser {
+    Inner(ConstraintError),
+    MissingTenant,
+}
+
+impl From<ConstraintError> for InvalidTenantUser {
+    fn from(err: ConstraintError) -> Self {
+        Self::Inner(err)
+    }
+}
+
+#[braid(nested, validator)]
+pub struct TenantUser(UserId);
+
+impl aliri_braid::Validator for TenantUser {
+    type Error = InvalidTenantUser;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        if raw.contains(':') {
+            Ok(())
+        } else {
+            Err(InvalidTenantUser::MissingTenant)
+        }
+    }
+}
+
+#[test]
+fn inner_braid_is_checked_first() {
+    assert_eq!(
+        Some(Constraint::NonEmpty),
+        violated(TenantScopedId::try_from(""))
+    );
+    assert_eq!(
+        Some(Constraint::NonEmpty),
+        violated(<&TenantScopedIdRef>::try_from(""))
+    );
+
+    let id = TenantScopedId::try_from("acme/alice").unwrap();
+    assert_eq!("acme/alice", id.as_str());
+}
+
+#[test]
+fn outer_constraints_are_checked_after_the_inner_braid() {
+    assert_eq!(
+        Some(Constraint::NonEmpty),
+        violated(ShortScopedId::try_from(""))
+    );
+    assert_eq!(
+        Some(Constraint::MaxLength(8)),
+        violated(ShortScopedId::try_from("much too long"))
+    );
+    assert!(ShortScopedId::try_from("short").is_ok());
+}
+
+#[test]
+fn outer_validator_receives_inner_errors() {
+    assert!(matches!(
+        TenantUser::try_from(""),
+        Err(InvalidTenantUser::Inner(err)) if err.constraint() == Constraint::NonEmpty
+    ));
+    assert_eq!(
+        Err(InvalidTenantUser::MissingTenant),
+        TenantUser::try_from("alice").map(drop)
+    );
+    assert!(TenantUser::try_from("acme:alice").is_ok());
+}
+
+#[test]
+fn unvalidated_inner_braids_need_no_checks() {
+    let value = WrapsPlain::from("anything");
+    assert_eq!("anything", value.as_str());
+    assert_eq!("anything", value.take().as_str());
+}
+
+#[test]
+fn inner_braid_is_reachable_from_the_outer_braid() {
+    let id = TenantScopedId::new(UserId::try_from("acme/alice").unwrap()).unwrap();
+    let borrowed: &TenantScopedIdRef = id.borrow();
+    assert_eq!("acme/alice", borrowed.as_str());
+    assert_eq!(id, borrowed.to_owned());
+
+    let inner: UserId = id.take();
+    assert_eq!("acme/alice", inner.as_str());
+}
+
+#[test]
+fn conversions_go_through_the_inner_braid() {
+    let id = TenantScopedId::try_from(String::from("acme/bob")).unwrap();
+    let boxed: Box<TenantScopedIdRef> = id.clone().into_boxed_ref();
+    assert_eq!(id, boxed.into_owned());
+
+    let concat = ShortScopedId::try_from("ab")
+        .unwrap()
+        .try_concat(ShortScopedIdRef::from_static("cd"))
+        .unwrap();
+    assert_eq!("abcd", concat.as_str());
+}
+
+#[test]
+fn deserialization_checks_the_inner_braid() {
+    let id: TenantScopedId = serde_json::from_str(r#""acme/carol""#).unwrap();
+    assert_eq!("acme/carol", id.as_str());
+    assert!(serde_json::from_str::<TenantScopedId>(r#""""#).is_err());
+}
@@ -0,0 +1,396 @@
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap},
+};
+
+use aliri_braid::{braid, ConstraintError};
+use serde::{Deserialize, Serialize};
+
+#[braid(serde)]
+pub struct Plain;
+
+#[braid(serde, min_len = 1)]
+pub struct Validated;
+
+#[braid(serde, normalizer = "Lowercase")]
+pub struct Normalized;
+
+pub struct Lowercase;
+
+impl aliri_braid::Validator for Lowercase {
+    type Error = ConstraintError;
+
+    fn validate(_: &str) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl aliri_braid::Normalizer for Lowercase {
+    fn normalize(raw: &str) -> Result<Cow<'_, str>, Self::Error> {
+        if raw.chars().any(char::is_uppercase) {
+            Ok(Cow::Owned(raw.to_lowercase()))
+        } else {
+            Ok(Cow::Borrowed(raw))
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Borrowing<'a> {
+    #[serde(borrow, deserialize_with = "PlainRef::deserialize_cow")]
+    plain: Cow<'a, PlainRef>,
+    #[serde(borrow, deserialize_with = "ValidatedRef::deserialize_cow")]
+    validated: Cow<'a, ValidatedRef>,
+    #[serde(borrow, deserialize_with = "NormalizedRef::deserialize_cow")]
+    normalized: Cow<'a, NormalizedRef>,
+}
+
+const NESTED_JSON: &str = r#"{
+    "seq": ["one", "two"],
+    "map": {"key": "value"},
+    "ordered": {"a": ["b", "c"]},
+    "boxed": "boxed",
+    "missing": null
+}"#;
+
+macro_rules! nested_containers {
+    ($module:ident, $ref:ty) => {
+        mod $module {
+            use super::*;
+
+            #[derive(Debug, PartialEq, Serialize, Deserialize)]
+            pub struct Nested<'a> {
+                #[serde(borrow)]
+                pub seq: Vec<Cow<'a, $ref>>,
+                #[serde(borrow)]
+                pub map: HashMap<&'a $ref, Cow<'a, $ref>>,
+                #[serde(borrow)]
+                pub ordered: BTreeMap<&'a $ref, Vec<&'a $ref>>,
+                pub boxed: Option<Box<$ref>>,
+                pub missing: Option<Box<$ref>>,
+            }
+
+            #[test]
+            fn round_trips_through_nested_containers() {
+                let nested: Nested = serde_json::from_str(NESTED_JSON).unwrap();
+                assert_eq!(2, nested.seq.len());
+                assert_eq!("two", nested.seq[1].as_str());
+                assert_eq!("value", nested.map[<$ref>::from_static("key")].as_str());
+                assert_eq!(
+                    vec!["b", "c"],
+                    nested.ordered[<$ref>::from_static("a")]
+                        .iter()
+                        .map(|r| r.as_str())
+                        .collect::<Vec<_>>()
+                );
+                assert_eq!("boxed", nested.boxed.as_deref().unwrap().as_str());
+                assert!(nested.missing.is_none());
+
+                let json = serde_json::to_string(&nested).unwrap();
+                let again: Nested = serde_json::from_str(&json).unwrap();
+                assert_eq!(nested, again);
+            }
+        }
+    };
+}
+
+nested_containers!(plain, PlainRef);
+nested_containers!(validated, ValidatedRef);
+nested_containers!(normalized, NormalizedRef);
+
+#[test]
+fn validated_nested_containers_reject_invalid_values() {
+    let json = r#"{"seq": [""], "map": {}, "ordered": {}, "boxed": null, "missing": null}"#;
+    assert!(serde_json::from_str::<validated::Nested>(json).is_err());
+
+    let json = r#"{"seq": [], "map": {"": "x"}, "ordered": {}, "boxed": null, "missing": null}"#;
+    assert!(serde_json::from_str::<validated::Nested>(json).is_err());
+}
+
+#[test]
+fn normalized_nested_containers_normalize_owned_values() {
+    let json = r#"{"seq": ["LOUD"], "map": {"key": "VALUE"}, "ordered": {}, "boxed": "BOXED", "missing": null}"#;
+    let nested: normalized::Nested = serde_json::from_str(json).unwrap();
+    assert_eq!("loud", nested.seq[0].as_str());
+    assert_eq!(
+        "value",
+        nested.map[NormalizedRef::from_static("key")].as_str()
+    );
+    assert_eq!("boxed", nested.boxed.unwrap().as_str());
+}
+
+#[test]
+fn deserialize_cow_borrows_from_input() {
+    let json = r#"{"plain": "a", "validated": "b", "normalized": "c"}"#;
+    let borrowing: Borrowing = serde_json::from_str(json).unwrap();
+    assert!(matches!(borrowing.plain, Cow::Borrowed(_)));
+    assert!(matches!(borrowing.validated, Cow::Borrowed(_)));
+    assert!(matches!(borrowing.normalized, Cow::Borrowed(_)));
+    assert_eq!("c", borrowing.normalized.as_str());
+}
+
+#[test]
+fn deserialize_cow_owns_escaped_or_normalized_input() {
+    let json = r#"{"plain": "a\"", "validated": "b\"", "normalized": "C"}"#;
+    let borrowing: Borrowing = serde_json::from_str(json).unwrap();
+    assert!(matches!(borrowing.plain, Cow::Owned(_)));
+    assert_eq!("a\"", borrowing.plain.as_str());
+    assert!(matches!(borrowing.validated, Cow::Owned(_)));
+    assert!(matches!(borrowing.normalized, Cow::Owned(_)));
+    assert_eq!("c", borrowing.normalized.as_str());
+}
+
+#[test]
+fn deserialize_cow_validates_input() {
+    let json = r#"{"plain": "a", "validated": "", "normalized": "c"}"#;
+    assert!(serde_json::from_str::<Borrowing>(json).is_err());
+
+    let json = r#"{"plain": "a", "validated": "\n", "normalized": "c"}"#;
+    assert!(serde_json::from_str::<Borrowing>(json).is_ok());
+}
+
+macro_rules! map_keys {
+    ($module:ident, $owned:ty, $ref:ty) => {
+        mod $module {
+            use super::*;
+
+            #[derive(Debug, PartialEq, Serialize, Deserialize)]
+            pub struct Keys<'a> {
+                pub owned: HashMap<$owned, u32>,
+                pub ordered: BTreeMap<$owned, u32>,
+                pub boxed: HashMap<Box<$ref>, u32>,
+                #[serde(borrow)]
+                pub borrowed: HashMap<&'a $ref, u32>,
+                #[serde(borrow)]
+                pub cow: BTreeMap<Cow<'a, $ref>, u32>,
+            }
+
+            #[test]
+            fn round_trips_in_map_key_position() {
+                let json = r#"{
+                    "owned": {"a": 1},
+                    "ordered": {"b": 2, "c": 3},
+                    "boxed": {"d": 4},
+                    "borrowed": {"e": 5},
+                    "cow": {"f": 6}
+                }"#;
+                let keys: Keys = serde_json::from_str(json).unwrap();
+                assert_eq!(1, keys.owned[<$ref>::from_static("a")]);
+                assert_eq!(3, keys.ordered[<$ref>::from_static("c")]);
+                assert_eq!(4, keys.boxed[<$ref>::from_static("d")]);
+                assert_eq!(5, keys.borrowed[<$ref>::from_static("e")]);
+                assert_eq!(6, keys.cow[<$ref>::from_static("f")]);
+
+                let json = serde_json::to_string(&keys).unwrap();
+                let again: Keys = serde_json::from_str(&json).unwrap();
+                assert_eq!(keys, again);
+            }
+
+            #[test]
+            fn serializes_borrowed_keys_as_strings() {
+                let mut map = HashMap::new();
+                map.insert(<$ref>::from_static("key"), 1);
+                assert_eq!(r#"{"key":1}"#, serde_json::to_string(&map).unwrap());
+
+                let mut map = BTreeMap::new();
+                map.insert(<$owned>::from_static("key"), 1);
+                assert_eq!(r#"{"key":1}"#, serde_json::to_string(&map).unwrap());
+            }
+        }
+    };
+}
+
+map_keys!(plain_keys, Plain, PlainRef);
+map_keys!(validated_keys, Validated, ValidatedRef);
+map_keys!(normalized_keys, Normalized, NormalizedRef);
+
+#[test]
+fn validated_map_keys_reject_invalid_values() {
+    let json = r#"{"owned": {"": 1}, "ordered": {}, "boxed": {}, "borrowed": {}, "cow": {}}"#;
+    assert!(serde_json::from_str::<validated_keys::Keys>(json).is_err());
+
+    let json = r#"{"owned": {}, "ordered": {}, "boxed": {"": 1}, "borrowed": {}, "cow": {}}"#;
+    assert!(serde_json::from_str::<validated_keys::Keys>(json).is_err());
+
+    let json = r#"{"owned": {}, "ordered": {}, "boxed": {}, "borrowed": {"": 1}, "cow": {}}"#;
+    assert!(serde_json::from_str::<validated_keys::Keys>(json).is_err());
+}
+
+#[test]
+fn normalized_map_keys_are_normalized() {
+    let json = r#"{
+        "owned": {"LOUD": 1},
+        "ordered": {"Mixed": 2},
+        "boxed": {"BOXED": 3},
+        "borrowed": {},
+        "cow": {"COW": 4}
+    }"#;
+    let keys: normalized_keys::Keys = serde_json::from_str(json).unwrap();
+    assert_eq!(1, keys.owned[NormalizedRef::from_static("loud")]);
+    assert_eq!(2, keys.ordered[NormalizedRef::from_static("mixed")]);
+    assert_eq!(3, keys.boxed[NormalizedRef::from_static("boxed")]);
+    assert_eq!(4, keys.cow[NormalizedRef::from_static("cow")]);
+}
+
+#[test]
+fn normalized_map_keys_merge_equivalent_keys() {
+    let json =
+        r#"{"owned": {"KEY": 1, "key": 2}, "ordered": {}, "boxed": {}, "borrowed": {}, "cow": {}}"#;
+    let keys: normalized_keys::Keys = serde_json::from_str(json).unwrap();
+    assert_eq!(1, keys.owned.len());
+    assert_eq!(2, keys.owned[NormalizedRef::from_static("key")]);
+}
+
+macro_rules! smart_pointers {
+    ($module:ident, $owned:ty, $ref:ty) => {
+        mod $module {
+            use std::{rc::Rc, sync::Arc};
+
+            use super::*;
+
+            #[derive(Debug, PartialEq, Serialize, Deserialize)]
+            pub struct Pointers<'a> {
+                pub owned: $owned,
+                pub boxed: Box<$ref>,
+                pub arc: Arc<$ref>,
+                pub rc: Rc<$ref>,
+                #[serde(borrow)]
+                pub cow: Cow<'a, $ref>,
+            }
+
+            #[test]
+            fn serialize_like_the_owned_form() {
+                let owned = <$owned>::from_static("value");
+                let expected = serde_json::to_string(&owned).unwrap();
+                let borrowed: &$ref = &owned;
+
+                assert_eq!(expected, serde_json::to_string(borrowed).unwrap());
+                assert_eq!(
+                    expected,
+                    serde_json::to_string(&owned.clone().into_boxed_ref()).unwrap()
+                );
+                assert_eq!(
+                    expected,
+                    serde_json::to_string(&Arc::<$ref>::from(borrowed)).unwrap()
+                );
+                assert_eq!(
+                    expected,
+                    serde_json::to_string(&Rc::<$ref>::from(borrowed)).unwrap()
+                );
+                assert_eq!(
+                    expected,
+                    serde_json::to_string(&Cow::Borrowed(borrowed)).unwrap()
+                );
+                assert_eq!(
+                    expected,
+                    serde_json::to_string(&Cow::<$ref>::Owned(owned.clone())).unwrap()
+                );
+            }
+
+            #[test]
+            fn round_trip_symmetrically() {
+                let json = r#"{"owned": "a", "boxed": "b", "arc": "c", "rc": "d", "cow": "e"}"#;
+                let pointers: Pointers = serde_json::from_str(json).unwrap();
+                assert_eq!("b", pointers.boxed.as_str());
+                assert_eq!("c", pointers.arc.as_str());
+                assert_eq!("d", pointers.rc.as_str());
+                assert_eq!("e", pointers.cow.as_str());
+
+                let json = serde_json::to_string(&pointers).unwrap();
+                assert_eq!(
+                    r#"{"owned":"a","boxed":"b","arc":"c","rc":"d","cow":"e"}"#,
+                    json
+                );
+                let again: Pointers = serde_json::from_str(&json).unwrap();
+                assert_eq!(pointers, again);
+            }
+        }
+    };
+}
+
+smart_pointers!(plain_pointers, Plain, PlainRef);
+smart_pointers!(validated_pointers, Validated, ValidatedRef);
+smart_pointers!(normalized_pointers, Normalized, NormalizedRef);
+
+#[test]
+fn validated_smart_pointers_reject_invalid_values() {
+    let json = r#"{"owned": "a", "boxed": "", "arc": "c", "rc": "d", "cow": "e"}"#;
+    assert!(serde_json::from_str::<validated_pointers::Pointers>(json).is_err());
+
+    let json = r#"{"owned": "a", "boxed": "b", "arc": "", "rc": "d", "cow": "e"}"#;
+    assert!(serde_json::from_str::<validated_pointers::Pointers>(json).is_err());
+
+    let json = r#"{"owned": "a", "boxed": "b", "arc": "c", "rc": "", "cow": "e"}"#;
+    assert!(serde_json::from_str::<validated_pointers::Pointers>(json).is_err());
+}
+
+#[test]
+fn normalized_smart_pointers_are_normalized() {
+    let json = r#"{"owned": "A", "boxed": "B", "arc": "C", "rc": "D", "cow": "E"}"#;
+    let pointers: normalized_pointers::Pointers = serde_json::from_str(json).unwrap();
+    assert_eq!("b", pointers.boxed.as_str());
+    assert_eq!("c", pointers.arc.as_str());
+    assert_eq!("d", pointers.rc.as_str());
+    assert_eq!("e", pointers.cow.as_str());
+}
+
+fn deserialize_in_place<'de, T: Deserialize<'de>>(
+    json: &'de str,
+    place: &mut T,
+) -> serde_json::Result<()> {
+    T::deserialize_in_place(&mut serde_json::Deserializer::from_str(json), place)
+}
+
+#[test]
+fn deserialize_in_place_reuses_allocation() {
+    let mut buf = String::with_capacity(32);
+    buf.push_str("original");
+    let mut place = Plain::new(buf);
+    let ptr = place.as_str().as_ptr();
+
+    deserialize_in_place(r#""replaced""#, &mut place).unwrap();
+    assert_eq!("replaced", place.as_str());
+    assert_eq!(ptr, place.as_str().as_ptr());
+
+    deserialize_in_place(r#""escaped\"""#, &mut place).unwrap();
+    assert_eq!("escaped\"", place.as_str());
+}
+
+#[test]
+fn deserialize_in_place_keeps_value_on_failure() {
+    let mut place = Validated::from_static("original");
+    assert!(deserialize_in_place(r#""""#, &mut place).is_err());
+    assert_eq!("original", place.as_str());
+
+    assert!(deserialize_in_place("1", &mut place).is_err());
+    assert_eq!("original", place.as_str());
+}
+
+#[test]
+fn deserialize_in_place_normalizes_value() {
+    let mut place = Normalized::from_static("original");
+    deserialize_in_place(r#""LOUD""#, &mut place).unwrap();
+    assert_eq!("loud", place.as_str());
+
+    deserialize_in_place(r#""quiet""#, &mut place).unwrap();
+    assert_eq!("quiet", place.as_str());
+}
+
+#[test]
+fn deserialize_in_place_refreshes_buffer_of_braids() {
+    let mut place = vec![Validated::from_static("one"), Validated::from_static("two")];
+    let ptrs: Vec<_> = place.iter().map(|v| v.as_str().as_ptr()).collect();
+
+    deserialize_in_place(r#"["uno", "dos", "tres"]"#, &mut place).unwrap();
+    assert_eq!(
+        vec!["uno", "dos", "tres"],
+        place.iter().map(|v| v.as_str()).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        ptrs[..],
+        place
+            .iter()
+            .map(|v| v.as_str().as_ptr())
+            .collect::<Vec<_>>()[..2]
+    );
+}
@@ -30,6 +30,7 @@ impl error::Error for InvalidString {}
 #[braid(
     serde,
     normalizer,
+    add,
     ref_name = "LowerStr",
     ref_doc = "A borrowed reference to a non-empty, lowercase string"
 )]
@@ -50,7 +51,7 @@ impl aliri_braid::Validator for LowerString {
 }
 
 impl aliri_braid::Normalizer for LowerString {
-    fn normalize(s: &str) -> Result<Cow<str>, Self::Error> {
+    fn normalize(s: &str) -> Result<Cow<'_, str>, Self::Error> {
         if s.is_empty() {
             Err(InvalidString::EmptyString)
         } else if s.contains(|c: char| c.is_uppercase()) {
@@ -61,6 +62,68 @@ impl aliri_braid::Normalizer for LowerString {
     }
 }
 
+/// A string with surrounding whitespace trimmed
+///
+/// The validator only rejects values that cannot be normalized, so untrimmed
+/// values can still be created through the unchecked constructors.
+#[braid(normalizer)]
+pub struct Trimmed;
+
+impl aliri_braid::Validator for Trimmed {
+    type Error = InvalidString;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        if raw.trim().is_empty() {
+            Err(InvalidString::EmptyString)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl aliri_braid::Normalizer for Trimmed {
+    fn normalize(s: &str) -> Result<Cow<'_, str>, Self::Error> {
+        <Self as aliri_braid::Validator>::validate(s)?;
+        let trimmed = s.trim();
+        if trimmed.len() == s.len() {
+            Ok(Cow::Borrowed(s))
+        } else {
+            Ok(Cow::Owned(trimmed.to_owned()))
+        }
+    }
+}
+
+/// An ASCII string normalized to lowercase in place
+#[braid(normalizer)]
+pub struct AsciiLower;
+
+impl aliri_braid::Validator for AsciiLower {
+    type Error = InvalidString;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        if raw.bytes().any(|b| b.is_ascii_uppercase()) {
+            Err(InvalidString::InvalidCharacter)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl aliri_braid::Normalizer for AsciiLower {
+    fn normalize(s: &str) -> Result<Cow<'_, str>, Self::Error> {
+        if s.bytes().any(|b| b.is_ascii_uppercase()) {
+            Ok(Cow::Owned(s.to_ascii_lowercase()))
+        } else {
+            Ok(Cow::Borrowed(s))
+        }
+    }
+
+    fn normalize_owned(mut raw: String) -> Result<String, Self::Error> {
+        raw.make_ascii_lowercase();
+        Ok(raw)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -80,7 +143,7 @@ mod tests {
     #[test]
     fn owned_rejects_invalid() {
         let x = LowerString::new("".to_owned());
-        assert!(matches!(x, Err(_)));
+        assert!(x.is_err());
     }
 
     #[test]
@@ -106,7 +169,7 @@ mod tests {
     #[test]
     #[should_panic]
     fn static_ref_handles_panics_on_non_normal() {
-        LowerStr::from_static("TestIng");
+        let _ = LowerStr::from_static("TestIng");
     }
 
     fn needs_ref(_: &LowerStr) {}
@@ -141,7 +204,7 @@ mod tests {
     #[test]
     fn ref_rejects_invalid() {
         let x = LowerStr::from_str("");
-        assert!(matches!(x, Err(_)));
+        assert!(x.is_err());
     }
 
     #[test]
@@ -153,13 +216,13 @@ mod tests {
     #[test]
     fn ref_norm_rejects_valid_non_normal() {
         let x = LowerStr::from_normalized_str("TestIng");
-        assert!(matches!(x, Err(_)));
+        assert!(x.is_err());
     }
 
     #[test]
     fn ref_norm_rejects_invalid() {
         let x = LowerStr::from_normalized_str("");
-        assert!(matches!(x, Err(_)));
+        assert!(x.is_err());
     }
 
     #[allow(dead_code)]
@@ -198,4 +261,72 @@ mod tests {
         let owned = LowerStr::from_normalized_str("orange").unwrap();
         let _reference: &str = owned.as_ref();
     }
+
+    #[test]
+    fn owned_try_concat() {
+        let owned = LowerString::new("ORANGE".to_owned()).unwrap();
+        let suffix = LowerStr::from_normalized_str("juice").unwrap();
+        assert_eq!("orangejuice", owned.try_concat(suffix).unwrap().as_str());
+    }
+
+    #[test]
+    fn owned_try_from_iter_normalizes_each() {
+        let owned = LowerString::try_from_iter(vec!["ORANGE".to_owned(), "Kiwi".to_owned()]);
+        assert_eq!(
+            vec!["orange", "kiwi"],
+            owned
+                .unwrap()
+                .iter()
+                .map(|s| s.as_str())
+                .collect::<Vec<_>>()
+        );
+
+        let (idx, _) =
+            LowerString::try_from_iter(vec!["orange".to_owned(), String::new()]).unwrap_err();
+        assert_eq!(1, idx);
+    }
+
+    #[test]
+    fn is_normalized_checks_without_constructing() {
+        assert!(LowerStr::is_normalized("orange"));
+        assert!(!LowerStr::is_normalized("ORANGE"));
+        assert!(!LowerStr::is_normalized(""));
+        assert!(!TrimmedRef::is_normalized(" padded "));
+    }
+
+    #[test]
+    #[allow(unsafe_code)]
+    fn normalize_repairs_unchecked_values() {
+        let padded = unsafe { Trimmed::new_unchecked(" padded ".to_owned()) };
+        assert_eq!(" padded ", padded.as_str());
+
+        let normalized = padded.normalized();
+        assert!(matches!(normalized, Cow::Owned(_)));
+        assert_eq!("padded", normalized.as_str());
+
+        assert_eq!("padded", padded.normalize().as_str());
+    }
+
+    #[test]
+    fn normalize_keeps_normalized_values() {
+        let owned = LowerString::from_static("orange");
+        assert!(matches!(owned.normalized(), Cow::Borrowed(_)));
+        assert_eq!("orange", owned.normalize().as_str());
+    }
+
+    #[test]
+    fn owned_constructor_reuses_normalized_allocation() {
+        let raw = "orange".to_owned();
+        let ptr = raw.as_ptr();
+        assert_eq!(ptr, LowerString::new(raw).unwrap().as_str().as_ptr());
+    }
+
+    #[test]
+    fn owned_constructor_normalizes_in_place() {
+        let raw = "MixedCase".to_owned();
+        let ptr = raw.as_ptr();
+        let normalized = AsciiLower::new(raw).unwrap();
+        assert_eq!("mixedcase", normalized.as_str());
+        assert_eq!(ptr, normalized.as_str().as_ptr());
+    }
 }
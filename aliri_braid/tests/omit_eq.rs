@@ -0,0 +1,74 @@
+use std::{
+    borrow::Borrow,
+    collections::HashSet,
+    hash::{Hash, Hasher},
+};
+
+use aliri_braid::{braid, braid_ref};
+use static_assertions::{assert_impl_all, assert_not_impl_any};
+
+/// A name qualified by a namespace, such as `core:user`, compared by its local part only
+#[braid(eq = "omit", hash = "custom", ord = "omit")]
+pub struct QualifiedName;
+
+impl QualifiedNameRef {
+    fn local(&self) -> &str {
+        self.as_str()
+            .rsplit_once(':')
+            .map_or(self.as_str(), |(_, local)| local)
+    }
+}
+
+impl PartialEq for QualifiedNameRef {
+    fn eq(&self, other: &Self) -> bool {
+        self.local() == other.local()
+    }
+}
+
+impl Eq for QualifiedNameRef {}
+
+impl Hash for QualifiedNameRef {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.local().hash(state)
+    }
+}
+
+impl PartialEq for QualifiedName {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl Eq for QualifiedName {}
+
+impl Hash for QualifiedName {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state)
+    }
+}
+
+#[braid_ref(eq = "omit", hash = "omit", ord = "omit")]
+pub struct Label;
+
+assert_impl_all!(QualifiedName: Borrow<QualifiedNameRef>);
+assert_not_impl_any!(QualifiedName: Borrow<str>, PartialEq<QualifiedNameRef>, PartialEq<&'static QualifiedNameRef>);
+assert_not_impl_any!(QualifiedNameRef: Borrow<str>, PartialEq<QualifiedName>);
+assert_not_impl_any!(Label: PartialEq, Hash, Borrow<str>);
+
+#[test]
+fn user_equality_is_used_for_both_forms() {
+    let a = QualifiedName::from_static("core:user");
+    let b = QualifiedName::from_static("ext:user");
+    assert_eq!(a, b);
+    assert_ne!(a, QualifiedName::from_static("core:group"));
+
+    let mut set = HashSet::new();
+    set.insert(a);
+    assert!(set.contains(QualifiedNameRef::from_static("user")));
+    assert!(!set.contains(QualifiedNameRef::from_static("group")));
+}
+
+#[test]
+fn borrowed_only_braids_can_omit_equality() {
+    assert_eq!("label", Label::from_static("label").as_str());
+}
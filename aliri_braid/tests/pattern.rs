@@ -0,0 +1,122 @@
+use std::borrow::Cow;
+
+use aliri_braid::{braid, braid_ref, Constraint, ConstraintError};
+
+#[braid(pattern = "[a-z]+-[0-9]{3}")]
+pub struct Sku;
+
+#[braid_ref(pattern = "v[0-9]+")]
+pub struct Version;
+
+#[braid(pattern = "[a-z]+-[0-9]{3}", max_len = 16, same_repr_as = "LegacySku")]
+pub struct MigratedSku;
+
+#[braid(
+    max_len = 16,
+    pattern = "[a-z]+-[0-9]{3}",
+    same_repr_as = "MigratedSku"
+)]
+pub struct LegacySku;
+
+#[braid_ref(pattern = "a|abc")]
+pub struct Alternation;
+
+#[braid_ref(pattern = "a(é|è)")]
+pub struct Accent;
+
+#[braid(pattern = "[a-z]+", max_len = 8, normalizer = "Lowercase")]
+pub struct Word;
+
+pub struct Lowercase;
+
+impl aliri_braid::Validator for Lowercase {
+    type Error = ConstraintError;
+
+    fn validate(_: &str) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl aliri_braid::Normalizer for Lowercase {
+    fn normalize(raw: &str) -> Result<Cow<'_, str>, Self::Error> {
+        if raw.chars().any(char::is_uppercase) {
+            Ok(Cow::Owned(raw.to_lowercase()))
+        } else {
+            Ok(Cow::Borrowed(raw))
+        }
+    }
+}
+
+#[test]
+fn matching_value_is_accepted() {
+    let sku = Sku::new("widget-042".to_owned()).unwrap();
+    assert_eq!("widget-042", sku.as_str());
+    assert_eq!("v12", Version::from_str("v12").unwrap().as_str());
+}
+
+#[test]
+fn pattern_must_match_entire_value() {
+    for (raw, position) in [
+        ("widget-42", 9),
+        ("widget-042!", 10),
+        (" widget-042", 0),
+        ("widget-0421", 10),
+        ("widget_042", 6),
+        ("", 0),
+    ] {
+        let err = SkuRef::from_str(raw).unwrap_err();
+        assert_eq!(
+            Constraint::Pattern(r"[a-z]+-[0-9]{3}"),
+            err.constraint(),
+            "{:?}",
+            raw
+        );
+        assert_eq!(position, err.position(), "{:?}", raw);
+        assert_eq!(raw, err.value());
+    }
+
+    assert!(Version::from_str("version v12").is_err());
+}
+
+#[test]
+fn position_is_end_of_longest_viable_prefix() {
+    assert_eq!(2, Alternation::from_str("abd").unwrap_err().position());
+    assert_eq!(1, Alternation::from_str("ax").unwrap_err().position());
+    assert_eq!(3, Alternation::from_str("abcd").unwrap_err().position());
+}
+
+#[test]
+fn position_falls_on_char_boundary() {
+    let err = Accent::from_str("aê").unwrap_err();
+    assert_eq!(1, err.position());
+}
+
+#[test]
+fn pattern_is_displayed_in_error() {
+    let err = Version::from_str("x12").unwrap_err();
+    assert_eq!(
+        r#"invalid value "x12": must match the pattern "v[0-9]+" (at byte 0)"#,
+        err.to_string()
+    );
+}
+
+#[test]
+fn pattern_is_checked_after_normalization() {
+    assert_eq!("braid", Word::new("Braid".to_owned()).unwrap().as_str());
+
+    let err = Word::new("Braid2".to_owned()).unwrap_err();
+    assert_eq!(Constraint::Pattern("[a-z]+"), err.constraint());
+}
+
+#[test]
+fn other_constraints_are_checked_alongside_pattern() {
+    let err = Word::new("braidbraid".to_owned()).unwrap_err();
+    assert_eq!(Constraint::MaxLength(8), err.constraint());
+}
+
+#[test]
+fn braids_with_the_same_pattern_share_a_representation() {
+    let legacy = LegacySku::from_static("widget-042");
+    let migrated = MigratedSku::from(legacy.clone());
+    assert_eq!(legacy.as_str(), migrated.as_str());
+}
@@ -0,0 +1,129 @@
+use std::convert::{TryFrom, TryInto};
+
+use aliri_braid::{
+    braid, from_proto_optional, from_proto_repeated, into_proto_optional, into_proto_repeated,
+    BraidMessage, ConstraintError,
+};
+use prost::Message;
+
+#[braid(max_len = 8)]
+pub struct Username;
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct UserDto {
+    #[prost(string, tag = "1")]
+    pub name: String,
+    #[prost(string, tag = "2")]
+    pub manager: String,
+    #[prost(string, repeated, tag = "3")]
+    pub aliases: Vec<String>,
+    #[prost(message, optional, tag = "4")]
+    pub sponsor: Option<BraidMessage<Username>>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct User {
+    pub name: Username,
+    pub manager: Option<Username>,
+    pub aliases: Vec<Username>,
+}
+
+impl TryFrom<UserDto> for User {
+    type Error = ConstraintError;
+
+    fn try_from(dto: UserDto) -> Result<Self, Self::Error> {
+        Ok(Self {
+            name: dto.name.try_into()?,
+            manager: from_proto_optional(dto.manager)?,
+            aliases: from_proto_repeated(dto.aliases)?,
+        })
+    }
+}
+
+impl From<User> for UserDto {
+    fn from(user: User) -> Self {
+        Self {
+            name: user.name.into(),
+            manager: into_proto_optional(user.manager),
+            aliases: into_proto_repeated(user.aliases),
+            sponsor: None,
+        }
+    }
+}
+
+#[test]
+fn dtos_convert_to_and_from_braids() {
+    let dto = UserDto {
+        name: "alice".to_owned(),
+        manager: String::new(),
+        aliases: vec!["al".to_owned(), "ally".to_owned()],
+        sponsor: None,
+    };
+
+    let user = User::try_from(dto.clone()).unwrap();
+    assert_eq!(Username::from_static("alice"), user.name);
+    assert_eq!(None, user.manager);
+    assert_eq!(
+        vec![Username::from_static("al"), Username::from_static("ally")],
+        user.aliases
+    );
+
+    assert_eq!(dto, UserDto::from(user));
+}
+
+#[test]
+fn invalid_dto_values_are_rejected() {
+    let dto = UserDto {
+        name: "alice".to_owned(),
+        manager: String::new(),
+        aliases: vec!["much too long".to_owned()],
+        sponsor: None,
+    };
+
+    assert!(User::try_from(dto).is_err());
+}
+
+#[test]
+fn braid_message_is_encoded_as_string_value() {
+    let message = BraidMessage::new(Username::from_static("alice"));
+    let encoded = message.encode_to_vec();
+
+    assert_eq!(message.encoded_len(), encoded.len());
+    assert_eq!("alice", String::decode(encoded.as_slice()).unwrap());
+    assert_eq!(message, BraidMessage::decode(encoded.as_slice()).unwrap());
+}
+
+#[test]
+fn braid_message_is_validated_on_decode() {
+    let encoded = "much too long".to_owned().encode_to_vec();
+    let err = BraidMessage::<Username>::decode(encoded.as_slice()).unwrap_err();
+    assert!(
+        err.to_string().contains("must be at most 8 bytes long"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn empty_braid_message_decodes_as_absent() {
+    let message = BraidMessage::<Username>::decode(&[][..]).unwrap();
+    assert_eq!(None, message.get());
+    assert!(BraidMessage::<Username>::default()
+        .encode_to_vec()
+        .is_empty());
+}
+
+#[test]
+fn braid_message_can_be_a_message_field() {
+    let dto = UserDto {
+        name: "alice".to_owned(),
+        sponsor: Some(Username::from_static("bob").into()),
+        ..UserDto::default()
+    };
+
+    let decoded = UserDto::decode(dto.encode_to_vec().as_slice()).unwrap();
+    assert_eq!(
+        Some(&Username::from_static("bob")),
+        decoded.sponsor.as_ref().and_then(BraidMessage::get)
+    );
+}
@@ -0,0 +1,88 @@
+#![allow(unsafe_code)]
+
+use std::{collections::HashSet, convert::TryFrom};
+
+use aliri_braid::{braid, Provenance};
+
+#[braid(provenance, max_len = 8)]
+pub struct AccountId;
+
+#[braid(provenance, convert_case = "snake")]
+pub struct FieldName {
+    name: String,
+}
+
+#[cfg(debug_assertions)]
+fn unchecked_location(value: &AccountId) -> u32 {
+    match value.provenance() {
+        Some(Provenance::Unchecked(location)) => {
+            assert_eq!(file!(), location.file());
+            location.line()
+        }
+        other => panic!("expected an unchecked provenance, found {:?}", other),
+    }
+}
+
+#[test]
+#[cfg(debug_assertions)]
+fn checked_constructors_record_checked_values() {
+    let id = AccountId::try_from("acct-1").unwrap();
+    assert_eq!(Some(Provenance::Checked), id.provenance());
+
+    let from_ref = AccountIdRef::from_static("acct-2").to_owned();
+    assert_eq!(Some(Provenance::Checked), from_ref.provenance());
+
+    let boxed: Box<AccountIdRef> = id.clone().into_boxed_ref();
+    assert_eq!(Some(Provenance::Checked), boxed.into_owned().provenance());
+
+    let field = FieldName::new("DisplayName".to_owned()).unwrap();
+    assert_eq!(Some(Provenance::Checked), field.provenance());
+}
+
+#[test]
+#[cfg(debug_assertions)]
+fn unchecked_constructors_record_the_caller() {
+    let line = line!() + 1;
+    let id = unsafe { AccountId::new_unchecked("acct-3".to_owned()) };
+    assert_eq!(line, unchecked_location(&id));
+    assert!(!id.provenance().unwrap().is_checked());
+
+    let line = line!() + 1;
+    let id = unsafe { AccountId::new_unchecked_checked("acct-3".to_owned()) };
+    assert_eq!(line, unchecked_location(&id));
+
+    let field = unsafe { FieldName::new_unchecked("display_name".to_owned()) };
+    assert!(matches!(field.provenance(), Some(Provenance::Unchecked(_))));
+}
+
+const EMPTY_ID: AccountId = unsafe { AccountId::new_unchecked(String::new()) };
+
+#[test]
+fn unchecked_constructors_are_const() {
+    assert_eq!("", EMPTY_ID.as_str());
+}
+
+#[test]
+fn provenance_is_ignored_by_comparisons() {
+    let checked = AccountId::try_from("acct-4").unwrap();
+    let unchecked = unsafe { AccountId::new_unchecked("acct-4".to_owned()) };
+    assert_eq!(checked, unchecked);
+
+    let set: HashSet<_> = vec![checked, unchecked].into_iter().collect();
+    assert_eq!(1, set.len());
+}
+
+#[test]
+#[cfg(not(debug_assertions))]
+fn provenance_compiles_to_nothing_in_release() {
+    assert_eq!(
+        std::mem::size_of::<String>(),
+        std::mem::size_of::<AccountId>()
+    );
+
+    let unchecked = unsafe { AccountId::new_unchecked("acct-5".to_owned()) };
+    assert_eq!(None, unchecked.provenance());
+}
+
+#[braid(provenance, max_len = 8, unchecked = "omit")]
+pub struct SealedId;
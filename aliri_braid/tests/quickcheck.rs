@@ -0,0 +1,78 @@
+use std::marker::PhantomData;
+
+use aliri_braid::braid;
+use quickcheck::{Arbitrary, Gen};
+use quickcheck_macros::quickcheck;
+
+#[braid(quickcheck)]
+pub struct Plain;
+
+#[braid(quickcheck, max_len = 8, charset = "ascii_alphanumeric")]
+pub struct Constrained;
+
+#[braid(quickcheck, convert_case = "snake")]
+pub struct Snake;
+
+#[braid(quickcheck, min_len = 1)]
+pub struct Id<T>(String, PhantomData<T>);
+
+pub struct User;
+
+#[cfg_attr(miri, ignore = "takes too long on miri")]
+#[quickcheck]
+fn plain_values_are_arbitrary_strings(value: Plain) -> bool {
+    Plain::from(value.as_str()) == value
+}
+
+#[cfg_attr(miri, ignore = "takes too long on miri")]
+#[quickcheck]
+fn constrained_values_are_valid(value: Constrained) -> bool {
+    value.as_str().len() <= 8
+        && value.as_str().chars().all(|c| c.is_ascii_alphanumeric())
+        && ConstrainedRef::from_str(value.as_str()).is_ok()
+}
+
+#[cfg_attr(miri, ignore = "takes too long on miri")]
+#[quickcheck]
+fn normalized_values_are_in_normal_form(value: Snake) -> bool {
+    SnakeRef::from_normalized_str(value.as_str()).is_ok()
+}
+
+#[cfg_attr(miri, ignore = "takes too long on miri")]
+#[quickcheck]
+fn generic_values_are_valid(value: Id<User>) -> bool {
+    !value.as_str().is_empty()
+}
+
+#[test]
+fn shrunk_values_remain_valid() {
+    let mut g = Gen::new(32);
+    for _ in 0..100 {
+        let value = Constrained::arbitrary(&mut g);
+        for shrunk in value.shrink() {
+            assert!(ConstrainedRef::from_str(shrunk.as_str()).is_ok());
+            assert_ne!(value, shrunk);
+        }
+
+        let value = Snake::arbitrary(&mut g);
+        for shrunk in value.shrink().take(100) {
+            assert!(SnakeRef::from_normalized_str(shrunk.as_str()).is_ok());
+            assert_ne!(value, shrunk);
+        }
+    }
+}
+
+#[test]
+fn shrinking_finds_a_minimal_value() {
+    fn is_short(value: Plain) -> bool {
+        value.as_str().chars().count() < 3
+    }
+
+    let result = std::panic::catch_unwind(|| {
+        quickcheck::QuickCheck::new()
+            .tests(1000)
+            .quickcheck(is_short as fn(Plain) -> bool)
+    });
+    let message = *result.unwrap_err().downcast::<String>().unwrap();
+    assert!(message.contains(r#"("\0\0\0")"#), "{}", message);
+}
@@ -0,0 +1,53 @@
+use aliri_braid::{braid, braid_ref};
+use redis::{FromRedisValue, ToRedisArgs, Value};
+
+#[braid(redis)]
+pub struct CacheKey;
+
+#[braid(redis, max_len = 8)]
+pub struct SessionId;
+
+#[braid_ref(redis)]
+pub struct Channel;
+
+static_assertions::assert_impl_all!(CacheKey: ToRedisArgs, redis::ToSingleRedisArg, FromRedisValue);
+static_assertions::assert_impl_all!(&'static CacheKeyRef: ToRedisArgs, redis::ToSingleRedisArg);
+static_assertions::assert_impl_all!(&'static Channel: ToRedisArgs);
+
+#[test]
+fn owned_is_written_as_single_arg() {
+    let key = CacheKey::from_static("user:1");
+    assert_eq!(vec![b"user:1".to_vec()], key.to_redis_args());
+}
+
+#[test]
+fn borrowed_is_written_as_single_arg() {
+    let key = CacheKeyRef::from_static("user:1");
+    assert_eq!(vec![b"user:1".to_vec()], key.to_redis_args());
+    assert_eq!(
+        vec![b"news".to_vec()],
+        Channel::from_static("news").to_redis_args()
+    );
+}
+
+#[test]
+fn owned_is_read_from_value() {
+    let key = CacheKey::from_redis_value(Value::BulkString(b"user:1".to_vec())).unwrap();
+    assert_eq!("user:1", key.as_str());
+
+    let key = CacheKey::from_redis_value(Value::SimpleString("user:2".to_owned())).unwrap();
+    assert_eq!("user:2", key.as_str());
+}
+
+#[test]
+fn invalid_values_are_rejected() {
+    let err =
+        SessionId::from_redis_value(Value::BulkString(b"much too long".to_vec())).unwrap_err();
+    assert!(
+        err.to_string().contains("must be at most 8 bytes long"),
+        "{}",
+        err
+    );
+
+    assert!(CacheKey::from_redis_value(Value::Nil).is_err());
+}
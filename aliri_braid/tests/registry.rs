@@ -0,0 +1,53 @@
+use aliri_braid::{braid, braid_ref, CheckMode, Constraint};
+
+#[braid]
+pub struct Plain;
+
+#[braid(min_len = 1, prefix = "id:")]
+pub struct Tagged;
+
+#[braid_ref(max_len = 8)]
+pub struct Short;
+
+mod nested {
+    #[aliri_braid::braid(serde)]
+    pub struct Inner;
+}
+
+fn find(ref_name: &str) -> &'static aliri_braid::BraidInfo {
+    aliri_braid::braids()
+        .find(|info| info.ref_name() == ref_name)
+        .unwrap_or_else(|| panic!("{} was not registered", ref_name))
+}
+
+#[test]
+fn registers_unchecked_braid() {
+    let info = find("PlainRef");
+    assert_eq!(Some("Plain"), info.owned_name());
+    assert_eq!(CheckMode::None, info.check_mode());
+    assert!(info.constraints().is_empty());
+    assert_eq!(module_path!(), info.module_path());
+}
+
+#[test]
+fn registers_declared_constraints() {
+    let info = find("TaggedRef");
+    assert_eq!(CheckMode::Validate, info.check_mode());
+    assert_eq!(
+        &[Constraint::MinLength(1), Constraint::Prefix("id:")],
+        info.constraints()
+    );
+}
+
+#[test]
+fn registers_ref_only_braid() {
+    let info = find("Short");
+    assert_eq!(None, info.owned_name());
+    assert_eq!(&[Constraint::MaxLength(8)], info.constraints());
+}
+
+#[test]
+fn registers_module_path_of_declaration() {
+    let info = find("InnerRef");
+    assert_eq!(concat!(module_path!(), "::nested"), info.module_path());
+}
@@ -0,0 +1,95 @@
+use std::collections::BTreeMap;
+
+use aliri_braid::braid;
+use sea_orm::{
+    sea_query::{ColumnType, Nullable, StringLen, ValueType},
+    DbBackend, DbErr, EntityTrait, MockDatabase, Value,
+};
+
+#[braid(sea_orm)]
+pub struct UserId;
+
+#[braid(sea_orm, max_len = 8)]
+pub struct Username;
+
+mod user {
+    use std::convert::TryInto;
+
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+    #[sea_orm(table_name = "users")]
+    pub struct Model {
+        #[sea_orm(primary_key, auto_increment = false)]
+        pub id: super::UserId,
+        pub name: super::Username,
+        pub nickname: Option<super::Username>,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+fn row(name: &str) -> BTreeMap<&'static str, Value> {
+    let mut row = BTreeMap::new();
+    row.insert("id", Value::from("u-1".to_owned()));
+    row.insert("name", Value::from(name.to_owned()));
+    row.insert("nickname", <String as Nullable>::null());
+    row
+}
+
+#[test]
+fn owned_converts_into_value() {
+    let name = Username::from_static("alice");
+    assert_eq!(Value::from("alice".to_owned()), Value::from(name));
+    assert_eq!(<String as Nullable>::null(), <Username as Nullable>::null());
+}
+
+#[test]
+fn value_type_validates() {
+    let name = <Username as ValueType>::try_from(Value::from("alice".to_owned())).unwrap();
+    assert_eq!("alice", name.as_str());
+
+    assert!(<Username as ValueType>::try_from(Value::from("much too long".to_owned())).is_err());
+    assert!(<Username as ValueType>::try_from(Value::from(7)).is_err());
+
+    assert_eq!("Username", Username::type_name());
+    assert_eq!(ColumnType::String(StringLen::None), Username::column_type());
+}
+
+#[test]
+fn models_are_read_with_braid_columns() {
+    let db = MockDatabase::new(DbBackend::Postgres)
+        .append_query_results([vec![row("alice")]])
+        .into_connection();
+
+    let model = pollster::block_on(user::Entity::find().one(&db))
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(
+        user::Model {
+            id: UserId::from_static("u-1"),
+            name: Username::from_static("alice"),
+            nickname: None,
+        },
+        model
+    );
+}
+
+#[test]
+fn invalid_columns_are_rejected_on_read() {
+    let db = MockDatabase::new(DbBackend::Postgres)
+        .append_query_results([vec![row("much too long")]])
+        .into_connection();
+
+    let err = pollster::block_on(user::Entity::find().one(&db)).unwrap_err();
+    assert!(matches!(err, DbErr::Type(_)), "{:?}", err);
+    assert!(
+        err.to_string().contains("must be at most 8 bytes long"),
+        "{}",
+        err
+    );
+}
@@ -0,0 +1,115 @@
+use std::collections::{BTreeMap, HashMap};
+
+use aliri_braid::{braid, BraidAs};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+#[braid(max_len = 8)]
+pub struct Username;
+
+#[braid(normalizer = "Lowercase")]
+pub struct Tag;
+
+pub struct Lowercase;
+
+impl aliri_braid::Validator for Lowercase {
+    type Error = aliri_braid::ConstraintError;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        match raw.find(char::is_uppercase) {
+            Some(idx) => Err(aliri_braid::ConstraintError::new(
+                raw,
+                aliri_braid::Constraint::MaxLength(idx),
+                idx,
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+impl aliri_braid::Normalizer for Lowercase {
+    fn normalize(raw: &str) -> Result<std::borrow::Cow<'_, str>, Self::Error> {
+        Ok(raw.to_lowercase().into())
+    }
+}
+
+#[serde_as]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Team {
+    #[serde_as(as = "BraidAs")]
+    pub lead: Username,
+    #[serde_as(as = "Vec<BraidAs>")]
+    pub members: Vec<Username>,
+    #[serde_as(as = "Option<BraidAs>")]
+    pub deputy: Option<Username>,
+    #[serde_as(as = "BTreeMap<BraidAs, _>")]
+    pub roles: BTreeMap<Username, u32>,
+    #[serde_as(as = "HashMap<BraidAs, BraidAs>")]
+    pub tags: HashMap<Tag, Username>,
+}
+
+#[test]
+fn braids_round_trip_through_adapters() {
+    let team = Team {
+        lead: Username::from_static("alice"),
+        members: vec![Username::from_static("bob"), Username::from_static("carol")],
+        deputy: None,
+        roles: vec![(Username::from_static("bob"), 2)]
+            .into_iter()
+            .collect(),
+        tags: vec![(Tag::from_static("ops"), Username::from_static("carol"))]
+            .into_iter()
+            .collect(),
+    };
+
+    let json = serde_json::to_value(&team).unwrap();
+    assert_eq!(
+        serde_json::json!({
+            "lead": "alice",
+            "members": ["bob", "carol"],
+            "deputy": null,
+            "roles": { "bob": 2 },
+            "tags": { "ops": "carol" },
+        }),
+        json
+    );
+
+    assert_eq!(team, serde_json::from_value(json).unwrap());
+}
+
+#[test]
+fn map_keys_are_normalized() {
+    let team: Team = serde_json::from_str(
+        r#"{"lead":"alice","members":[],"deputy":"bob","roles":{},"tags":{"OPS":"carol"}}"#,
+    )
+    .unwrap();
+
+    assert_eq!(Some(&Username::from_static("bob")), team.deputy.as_ref());
+    assert_eq!(
+        Some(&Username::from_static("carol")),
+        team.tags.get(&Tag::from_static("ops"))
+    );
+}
+
+#[test]
+fn invalid_values_are_rejected() {
+    let err = serde_json::from_str::<Team>(
+        r#"{"lead":"alice","members":["much too long"],"deputy":null,"roles":{},"tags":{}}"#,
+    )
+    .unwrap_err();
+    assert!(
+        err.to_string().contains("must be at most 8 bytes long"),
+        "{}",
+        err
+    );
+
+    let err = serde_json::from_str::<Team>(
+        r#"{"lead":"alice","members":[],"deputy":null,"roles":{"much too long":1},"tags":{}}"#,
+    )
+    .unwrap_err();
+    assert!(
+        err.to_string().contains("must be at most 8 bytes long"),
+        "{}",
+        err
+    );
+}
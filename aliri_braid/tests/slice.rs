@@ -0,0 +1,63 @@
+use aliri_braid::{braid, braid_ref, Constraint};
+
+#[braid(slice)]
+pub struct Path;
+
+#[braid(slice, min_len = 3)]
+pub struct Label;
+
+#[braid(slice, convert_case = "snake")]
+pub struct FieldName;
+
+#[braid_ref(slice, max_len = 8)]
+pub struct TokenRef(str);
+
+#[test]
+fn unvalidated_slices_are_infallible() {
+    let path = PathRef::from_static("/usr/local/bin");
+    assert_eq!("/usr", path.slice(..4).as_str());
+    assert_eq!("bin", path.slice(11..).as_str());
+
+    let (head, tail) = path.split_at(4);
+    assert_eq!("/usr", head.as_str());
+    assert_eq!("/local/bin", tail.as_str());
+}
+
+#[test]
+fn validated_slices_are_checked_again() {
+    let label = LabelRef::from_static("frontend");
+    assert_eq!("front", label.slice(..5).unwrap().as_str());
+    assert_eq!(
+        Constraint::MinLength(3),
+        label.slice(..2).unwrap_err().constraint()
+    );
+
+    let (head, tail) = label.split_at(5).unwrap();
+    assert_eq!("front", head.as_str());
+    assert_eq!("end", tail.as_str());
+    assert!(label.split_at(6).is_err());
+}
+
+#[test]
+fn normalized_slices_borrow_the_original_value() {
+    let field = FieldName::new("UserDisplayName".to_owned()).unwrap();
+    assert_eq!("user_display_name", field.as_str());
+    let slice = field.slice(5..).unwrap();
+    assert_eq!("display_name", slice.as_str());
+    assert_eq!(field.as_str()[5..].as_ptr(), slice.as_str().as_ptr());
+    assert!(field.slice(4..).is_err());
+}
+
+#[test]
+fn borrowed_only_braids_can_be_sliced() {
+    let token = TokenRef::from_static("abcdefgh");
+    assert_eq!("cde", token.slice(2..5).unwrap().as_str());
+    assert!(token.split_at(4).is_ok());
+}
+
+#[test]
+#[should_panic]
+fn slicing_within_a_char_panics() {
+    let path = PathRef::from_static("ünïcode");
+    let _ = path.slice(..1);
+}
@@ -0,0 +1,64 @@
+extern crate std as my_std;
+
+use std::borrow::Cow;
+
+use aliri_braid::{braid, braid_ref};
+
+#[braid(std_path = "my_std", serde)]
+pub struct Facade;
+
+#[braid(std_path = "my_std", serde, max_len = 4, from_bytes)]
+pub struct FacadeValidated;
+
+#[braid(std_path = "my_std", normalizer = "Lowercase")]
+pub struct FacadeNormalized;
+
+#[braid_ref(std_path = "my_std", min_len = 1)]
+pub struct FacadeRefOnly;
+
+pub struct Lowercase;
+
+impl aliri_braid::Validator for Lowercase {
+    type Error = aliri_braid::ConstraintError;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        match raw.char_indices().find(|(_, c)| c.is_uppercase()) {
+            Some((idx, _)) => Err(aliri_braid::ConstraintError::new(
+                raw,
+                aliri_braid::Constraint::MinLength(0),
+                idx,
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+impl aliri_braid::Normalizer for Lowercase {
+    fn normalize(raw: &str) -> Result<Cow<'_, str>, Self::Error> {
+        if raw.chars().any(char::is_uppercase) {
+            Ok(Cow::Owned(raw.to_lowercase()))
+        } else {
+            Ok(Cow::Borrowed(raw))
+        }
+    }
+}
+
+#[test]
+fn facade_braids_behave_like_std_braids() {
+    let owned = Facade::from_static("value");
+    assert_eq!(owned, FacadeRef::from_str("value"));
+    assert_eq!("\"value\"", serde_json::to_string(&owned).unwrap());
+
+    assert!(FacadeValidated::new("long!".to_owned()).is_err());
+    assert_eq!(
+        "ok",
+        FacadeValidatedRef::from_bytes_validated(b"ok")
+            .unwrap()
+            .as_str()
+    );
+
+    assert_eq!("abc", FacadeNormalized::from_static("ABC").as_str());
+    assert!(FacadeNormalizedRef::from_normalized_str("ABC").is_err());
+
+    assert!(FacadeRefOnly::from_str("").is_err());
+}
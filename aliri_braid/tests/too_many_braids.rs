@@ -40,7 +40,7 @@ impl aliri_braid::Validator for LowerString {
 }
 
 impl aliri_braid::Normalizer for LowerString {
-    fn normalize(s: &str) -> Result<Cow<str>, Self::Error> {
+    fn normalize(s: &str) -> Result<Cow<'_, str>, Self::Error> {
         if s.is_empty() {
             Err(InvalidString::EmptyString)
         } else if s.contains(|c: char| c.is_uppercase()) {
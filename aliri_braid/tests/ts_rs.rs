@@ -0,0 +1,73 @@
+use std::marker::PhantomData;
+
+use aliri_braid::{braid, braid_ref};
+use ts_rs::TS;
+
+/// A user's login name
+#[braid(ts_rs)]
+pub struct Username;
+
+#[braid(ts_rs, ref_name = "BorrowedLabel")]
+pub struct Label;
+
+#[braid_ref(ts_rs)]
+pub struct Topic;
+
+pub struct User;
+
+#[braid(ts_rs)]
+pub struct Id<T>(String, PhantomData<T>);
+
+#[derive(TS)]
+pub struct Account {
+    pub username: Username,
+    pub label: Option<Label>,
+}
+
+#[test]
+fn owned_is_declared_as_string_alias() {
+    assert_eq!("Username", Username::name());
+    assert_eq!("string", Username::inline());
+    assert_eq!("type Username = string;", Username::decl());
+    assert_eq!(
+        Some("/**\n * Rust type `Username`\n */\n".to_owned()),
+        Username::docs()
+    );
+    assert_eq!(
+        Some(std::path::PathBuf::from("Username.ts")),
+        Username::output_path()
+    );
+}
+
+#[test]
+fn borrowed_refers_to_owned_declaration() {
+    assert_eq!("Label", BorrowedLabel::name());
+    assert_eq!("type Label = string;", BorrowedLabel::decl());
+    assert_eq!(Label::output_path(), BorrowedLabel::output_path());
+}
+
+#[test]
+fn ref_only_is_declared_as_string_alias() {
+    assert_eq!("Topic", Topic::name());
+    assert_eq!("type Topic = string;", Topic::decl());
+}
+
+#[test]
+fn generic_braids_share_a_declaration() {
+    assert_eq!("Id", Id::<User>::name());
+    assert_eq!("type Id = string;", IdRef::<User>::decl());
+}
+
+#[test]
+fn fields_refer_to_braid_declarations() {
+    let decl = Account::decl();
+    assert!(decl.contains("username: Username"), "{}", decl);
+    assert!(decl.contains("label: Label | null"), "{}", decl);
+
+    let export = Account::export_to_string().unwrap();
+    assert!(
+        export.contains("import type { Username } from \"./Username\";"),
+        "{}",
+        export
+    );
+}
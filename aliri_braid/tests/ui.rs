@@ -0,0 +1,13 @@
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+    #[cfg(feature = "regex")]
+    t.compile_fail("tests/ui/regex/*.rs");
+    #[cfg(not(feature = "idna"))]
+    t.compile_fail("tests/ui/no_idna/*.rs");
+    #[cfg(not(feature = "uri"))]
+    t.compile_fail("tests/ui/no_uri/*.rs");
+    #[cfg(not(feature = "uuid"))]
+    t.compile_fail("tests/ui/no_uuid/*.rs");
+}
@@ -0,0 +1,14 @@
+use aliri_braid::braid;
+
+#[braid(add_str, validator = "Username")]
+pub struct Username;
+
+impl aliri_braid::Validator for Username {
+    type Error = std::convert::Infallible;
+
+    fn validate(_: &str) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use aliri_braid::braid;
+
+#[braid(as_path, no_expose = "opaque")]
+pub struct SecretFile;
+
+fn main() {}
@@ -0,0 +1,9 @@
+use aliri_braid::braid;
+
+#[braid(assert_layout, owned_attr(repr(C, align(64))))]
+pub struct Aligned;
+
+#[braid(assert_layout)]
+pub struct Generic<T>(String, std::marker::PhantomData<T>);
+
+fn main() {}
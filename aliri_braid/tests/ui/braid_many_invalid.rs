@@ -0,0 +1,9 @@
+use aliri_braid::braid_many;
+
+braid_many!(serde, unknown_param; UserId, OrderId);
+
+braid_many!(serde; );
+
+braid_many!(serde UserId);
+
+fn main() {}
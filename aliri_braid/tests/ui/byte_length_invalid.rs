@@ -0,0 +1,18 @@
+use aliri_braid::{braid, braid_ref};
+
+#[braid(min_bytes = "4")]
+pub struct NotAnInteger;
+
+#[braid(max_bytes = 4, max_bytes = 8)]
+pub struct Repeated;
+
+#[braid(max_len = 4, max_bytes = 8)]
+pub struct Aliased;
+
+#[braid(min_bytes = 8, max_bytes = 4)]
+pub struct Inverted;
+
+#[braid_ref(max_bytes = "8")]
+pub struct RefNotAnInteger;
+
+fn main() {}
@@ -0,0 +1,12 @@
+use aliri_braid::braid;
+
+#[braid]
+pub struct Username;
+
+impl Username {
+    pub fn new(raw: String) -> Self {
+        Self(raw)
+    }
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+use aliri_braid::braid;
+
+#[braid(validator = "NonEmpty")]
+pub struct Username;
+
+impl UsernameRef {
+    pub fn from_static(raw: &'static str) -> &'static Self {
+        Self::from_str(raw).unwrap()
+    }
+}
+
+pub struct NonEmpty;
+
+impl aliri_braid::Validator for NonEmpty {
+    type Error = aliri_braid::ConstraintError;
+
+    fn validate(_: &str) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use aliri_braid::braid;
+
+#[braid]
+pub struct Username;
+
+pub struct UsernameRef;
+
+fn main() {}
@@ -0,0 +1,9 @@
+use aliri_braid::braid;
+
+#[braid(serde, feature_gate(display = "fmt"))]
+pub struct UnknownIntegration;
+
+#[braid(serde, feature_gate(serde = "serde", serde = "serde-json"))]
+pub struct RepeatedIntegration;
+
+fn main() {}
@@ -0,0 +1,10 @@
+use aliri_braid::braid_ref;
+
+#[braid_ref(to_owned = "to_string")]
+pub struct Unowned;
+
+fn to_string(value: &Unowned) -> String {
+    value.as_str().to_owned()
+}
+
+fn main() {}
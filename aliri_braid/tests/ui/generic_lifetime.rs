@@ -0,0 +1,6 @@
+use aliri_braid::braid;
+
+#[braid]
+pub struct Scoped<'a>(String, std::marker::PhantomData<&'a ()>);
+
+fn main() {}
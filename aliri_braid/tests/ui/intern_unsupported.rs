@@ -0,0 +1,12 @@
+use aliri_braid::{braid, braid_ref};
+
+#[braid(intern, no_expose = "opaque")]
+pub struct Secret;
+
+#[braid_ref(intern)]
+pub struct Unallocated;
+
+#[braid(intern, omit(arc))]
+pub struct Unshared;
+
+fn main() {}
@@ -0,0 +1,8 @@
+use aliri_braid::{braid, braid_lit};
+
+#[braid(max_len = 4)]
+pub struct Code;
+
+fn main() {
+    let _ = braid_lit!(Code, "too long");
+}
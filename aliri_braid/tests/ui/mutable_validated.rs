@@ -0,0 +1,6 @@
+use aliri_braid::braid;
+
+#[braid(mutable, min_len = 1)]
+pub struct Username;
+
+fn main() {}
@@ -0,0 +1,6 @@
+use aliri_braid::braid;
+
+#[braid(eq = "ascii_case_insensitive", ord = "natural")]
+pub struct HostName;
+
+fn main() {}
@@ -0,0 +1,9 @@
+use aliri_braid::braid;
+
+#[braid]
+pub struct Inner;
+
+#[braid(nested, normalizer)]
+pub struct Normalized(Inner);
+
+fn main() {}
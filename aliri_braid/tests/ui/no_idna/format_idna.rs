@@ -0,0 +1,6 @@
+use aliri_braid::braid;
+
+#[braid(format = "idna")]
+pub struct DomainName;
+
+fn main() {}
@@ -0,0 +1,6 @@
+use aliri_braid::braid;
+
+#[braid(format = "uri")]
+pub struct Endpoint;
+
+fn main() {}
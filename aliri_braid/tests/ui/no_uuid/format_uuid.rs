@@ -0,0 +1,6 @@
+use aliri_braid::braid;
+
+#[braid(format = "uuid")]
+pub struct AccountId;
+
+fn main() {}
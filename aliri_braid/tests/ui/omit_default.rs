@@ -0,0 +1,6 @@
+use aliri_braid::braid;
+
+#[braid(default = "guest", omit(from_static))]
+pub struct Username;
+
+fn main() {}
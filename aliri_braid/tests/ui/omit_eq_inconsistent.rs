@@ -0,0 +1,9 @@
+use aliri_braid::braid;
+
+#[braid(eq = "omit", ord = "omit")]
+pub struct Qualified;
+
+#[braid(eq = "omit", hash = "omit")]
+pub struct Ordered;
+
+fn main() {}
@@ -0,0 +1,12 @@
+mod opaque {
+    use aliri_braid::braid;
+
+    #[braid(no_expose = "opaque")]
+    pub struct Secret;
+}
+
+fn main() {
+    let secret = opaque::Secret::from_static("hunter2");
+    let _ = secret.as_str();
+    let _: &str = secret.as_ref();
+}
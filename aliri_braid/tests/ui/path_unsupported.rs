@@ -0,0 +1,18 @@
+use aliri_braid::braid;
+
+#[braid(path, relative, absolute)]
+pub struct Both;
+
+#[braid(path, relative, validator)]
+pub struct Checked;
+
+#[braid(path, ref_inner = "std::path::Path")]
+pub struct Doubled;
+
+#[braid(c_str, relative)]
+pub struct Misplaced;
+
+#[braid(no_std, path)]
+pub struct Unavailable;
+
+fn main() {}
@@ -0,0 +1,9 @@
+use aliri_braid::braid;
+
+#[braid(provenance)]
+pub struct Plain;
+
+#[braid(provenance, max_len = 4, assert_layout)]
+pub struct Laid;
+
+fn main() {}
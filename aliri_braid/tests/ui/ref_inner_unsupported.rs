@@ -0,0 +1,9 @@
+use aliri_braid::braid;
+
+#[braid(ref_inner = "std::path::Path", serde)]
+pub struct ConfigDir(std::path::PathBuf);
+
+#[braid(ref_inner = "[u8]")]
+pub struct Undeclared;
+
+fn main() {}
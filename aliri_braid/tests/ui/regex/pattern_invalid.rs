@@ -0,0 +1,6 @@
+use aliri_braid::braid;
+
+#[braid(pattern = "[a-z")]
+pub struct Unclosed;
+
+fn main() {}
@@ -0,0 +1,6 @@
+use aliri_braid::braid;
+
+#[braid(pattern = r"\bword\b")]
+pub struct Word;
+
+fn main() {}
@@ -0,0 +1,13 @@
+mod restricted {
+    use aliri_braid::braid;
+
+    #[braid(expose = "pub(self)")]
+    pub struct Internal;
+}
+
+fn main() {
+    let _ = restricted::Internal::from_static("abc");
+    let _ = restricted::InternalRef::from_str("abc");
+    let internal = restricted::Internal::from("abc");
+    let _ = internal.as_str();
+}
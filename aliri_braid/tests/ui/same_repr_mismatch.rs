@@ -0,0 +1,15 @@
+use aliri_braid::braid;
+
+#[braid(max_len = 8, same_repr_as = "OrgId")]
+pub struct TenantId;
+
+#[braid(max_len = 16, same_repr_as = "TenantId")]
+pub struct OrgId;
+
+#[braid(same_repr_as = "UserId")]
+pub struct AccountId;
+
+#[braid]
+pub struct UserId;
+
+fn main() {}
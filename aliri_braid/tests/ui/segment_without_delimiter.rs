@@ -0,0 +1,9 @@
+use aliri_braid::braid;
+
+#[braid]
+pub struct Part;
+
+#[braid(segment = "PartRef")]
+pub struct Path;
+
+fn main() {}
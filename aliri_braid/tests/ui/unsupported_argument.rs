@@ -0,0 +1,21 @@
+use aliri_braid::{braid, braid_ref};
+
+#[braid(ref = "NameRef")]
+pub struct Name;
+
+#[braid(max_lenght = 4)]
+pub struct Misspelled;
+
+#[braid(max_len)]
+pub struct MissingValue;
+
+#[braid(omit = "from_static")]
+pub struct WrongForm;
+
+#[braid(frobnicate)]
+pub struct Unknown;
+
+#[braid_ref(ref_name = "Other", owned = "String")]
+pub struct OwnedOnlyRef(str);
+
+fn main() {}
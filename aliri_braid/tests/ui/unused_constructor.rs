@@ -0,0 +1,12 @@
+#![deny(unused_must_use)]
+
+use aliri_braid::braid;
+
+#[braid]
+pub struct Username;
+
+fn main() {
+    Username::from_static("abc");
+    UsernameRef::from_str("abc");
+    Username::from_static("abc").take();
+}
@@ -0,0 +1,132 @@
+use std::{borrow::Cow, convert::Infallible};
+
+use aliri_braid::braid;
+
+#[braid(unicode)]
+pub struct DisplayName;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct DanglingHyphen;
+
+impl From<Infallible> for DanglingHyphen {
+    fn from(x: Infallible) -> Self {
+        match x {}
+    }
+}
+
+#[braid(unicode, validator)]
+pub struct Slug;
+
+impl aliri_braid::Validator for Slug {
+    type Error = DanglingHyphen;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        if raw.ends_with('-') {
+            Err(DanglingHyphen)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[braid(unicode, normalizer)]
+pub struct Trimmed;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Blank;
+
+impl From<Infallible> for Blank {
+    fn from(x: Infallible) -> Self {
+        match x {}
+    }
+}
+
+impl aliri_braid::Validator for Trimmed {
+    type Error = Blank;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        if raw.trim_end().is_empty() || raw != raw.trim_end() {
+            Err(Blank)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl aliri_braid::Normalizer for Trimmed {
+    fn normalize(raw: &str) -> Result<Cow<'_, str>, Self::Error> {
+        if raw.trim_end().is_empty() {
+            Err(Blank)
+        } else if raw.ends_with(char::is_whitespace) {
+            Ok(Cow::Owned(raw.trim_end().to_owned()))
+        } else {
+            Ok(Cow::Borrowed(raw))
+        }
+    }
+}
+
+#[test]
+fn grapheme_len_counts_user_perceived_characters() {
+    let name = DisplayName::from_static("e\u{301}te\u{301} 👩‍🔬");
+    assert_eq!(5, name.grapheme_len());
+    assert_eq!(0, DisplayName::from_static("").grapheme_len());
+}
+
+#[test]
+fn truncation_never_splits_graphemes() {
+    let name = DisplayName::from_static("e\u{301}te\u{301} 👩‍🔬");
+
+    let truncated = name.truncate_graphemes(1);
+    assert_eq!("e\u{301}", truncated.as_str());
+    assert!(matches!(truncated, Cow::Borrowed(_)));
+
+    assert_eq!("e\u{301}te\u{301} ", name.truncate_graphemes(4).as_str());
+    assert_eq!(name, name.truncate_graphemes(5));
+    assert_eq!(name, name.truncate_graphemes(100));
+    assert_eq!("", name.truncate_graphemes(0).as_str());
+}
+
+#[test]
+fn truncation_revalidates_fallible_braids() {
+    let slug = Slug::from_static("a-b");
+
+    assert_eq!("a", slug.truncate_graphemes(1).unwrap().as_str());
+    assert_eq!(Err(DanglingHyphen), slug.truncate_graphemes(2));
+    assert_eq!(slug, slug.truncate_graphemes(3).unwrap());
+}
+
+#[test]
+fn truncation_renormalizes_normalized_braids() {
+    let trimmed = Trimmed::from_static("ab cd");
+
+    let truncated = trimmed.truncate_graphemes(3).unwrap();
+    assert_eq!("ab", truncated.as_str());
+    assert!(matches!(truncated, Cow::Owned(_)));
+
+    let truncated = trimmed.truncate_graphemes(2).unwrap();
+    assert_eq!("ab", truncated.as_str());
+    assert!(matches!(truncated, Cow::Borrowed(_)));
+
+    assert_eq!(Err(Blank), trimmed.truncate_graphemes(0));
+}
+
+#[braid(eq = "unicode_case_insensitive")]
+pub struct Username;
+
+#[test]
+fn unicode_case_insensitive_equality() {
+    use std::collections::HashSet;
+
+    let name = Username::from_static("ΑΘΗΝΑ");
+    assert_eq!(name, UsernameRef::from_static("αθηνα"));
+    assert_ne!(name, UsernameRef::from_static("αθηνb"));
+    assert!(Username::from_static("äpfel") < Username::from_static("ÖL"));
+    assert!(Username::from_static("apple") < Username::from_static("Zebra"));
+
+    let names: HashSet<Username> = ["Ärger", "ärger", "ÄRGER"]
+        .iter()
+        .copied()
+        .map(Username::from_static)
+        .collect();
+    assert_eq!(1, names.len());
+}
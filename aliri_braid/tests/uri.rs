@@ -0,0 +1,29 @@
+use aliri_braid::{braid, Constraint, Format};
+
+#[braid(format = "uri")]
+pub struct Endpoint;
+
+#[test]
+fn uris() {
+    for valid in [
+        "https://example.com/path?query#fragment",
+        "urn:isbn:0451450523",
+        "mailto:user@example.com",
+    ] {
+        assert!(EndpointRef::from_str(valid).is_ok(), "{}", valid);
+    }
+
+    for (invalid, position) in [
+        ("/relative/path", 0),
+        ("relative", 8),
+        ("https://exa mple.com", 11),
+        ("https://example.com/%zz", 20),
+        ("https://example.com/path?a=b c", 28),
+        ("https://example.com/#frag#ment", 25),
+        ("https://example.com:80a/", 8),
+    ] {
+        let err = EndpointRef::from_str(invalid).unwrap_err();
+        assert_eq!(Constraint::Format(Format::Uri), err.constraint());
+        assert_eq!(position, err.position(), "{}", invalid);
+    }
+}
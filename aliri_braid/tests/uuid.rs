@@ -0,0 +1,42 @@
+use std::convert::TryFrom;
+
+use aliri_braid::{braid, Constraint, Format};
+use uuid::Uuid;
+
+#[braid(format = "uuid")]
+pub struct OrderId;
+
+const CANONICAL: &str = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+
+#[test]
+fn normalizes_into_canonical_form() {
+    for raw in [
+        CANONICAL,
+        "67E55044-10B1-426F-9247-BB680E5FE0C8",
+        "67e5504410b1426f9247bb680e5fe0c8",
+        "{67e55044-10b1-426f-9247-bb680e5fe0c8}",
+        "urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8",
+    ] {
+        assert_eq!(CANONICAL, OrderId::new(raw.to_owned()).unwrap().as_str());
+    }
+}
+
+#[test]
+fn rejects_invalid_and_non_canonical_text() {
+    let err = OrderId::new("order-1".to_owned()).unwrap_err();
+    assert_eq!(Constraint::Format(Format::Uuid), err.constraint());
+
+    let err = OrderIdRef::from_normalized_str("67E55044-10b1-426f-9247-bb680e5fe0c8").unwrap_err();
+    assert_eq!(Constraint::Format(Format::Uuid), err.constraint());
+    assert_eq!(2, err.position());
+    assert!(OrderIdRef::from_normalized_str(CANONICAL).is_ok());
+}
+
+#[test]
+fn converts_to_and_from_uuid() {
+    let uuid = Uuid::parse_str(CANONICAL).unwrap();
+    let id = OrderId::from(uuid);
+    assert_eq!(CANONICAL, id.as_str());
+    assert_eq!(Ok(uuid), Uuid::try_from(&id));
+    assert_eq!(Ok(uuid), Uuid::try_from(&*id));
+}
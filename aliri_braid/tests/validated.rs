@@ -52,6 +52,44 @@ impl aliri_braid::Validator for ScopeToken {
     }
 }
 
+static COUNTED_VALIDATIONS: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+/// A braid whose validator counts how many times it has been called
+#[braid(validator)]
+pub struct Counted;
+
+impl aliri_braid::Validator for Counted {
+    type Error = InvalidScopeToken;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        COUNTED_VALIDATIONS.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        if raw.is_empty() {
+            Err(InvalidScopeToken::EmptyString)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+static BUILT_VALIDATIONS: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+/// Like `Counted`, but with a separate count so that tests of each can run in parallel
+#[braid(validator, build)]
+pub struct Built;
+
+impl aliri_braid::Validator for Built {
+    type Error = InvalidScopeToken;
+
+    fn validate(raw: &str) -> Result<(), Self::Error> {
+        BUILT_VALIDATIONS.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        if raw.is_empty() {
+            Err(InvalidScopeToken::EmptyString)
+        } else {
+            Ok(())
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::borrow::Borrow;
@@ -121,13 +159,13 @@ mod tests {
     #[test]
     #[should_panic]
     fn from_static_ref_panics_on_empty() {
-        ScopeTokenRef::from_static("");
+        let _ = ScopeTokenRef::from_static("");
     }
 
     #[test]
     #[should_panic]
     fn from_static_owned_panics_on_empty() {
-        ScopeToken::from_static("");
+        let _ = ScopeToken::from_static("");
     }
 
     #[test]
@@ -220,4 +258,35 @@ mod tests {
         let owned = ScopeTokenRef::from_static("https://crates.io/scopes/publish:crate");
         let _reference: &str = owned.borrow();
     }
+
+    #[test]
+    fn owned_try_from_str_validates_once() {
+        use std::{convert::TryFrom, sync::atomic::Ordering};
+
+        let before = COUNTED_VALIDATIONS.load(Ordering::SeqCst);
+        let owned = Counted::try_from("value").unwrap();
+        assert_eq!(1, COUNTED_VALIDATIONS.load(Ordering::SeqCst) - before);
+
+        let before = COUNTED_VALIDATIONS.load(Ordering::SeqCst);
+        let parsed: Counted = "value".parse().unwrap();
+        assert_eq!(1, COUNTED_VALIDATIONS.load(Ordering::SeqCst) - before);
+
+        assert_eq!(owned, parsed);
+        assert!(Counted::try_from("").is_err());
+    }
+
+    #[test]
+    fn owned_build_validates_once() {
+        use std::{fmt::Write, sync::atomic::Ordering};
+
+        let before = BUILT_VALIDATIONS.load(Ordering::SeqCst);
+        let built = Built::build(|s| {
+            let tail = "ue";
+            write!(s, "val{}", tail).unwrap();
+        })
+        .unwrap();
+        assert_eq!(1, BUILT_VALIDATIONS.load(Ordering::SeqCst) - before);
+        assert_eq!("value", built.as_str());
+        assert!(Built::build(|_| {}).is_err());
+    }
 }
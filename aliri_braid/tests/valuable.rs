@@ -0,0 +1,56 @@
+use aliri_braid::{braid, braid_ref};
+use valuable::{Valuable, Value, Visit};
+
+#[braid(valuable)]
+pub struct RequestId;
+
+#[braid(valuable, max_len = 8)]
+pub struct Token;
+
+#[braid_ref(valuable)]
+pub struct Label;
+
+#[derive(Default)]
+struct Recorder(Vec<String>);
+
+impl Visit for Recorder {
+    fn visit_value(&mut self, value: Value<'_>) {
+        match value {
+            Value::String(s) => self.0.push(s.to_owned()),
+            _ => panic!("unexpected value: {:?}", value),
+        }
+    }
+}
+
+fn record<T: Valuable + ?Sized>(value: &T) -> Vec<String> {
+    let mut recorder = Recorder::default();
+    value.visit(&mut recorder);
+    recorder.0
+}
+
+#[test]
+fn owned_records_as_string() {
+    let id = RequestId::from_static("req-1234");
+    assert!(matches!(id.as_value(), Value::String("req-1234")));
+    assert_eq!(vec!["req-1234"], record(&id));
+}
+
+#[test]
+fn borrowed_records_as_string() {
+    let id = RequestIdRef::from_static("req-1234");
+    assert!(matches!(id.as_value(), Value::String("req-1234")));
+    assert_eq!(vec!["req-1234"], record(id));
+}
+
+#[test]
+fn validated_records_as_string() {
+    let token = Token::new("abc".to_owned()).unwrap();
+    assert_eq!(vec!["abc"], record(&token));
+    assert_eq!(vec!["abc"], record::<TokenRef>(&token));
+}
+
+#[test]
+fn braid_ref_records_as_string() {
+    let label = Label::from_static("blue");
+    assert!(matches!(label.as_value(), Value::String("blue")));
+}
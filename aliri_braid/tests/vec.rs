@@ -0,0 +1,93 @@
+use aliri_braid::{braid, ConstraintError};
+
+#[braid(vec, view_slices)]
+pub struct Tag;
+
+#[braid(vec, max_len = 4)]
+pub struct Code;
+
+#[braid(vec, normalizer = "Lowercase")]
+pub struct Word;
+
+pub struct Lowercase;
+
+impl aliri_braid::Validator for Lowercase {
+    type Error = ConstraintError;
+
+    fn validate(_: &str) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl aliri_braid::Normalizer for Lowercase {
+    fn normalize(raw: &str) -> Result<std::borrow::Cow<'_, str>, Self::Error> {
+        Ok(raw.to_lowercase().into())
+    }
+}
+
+#[braid(vec, provenance, max_len = 4)]
+pub struct TrackedCode;
+
+#[test]
+fn unvalidated_vectors_reuse_their_allocation() {
+    let raw = vec![String::from("a"), String::from("b")];
+    let ptr = raw.as_ptr() as usize;
+
+    let tags = Tag::from_vec(raw);
+    assert_eq!(ptr, tags.as_ptr() as usize);
+    assert_eq!(vec![Tag::from_static("a"), Tag::from_static("b")], tags);
+
+    let raw = Tag::into_inner_vec(tags);
+    assert_eq!(ptr, raw.as_ptr() as usize);
+    assert_eq!(vec!["a", "b"], raw);
+}
+
+#[test]
+fn string_slices_are_borrowed_in_place() {
+    let raw = vec!["a", "b"];
+    let ptr = raw.as_ptr() as usize;
+
+    let tags = TagRef::from_str_vec(raw);
+    assert_eq!(ptr, tags.as_ptr() as usize);
+    assert_eq!(
+        vec![TagRef::from_static("a"), TagRef::from_static("b")],
+        tags
+    );
+}
+
+#[test]
+fn validated_vectors_are_checked() {
+    let codes = Code::try_from_vec(vec![String::from("ab"), String::from("cd")]).unwrap();
+    assert_eq!(vec!["ab", "cd"], Code::into_inner_vec(codes));
+
+    let err = Code::try_from_vec(vec![String::from("ab"), String::from("too long")]).unwrap_err();
+    assert_eq!(1, err.0);
+}
+
+#[test]
+fn normalized_vectors_are_normalized() {
+    let words = Word::try_from_vec(vec![String::from("Hello"), String::from("world")]).unwrap();
+    assert_eq!(vec!["hello", "world"], Word::into_inner_vec(words));
+}
+
+#[test]
+fn values_carrying_provenance_are_converted() {
+    let codes = TrackedCode::try_from_vec(vec![String::from("ab")]).unwrap();
+    assert!(codes[0].provenance().map_or(true, |p| p.is_checked()));
+    assert_eq!(vec!["ab"], TrackedCode::into_inner_vec(codes));
+}
+
+#[test]
+fn string_slices_are_viewed_in_place() {
+    let raw = [String::from("a"), String::from("b")];
+
+    let tags = Tag::from_slice(&raw);
+    assert_eq!(raw.as_ptr() as usize, tags.as_ptr() as usize);
+    assert_eq!([Tag::from_static("a"), Tag::from_static("b")], tags);
+    assert_eq!(&raw, Tag::as_inner_slice(tags));
+
+    let raw = ["a", "b"];
+    let tags = TagRef::from_str_slice(&raw);
+    assert_eq!(raw.as_ptr() as usize, tags.as_ptr() as usize);
+    assert_eq!([TagRef::from_static("a"), TagRef::from_static("b")], tags);
+}
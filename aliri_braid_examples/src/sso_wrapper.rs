@@ -65,7 +65,7 @@ impl aliri_braid::Validator for LowerCompactString {
 }
 
 impl aliri_braid::Normalizer for LowerCompactString {
-    fn normalize(s: &str) -> Result<Cow<str>, Self::Error> {
+    fn normalize(s: &str) -> Result<Cow<'_, str>, Self::Error> {
         if s.is_empty() {
             Err(InvalidString::EmptyString)
         } else if s.contains(char::is_uppercase) {
@@ -0,0 +1,92 @@
+use quote::{quote, ToTokens};
+
+use super::{expose::Expose, symbol, StdLib};
+
+/// Opts a string braid into being used as a filesystem path
+///
+/// Generates `AsRef<Path>` and `AsRef<OsStr>` for both the owned and borrowed
+/// types, so that values can be passed directly to `std::fs` and
+/// `std::process` APIs.
+#[derive(Default)]
+pub struct AsPath {
+    param: Option<syn::Path>,
+}
+
+impl AsPath {
+    pub fn set(&mut self, param: &syn::Path) {
+        self.param = Some(param.clone());
+    }
+
+    /// Rejects combinations in which a path could not be generated or would
+    /// expose a value that is otherwise kept opaque
+    pub fn ensure_supported(&self, std_lib: &StdLib, expose: Expose) -> Result<(), syn::Error> {
+        let param = match &self.param {
+            Some(param) => param,
+            None => return Ok(()),
+        };
+
+        let conflict = if std_lib.core() == "core" {
+            Some(symbol::NO_STD.to_string())
+        } else if !expose.str() {
+            Some(format!("{} = \"opaque\"", symbol::NO_EXPOSE))
+        } else {
+            None
+        };
+
+        match conflict {
+            Some(conflict) => Err(syn::Error::new_spanned(
+                param,
+                format!(
+                    "{} cannot be combined with {}",
+                    param.to_token_stream(),
+                    conflict
+                ),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    pub fn tokens(
+        &self,
+        owned_ty: &syn::Ident,
+        ref_ty: &syn::Type,
+        std_lib: &StdLib,
+    ) -> Option<proc_macro2::TokenStream> {
+        self.param.as_ref()?;
+        let core = std_lib.core();
+
+        Some(quote! {
+            #[automatically_derived]
+            impl ::#core::convert::AsRef<::#core::path::Path> for #ref_ty {
+                #[inline]
+                fn as_ref(&self) -> &::#core::path::Path {
+                    ::#core::path::Path::new(self.as_str())
+                }
+            }
+
+            #[automatically_derived]
+            impl ::#core::convert::AsRef<::#core::ffi::OsStr> for #ref_ty {
+                #[inline]
+                fn as_ref(&self) -> &::#core::ffi::OsStr {
+                    ::#core::ffi::OsStr::new(self.as_str())
+                }
+            }
+
+            #[automatically_derived]
+            impl ::#core::convert::AsRef<::#core::path::Path> for #owned_ty {
+                #[inline]
+                fn as_ref(&self) -> &::#core::path::Path {
+                    ::#core::convert::AsRef::as_ref(::#core::borrow::Borrow::<#ref_ty>::borrow(self))
+                }
+            }
+
+            #[automatically_derived]
+            impl ::#core::convert::AsRef<::#core::ffi::OsStr> for #owned_ty {
+                #[inline]
+                fn as_ref(&self) -> &::#core::ffi::OsStr {
+                    ::#core::convert::AsRef::as_ref(::#core::borrow::Borrow::<#ref_ty>::borrow(self))
+                }
+            }
+        })
+    }
+}
@@ -1,18 +1,38 @@
 use quote::{quote, ToTokens, TokenStreamExt};
 
-use super::{impls::ToImpl, AttrList, CheckMode, Field, FieldName, Impls, StdLib};
+use super::{
+    equality::Equality, impls::ToImpl, CheckMode, Constructor, Expose, Field, FieldName, Impls,
+    Omit, StdLib, Unchecked,
+};
 
 pub struct RefCodeGen<'a> {
-    pub doc: &'a [syn::Lit],
+    pub doc: &'a [syn::Attribute],
     pub common_attrs: &'a [syn::Attribute],
-    pub attrs: &'a AttrList,
+    pub attrs: &'a [proc_macro2::TokenStream],
     pub vis: &'a syn::Visibility,
     pub ty: &'a syn::Type,
     pub ident: syn::Ident,
     pub field: Field,
     pub check_mode: &'a CheckMode,
-    pub owned_ty: Option<&'a syn::Ident>,
+    pub error: Option<&'a syn::Type>,
+    pub owned_ty: Option<syn::Type>,
+    pub owned_field: Option<&'a Field>,
+    pub to_owned_fn: Option<&'a syn::Path>,
+    pub as_str_fn: Option<&'a syn::Path>,
+    pub alloc: bool,
     pub std_lib: &'a StdLib,
+    pub unchecked: &'a Unchecked,
+    pub constructor: &'a Constructor,
+    pub omit: &'a Omit,
+    pub expose: Expose,
+    pub from_bytes: bool,
+    pub unicode: bool,
+    pub arena: bool,
+    pub view_slices: bool,
+    pub vec: bool,
+    pub slice: bool,
+    pub intern: bool,
+    pub equality: Equality,
     pub impls: &'a Impls,
 }
 
@@ -20,16 +40,32 @@ impl<'a> RefCodeGen<'a> {
     fn inherent(&self) -> proc_macro2::TokenStream {
         let ty = &self.ty;
         let field_name = &self.field.name;
-        let inherent = self.check_inherent();
-
+        let inherent = super::respan_to_declaration(self.check_inherent(), &self.ident);
+        let from_bytes = self.bytes_inherent();
+        let unicode = self.unicode_inherent();
+        let arena = self.arena_inherent();
+        let slice = self.slice_inherent();
+        let vec = self.vec_inherent();
+        let str_slice = self.str_slice_inherent();
+        let vis = self.expose.str_vis();
+
+        // The constructors are spanned to the declaration, so unused ones would otherwise be
+        // reported as dead code in braids that are private to their crate
         quote! {
             #[automatically_derived]
+            #[allow(dead_code)]
             impl #ty {
                 #inherent
+                #slice
+                #vec
+                #str_slice
+                #from_bytes
+                #unicode
+                #arena
 
                 /// Provides access to the underlying value as a string slice.
                 #[inline]
-                pub const fn as_str(&self) -> &str {
+                #vis const fn as_str(&self) -> &str {
                     &self.#field_name
                 }
             }
@@ -44,7 +80,512 @@ impl<'a> RefCodeGen<'a> {
         }
     }
 
-    fn pointer_reinterpret_safety_comment(&self, is_mut: bool) -> proc_macro2::TokenStream {
+    fn arena_inherent(&self) -> Option<proc_macro2::TokenStream> {
+        if !self.arena {
+            return None;
+        }
+
+        let core = self.std_lib.core();
+        let braid = self.std_lib.braid();
+        let from_str = self.constructor.borrowed();
+        let from_str_unchecked = self.unchecked.ref_constructor();
+        let (ret, body) = match self.check_mode {
+            CheckMode::None => (
+                quote! { &'a Self },
+                quote! { Self::#from_str(#braid::Arena::alloc_str(arena, raw)) },
+            ),
+            CheckMode::Validate(validator) => {
+                let validator = crate::as_validator(validator, self.std_lib);
+                let error = super::error_type(self.error, &validator);
+                let unchecked_safety_comment = Self::unchecked_safety_comment(false);
+                let from_str_checked = self
+                    .unchecked
+                    .call(quote! { Self::#from_str_unchecked(raw) });
+                (
+                    quote! { ::#core::result::Result<&'a Self, #error> },
+                    quote! {
+                        #validator::validate(raw)?;
+                        let raw = #braid::Arena::alloc_str(arena, raw);
+                        #unchecked_safety_comment
+                        ::#core::result::Result::Ok(#from_str_checked)
+                    },
+                )
+            }
+            CheckMode::Normalize(normalizer) => {
+                let error =
+                    super::error_type(self.error, &crate::as_validator(normalizer, self.std_lib));
+                let normalizer = crate::as_normalizer(normalizer, self.std_lib);
+                let unchecked_safety_comment = Self::unchecked_safety_comment(true);
+                let from_str_checked = self
+                    .unchecked
+                    .call(quote! { Self::#from_str_unchecked(raw) });
+                (
+                    quote! { ::#core::result::Result<&'a Self, #error> },
+                    quote! {
+                        let normalized = #normalizer::normalize(raw)?;
+                        let raw = #braid::Arena::alloc_str(arena, &normalized);
+                        #unchecked_safety_comment
+                        ::#core::result::Result::Ok(#from_str_checked)
+                    },
+                )
+            }
+        };
+
+        let doc_comment = format!(
+            "Copies the string slice into an arena as a strongly-typed {ty}\n\nThe value is \
+             checked as if passed to [`{from_str}`][Self::{from_str}]{normalized} before it is \
+             copied, and the returned reference lives as long as the arena. This avoids a \
+             separate heap allocation for each value when many values are parsed at once.",
+            ty = self.ident,
+            normalized = if matches!(self.check_mode, CheckMode::Normalize(_)) {
+                ", and only the normalized form is copied"
+            } else {
+                ""
+            },
+        );
+
+        Some(quote! {
+            #[allow(unsafe_code)]
+            #[inline]
+            #[doc = #doc_comment]
+            pub fn alloc_in<'a, A: #braid::Arena + ?::#core::marker::Sized>(
+                arena: &'a A,
+                raw: &str,
+            ) -> #ret {
+                #body
+            }
+        })
+    }
+
+    fn intern_impl(&self) -> Option<proc_macro2::TokenStream> {
+        if !self.intern {
+            return None;
+        }
+
+        let ty = &self.ty;
+        let core = self.std_lib.core();
+        let alloc = self.std_lib.alloc();
+        let braid = self.std_lib.braid();
+        let from_str = self.constructor.borrowed();
+        let (error, check) = match self.check_mode {
+            CheckMode::None => (
+                quote! { ::#core::convert::Infallible },
+                quote! { Self::#from_str(raw) },
+            ),
+            CheckMode::Validate(validator) => (
+                super::error_type(self.error, &crate::as_validator(validator, self.std_lib)),
+                quote! { Self::#from_str(raw)? },
+            ),
+            CheckMode::Normalize(normalizer) => (
+                super::error_type(self.error, &crate::as_validator(normalizer, self.std_lib)),
+                quote! { &*Self::#from_str(raw)? },
+            ),
+        };
+
+        // Only checked values are interned, so a value that is already present needs no
+        // further checks
+        Some(quote! {
+            #[automatically_derived]
+            impl #braid::Intern for #ty {
+                type Error = #error;
+
+                #[inline]
+                fn intern_in(
+                    interner: &mut #braid::Interner<Self>,
+                    raw: &str,
+                ) -> ::#core::result::Result<::#alloc::sync::Arc<Self>, Self::Error> {
+                    if let ::#core::option::Option::Some(interned) = interner.get(raw) {
+                        return ::#core::result::Result::Ok(interned);
+                    }
+
+                    let value = #check;
+                    ::#core::result::Result::Ok(interner.insert(value))
+                }
+            }
+        })
+    }
+
+    fn unicode_inherent(&self) -> Option<proc_macro2::TokenStream> {
+        if !self.unicode {
+            return None;
+        }
+
+        let core = self.std_lib.core();
+        let alloc = self.std_lib.alloc();
+        let braid = self.std_lib.braid();
+        let from_str = self.constructor.borrowed();
+        let cow = quote! { ::#alloc::borrow::Cow<'_, Self> };
+        let (ret, unchanged, truncated) = match self.check_mode {
+            CheckMode::None => (
+                cow,
+                quote! { ::#alloc::borrow::Cow::Borrowed(self) },
+                quote! { ::#alloc::borrow::Cow::Borrowed(Self::#from_str(raw)) },
+            ),
+            CheckMode::Validate(validator) => {
+                let error =
+                    super::error_type(self.error, &crate::as_validator(validator, self.std_lib));
+                (
+                    quote! { ::#core::result::Result<#cow, #error> },
+                    quote! { ::#core::result::Result::Ok(::#alloc::borrow::Cow::Borrowed(self)) },
+                    quote! { Self::#from_str(raw).map(::#alloc::borrow::Cow::Borrowed) },
+                )
+            }
+            CheckMode::Normalize(normalizer) => {
+                let error =
+                    super::error_type(self.error, &crate::as_validator(normalizer, self.std_lib));
+                (
+                    quote! { ::#core::result::Result<#cow, #error> },
+                    quote! { ::#core::result::Result::Ok(::#alloc::borrow::Cow::Borrowed(self)) },
+                    quote! { Self::#from_str(raw) },
+                )
+            }
+        };
+
+        let truncate_doc = format!(
+            "Shortens the value to at most `n` extended grapheme clusters\n\nThe value is only \
+             ever cut on a grapheme boundary, so user-perceived characters are never split. A \
+             value that is already short enough is returned as is.{checked}",
+            checked = match self.check_mode {
+                CheckMode::None => "",
+                CheckMode::Validate(_) => {
+                    "\n\n# Errors\n\nReturns an error if the truncated value is no longer valid."
+                }
+                CheckMode::Normalize(_) => {
+                    "\n\n# Errors\n\nReturns an error if the truncated value is no longer valid. \
+                     The truncated value is normalized again, and so may be owned."
+                }
+            },
+        );
+
+        Some(quote! {
+            /// Counts the extended grapheme clusters, or user-perceived characters, in the value
+            #[inline]
+            pub fn grapheme_len(&self) -> usize {
+                #braid::__private::grapheme_len(self.as_str())
+            }
+
+            #[inline]
+            #[doc = #truncate_doc]
+            pub fn truncate_graphemes(&self, n: usize) -> #ret {
+                let raw = #braid::__private::grapheme_prefix(self.as_str(), n);
+                if raw.len() == self.as_str().len() {
+                    return #unchanged;
+                }
+
+                #truncated
+            }
+        })
+    }
+
+    /// Reinterprets vectors of string slices, which is only possible without checks
+    fn vec_inherent(&self) -> Option<proc_macro2::TokenStream> {
+        if !self.vec
+            || !matches!(self.check_mode, CheckMode::None)
+            || (self.owned_ty.is_none() && !self.alloc)
+        {
+            return None;
+        }
+
+        let core = self.std_lib.core();
+        let alloc = self.std_lib.alloc();
+        let doc_comment = format!(
+            "Borrows each of the provided string slices as a `{ty}`\n\nThe allocation of the \
+             vector is reused, without copying the references.",
+            ty = self.ident,
+        );
+        let safety_doc = format!(
+            "SAFETY: `{ty}` is `#[repr(transparent)]` around a single `str` field, so a `&str` \
+             has the same layout as a `&{ty}`, and the allocation of a `Vec<&str>` can be safely \
+             reinterpreted as that of a `Vec<&{ty}>`",
+            ty = self.ident,
+        );
+
+        self.omit.retain(
+            "from_str_vec",
+            quote! {
+                #[doc = #doc_comment]
+                #[allow(unsafe_code)]
+                #[inline]
+                pub fn from_str_vec<'a>(values: ::#alloc::vec::Vec<&'a str>) -> ::#alloc::vec::Vec<&'a Self> {
+                    let mut values = ::#core::mem::ManuallyDrop::new(values);
+                    #[doc = #safety_doc]
+                    fn vec_safety_comment() {}
+                    unsafe {
+                        ::#alloc::vec::Vec::from_raw_parts(
+                            values.as_mut_ptr() as *mut &'a Self,
+                            values.len(),
+                            values.capacity(),
+                        )
+                    }
+                }
+            },
+        )
+    }
+
+    /// Views slices of string slices as slices of the braid, which is only possible without
+    /// checks
+    fn str_slice_inherent(&self) -> Option<proc_macro2::TokenStream> {
+        if !self.view_slices || !matches!(self.check_mode, CheckMode::None) {
+            return None;
+        }
+
+        let doc_comment = format!(
+            "Views each of the provided string slices as a `{ty}`, without copying",
+            ty = self.ident,
+        );
+        let safety_doc = format!(
+            "SAFETY: `{ty}` is `#[repr(transparent)]` around a single `str` field, so a `&str` \
+             has the same layout as a `&{ty}`, and a `*const [&str]` can be safely reinterpreted \
+             as a `*const [&{ty}]`",
+            ty = self.ident,
+        );
+
+        self.omit.retain(
+            "from_str_slice",
+            quote! {
+                #[doc = #doc_comment]
+                #[allow(unsafe_code)]
+                #[inline]
+                pub fn from_str_slice<'a, 'b>(values: &'a [&'b str]) -> &'a [&'b Self] {
+                    #[doc = #safety_doc]
+                    fn slice_safety_comment() {}
+                    unsafe { &*(values as *const [&'b str] as *const [&'b Self]) }
+                }
+            },
+        )
+    }
+
+    fn slice_inherent(&self) -> Option<proc_macro2::TokenStream> {
+        if !self.slice {
+            return None;
+        }
+
+        let core = self.std_lib.core();
+        let from_str = self.constructor.borrowed();
+        // Normalized values are only borrowed when the sub-slice is already in normalized form
+        let checked_from_str = match self.check_mode {
+            CheckMode::None => None,
+            CheckMode::Validate(validator) => Some((
+                super::error_type(self.error, &crate::as_validator(validator, self.std_lib)),
+                from_str.clone(),
+            )),
+            CheckMode::Normalize(normalizer) => Some((
+                super::error_type(self.error, &crate::as_validator(normalizer, self.std_lib)),
+                syn::Ident::new("from_normalized_str", proc_macro2::Span::call_site()),
+            )),
+        };
+        let (ret, split_ret, check, split_check) = match checked_from_str {
+            None => (
+                quote! { &Self },
+                quote! { (&Self, &Self) },
+                quote! { Self::#from_str(raw) },
+                quote! { (Self::#from_str(head), Self::#from_str(tail)) },
+            ),
+            Some((error, from_str)) => (
+                quote! { ::#core::result::Result<&Self, #error> },
+                quote! { ::#core::result::Result<(&Self, &Self), #error> },
+                quote! { Self::#from_str(raw) },
+                quote! {
+                    ::#core::result::Result::Ok((Self::#from_str(head)?, Self::#from_str(tail)?))
+                },
+            ),
+        };
+
+        let checked = match self.check_mode {
+            CheckMode::None => "",
+            CheckMode::Validate(_) => {
+                "\n\n# Errors\n\nReturns an error if the sub-slice is not itself a valid value."
+            }
+            CheckMode::Normalize(_) => {
+                "\n\n# Errors\n\nReturns an error if the sub-slice is not itself a valid value in \
+                 normalized form."
+            }
+        };
+        let slice_doc = format!(
+            "Borrows a sub-slice of the value as a strongly-typed {ty}\n\nThe range is given in \
+             bytes, as when indexing a `str`.{checked}\n\n# Panics\n\nPanics if the range is out \
+             of bounds or does not fall on `char` boundaries.",
+            ty = self.ident,
+        );
+        let split_at_doc = format!(
+            "Divides the value into two strongly-typed {ty} values at a byte index\n\nThe first \
+             value contains the bytes before `mid`, and the second the remainder, as with \
+             [`str::split_at`].{checked}\n\n# Panics\n\nPanics if `mid` is out of bounds or not \
+             on a `char` boundary.",
+            ty = self.ident,
+            checked = checked.replace("the sub-slice is", "either half is"),
+        );
+
+        let slice = self.omit.retain(
+            "slice",
+            quote! {
+                #[inline]
+                #[doc = #slice_doc]
+                #[track_caller]
+                pub fn slice<R>(&self, range: R) -> #ret
+                where
+                    R: ::#core::slice::SliceIndex<str, Output = str>,
+                {
+                    let raw = &self.as_str()[range];
+                    #check
+                }
+            },
+        );
+        let split_at = self.omit.retain(
+            "split_at",
+            quote! {
+                #[inline]
+                #[doc = #split_at_doc]
+                #[track_caller]
+                pub fn split_at(&self, mid: usize) -> #split_ret {
+                    let (head, tail) = self.as_str().split_at(mid);
+                    #split_check
+                }
+            },
+        );
+
+        Some(quote! {
+            #slice
+            #split_at
+        })
+    }
+
+    fn bytes_inherent(&self) -> Option<proc_macro2::TokenStream> {
+        if !self.from_bytes {
+            return None;
+        }
+
+        let core = self.std_lib.core();
+        let braid = self.std_lib.braid();
+        let from_str = self.constructor.borrowed();
+        let (error, check) = match self.check_mode {
+            CheckMode::None => (
+                quote! { ::#core::convert::Infallible },
+                quote! { ::#core::result::Result::Ok(Self::#from_str(raw)) },
+            ),
+            CheckMode::Validate(validator) => (
+                super::error_type(self.error, &crate::as_validator(validator, self.std_lib)),
+                quote! { Self::#from_str(raw) },
+            ),
+            CheckMode::Normalize(normalizer) => (
+                super::error_type(self.error, &crate::as_validator(normalizer, self.std_lib)),
+                quote! { Self::from_normalized_str(raw) },
+            ),
+        };
+
+        let doc_comment = format!(
+            "Reinterprets a byte slice in place as a strongly-typed {ty}, without copying\n\nThe \
+             bytes are first checked to be valid UTF-8 and then checked as if passed to \
+             [`{from_str}`][Self::{from_str}]{normalized}. This makes it suitable for values \
+             borrowed from memory-mapped files or other untrusted byte buffers.\n\nNo additional \
+             unsafe code is involved: the returned reference borrows from `raw`, and the \
+             reinterpretation is only performed once both checks have passed, at which point \
+             `raw` is known to uphold every invariant of `{ty}`.",
+            ty = self.ident,
+            normalized = if matches!(self.check_mode, CheckMode::Normalize(_)) {
+                ", requiring that the value already be normalized"
+            } else {
+                ""
+            },
+        );
+
+        Some(quote! {
+            #[inline]
+            #[doc = #doc_comment]
+            pub fn from_bytes_validated(
+                raw: &[u8],
+            ) -> ::#core::result::Result<&Self, #braid::FromBytesError<#error>> {
+                let raw = ::#core::str::from_utf8(raw).map_err(#braid::FromBytesError::Utf8)?;
+                #check.map_err(#braid::FromBytesError::Invalid)
+            }
+        })
+    }
+
+    /// A deprecated alias forwarding the former name of the checked constructor to its current
+    /// name, if the naming generation renamed it
+    fn constructor_alias(
+        &self,
+        constness: Option<syn::Token![const]>,
+        ret: proc_macro2::TokenStream,
+    ) -> Option<proc_macro2::TokenStream> {
+        let alias = self.constructor.deprecated_borrowed()?;
+        let from_str = self.constructor.borrowed();
+        let doc = format!("Deprecated alias of [`{from_str}`][Self::{from_str}]");
+        let note = format!("renamed to `{from_str}`");
+
+        Some(quote! {
+            #[doc = #doc]
+            #[deprecated(note = #note)]
+            #[allow(clippy::should_implement_trait)]
+            #[inline]
+            pub #constness fn #alias(raw: &str) -> #ret {
+                Self::#from_str(raw)
+            }
+        })
+    }
+
+    /// The owned type, if `into_owned()` can convert a boxed value into it
+    ///
+    /// The conversion requires the owned type's field to implement `From<Box<str>>`, which is
+    /// not assumed when `box_conversions` are omitted or when the owned type is only reachable
+    /// through a `to_owned` conversion function.
+    fn boxed_owned_ty(&self) -> Option<&syn::Type> {
+        self.owned_ty
+            .as_ref()
+            .filter(|_| self.omit.find("box_conversions").is_none() && self.to_owned_fn.is_none())
+    }
+
+    /// Whether the owned type is a foreign type reached through conversion functions, whose
+    /// contents cannot be trusted to have been checked
+    fn is_foreign_owned(&self) -> bool {
+        self.to_owned_fn.is_some() || self.as_str_fn.is_some()
+    }
+
+    /// Borrows the string slice from a reference to the owned type
+    fn owned_as_str(&self, value: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        match self.as_str_fn {
+            Some(as_str) => quote! { #as_str(#value) },
+            None => quote! { #value.as_str() },
+        }
+    }
+
+    /// Constructs the owned type from a value of its field that is known to conform
+    ///
+    /// An owned type that records its provenance is constructed directly, so that values derived
+    /// from checked ones are not attributed to its unchecked constructor.
+    fn owned_from_checked(
+        &self,
+        owned_ty: &syn::Type,
+        value: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        match self.owned_field {
+            Some(owned_field) if owned_field.provenance.is_some() => {
+                owned_field.constructor(owned_ty, value)
+            }
+            _ => {
+                let new_unchecked = self.unchecked.owned_constructor();
+                self.unchecked
+                    .call(quote! { #owned_ty::#new_unchecked(#value) })
+            }
+        }
+    }
+
+    /// Converts a `&str` or `Box<str>` into the field of the owned type, which is not known for
+    /// existing owned types
+    fn owned_field_from(&self, value: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        match self.owned_field {
+            Some(owned_field) => owned_field.convert_str(value, self.std_lib),
+            None => {
+                let core = self.std_lib.core();
+                quote! { ::#core::convert::From::from(#value) }
+            }
+        }
+    }
+
+    pub(super) fn pointer_reinterpret_safety_comment(
+        &self,
+        is_mut: bool,
+    ) -> proc_macro2::TokenStream {
         let doc = format!(
             "SAFETY: `{ty}` is `#[repr(transparent)]` around a single `str` field, so a `*{ptr} \
              str` can be safely reinterpreted as a `*{ptr} {ty}`",
@@ -77,8 +618,9 @@ impl<'a> RefCodeGen<'a> {
 
     fn infallible_inherent(&self) -> proc_macro2::TokenStream {
         let ty = &self.ty;
-        let core = self.std_lib.core();
         let alloc = self.std_lib.alloc();
+        let new = self.constructor.owned();
+        let from_str = self.constructor.borrowed();
 
         let doc_comment = format!(
             "Transparently reinterprets the string slice as a strongly-typed {}",
@@ -91,34 +633,51 @@ impl<'a> RefCodeGen<'a> {
         );
 
         let pointer_reinterpret_safety_comment = self.pointer_reinterpret_safety_comment(false);
+        let constructor_alias = self.constructor_alias(Some(Default::default()), quote! { &Self });
+        let from_static = self.omit.retain(
+            "from_static",
+            quote! {
+                #[inline]
+                #[doc = #static_doc_comment]
+                #[track_caller]
+                pub const fn from_static(raw: &'static str) -> &'static Self {
+                    Self::#from_str(raw)
+                }
+            },
+        );
 
-        let into_owned = self.owned_ty.map(|owned_ty| {
+        let into_owned = self.boxed_owned_ty().and_then(|owned_ty| {
             let into_owned_doc = format!(
                 "Converts a [`Box<{}>`] into a [`{}`] without copying or allocating",
-                self.ident, owned_ty,
+                self.ident,
+                owned_ty.to_token_stream(),
             );
 
             let box_pointer_reinterpret_safety_comment =
                 self.pointer_reinterpret_safety_comment(true);
+            let from_boxed = self.owned_field_from(quote! { boxed });
 
-            quote! {
-                #[allow(unsafe_code)]
-                #[inline]
-                #[doc = #into_owned_doc]
-                pub fn into_owned(self: ::#alloc::boxed::Box<#ty>) -> #owned_ty {
-                    #box_pointer_reinterpret_safety_comment
-                    let raw = ::#alloc::boxed::Box::into_raw(self);
-                    let boxed = unsafe { ::#alloc::boxed::Box::from_raw(raw as *mut str) };
-                    #owned_ty::new(::#core::convert::From::from(boxed))
-                }
-            }
+            self.omit.retain(
+                "into_owned",
+                quote! {
+                    #[allow(unsafe_code)]
+                    #[inline]
+                    #[doc = #into_owned_doc]
+                    pub fn into_owned(self: ::#alloc::boxed::Box<#ty>) -> #owned_ty {
+                        #box_pointer_reinterpret_safety_comment
+                        let raw = ::#alloc::boxed::Box::into_raw(self);
+                        let boxed = unsafe { ::#alloc::boxed::Box::from_raw(raw as *mut str) };
+                        #owned_ty::#new(#from_boxed)
+                    }
+                },
+            )
         });
 
         quote! {
             #[allow(unsafe_code)]
             #[inline]
             #[doc = #doc_comment]
-            pub const fn from_str(raw: &str) -> &Self {
+            pub const fn #from_str(raw: &str) -> &Self {
                 let ptr: *const str = raw;
                 #pointer_reinterpret_safety_comment
                 unsafe {
@@ -126,13 +685,8 @@ impl<'a> RefCodeGen<'a> {
                 }
             }
 
-            #[inline]
-            #[doc = #static_doc_comment]
-            #[track_caller]
-            pub const fn from_static(raw: &'static str) -> &'static Self {
-                Self::from_str(raw)
-            }
-
+            #constructor_alias
+            #from_static
             #into_owned
         }
     }
@@ -152,9 +706,46 @@ impl<'a> RefCodeGen<'a> {
             validator.to_token_stream(),
         );
 
+        let try_static_doc_comment = format!(
+            "Transparently reinterprets the static string slice as a strongly-typed {} if it \
+             conforms to [`{}`], returning an error instead of panicking if it does not",
+            self.ident,
+            validator.to_token_stream(),
+        );
+
         let doc_comment_unsafe = format!(
-            "Transparently reinterprets the string slice as a strongly-typed {} without validating",
+            "Transparently reinterprets the string slice as a strongly-typed {} without \
+             validating\n\n# Safety\n\nConsumers of this function must ensure that values conform \
+             to [`{}`]. Failure to maintain this invariant may lead to undefined behavior.\n\nTo \
+             check this invariant in debug builds, use [`{}::{}`] instead.",
             self.ident,
+            validator.to_token_stream(),
+            self.ident,
+            self.unchecked.ref_checked_constructor(),
+        );
+        let doc_comment_checked = format!(
+            "Transparently reinterprets the string slice as a strongly-typed {} without \
+             validating in release builds\n\n# Safety\n\nConsumers of this function must ensure \
+             that values conform to [`{}`]. Failure to maintain this invariant may lead to \
+             undefined behavior.\n\nUnlike [`{}::{}`], this function is not `const`, and checks \
+             this invariant with a debug assertion in debug builds.",
+            self.ident,
+            validator.to_token_stream(),
+            self.ident,
+            self.unchecked.ref_constructor(),
+        );
+        let from_str = self.constructor.borrowed();
+        let from_str_unchecked = self.unchecked.ref_constructor();
+        let unchecked_vis = self.unchecked.vis(true);
+        let unchecked_attrs = self.unchecked.attrs();
+        let unsafety = self.unchecked.unsafety();
+        let from_str_checked = self
+            .unchecked
+            .call(quote! { Self::#from_str_unchecked(raw) });
+        let from_str_unchecked_checked = self.unchecked.ref_checked_constructor();
+        let debug_assert_msg = format!(
+            "invalid value passed to {}::{}",
+            self.ident, from_str_unchecked_checked
         );
 
         let ty = &self.ty;
@@ -162,61 +753,99 @@ impl<'a> RefCodeGen<'a> {
         let alloc = self.std_lib.alloc();
         let unchecked_safety_comment = Self::unchecked_safety_comment(false);
         let pointer_reinterpret_safety_comment = self.pointer_reinterpret_safety_comment(false);
-        let into_owned = self.owned_ty.map(|owned_ty| {
+        let into_owned = self.boxed_owned_ty().and_then(|owned_ty| {
             let into_owned_doc = format!(
                 "Converts a [`Box<{}>`] into a [`{}`] without copying or allocating",
-                self.ident, owned_ty,
+                self.ident,
+                owned_ty.to_token_stream(),
             );
 
             let box_pointer_reinterpret_safety_comment =
                 self.pointer_reinterpret_safety_comment(true);
+            let from_boxed = self.owned_field_from(quote! { boxed });
+            let into_owned_unchecked = self.owned_from_checked(owned_ty, quote! { s });
+
+            self.omit.retain(
+                "into_owned",
+                quote! {
+                    #[allow(unsafe_code)]
+                    #[inline]
+                    #[doc = #into_owned_doc]
+                    pub fn into_owned(self: ::#alloc::boxed::Box<#ty>) -> #owned_ty {
+                        #box_pointer_reinterpret_safety_comment
+                        let raw = ::#alloc::boxed::Box::into_raw(self);
+                        let boxed = unsafe { ::#alloc::boxed::Box::from_raw(raw as *mut str) };
+                        let s = #from_boxed;
+                        #unchecked_safety_comment
+                        #into_owned_unchecked
+                    }
+                },
+            )
+        });
 
+        let validator = crate::as_validator(validator, self.std_lib);
+        let error = super::error_type(self.error, &validator);
+        let constructor_alias =
+            self.constructor_alias(None, quote! { ::#core::result::Result<&Self, #error> });
+        let from_static = self.omit.retain(
+            "from_static",
             quote! {
-                #[allow(unsafe_code)]
                 #[inline]
-                #[doc = #into_owned_doc]
-                pub fn into_owned(self: ::#alloc::boxed::Box<#ty>) -> #owned_ty {
-                    #box_pointer_reinterpret_safety_comment
-                    let raw = ::#alloc::boxed::Box::into_raw(self);
-                    let boxed = unsafe { ::#alloc::boxed::Box::from_raw(raw as *mut str) };
-                    let s = ::#core::convert::From::from(boxed);
-                    #unchecked_safety_comment
-                    unsafe { #owned_ty::new_unchecked(s) }
+                #[doc = #static_doc_comment]
+                #[doc = ""]
+                #[doc = "# Panics"]
+                #[doc = ""]
+                #[doc = "This function will panic if the provided raw string is not valid."]
+                #[track_caller]
+                pub fn from_static(raw: &'static str) -> &'static Self {
+                    Self::#from_str(raw).expect(::#core::concat!("invalid ", ::#core::stringify!(#ty)))
                 }
-            }
-        });
-
-        let validator = crate::as_validator(validator);
+            },
+        );
+        let try_from_static = self.omit.retain(
+            "try_from_static",
+            quote! {
+                #[inline]
+                #[doc = #try_static_doc_comment]
+                pub fn try_from_static(raw: &'static str) -> ::#core::result::Result<&'static Self, #error> {
+                    Self::#from_str(raw)
+                }
+            },
+        );
 
         quote! {
-            #[allow(unsafe_code)]
+            #[allow(unsafe_code, clippy::should_implement_trait)]
             #[inline]
             #[doc = #doc_comment]
-            pub fn from_str(raw: &str) -> ::#core::result::Result<&Self, #validator::Error> {
+            pub fn #from_str(raw: &str) -> ::#core::result::Result<&Self, #error> {
                 #validator::validate(raw)?;
                 #unchecked_safety_comment
-                ::#core::result::Result::Ok(unsafe { Self::from_str_unchecked(raw) })
+                ::#core::result::Result::Ok(#from_str_checked)
             }
 
+            #constructor_alias
+
             #[allow(unsafe_code)]
             #[inline]
             #[doc = #doc_comment_unsafe]
-            pub const unsafe fn from_str_unchecked(raw: &str) -> &Self {
+            #unchecked_attrs
+            #unchecked_vis const #unsafety fn #from_str_unchecked(raw: &str) -> &Self {
                 #pointer_reinterpret_safety_comment
-                &*(raw as *const str as *const Self)
+                unsafe { &*(raw as *const str as *const Self) }
             }
 
+            #[allow(unsafe_code)]
             #[inline]
-            #[doc = #static_doc_comment]
-            #[doc = ""]
-            #[doc = "# Panics"]
-            #[doc = ""]
-            #[doc = "This function will panic if the provided raw string is not valid."]
+            #[doc = #doc_comment_checked]
+            #unchecked_attrs
             #[track_caller]
-            pub fn from_static(raw: &'static str) -> &'static Self {
-                Self::from_str(raw).expect(concat!("invalid ", stringify!(#ty)))
+            #unchecked_vis #unsafety fn #from_str_unchecked_checked(raw: &str) -> &Self {
+                ::#core::debug_assert!(#validator::validate(raw).is_ok(), #debug_assert_msg);
+                #from_str_checked
             }
 
+            #from_static
+            #try_from_static
             #into_owned
         }
     }
@@ -236,6 +865,14 @@ impl<'a> RefCodeGen<'a> {
             normalizer.to_token_stream(),
         );
 
+        let try_static_doc_comment = format!(
+            "Transparently reinterprets a static string slice as a strongly-typed {} if it \
+             conforms to [`{}`] and is already normalized, returning an error instead of \
+             panicking if it does not",
+            self.ident,
+            normalizer.to_token_stream(),
+        );
+
         let doc_comment_norm = format!(
             "Transparently reinterprets the string slice as a strongly-typed `{}` if it conforms \
              to [`{}`], producing an error if normalization is necessary",
@@ -247,9 +884,37 @@ impl<'a> RefCodeGen<'a> {
             "Transparently reinterprets the string slice as a strongly-typed `{}` without \
              validating\n\n# Safety\n\nCalls to this function must ensure that the value being \
              passed conforms to [`{}`] and is already in normalized form. Failure to do this may \
-             result in undefined behavior if other code relies on this invariant.",
+             result in undefined behavior if other code relies on this invariant.\n\nTo check \
+             this invariant in debug builds, use [`{}::{}`] instead.",
+            self.ident,
+            normalizer.to_token_stream(),
+            self.ident,
+            self.unchecked.ref_checked_constructor(),
+        );
+        let doc_comment_checked = format!(
+            "Transparently reinterprets the string slice as a strongly-typed `{}` without \
+             validating in release builds\n\n# Safety\n\nCalls to this function must ensure that \
+             the value being passed conforms to [`{}`] and is already in normalized form. Failure \
+             to do this may result in undefined behavior if other code relies on this \
+             invariant.\n\nUnlike [`{}::{}`], this function is not `const`, and checks this \
+             invariant with a debug assertion in debug builds.",
             self.ident,
             normalizer.to_token_stream(),
+            self.ident,
+            self.unchecked.ref_constructor(),
+        );
+        let from_str = self.constructor.borrowed();
+        let from_str_unchecked = self.unchecked.ref_constructor();
+        let unchecked_vis = self.unchecked.vis(true);
+        let unchecked_attrs = self.unchecked.attrs();
+        let unsafety = self.unchecked.unsafety();
+        let from_str_checked = self
+            .unchecked
+            .call(quote! { Self::#from_str_unchecked(raw) });
+        let from_str_unchecked_checked = self.unchecked.ref_checked_constructor();
+        let debug_assert_msg = format!(
+            "invalid or non-normalized value passed to {}::{}",
+            self.ident, from_str_unchecked_checked
         );
 
         let doc_comment_cow_unsafe = format!(
@@ -268,102 +933,249 @@ impl<'a> RefCodeGen<'a> {
         let unchecked_safety_comment = Self::unchecked_safety_comment(true);
         let pointer_reinterpret_safety_comment = self.pointer_reinterpret_safety_comment(false);
 
-        let validator = crate::as_validator(normalizer);
-        let normalizer = crate::as_normalizer(normalizer);
+        let validator = crate::as_validator(normalizer, self.std_lib);
+        let error = super::error_type(self.error, &validator);
+        let normalizer = crate::as_normalizer(normalizer, self.std_lib);
+
+        let from_static = self.omit.retain(
+            "from_static",
+            quote! {
+                #[inline]
+                #[doc = #static_doc_comment]
+                #[doc = ""]
+                #[doc = "# Panics"]
+                #[doc = ""]
+                #[doc = "This function will panic if the provided raw string is not normalized."]
+                #[track_caller]
+                pub fn from_static(raw: &'static str) -> &'static Self {
+                    Self::from_normalized_str(raw).expect(::#core::concat!("non-normalized ", ::#core::stringify!(#ty)))
+                }
+            },
+        );
+        let try_from_static = self.omit.retain(
+            "try_from_static",
+            quote! {
+                #[inline]
+                #[doc = #try_static_doc_comment]
+                pub fn try_from_static(raw: &'static str) -> ::#core::result::Result<&'static Self, #error> {
+                    Self::from_normalized_str(raw)
+                }
+            },
+        );
 
-        let into_owned = self.owned_ty.map(|owned_ty| {
+        let into_owned = self.owned_ty.as_ref().map(|owned_ty| {
             let into_owned_doc = format!(
                 "Converts a [`Box<{}>`] into a [`{}`] without copying or allocating",
                 self.ident,
-                owned_ty,
+                owned_ty.to_token_stream(),
             );
 
             let box_pointer_reinterpret_safety_comment = self.pointer_reinterpret_safety_comment(true);
+            let from_boxed = self.owned_field_from(quote! { boxed });
+            let into_owned_unchecked = self.owned_from_checked(owned_ty, quote! { s });
+            let constructor_alias = self.constructor_alias(
+                None,
+                quote! { ::#core::result::Result<::#alloc::borrow::Cow<'_, Self>, #error> },
+            );
+            let normalized_fn = self.omit.retain(
+                "normalized",
+                quote! {
+                    /// Normalizes a value that may have been created without normalization, such
+                    /// as through an unchecked constructor, borrowing it if no changes are needed
+                    ///
+                    /// Values that cannot be normalized are borrowed unchanged.
+                    #[inline]
+                    pub fn normalized(&self) -> ::#alloc::borrow::Cow<'_, Self> {
+                        ::#core::result::Result::unwrap_or(
+                            Self::#from_str(self.as_str()),
+                            ::#alloc::borrow::Cow::Borrowed(self),
+                        )
+                    }
+                },
+            );
+            let owned_from_normalized = match self.to_owned_fn {
+                Some(to_owned) => quote! { #to_owned(Self::#from_str_unchecked(&normalized)) },
+                None => self.owned_from_checked(
+                    owned_ty,
+                    quote! { ::#core::convert::From::from(normalized) },
+                ),
+            };
+            let into_owned_fn = self.boxed_owned_ty().and_then(|_| self.omit.retain(
+                "into_owned",
+                quote! {
+                    #[allow(unsafe_code)]
+                    #[inline]
+                    #[doc = #into_owned_doc]
+                    pub fn into_owned(self: ::#alloc::boxed::Box<#ty>) -> #owned_ty {
+                        #box_pointer_reinterpret_safety_comment
+                        let raw = ::#alloc::boxed::Box::into_raw(self);
+                        let boxed = unsafe { ::#alloc::boxed::Box::from_raw(raw as *mut str) };
+                        let s = #from_boxed;
+                        #unchecked_safety_comment
+                        #into_owned_unchecked
+                    }
+                },
+            ));
 
             quote! {
-                #[allow(unsafe_code)]
+                #[allow(unsafe_code, clippy::should_implement_trait)]
                 #[inline]
                 #[doc = #doc_comment]
-                pub fn from_str(raw: &str) -> ::#core::result::Result<::#alloc::borrow::Cow<Self>, #validator::Error> {
+                pub fn #from_str(raw: &str) -> ::#core::result::Result<::#alloc::borrow::Cow<'_, Self>, #error> {
                     let cow = #normalizer::normalize(raw)?;
                     #unchecked_safety_comment
                     ::#core::result::Result::Ok(unsafe { Self::from_cow_str_unchecked(cow) })
                 }
 
+                #constructor_alias
+
                 #[allow(unsafe_code)]
                 #[inline]
                 #[doc = #doc_comment_cow_unsafe]
-                unsafe fn from_cow_str_unchecked(cow: ::#alloc::borrow::Cow<str>) -> ::#alloc::borrow::Cow<Self> {
+                unsafe fn from_cow_str_unchecked(cow: ::#alloc::borrow::Cow<'_, str>) -> ::#alloc::borrow::Cow<'_, Self> {
                     match cow {
                         ::#alloc::borrow::Cow::Borrowed(raw) => {
-                            let value = Self::from_str_unchecked(raw);
+                            let value = Self::#from_str_unchecked(raw);
                             ::#alloc::borrow::Cow::Borrowed(value)
                         }
                         ::#alloc::borrow::Cow::Owned(normalized) => {
-                            let value = #owned_ty::new_unchecked(::#core::convert::From::from(normalized));
+                            let value = #owned_from_normalized;
                             ::#alloc::borrow::Cow::Owned(value)
                         }
                     }
                 }
 
-                #[allow(unsafe_code)]
-                #[inline]
-                #[doc = #into_owned_doc]
-                pub fn into_owned(self: ::#alloc::boxed::Box<#ty>) -> #owned_ty {
-                    #box_pointer_reinterpret_safety_comment
-                    let raw = ::#alloc::boxed::Box::into_raw(self);
-                    let boxed = unsafe { ::#alloc::boxed::Box::from_raw(raw as *mut str) };
-                    let s = ::#core::convert::From::from(boxed);
-                    #unchecked_safety_comment
-                    unsafe { #owned_ty::new_unchecked(s) }
-                }
+                #into_owned_fn
+                #normalized_fn
             }
         });
 
+        let is_normalized = self.omit.retain(
+            "is_normalized",
+            quote! {
+                /// Determines whether a string slice conforms and is already in normalized form,
+                /// such that normalizing it would borrow it unchanged
+                #[inline]
+                pub fn is_normalized(raw: &str) -> bool {
+                    ::#core::matches!(
+                        #normalizer::normalize(raw),
+                        ::#core::result::Result::Ok(::#alloc::borrow::Cow::Borrowed(_))
+                    )
+                }
+            },
+        );
+
         quote! {
+            #is_normalized
+
             #[allow(unsafe_code)]
             #[inline]
             #[doc = #doc_comment_norm]
-            pub fn from_normalized_str(raw: &str) -> ::#core::result::Result<&Self, #validator::Error> {
+            pub fn from_normalized_str(raw: &str) -> ::#core::result::Result<&Self, #error> {
                 #validator::validate(raw)?;
                 #unchecked_safety_comment
-                ::#core::result::Result::Ok(unsafe { Self::from_str_unchecked(raw) })
+                ::#core::result::Result::Ok(#from_str_checked)
             }
 
             #[allow(unsafe_code)]
             #[inline]
             #[doc = #doc_comment_unsafe]
-            pub const unsafe fn from_str_unchecked(raw: &str) -> &Self {
+            #unchecked_attrs
+            #unchecked_vis const #unsafety fn #from_str_unchecked(raw: &str) -> &Self {
                 #pointer_reinterpret_safety_comment
-                &*(raw as *const str as *const Self)
+                unsafe { &*(raw as *const str as *const Self) }
             }
 
+            #[allow(unsafe_code)]
             #[inline]
-            #[doc = #static_doc_comment]
-            #[doc = ""]
-            #[doc = "# Panics"]
-            #[doc = ""]
-            #[doc = "This function will panic if the provided raw string is not normalized."]
+            #[doc = #doc_comment_checked]
+            #unchecked_attrs
             #[track_caller]
-            pub fn from_static(raw: &'static str) -> &'static Self {
-                Self::from_normalized_str(raw).expect(concat!("non-normalized ", stringify!(#ty)))
+            #unchecked_vis #unsafety fn #from_str_unchecked_checked(raw: &str) -> &Self {
+                ::#core::debug_assert!(#validator::validate(raw).is_ok(), #debug_assert_msg);
+                #from_str_checked
             }
 
+            #from_static
+            #try_from_static
             #into_owned
         }
     }
 
     fn comparison(&self) -> Option<proc_macro2::TokenStream> {
-        self.owned_ty.map(|owned_ty| {
+        self.owned_ty.as_ref().map(|owned_ty| {
             let ty = &self.ty;
             let core = self.std_lib.core();
             let alloc = self.std_lib.alloc();
-
-            let create = match &self.field.name {
-                FieldName::Unnamed => quote! { #owned_ty(self.0.into()) },
-                FieldName::Named(field_name) => {
-                    quote! { #owned_ty { #field_name: self.#field_name.into() } }
-                }
+            let braid = self.std_lib.braid();
+
+            let forms = [
+                quote! { #owned_ty },
+                quote! { #ty },
+                quote! { &'_ #ty },
+                quote! { ::#alloc::boxed::Box<#ty> },
+                quote! { ::#alloc::borrow::Cow<'_, #ty> },
+            ];
+
+            // An existing owned type may be defined in another crate, in which case comparisons
+            // between it and `Cow` would not satisfy the orphan rules
+            let as_str = |i: usize, value: proc_macro2::TokenStream| match i {
+                0 => self.owned_as_str(value),
+                _ => quote! { #value.as_str() },
+            };
+            let is_local = |i: usize, j: usize| {
+                self.owned_field.is_some() || !matches!((i, j), (0, 4) | (4, 0))
+            };
+            // Omitted equality leaves comparisons between the forms to the consumer as well
+            let compared: &[_] = if self.equality.is_omitted() {
+                &[]
+            } else {
+                &forms
+            };
+            let equality = compared.iter().enumerate().flat_map(|(i, lhs)| {
+                compared
+                    .iter()
+                    .enumerate()
+                    .filter(move |&(j, _)| i != j && is_local(i, j))
+                    .map(move |(j, rhs)| {
+                        // Every form dereferences to the borrowed type except an existing owned
+                        // type, which is only known to provide its string slice
+                        let eq = if self.owned_field.is_none() && (i == 0 || j == 0) {
+                            self.equality.eq(
+                                as_str(i, quote! { self }),
+                                as_str(j, quote! { other }),
+                                self.std_lib,
+                            )
+                        } else {
+                            quote! { #braid::__private::eq::<#ty>(self, other) }
+                        };
+                        quote! {
+                            #[automatically_derived]
+                            impl ::#core::cmp::PartialEq<#rhs> for #lhs {
+                                #[inline]
+                                fn eq(&self, other: &#rhs) -> bool {
+                                    #eq
+                                }
+                            }
+                        }
+                    })
+            });
+            let equality = quote! { #(#equality)* };
+
+            let field_name = &self.field.name;
+            let allow_unsafe = self
+                .owned_field
+                .is_none()
+                .then(|| quote! { #[allow(unsafe_code)] });
+            let (create, borrow) = match self.owned_field {
+                Some(owned_field) => (
+                    owned_field.constructor(
+                        owned_ty,
+                        owned_field.convert_str(quote! { &self.#field_name }, self.std_lib),
+                    ),
+                    None,
+                ),
+                None => self.existing_owned_glue(owned_ty),
             };
 
             quote! {
@@ -371,45 +1183,87 @@ impl<'a> RefCodeGen<'a> {
                 impl ::#alloc::borrow::ToOwned for #ty {
                     type Owned = #owned_ty;
 
+                    #allow_unsafe
                     #[inline]
                     fn to_owned(&self) -> Self::Owned {
                         #create
                     }
                 }
 
-                #[automatically_derived]
-                impl ::#core::cmp::PartialEq<#ty> for #owned_ty {
-                    #[inline]
-                    fn eq(&self, other: &#ty) -> bool {
-                        self.as_str() == other.as_str()
-                    }
-                }
+                #borrow
+                #equality
+            }
+        })
+    }
 
-                #[automatically_derived]
-                impl ::#core::cmp::PartialEq<#owned_ty> for #ty {
-                    #[inline]
-                    fn eq(&self, other: &#owned_ty) -> bool {
-                        self.as_str() == other.as_str()
-                    }
-                }
+    /// Converts to and borrows from an existing owned type through its public constructors and
+    /// `as_str()`, or through the given conversion functions, as its fields are not known
+    ///
+    /// A foreign owned type makes no promises about its contents, so borrowing from it checks
+    /// the value rather than using the unchecked constructor.
+    fn existing_owned_glue(
+        &self,
+        owned_ty: &syn::Type,
+    ) -> (proc_macro2::TokenStream, Option<proc_macro2::TokenStream>) {
+        let ty = &self.ty;
+        let core = self.std_lib.core();
+        let new = self.constructor.owned();
+        let from_str = self.constructor.borrowed();
+        let value = quote! { ::#core::convert::From::from(self.as_str()) };
+        let raw = self.owned_as_str(quote! { self });
+        let invalid_msg = format!(
+            "`{}` does not hold a valid `{}`",
+            owned_ty.to_token_stream(),
+            ty.to_token_stream(),
+        );
 
-                #[automatically_derived]
-                impl ::#core::cmp::PartialEq<&'_ #ty> for #owned_ty {
-                    #[inline]
-                    fn eq(&self, other: &&#ty) -> bool {
-                        self.as_str() == other.as_str()
+        let (create, borrow) = match self.check_mode {
+            CheckMode::None => (
+                quote! { #owned_ty::#new(#value) },
+                quote! { #ty::#from_str(#raw) },
+            ),
+            CheckMode::Validate(_) | CheckMode::Normalize(_) => {
+                let new_unchecked = self.unchecked.owned_constructor();
+                let borrow = if self.is_foreign_owned() {
+                    let from_str = match self.check_mode {
+                        CheckMode::Normalize(_) => quote! { from_normalized_str },
+                        _ => from_str.to_token_stream(),
+                    };
+                    quote! {
+                        match #ty::#from_str(#raw) {
+                            ::#core::result::Result::Ok(value) => value,
+                            ::#core::result::Result::Err(_) => ::#core::panic!(#invalid_msg),
+                        }
                     }
-                }
+                } else {
+                    let from_str_unchecked = self.unchecked.ref_constructor();
+                    self.unchecked
+                        .call(quote! { #ty::#from_str_unchecked(#raw) })
+                };
+                (
+                    self.unchecked
+                        .call(quote! { #owned_ty::#new_unchecked(#value) }),
+                    borrow,
+                )
+            }
+        };
+        let create = match self.to_owned_fn {
+            Some(to_owned) => quote! { #to_owned(self) },
+            None => create,
+        };
 
-                #[automatically_derived]
-                impl ::#core::cmp::PartialEq<#owned_ty> for &'_ #ty {
-                    #[inline]
-                    fn eq(&self, other: &#owned_ty) -> bool {
-                        self.as_str() == other.as_str()
-                    }
+        let borrow = quote! {
+            #[automatically_derived]
+            impl ::#core::borrow::Borrow<#ty> for #owned_ty {
+                #[allow(unsafe_code)]
+                #[inline]
+                fn borrow(&self) -> &#ty {
+                    #borrow
                 }
             }
-        })
+        };
+
+        (create, Some(borrow))
     }
 
     fn conversion(&self) -> proc_macro2::TokenStream {
@@ -417,54 +1271,69 @@ impl<'a> RefCodeGen<'a> {
         let field_name = &self.field.name;
         let core = self.std_lib.core();
         let alloc = self.std_lib.alloc();
+        let from_str = self.constructor.borrowed();
         let pointer_reinterpret_safety_comment = self.pointer_reinterpret_safety_comment(false);
 
-        let from_str = match &self.check_mode {
-            CheckMode::None => quote! {
+        let borrow_str = (!matches!(self.check_mode, CheckMode::Normalize(_))
+            && self.equality.is_exact()
+            && self.impls.hash.matches_str())
+        .then(|| {
+            quote! {
                 #[automatically_derived]
-                impl<'a> ::#core::convert::From<&'a str> for &'a #ty {
+                impl ::#core::borrow::Borrow<str> for #ty {
                     #[inline]
-                    fn from(s: &'a str) -> &'a #ty {
-                        #ty::from_str(s)
+                    fn borrow(&self) -> &str {
+                        &self.#field_name
                     }
                 }
+            }
+        });
+        let str_impls = self.expose.str().then(|| {
+            quote! {
+                #borrow_str
 
                 #[automatically_derived]
-                impl ::#core::borrow::Borrow<str> for #ty {
+                impl ::#core::convert::AsRef<str> for #ty {
                     #[inline]
-                    fn borrow(&self) -> &str {
+                    fn as_ref(&self) -> &str {
                         &self.#field_name
                     }
                 }
+            }
+        });
+
+        let from_str_impl = match &self.check_mode {
+            CheckMode::None => quote! {
+                #[automatically_derived]
+                impl<'a> ::#core::convert::From<&'a str> for &'a #ty {
+                    #[inline]
+                    fn from(s: &'a str) -> &'a #ty {
+                        #ty::#from_str(s)
+                    }
+                }
             },
             CheckMode::Validate(validator) => {
-                let validator = crate::as_validator(validator);
+                let validator = crate::as_validator(validator, self.std_lib);
+                let error = super::error_type(self.error, &validator);
                 quote! {
                     #[automatically_derived]
                     impl<'a> ::#core::convert::TryFrom<&'a str> for &'a #ty {
-                        type Error = #validator::Error;
+                        type Error = #error;
 
                         #[inline]
                         fn try_from(s: &'a str) -> ::#core::result::Result<&'a #ty, Self::Error> {
-                            #ty::from_str(s)
-                        }
-                    }
-
-                    #[automatically_derived]
-                    impl ::#core::borrow::Borrow<str> for #ty {
-                        #[inline]
-                        fn borrow(&self) -> &str {
-                            &self.#field_name
+                            #ty::#from_str(s)
                         }
                     }
                 }
             }
             CheckMode::Normalize(normalizer) => {
-                let validator = crate::as_validator(normalizer);
+                let validator = crate::as_validator(normalizer, self.std_lib);
+                let error = super::error_type(self.error, &validator);
                 quote! {
                     #[automatically_derived]
                     impl<'a> ::#core::convert::TryFrom<&'a str> for &'a #ty {
-                        type Error = #validator::Error;
+                        type Error = #error;
 
                         #[inline]
                         fn try_from(s: &'a str) -> ::#core::result::Result<&'a #ty, Self::Error> {
@@ -475,7 +1344,7 @@ impl<'a> RefCodeGen<'a> {
             }
         };
 
-        let alloc_from = self.owned_ty.is_some().then(|| {
+        let cow_from = self.owned_ty.is_some().then(|| {
             quote!{
                 #[automatically_derived]
                 impl<'a> ::#core::convert::From<&'a #ty> for ::#alloc::borrow::Cow<'a, #ty> {
@@ -493,42 +1362,65 @@ impl<'a> RefCodeGen<'a> {
                         ::#core::borrow::Borrow::borrow(r)
                     }
                 }
+            }
+        });
 
-                #[automatically_derived]
-                impl ::#core::convert::From<&'_ #ty> for ::#alloc::rc::Rc<#ty> {
-                    #[allow(unsafe_code)]
-                    #[inline]
-                    fn from(r: &'_ #ty) -> Self {
-                        #pointer_reinterpret_safety_comment
-                        let rc = ::#alloc::rc::Rc::<str>::from(r.as_str());
-                        unsafe { ::#alloc::rc::Rc::from_raw(::#alloc::rc::Rc::into_raw(rc) as *const #ty) }
+        let alloc_from = (self.owned_ty.is_some() || self.alloc).then(|| {
+            let box_safety_comment = self.pointer_reinterpret_safety_comment(true);
+            let rc_from = self.omit.retain(
+                "rc",
+                quote! {
+                    #[automatically_derived]
+                    impl ::#core::convert::From<&'_ #ty> for ::#alloc::rc::Rc<#ty> {
+                        #[allow(unsafe_code)]
+                        #[inline]
+                        fn from(r: &'_ #ty) -> Self {
+                            #pointer_reinterpret_safety_comment
+                            let rc = ::#alloc::rc::Rc::<str>::from(r.as_str());
+                            unsafe { ::#alloc::rc::Rc::from_raw(::#alloc::rc::Rc::into_raw(rc) as *const #ty) }
+                        }
                     }
-                }
+                },
+            );
+            // Targets without atomics provide `alloc` but not `alloc::sync`
+            let arc_from = self.omit.retain(
+                "arc",
+                quote! {
+                    #[automatically_derived]
+                    impl ::#core::convert::From<&'_ #ty> for ::#alloc::sync::Arc<#ty> {
+                        #[allow(unsafe_code)]
+                        #[inline]
+                        fn from(r: &'_ #ty) -> Self {
+                            #pointer_reinterpret_safety_comment
+                            let arc = ::#alloc::sync::Arc::<str>::from(r.as_str());
+                            unsafe { ::#alloc::sync::Arc::from_raw(::#alloc::sync::Arc::into_raw(arc) as *const #ty) }
+                        }
+                    }
+                },
+            );
 
+            quote!{
                 #[automatically_derived]
-                impl ::#core::convert::From<&'_ #ty> for ::#alloc::sync::Arc<#ty> {
+                impl ::#core::convert::From<&'_ #ty> for ::#alloc::boxed::Box<#ty> {
                     #[allow(unsafe_code)]
                     #[inline]
                     fn from(r: &'_ #ty) -> Self {
-                        #pointer_reinterpret_safety_comment
-                        let arc = ::#alloc::sync::Arc::<str>::from(r.as_str());
-                        unsafe { ::#alloc::sync::Arc::from_raw(::#alloc::sync::Arc::into_raw(arc) as *const #ty) }
+                        #box_safety_comment
+                        let boxed = ::#alloc::boxed::Box::<str>::from(r.as_str());
+                        unsafe { ::#alloc::boxed::Box::from_raw(::#alloc::boxed::Box::into_raw(boxed) as *mut #ty) }
                     }
                 }
+
+                #rc_from
+                #arc_from
             }
         });
 
         quote! {
-            #from_str
-
-            #[automatically_derived]
-            impl ::#core::convert::AsRef<str> for #ty {
-                #[inline]
-                fn as_ref(&self) -> &str {
-                    &self.#field_name
-                }
-            }
+            #from_str_impl
+            #str_impls
 
+            #cow_from
             #alloc_from
         }
     }
@@ -537,13 +1429,49 @@ impl<'a> RefCodeGen<'a> {
         let inherent = self.inherent();
         let comparison = self.comparison();
         let conversion = self.conversion();
+        let support = self.support();
+        let intern = self.intern_impl();
         let debug = self.impls.debug.to_borrowed_impl(self);
         let display = self.impls.display.to_borrowed_impl(self);
         let ord = self.impls.ord.to_borrowed_impl(self);
-        let serde = self.impls.serde.to_borrowed_impl(self);
+        let serde = self.impls.gates.gate(
+            super::symbol::SERDE,
+            self.impls.serde.to_borrowed_impl(self),
+        );
+        let valuable = self.impls.gates.gate(
+            super::symbol::VALUABLE,
+            self.impls.valuable.to_borrowed_impl(self),
+        );
+        let defmt = self.impls.gates.gate(
+            super::symbol::DEFMT,
+            self.impls.defmt.to_borrowed_impl(self),
+        );
+        let ts_rs = self.impls.gates.gate(
+            super::symbol::TS_RS,
+            self.impls.ts_rs.to_borrowed_impl(self),
+        );
+        let redis = self.impls.gates.gate(
+            super::symbol::REDIS,
+            self.impls.redis.to_borrowed_impl(self),
+        );
+        let bson = self
+            .impls
+            .gates
+            .gate(super::symbol::BSON, self.impls.bson.to_borrowed_impl(self));
+        let hash = self.impls.hash.is_generated();
+        let derives = self.equality.derives(hash);
+        // Exact ordering is derived, while any other ordering is implemented separately
+        let (ord_derive, ord) = if self.equality.is_exact() && !self.impls.ord.is_custom() {
+            (ord, None)
+        } else {
+            (None, ord)
+        };
+        let field_name = &self.field.name;
+        let equality =
+            self.equality
+                .impls(&self.ty, |v| quote! { &#v.#field_name }, hash, self.std_lib);
 
-        let ref_doc: proc_macro2::TokenStream =
-            self.doc.iter().map(|d| quote! { #[doc = #d] }).collect();
+        let ref_doc: proc_macro2::TokenStream = self.doc.iter().map(|d| quote! { #d }).collect();
         let ref_attrs: proc_macro2::TokenStream =
             self.attrs.iter().map(|a| quote! {#[#a]}).collect();
         let common_attrs = {
@@ -562,28 +1490,67 @@ impl<'a> RefCodeGen<'a> {
             attrs.append_all(&self.field.attrs);
             attrs
         };
+        let phantoms = self.field.phantoms.iter().map(|p| {
+            let attrs = &p.attrs;
+            let ty = &p.ty;
+            match &p.name {
+                FieldName::Named(name) => quote! { #(#attrs)* #name: #ty, },
+                FieldName::Unnamed(_) => quote! { #(#attrs)* #ty, },
+            }
+        });
         let body = match &self.field.name {
-            FieldName::Named(name) => quote! ( { #field_attrs #name: str } ),
-            FieldName::Unnamed => quote! { ( #field_attrs str ); },
+            FieldName::Named(name) => quote! ( { #(#phantoms)* #field_attrs #name: str } ),
+            FieldName::Unnamed(_) => quote! { ( #(#phantoms)* #field_attrs str ); },
         };
+        let decl = super::respan_to_declaration(quote! { #vis struct #ty #body }, &self.ident);
 
         quote! {
             #[repr(transparent)]
-            #[derive(Hash, PartialEq, Eq)]
-            #ord
+            #derives
+            #ord_derive
             #ref_doc
             #ref_attrs
             #common_attrs
-            #vis struct #ty #body
+            #decl
 
             #inherent
             #comparison
             #conversion
+            #support
+            #intern
+            #equality
+            #ord
             #debug
             #display
             #serde
+            #valuable
+            #defmt
+            #ts_rs
+            #redis
+            #bson
         }
     }
+
+    fn support(&self) -> Option<proc_macro2::TokenStream> {
+        let ty = &self.ty;
+        let field_name = &self.field.name;
+        let braid = self.std_lib.braid();
+
+        self.expose.str().then(|| {
+            quote! {
+                #[automatically_derived]
+                impl #braid::__private::Sealed for #ty {}
+
+                #[automatically_derived]
+                impl #braid::BraidRef for #ty {
+                    #[inline]
+                    fn as_str(&self) -> &str {
+                        &self.#field_name
+                    }
+                }
+            }
+        })
+    }
 }
 
 fn is_doc_attribute(attr: &syn::Attribute) -> bool {
@@ -3,18 +3,14 @@ use quote::ToTokens;
 pub const VALIDATOR: &str = "validator";
 pub const NORMALIZER: &str = "normalizer";
 
+#[derive(Default)]
 pub enum CheckMode {
+    #[default]
     None,
     Validate(syn::Type),
     Normalize(syn::Type),
 }
 
-impl Default for CheckMode {
-    fn default() -> Self {
-        Self::None
-    }
-}
-
 impl CheckMode {
     pub fn serde_err_handler(&self) -> Option<proc_macro2::TokenStream> {
         match self {
@@ -24,19 +20,14 @@ impl CheckMode {
     }
 }
 
-#[derive(Clone)]
+#[derive(Clone, Default)]
 pub enum IndefiniteCheckMode {
+    #[default]
     None,
     Validate(Option<syn::Type>),
     Normalize(Option<syn::Type>),
 }
 
-impl Default for IndefiniteCheckMode {
-    fn default() -> Self {
-        Self::None
-    }
-}
-
 impl IndefiniteCheckMode {
     pub fn try_set_validator(&mut self, validator: Option<syn::Type>) -> Result<(), String> {
         if matches!(self, Self::None) {
@@ -0,0 +1,714 @@
+use quote::{quote, ToTokens};
+
+use super::{
+    check_mode::{ident_to_type, CheckMode, IndefiniteCheckMode},
+    symbol::{self, Symbol},
+    StdLib,
+};
+
+/// A declarative constraint from which a validator is synthesized
+pub enum Constraint {
+    NonEmpty(proc_macro2::Span),
+    Charset(Charset, proc_macro2::Span),
+    /// A minimum length in bytes, declared with either `min_len` or `min_bytes`
+    MinLen(syn::LitInt, Symbol),
+    /// A maximum length in bytes, declared with either `max_len` or `max_bytes`
+    MaxLen(syn::LitInt, Symbol),
+    MinChars(syn::LitInt),
+    MaxChars(syn::LitInt),
+    Prefix(syn::LitStr),
+    Suffix(syn::LitStr),
+    /// A regular expression that the entire value must match
+    Pattern(syn::LitStr),
+}
+
+impl Constraint {
+    fn symbol(&self) -> Symbol {
+        match self {
+            Self::NonEmpty(_) => symbol::NON_EMPTY,
+            Self::Charset(..) => symbol::CHARSET,
+            Self::MinLen(..) => symbol::MIN_LEN,
+            Self::MaxLen(..) => symbol::MAX_LEN,
+            Self::MinChars(_) => symbol::MIN_CHARS,
+            Self::MaxChars(_) => symbol::MAX_CHARS,
+            Self::Prefix(_) => symbol::PREFIX,
+            Self::Suffix(_) => symbol::SUFFIX,
+            Self::Pattern(_) => symbol::PATTERN,
+        }
+    }
+
+    /// Constructs a `pattern` constraint, checking that the regular expression
+    /// compiles during expansion
+    pub fn pattern(lit: syn::LitStr) -> Result<Self, syn::Error> {
+        if !cfg!(feature = "regex") {
+            return Err(syn::Error::new(
+                lit.span(),
+                format!(
+                    "{} requires the `regex` feature of aliri_braid",
+                    symbol::PATTERN
+                ),
+            ));
+        }
+
+        #[cfg(feature = "regex")]
+        {
+            use regex_automata::{dfa::dense, MatchKind};
+
+            // Mirrors the configuration with which `aliri_braid::__private::Pattern` builds its
+            // DFA, so that any pattern accepted here can be compiled at runtime
+            dense::Builder::new()
+                .configure(dense::Config::new().match_kind(MatchKind::All))
+                .build(&lit.value())
+                .map_err(|err| {
+                    let mut message = format!("invalid {}: {}", symbol::PATTERN, err);
+                    let mut source = std::error::Error::source(&err);
+                    while let Some(err) = source {
+                        message = format!("{}: {}", message, err);
+                        source = err.source();
+                    }
+                    syn::Error::new(lit.span(), message)
+                })?;
+        }
+
+        Ok(Self::Pattern(lit))
+    }
+
+    /// The parameter with which the constraint was declared, which may be an alias of
+    /// [`Self::symbol`]
+    fn name(&self) -> Symbol {
+        match self {
+            Self::MinLen(_, name) | Self::MaxLen(_, name) => *name,
+            _ => self.symbol(),
+        }
+    }
+
+    fn span(&self) -> proc_macro2::Span {
+        match self {
+            Self::NonEmpty(span) | Self::Charset(_, span) => *span,
+            Self::MinLen(lit, _)
+            | Self::MaxLen(lit, _)
+            | Self::MinChars(lit)
+            | Self::MaxChars(lit) => lit.span(),
+            Self::Prefix(lit) | Self::Suffix(lit) | Self::Pattern(lit) => lit.span(),
+        }
+    }
+
+    fn length(&self) -> Option<&syn::LitInt> {
+        match self {
+            Self::MinLen(lit, _)
+            | Self::MaxLen(lit, _)
+            | Self::MinChars(lit)
+            | Self::MaxChars(lit) => Some(lit),
+            Self::NonEmpty(_)
+            | Self::Charset(..)
+            | Self::Prefix(_)
+            | Self::Suffix(_)
+            | Self::Pattern(_) => None,
+        }
+    }
+
+    fn descriptor(&self, std_lib: &StdLib) -> proc_macro2::TokenStream {
+        let braid = std_lib.braid();
+        match self {
+            Self::NonEmpty(_) => quote! { #braid::Constraint::NonEmpty },
+            Self::Charset(charset, _) => {
+                let variant = charset.variant();
+                quote! { #braid::Constraint::Charset(#braid::Charset::#variant) }
+            }
+            Self::MinLen(min, _) => quote! { #braid::Constraint::MinLength(#min) },
+            Self::MaxLen(max, _) => quote! { #braid::Constraint::MaxLength(#max) },
+            Self::MinChars(min) => quote! { #braid::Constraint::MinChars(#min) },
+            Self::MaxChars(max) => quote! { #braid::Constraint::MaxChars(#max) },
+            Self::Prefix(prefix) => quote! { #braid::Constraint::Prefix(#prefix) },
+            Self::Suffix(suffix) => quote! { #braid::Constraint::Suffix(#suffix) },
+            Self::Pattern(pattern) => quote! { #braid::Constraint::Pattern(#pattern) },
+        }
+    }
+
+    /// Checks a literal value against the constraint during expansion,
+    /// describing the violation if it does not conform
+    fn check_literal(&self, value: &str) -> Result<(), String> {
+        let satisfied = match self {
+            Self::NonEmpty(_) => !value.is_empty(),
+            Self::Charset(charset, _) => value.chars().all(|c| charset.allows(c)),
+            Self::MinLen(min, _) => value.len() >= min.base10_parse::<usize>().unwrap_or_default(),
+            Self::MaxLen(max, _) => {
+                value.len() <= max.base10_parse::<usize>().unwrap_or(usize::MAX)
+            }
+            Self::MinChars(min) => {
+                value.chars().count() >= min.base10_parse::<usize>().unwrap_or_default()
+            }
+            Self::MaxChars(max) => {
+                value.chars().count() <= max.base10_parse::<usize>().unwrap_or(usize::MAX)
+            }
+            Self::Prefix(prefix) => value.starts_with(&prefix.value()),
+            Self::Suffix(suffix) => value.ends_with(&suffix.value()),
+            // Literals are not checked against patterns during expansion
+            Self::Pattern(_) => true,
+        };
+
+        if satisfied {
+            return Ok(());
+        }
+
+        Err(match self {
+            Self::NonEmpty(_) => "must not be empty".to_owned(),
+            Self::Charset(charset, _) => format!("must only contain {}", charset.description()),
+            Self::MinLen(min, _) => format!("must be at least {} bytes long", min),
+            Self::MaxLen(max, _) => format!("must be at most {} bytes long", max),
+            Self::MinChars(min) => format!("must be at least {} characters long", min),
+            Self::MaxChars(max) => format!("must be at most {} characters long", max),
+            Self::Prefix(prefix) => format!("must start with {:?}", prefix.value()),
+            Self::Suffix(suffix) => format!("must end with {:?}", suffix.value()),
+            Self::Pattern(pattern) => format!("must match the pattern {:?}", pattern.value()),
+        })
+    }
+
+    /// Checks a value against the constraint as a `const`-evaluable
+    /// expression
+    fn const_check(&self, std_lib: &StdLib) -> proc_macro2::TokenStream {
+        let braid = std_lib.braid();
+        match self {
+            Self::NonEmpty(_) => quote! { !raw.is_empty() },
+            Self::Charset(charset, _) => {
+                let variant = charset.variant();
+                quote! { #braid::Charset::#variant.find_invalid(raw).is_none() }
+            }
+            Self::MinLen(min, _) => quote! { raw.len() >= #min },
+            Self::MaxLen(max, _) => quote! { raw.len() <= #max },
+            Self::MinChars(min) => quote! { #braid::__private::char_count(raw) >= #min },
+            Self::MaxChars(max) => quote! { #braid::__private::char_count(raw) <= #max },
+            Self::Prefix(prefix) => quote! { #braid::__private::starts_with(raw, #prefix) },
+            Self::Suffix(suffix) => quote! { #braid::__private::ends_with(raw, #suffix) },
+            Self::Pattern(_) => {
+                unreachable!("patterns cannot be checked during constant evaluation")
+            }
+        }
+    }
+
+    fn check(&self, std_lib: &StdLib) -> proc_macro2::TokenStream {
+        let core = std_lib.core();
+        let braid = std_lib.braid();
+        let descriptor = self.descriptor(std_lib);
+        let fail = |position: proc_macro2::TokenStream| {
+            quote! {
+                return ::#core::result::Result::Err(::#core::convert::From::from(
+                    #braid::ConstraintError::new(raw, #descriptor, #position)
+                ));
+            }
+        };
+
+        match self {
+            Self::NonEmpty(_) => {
+                let fail = fail(quote! { 0 });
+                quote! {
+                    if raw.is_empty() {
+                        #fail
+                    }
+                }
+            }
+            Self::Charset(charset, _) => {
+                let variant = charset.variant();
+                let fail = fail(quote! { position });
+                quote! {
+                    if let ::#core::option::Option::Some(position) =
+                        #braid::Charset::#variant.find_invalid(raw)
+                    {
+                        #fail
+                    }
+                }
+            }
+            Self::MinLen(min, _) => {
+                let fail = fail(quote! { raw.len() });
+                quote! {
+                    if raw.len() < #min {
+                        #fail
+                    }
+                }
+            }
+            Self::MaxLen(max, _) => {
+                let fail = fail(quote! { #max });
+                quote! {
+                    if raw.len() > #max {
+                        #fail
+                    }
+                }
+            }
+            Self::MinChars(min) => {
+                let fail = fail(quote! { raw.len() });
+                quote! {
+                    if #braid::__private::char_count(raw) < #min {
+                        #fail
+                    }
+                }
+            }
+            Self::MaxChars(max) => {
+                // The position of the first `char` beyond the limit
+                let fail = fail(quote! {
+                    ::#core::option::Option::map_or(
+                        ::#core::iter::Iterator::nth(&mut raw.char_indices(), #max),
+                        raw.len(),
+                        |(i, _)| i,
+                    )
+                });
+                quote! {
+                    if #braid::__private::char_count(raw) > #max {
+                        #fail
+                    }
+                }
+            }
+            Self::Prefix(prefix) => {
+                let fail = fail(quote! {
+                    ::#core::iter::Iterator::count(::#core::iter::Iterator::take_while(
+                        ::#core::iter::Iterator::zip(raw.bytes(), #prefix.bytes()),
+                        |(a, b)| a == b,
+                    ))
+                });
+                quote! {
+                    if !raw.starts_with(#prefix) {
+                        #fail
+                    }
+                }
+            }
+            Self::Suffix(suffix) => {
+                let fail = fail(quote! { raw.len().saturating_sub(#suffix.len()) });
+                quote! {
+                    if !raw.ends_with(#suffix) {
+                        #fail
+                    }
+                }
+            }
+            Self::Pattern(pattern) => {
+                let fail = fail(quote! { position });
+                quote! {
+                    {
+                        static PATTERN: #braid::__private::Pattern =
+                            #braid::__private::Pattern::new(#pattern);
+                        if let ::#core::result::Result::Err(position) = PATTERN.check(raw) {
+                            #fail
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn accessor(
+        &self,
+        rest: Option<&syn::Type>,
+        std_lib: &StdLib,
+    ) -> Option<proc_macro2::TokenStream> {
+        let core = std_lib.core();
+        let (name, check, affix) = match self {
+            Self::Prefix(prefix) => ("prefix", quote! { starts_with }, prefix),
+            Self::Suffix(suffix) => ("suffix", quote! { ends_with }, suffix),
+            Self::NonEmpty(_)
+            | Self::Charset(..)
+            | Self::MinLen(..)
+            | Self::MaxLen(..)
+            | Self::MinChars(_)
+            | Self::MaxChars(_)
+            | Self::Pattern(_) => return None,
+        };
+
+        let fn_name = quote::format_ident!("without_{}", name);
+        let slice = match self {
+            Self::Prefix(_) => quote! { &value[#affix.len()..] },
+            _ => quote! { &value[..value.len() - #affix.len()] },
+        };
+        let message = format!("value is missing the {} {:?}", name, affix.value());
+        // The constraint guarantees that the affix is present, so the value is sliced by its
+        // length, with a debug assertion to catch values that were created without checking.
+        let stripped = quote! {{
+            let value = self.as_str();
+            ::#core::debug_assert!(value.#check(#affix), #message);
+            #slice
+        }};
+
+        let tokens = if let Some(rest) = rest {
+            let doc = format!(
+                "Returns the value with the {} {:?} removed as a [`{}`]",
+                name,
+                affix.value(),
+                rest.to_token_stream(),
+            );
+
+            quote! {
+                #[doc = #doc]
+                #[inline]
+                pub fn #fn_name<'a>(&'a self) -> ::#core::result::Result<&'a #rest, <&'a #rest as ::#core::convert::TryFrom<&'a str>>::Error> {
+                    ::#core::convert::TryFrom::try_from(#stripped)
+                }
+            }
+        } else {
+            let doc = format!(
+                "Returns the value with the {} {:?} removed",
+                name,
+                affix.value()
+            );
+
+            quote! {
+                #[doc = #doc]
+                #[inline]
+                pub fn #fn_name(&self) -> &str {
+                    #stripped
+                }
+            }
+        };
+
+        Some(tokens)
+    }
+}
+
+/// A set of characters to which a braid value can be restricted
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Charset {
+    Ascii,
+    AsciiAlphanumeric,
+    NoControl,
+    Printable,
+}
+
+impl std::str::FromStr for Charset {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ascii" => Ok(Self::Ascii),
+            "ascii_alphanumeric" => Ok(Self::AsciiAlphanumeric),
+            "no_control" => Ok(Self::NoControl),
+            "printable" => Ok(Self::Printable),
+            _ => {
+                Err("valid values are: `ascii`, `ascii_alphanumeric`, `no_control`, or `printable`")
+            }
+        }
+    }
+}
+
+impl Charset {
+    /// The name of the corresponding `aliri_braid::Charset` variant
+    fn variant(self) -> proc_macro2::Ident {
+        let name = match self {
+            Self::Ascii => "Ascii",
+            Self::AsciiAlphanumeric => "AsciiAlphanumeric",
+            Self::NoControl => "NoControl",
+            Self::Printable => "Printable",
+        };
+        proc_macro2::Ident::new(name, proc_macro2::Span::call_site())
+    }
+
+    /// Mirrors `aliri_braid::Charset::find_invalid` for checking literals
+    /// during expansion
+    fn allows(self, c: char) -> bool {
+        match self {
+            Self::Ascii => c.is_ascii(),
+            Self::AsciiAlphanumeric => c.is_ascii_alphanumeric(),
+            Self::NoControl => !c.is_control(),
+            Self::Printable => c.is_ascii_graphic() || c == ' ',
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            Self::Ascii => "ASCII characters",
+            Self::AsciiAlphanumeric => "ASCII letters and digits",
+            Self::NoControl => "characters other than control characters",
+            Self::Printable => "printable ASCII characters",
+        }
+    }
+}
+
+/// The set of declarative constraints placed on a braid
+#[derive(Default)]
+pub struct Constraints {
+    constraints: Vec<Constraint>,
+    rest: Option<syn::Type>,
+}
+
+impl Constraints {
+    pub fn is_empty(&self) -> bool {
+        self.constraints.is_empty()
+    }
+
+    pub fn try_set_rest(&mut self, rest: syn::Type) -> Result<(), syn::Error> {
+        if self.rest.is_some() {
+            return Err(syn::Error::new_spanned(
+                rest,
+                format!("{} can only be specified once", symbol::REST),
+            ));
+        }
+
+        self.rest = Some(rest);
+        Ok(())
+    }
+
+    pub fn try_push(&mut self, constraint: Constraint) -> Result<(), syn::Error> {
+        if let Some(existing) = self
+            .constraints
+            .iter()
+            .find(|c| c.symbol() == constraint.symbol())
+        {
+            let message = if existing.name() == constraint.name() {
+                format!("{} can only be specified once", constraint.name())
+            } else {
+                format!(
+                    "{} cannot be specified along with {}",
+                    constraint.name(),
+                    existing.name()
+                )
+            };
+            return Err(syn::Error::new(constraint.span(), message));
+        }
+
+        if let Some(lit) = constraint.length() {
+            lit.base10_parse::<usize>()?;
+        }
+
+        self.constraints.push(constraint);
+        self.ensure_consistent_lengths()
+    }
+
+    /// Describes the declared constraints as a static slice of
+    /// `aliri_braid::Constraint` values
+    pub fn descriptors(&self, std_lib: &StdLib) -> proc_macro2::TokenStream {
+        let descriptors = self.constraints.iter().map(|c| c.descriptor(std_lib));
+        quote! { &[#(#descriptors),*] }
+    }
+
+    /// Checks a literal value against the declared constraints during
+    /// expansion
+    pub fn check_literal(&self, lit: &syn::LitStr) -> Result<(), syn::Error> {
+        let value = lit.value();
+        self.constraints.iter().try_for_each(|c| {
+            c.check_literal(&value).map_err(|violation| {
+                syn::Error::new(
+                    lit.span(),
+                    format!("invalid value {:?}: {}", value, violation),
+                )
+            })
+        })
+    }
+
+    /// Generates the `const` literal checks used by `braid_lit!` on each of
+    /// the given types
+    ///
+    /// Literals can only be checked during constant evaluation when the
+    /// declared constraints are the only checks placed on the braid, so no
+    /// checks are generated for braids with a validator, a normalizer, or a
+    /// pattern.
+    pub fn literal_impls(
+        &self,
+        check_mode: &IndefiniteCheckMode,
+        types: &[&syn::Type],
+        std_lib: &StdLib,
+    ) -> Option<proc_macro2::TokenStream> {
+        if !matches!(check_mode, IndefiniteCheckMode::None)
+            || self
+                .constraints
+                .iter()
+                .any(|c| matches!(c, Constraint::Pattern(_)))
+        {
+            return None;
+        }
+
+        let raw = if self.constraints.is_empty() {
+            quote! { _ }
+        } else {
+            quote! { raw }
+        };
+        let checks: Vec<_> = self
+            .constraints
+            .iter()
+            .map(|c| c.const_check(std_lib))
+            .collect();
+
+        Some(
+            types
+                .iter()
+                .map(|ty| {
+                    quote! {
+                        #[automatically_derived]
+                        impl #ty {
+                            #[doc(hidden)]
+                            #[inline]
+                            pub const fn __check_literal(#raw: &str) -> bool {
+                                #(#checks &&)* true
+                            }
+                        }
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    fn ensure_consistent_lengths(&self) -> Result<(), syn::Error> {
+        let find = |sym: Symbol| {
+            self.constraints
+                .iter()
+                .find(|c| c.symbol() == sym)
+                .and_then(|c| Some((c.length()?, c.name())))
+        };
+
+        // As every `char` takes at least one byte, a value cannot have fewer
+        // bytes than it has `char`s
+        let bounds = [
+            (symbol::MIN_LEN, symbol::MAX_LEN),
+            (symbol::MIN_CHARS, symbol::MAX_CHARS),
+            (symbol::MIN_CHARS, symbol::MAX_LEN),
+        ];
+
+        for (min_sym, max_sym) in bounds {
+            if let (Some((min, min_name)), Some((max, max_name))) = (find(min_sym), find(max_sym)) {
+                if min.base10_parse::<usize>()? > max.base10_parse::<usize>()? {
+                    return Err(syn::Error::new(
+                        max.span(),
+                        format!("{} cannot be less than {}", max_name, min_name),
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Resolves the check mode for a braid, synthesizing a validator for the
+    /// braid type if any constraints have been declared
+    ///
+    /// Any explicitly named validator or normalizer is run after the declared
+    /// constraints, and its error type must implement
+    /// `From<aliri_braid::ConstraintError>`. Accessors for declared affixes
+    /// are generated on the borrowed type.
+    pub fn resolve(
+        &self,
+        check_mode: IndefiniteCheckMode,
+        ty: &syn::Ident,
+        ref_ty: &syn::Type,
+        std_lib: &StdLib,
+    ) -> Result<(CheckMode, Option<proc_macro2::TokenStream>), syn::Error> {
+        let first = match self.constraints.first() {
+            Some(first) => first,
+            None => {
+                if let Some(rest) = &self.rest {
+                    return Err(rest_requires_affix(rest));
+                }
+
+                return Ok((check_mode.infer_validator_if_missing(ty), None));
+            }
+        };
+
+        let core = std_lib.core();
+        let alloc = std_lib.alloc();
+        let braid = std_lib.braid();
+        let checks: proc_macro2::TokenStream =
+            self.constraints.iter().map(|c| c.check(std_lib)).collect();
+        let accessors: proc_macro2::TokenStream = self
+            .constraints
+            .iter()
+            .filter_map(|c| c.accessor(self.rest.as_ref(), std_lib))
+            .collect();
+
+        if let Some(rest) = self.rest.as_ref().filter(|_| accessors.is_empty()) {
+            return Err(rest_requires_affix(rest));
+        }
+
+        let accessors = (!accessors.is_empty()).then(|| {
+            quote! {
+                #[automatically_derived]
+                impl #ref_ty {
+                    #accessors
+                }
+            }
+        });
+
+        let tokens = match &check_mode {
+            IndefiniteCheckMode::None => quote! {
+                #[automatically_derived]
+                impl #braid::Validator for #ty {
+                    type Error = #braid::ConstraintError;
+
+                    #[inline]
+                    fn validate(raw: &str) -> ::#core::result::Result<(), Self::Error> {
+                        #checks
+                        ::#core::result::Result::Ok(())
+                    }
+                }
+            },
+            IndefiniteCheckMode::Validate(Some(validator)) => {
+                let validator = crate::as_validator(validator, std_lib);
+                quote! {
+                    #[automatically_derived]
+                    impl #braid::Validator for #ty {
+                        type Error = #validator::Error;
+
+                        #[inline]
+                        fn validate(raw: &str) -> ::#core::result::Result<(), Self::Error> {
+                            #checks
+                            #validator::validate(raw)
+                        }
+                    }
+                }
+            }
+            IndefiniteCheckMode::Normalize(Some(normalizer)) => {
+                let validator = crate::as_validator(normalizer, std_lib);
+                let normalizer = crate::as_normalizer(normalizer, std_lib);
+                quote! {
+                    #[automatically_derived]
+                    impl #braid::Validator for #ty {
+                        type Error = #validator::Error;
+
+                        #[inline]
+                        fn validate(raw: &str) -> ::#core::result::Result<(), Self::Error> {
+                            #checks
+                            #validator::validate(raw)
+                        }
+                    }
+
+                    #[automatically_derived]
+                    impl #braid::Normalizer for #ty {
+                        #[inline]
+                        fn normalize(raw: &str) -> ::#core::result::Result<::#alloc::borrow::Cow<'_, str>, Self::Error> {
+                            let normalized = #normalizer::normalize(raw)?;
+                            {
+                                let raw: &str = &normalized;
+                                #checks
+                            }
+                            ::#core::result::Result::Ok(normalized)
+                        }
+                    }
+                }
+            }
+            IndefiniteCheckMode::Validate(None) | IndefiniteCheckMode::Normalize(None) => {
+                return Err(syn::Error::new(
+                    first.span(),
+                    "declarative constraints cannot be combined with a validator implemented on \
+                     the braid itself; name the validator type explicitly (`validator = \"Type\"`)",
+                ));
+            }
+        };
+
+        let check_mode = match check_mode {
+            IndefiniteCheckMode::Normalize(_) => CheckMode::Normalize(ident_to_type(ty)),
+            _ => CheckMode::Validate(ident_to_type(ty)),
+        };
+
+        Ok((
+            check_mode,
+            Some(quote! {
+                #tokens
+                #accessors
+            }),
+        ))
+    }
+}
+
+fn rest_requires_affix(rest: &syn::Type) -> syn::Error {
+    syn::Error::new_spanned(
+        rest,
+        format!(
+            "{} requires a {} or {} to be specified",
+            symbol::REST,
+            symbol::PREFIX,
+            symbol::SUFFIX
+        ),
+    )
+}
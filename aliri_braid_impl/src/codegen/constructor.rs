@@ -0,0 +1,65 @@
+use quote::format_ident;
+
+use super::{naming::Naming, CheckMode};
+
+/// The names of the checked constructors, `new()` on the owned type and
+/// `from_str()` on the borrowed type unless renamed
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Constructor {
+    name: Option<String>,
+    naming: Naming,
+    fallible: bool,
+}
+
+impl std::str::FromStr for Constructor {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if syn::parse_str::<syn::Ident>(s).is_ok() {
+            Ok(Self {
+                name: Some(s.to_owned()),
+                ..Self::default()
+            })
+        } else {
+            Err("expected the name of the constructor, such as `parse`")
+        }
+    }
+}
+
+impl Constructor {
+    /// Applies the naming generation to any constructor that has not been
+    /// explicitly renamed
+    ///
+    /// Under `v2`, the borrowed constructor is named `try_from_str()` if it
+    /// can fail, and `parse_ref()` otherwise.
+    pub fn with_naming(self, naming: Naming, check_mode: &CheckMode) -> Self {
+        Self {
+            naming,
+            fallible: !matches!(check_mode, CheckMode::None),
+            ..self
+        }
+    }
+
+    /// The name of the checked constructor on the owned type
+    pub fn owned(&self) -> syn::Ident {
+        self.name
+            .as_deref()
+            .map_or_else(|| format_ident!("new"), |name| format_ident!("{}", name))
+    }
+
+    /// The name of the checked constructor on the borrowed type
+    pub fn borrowed(&self) -> syn::Ident {
+        match (&self.name, self.naming) {
+            (Some(name), _) => format_ident!("{}", name),
+            (None, Naming::V1) => format_ident!("from_str"),
+            (None, Naming::V2) if self.fallible => format_ident!("try_from_str"),
+            (None, Naming::V2) => format_ident!("parse_ref"),
+        }
+    }
+
+    /// The former name of the checked constructor on the borrowed type, if
+    /// renamed by the naming generation
+    pub fn deprecated_borrowed(&self) -> Option<syn::Ident> {
+        (self.name.is_none() && self.naming == Naming::V2).then(|| format_ident!("from_str"))
+    }
+}
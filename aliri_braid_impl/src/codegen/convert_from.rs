@@ -0,0 +1,112 @@
+use quote::{quote, ToTokens};
+
+use super::{
+    check_mode::CheckMode,
+    symbol::{self, parse_expr_as_lit, parse_lit_into_type},
+    StdLib,
+};
+
+/// The set of other braids that a braid can be converted from
+///
+/// Conversions are infallible when the braid has no validator or normalizer,
+/// and fallible otherwise, as the invariants of the source braid are unknown.
+#[derive(Default)]
+pub struct ConvertFrom {
+    sources: Vec<syn::Type>,
+}
+
+impl ConvertFrom {
+    pub fn try_push(&mut self, nv: &syn::MetaNameValue) -> Result<(), syn::Error> {
+        let source = parse_lit_into_type(symbol::CONVERT_FROM, parse_expr_as_lit(&nv.value)?)?;
+        let name = source.to_token_stream().to_string();
+        if self
+            .sources
+            .iter()
+            .any(|s| s.to_token_stream().to_string() == name)
+        {
+            return Err(syn::Error::new_spanned(
+                nv,
+                format!(
+                    "{} `{}` can only be specified once",
+                    symbol::CONVERT_FROM,
+                    name
+                ),
+            ));
+        }
+
+        self.sources.push(source);
+        Ok(())
+    }
+
+    /// Generates conversions into the owned braid from each source braid and
+    /// from references to its borrowed form
+    pub fn tokens(
+        &self,
+        ty: &syn::Ident,
+        check_mode: &CheckMode,
+        error: Option<&syn::Type>,
+        std_lib: &StdLib,
+    ) -> Option<proc_macro2::TokenStream> {
+        if self.sources.is_empty() {
+            return None;
+        }
+
+        let core = std_lib.core();
+        let alloc = std_lib.alloc();
+        let error = match check_mode {
+            CheckMode::None => None,
+            CheckMode::Validate(validator) | CheckMode::Normalize(validator) => Some(
+                super::error_type(error, &crate::as_validator(validator, std_lib)),
+            ),
+        };
+
+        let tokens = self.sources.iter().map(|source| {
+            let source_ref = quote! { <#source as ::#core::ops::Deref>::Target };
+            let owned = quote! { ::#alloc::string::String::from(s) };
+            let borrowed = quote! { s.as_str() };
+
+            match &error {
+                None => quote! {
+                    #[automatically_derived]
+                    impl ::#core::convert::From<#source> for #ty {
+                        #[inline]
+                        fn from(s: #source) -> Self {
+                            ::#core::convert::From::from(#owned)
+                        }
+                    }
+
+                    #[automatically_derived]
+                    impl<'a> ::#core::convert::From<&'a #source_ref> for #ty {
+                        #[inline]
+                        fn from(s: &'a #source_ref) -> Self {
+                            ::#core::convert::From::from(#borrowed)
+                        }
+                    }
+                },
+                Some(error) => quote! {
+                    #[automatically_derived]
+                    impl ::#core::convert::TryFrom<#source> for #ty {
+                        type Error = #error;
+
+                        #[inline]
+                        fn try_from(s: #source) -> ::#core::result::Result<Self, Self::Error> {
+                            ::#core::convert::TryFrom::try_from(#owned)
+                        }
+                    }
+
+                    #[automatically_derived]
+                    impl<'a> ::#core::convert::TryFrom<&'a #source_ref> for #ty {
+                        type Error = #error;
+
+                        #[inline]
+                        fn try_from(s: &'a #source_ref) -> ::#core::result::Result<Self, Self::Error> {
+                            ::#core::convert::TryFrom::try_from(#borrowed)
+                        }
+                    }
+                },
+            }
+        });
+
+        Some(tokens.collect())
+    }
+}
@@ -0,0 +1,310 @@
+use quote::ToTokens;
+
+use super::{
+    symbol::{self, Symbol},
+    AttrList,
+};
+
+/// The forms in which a parameter can be given
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Form {
+    /// A bare flag, such as `serde`
+    Flag,
+    /// A name and value, such as `max_len = 8`
+    Value,
+    /// A parenthesized list, such as `omit(from_static)`
+    List,
+}
+
+/// A parameter accepted by one of the macros, along with the forms in which it can be given
+pub struct Param(Symbol, &'static [Form]);
+
+/// The parameters accepted by `braid`, other than those that select a borrowed type other
+/// than `str`
+const BRAID_PARAMS: &[Param] = &[
+    Param(symbol::REF, &[Form::Value]),
+    Param(symbol::ERROR, &[Form::Value]),
+    Param(symbol::VALIDATOR, &[Form::Flag, Form::Value]),
+    Param(symbol::NORMALIZER, &[Form::Flag, Form::Value]),
+    Param(symbol::CONVERT_CASE, &[Form::Value]),
+    Param(symbol::FORMAT, &[Form::Value]),
+    Param(symbol::REF_DOC, &[Form::Value]),
+    Param(symbol::OWNED_DOC, &[Form::Value]),
+    Param(symbol::ALIAS, &[Form::Value]),
+    Param(symbol::REF_ATTR, &[Form::List]),
+    Param(symbol::OWNED_ATTR, &[Form::List]),
+    Param(symbol::REF_FIELD_ATTR, &[Form::List]),
+    Param(symbol::OWNED_FIELD_ATTR, &[Form::List]),
+    Param(symbol::DEBUG, &[Form::Value]),
+    Param(symbol::DISPLAY, &[Form::Value]),
+    Param(symbol::ORD, &[Form::Value]),
+    Param(symbol::HASH, &[Form::Value]),
+    Param(symbol::CLONE, &[Form::Value]),
+    Param(symbol::SERDE, &[Form::Flag, Form::Value]),
+    Param(symbol::UNCHECKED, &[Form::Value]),
+    Param(symbol::CONSTRUCTOR, &[Form::Value]),
+    Param(symbol::NAMING, &[Form::Value]),
+    Param(symbol::CLAP, &[Form::Flag, Form::Value]),
+    Param(symbol::VALUABLE, &[Form::Flag, Form::Value]),
+    Param(symbol::DEFMT, &[Form::Flag, Form::Value]),
+    Param(symbol::TS_RS, &[Form::Flag, Form::Value]),
+    Param(symbol::REDIS, &[Form::Flag, Form::Value]),
+    Param(symbol::BSON, &[Form::Flag, Form::Value]),
+    Param(symbol::SEA_ORM, &[Form::Flag, Form::Value]),
+    Param(symbol::QUICKCHECK, &[Form::Flag, Form::Value]),
+    Param(symbol::NO_STD, &[Form::Flag]),
+    Param(symbol::CRATE, &[Form::Value]),
+    Param(symbol::STD_PATH, &[Form::Value]),
+    Param(symbol::FROM_BYTES, &[Form::Flag]),
+    Param(symbol::UNICODE, &[Form::Flag]),
+    Param(symbol::AS_PATH, &[Form::Flag]),
+    Param(symbol::ARENA, &[Form::Flag]),
+    Param(symbol::VIEW_SLICES, &[Form::Flag]),
+    Param(symbol::VEC, &[Form::Flag]),
+    Param(symbol::SLICE, &[Form::Flag]),
+    Param(symbol::INTERN, &[Form::Flag]),
+    Param(symbol::ASSERT_LAYOUT, &[Form::Flag]),
+    Param(symbol::NESTED, &[Form::Flag]),
+    Param(symbol::PROVENANCE, &[Form::Flag]),
+    Param(symbol::TEST_CTOR, &[Form::Flag]),
+    Param(symbol::MUTABLE, &[Form::Flag]),
+    Param(symbol::ADD, &[Form::Flag]),
+    Param(symbol::ADD_STR, &[Form::Flag]),
+    Param(symbol::BUILD, &[Form::Flag]),
+    Param(symbol::ITER, &[Form::Flag]),
+    Param(symbol::AXUM, &[Form::Flag]),
+    Param(symbol::DEFAULT, &[Form::Flag, Form::Value]),
+    Param(symbol::NO_MUST_USE, &[Form::Flag]),
+    Param(symbol::NO_EXPOSE, &[Form::Flag, Form::Value]),
+    Param(symbol::EQ, &[Form::Value]),
+    Param(symbol::CHARSET, &[Form::Value]),
+    Param(symbol::NON_EMPTY, &[Form::Flag]),
+    Param(symbol::MIN_LEN, &[Form::Value]),
+    Param(symbol::MIN_BYTES, &[Form::Value]),
+    Param(symbol::MAX_LEN, &[Form::Value]),
+    Param(symbol::MAX_BYTES, &[Form::Value]),
+    Param(symbol::MIN_CHARS, &[Form::Value]),
+    Param(symbol::MAX_CHARS, &[Form::Value]),
+    Param(symbol::PREFIX, &[Form::Value]),
+    Param(symbol::SUFFIX, &[Form::Value]),
+    Param(symbol::PATTERN, &[Form::Value]),
+    Param(symbol::REST, &[Form::Value]),
+    Param(symbol::PARSE_AS, &[Form::List]),
+    Param(symbol::SEGMENTS, &[Form::Value]),
+    Param(symbol::SEGMENT, &[Form::Value]),
+    Param(symbol::OMIT, &[Form::List]),
+    Param(symbol::FEATURE_GATE, &[Form::List]),
+    Param(symbol::EXPOSE, &[Form::Value]),
+    Param(symbol::CONVERT_FROM, &[Form::Value]),
+    Param(symbol::SAME_REPR_AS, &[Form::Value]),
+];
+
+/// The parameters accepted by `braid_ref`
+const REF_PARAMS: &[Param] = &[
+    Param(symbol::OWNED, &[Form::Value]),
+    Param(symbol::TO_OWNED, &[Form::Value]),
+    Param(symbol::AS_STR, &[Form::Value]),
+    Param(symbol::ERROR, &[Form::Value]),
+    Param(symbol::VALIDATOR, &[Form::Flag, Form::Value]),
+    Param(symbol::DEBUG, &[Form::Value]),
+    Param(symbol::DISPLAY, &[Form::Value]),
+    Param(symbol::ORD, &[Form::Value]),
+    Param(symbol::HASH, &[Form::Value]),
+    Param(symbol::SERDE, &[Form::Flag, Form::Value]),
+    Param(symbol::UNCHECKED, &[Form::Value]),
+    Param(symbol::CONSTRUCTOR, &[Form::Value]),
+    Param(symbol::NAMING, &[Form::Value]),
+    Param(symbol::VALUABLE, &[Form::Flag, Form::Value]),
+    Param(symbol::DEFMT, &[Form::Flag, Form::Value]),
+    Param(symbol::TS_RS, &[Form::Flag, Form::Value]),
+    Param(symbol::REDIS, &[Form::Flag, Form::Value]),
+    Param(symbol::BSON, &[Form::Flag, Form::Value]),
+    Param(symbol::NO_STD, &[Form::Flag]),
+    Param(symbol::CRATE, &[Form::Value]),
+    Param(symbol::STD_PATH, &[Form::Value]),
+    Param(symbol::FROM_BYTES, &[Form::Flag]),
+    Param(symbol::ARENA, &[Form::Flag]),
+    Param(symbol::VIEW_SLICES, &[Form::Flag]),
+    Param(symbol::VEC, &[Form::Flag]),
+    Param(symbol::SLICE, &[Form::Flag]),
+    Param(symbol::INTERN, &[Form::Flag]),
+    Param(symbol::NO_MUST_USE, &[Form::Flag]),
+    Param(symbol::ALLOC, &[Form::Flag]),
+    Param(symbol::CHARSET, &[Form::Value]),
+    Param(symbol::NON_EMPTY, &[Form::Flag]),
+    Param(symbol::MIN_LEN, &[Form::Value]),
+    Param(symbol::MIN_BYTES, &[Form::Value]),
+    Param(symbol::MAX_LEN, &[Form::Value]),
+    Param(symbol::MAX_BYTES, &[Form::Value]),
+    Param(symbol::MIN_CHARS, &[Form::Value]),
+    Param(symbol::MAX_CHARS, &[Form::Value]),
+    Param(symbol::PREFIX, &[Form::Value]),
+    Param(symbol::SUFFIX, &[Form::Value]),
+    Param(symbol::PATTERN, &[Form::Value]),
+    Param(symbol::REST, &[Form::Value]),
+    Param(symbol::PARSE_AS, &[Form::List]),
+    Param(symbol::SEGMENTS, &[Form::Value]),
+    Param(symbol::SEGMENT, &[Form::Value]),
+    Param(symbol::OMIT, &[Form::List]),
+    Param(symbol::FEATURE_GATE, &[Form::List]),
+    Param(symbol::EXPOSE, &[Form::Value]),
+    Param(symbol::EQ, &[Form::Value]),
+    Param(symbol::ALIAS, &[Form::Value]),
+];
+/// Names that are commonly mistaken for a parameter, along with the parameter meant
+const MISTAKES: &[(&str, Symbol)] = &[
+    ("ref", symbol::REF),
+    ("ref_ty", symbol::REF),
+    ("ref_type", symbol::REF),
+    ("validate", symbol::VALIDATOR),
+    ("normalize", symbol::NORMALIZER),
+    ("owned_type", symbol::OWNED),
+];
+
+/// The attribute macro whose arguments are being parsed
+#[derive(Clone, Copy)]
+pub enum Macro {
+    Braid,
+    BraidRef,
+}
+
+impl Macro {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Braid => "braid",
+            Self::BraidRef => "braid_ref",
+        }
+    }
+
+    fn params(self) -> &'static [Param] {
+        match self {
+            Self::Braid => BRAID_PARAMS,
+            Self::BraidRef => REF_PARAMS,
+        }
+    }
+
+    fn other(self) -> Self {
+        match self {
+            Self::Braid => Self::BraidRef,
+            Self::BraidRef => Self::Braid,
+        }
+    }
+}
+
+/// Parses the arguments to one of the macros
+///
+/// Keywords cannot be parsed as the name of an argument, so `ref` is reported here as an
+/// unsupported argument rather than as a bare syntax error.
+pub fn parse_args(input: syn::parse::ParseStream, target: Macro) -> syn::Result<AttrList> {
+    let mut args = AttrList::new();
+
+    while !input.is_empty() {
+        if input.peek(syn::Token![ref]) {
+            let keyword: syn::Token![ref] = input.parse()?;
+            return Err(unsupported_name(&keyword, "ref", target));
+        }
+
+        args.push_value(input.parse()?);
+        if input.is_empty() {
+            break;
+        }
+        args.push_punct(input.parse()?);
+    }
+
+    Ok(args)
+}
+
+/// Reports an argument that is not among the parameters supported by a macro
+///
+/// A parameter given in the wrong form is reported along with the forms it accepts. Otherwise,
+/// the most likely intended parameter is suggested, falling back to listing every supported
+/// parameter.
+pub fn unsupported_argument(arg: &syn::Meta, target: Macro) -> syn::Error {
+    let path = arg.path();
+
+    if let Some(Param(symbol, forms)) = target.params().iter().find(|Param(s, _)| path == *s) {
+        let forms = forms
+            .iter()
+            .map(|form| match form {
+                Form::Flag => format!("`{symbol}`"),
+                Form::Value => format!("`{symbol} = ...`"),
+                Form::List => format!("`{symbol}(...)`"),
+            })
+            .collect::<Vec<_>>()
+            .join(" or ");
+        return syn::Error::new_spanned(path, format!("`{symbol}` must be given as {forms}"));
+    }
+
+    unsupported_name(path, &path.to_token_stream().to_string(), target)
+}
+
+fn unsupported_name(tokens: &dyn ToTokens, name: &str, target: Macro) -> syn::Error {
+    let message = if let Some(suggestion) = suggest(name, target.params()) {
+        format!("unsupported argument `{name}`; did you mean `{suggestion}`?")
+    } else if target
+        .other()
+        .params()
+        .iter()
+        .any(|Param(s, _)| s.to_string() == name)
+    {
+        format!(
+            "`{name}` is only supported by `{}`, not `{}`",
+            target.other().name(),
+            target.name(),
+        )
+    } else {
+        format!(
+            "unsupported argument `{name}`; supported arguments are: {}",
+            target
+                .params()
+                .iter()
+                .map(|Param(s, _)| format!("`{s}`"))
+                .collect::<Vec<_>>()
+                .join(", "),
+        )
+    };
+
+    syn::Error::new_spanned(tokens, message)
+}
+
+/// Finds the supported parameter most likely meant by an unsupported name
+fn suggest(name: &str, supported: &[Param]) -> Option<Symbol> {
+    let is_supported = |symbol: &Symbol| supported.iter().any(|Param(s, _)| s == symbol);
+
+    if let Some((_, symbol)) = MISTAKES
+        .iter()
+        .find(|(mistake, symbol)| *mistake == name && is_supported(symbol))
+    {
+        return Some(*symbol);
+    }
+
+    let threshold = (name.len() / 3).max(1);
+    supported
+        .iter()
+        .map(|Param(s, _)| (edit_distance(name, &s.to_string()), *s))
+        .filter(|(distance, _)| *distance <= threshold)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, symbol)| symbol)
+}
+
+/// The Levenshtein distance between two names
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if ca == cb {
+                diagonal
+            } else {
+                1 + diagonal.min(above).min(row[j])
+            };
+            diagonal = above;
+        }
+    }
+
+    row[b.len()]
+}
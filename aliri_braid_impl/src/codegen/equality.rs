@@ -0,0 +1,155 @@
+use quote::{quote, ToTokens};
+
+use super::StdLib;
+
+/// How braid values are compared for equality, hashing, and ordering
+///
+/// Case-insensitive braids compare both forms through a helper in
+/// `aliri_braid`, keeping `Eq`, `Hash`, and `Ord` consistent with one another.
+/// As a `str` would hash and compare differently, `Borrow<str>` is not
+/// implemented for such braids.
+///
+/// Braids with omitted equality generate no comparisons at all, leaving the
+/// consumer to implement `PartialEq` and `Eq`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Equality {
+    #[default]
+    Exact,
+    AsciiCaseInsensitive,
+    UnicodeCaseInsensitive,
+    Omit,
+}
+
+impl std::str::FromStr for Equality {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "exact" => Ok(Self::Exact),
+            "ascii_case_insensitive" => Ok(Self::AsciiCaseInsensitive),
+            "unicode_case_insensitive" => Ok(Self::UnicodeCaseInsensitive),
+            "omit" => Ok(Self::Omit),
+            _ => Err("valid values are: `exact`, `ascii_case_insensitive`, \
+                      `unicode_case_insensitive`, or `omit`"),
+        }
+    }
+}
+
+impl Equality {
+    pub fn is_exact(self) -> bool {
+        self == Self::Exact
+    }
+
+    pub fn is_omitted(self) -> bool {
+        self == Self::Omit
+    }
+
+    fn helper(self, std_lib: &StdLib) -> Option<proc_macro2::TokenStream> {
+        let braid = std_lib.braid();
+        let helper = match self {
+            Self::Exact | Self::Omit => return None,
+            Self::AsciiCaseInsensitive => quote! { AsciiCaseInsensitive },
+            Self::UnicodeCaseInsensitive => quote! { UnicodeCaseInsensitive },
+        };
+
+        Some(quote! { <#braid::__private::#helper as #braid::__private::CaseInsensitive> })
+    }
+
+    /// The derives placed on each generated struct, including `Hash` if it is
+    /// generated
+    pub fn derives(self, hash: bool) -> Option<proc_macro2::TokenStream> {
+        match (self.is_exact(), hash) {
+            (false, _) => None,
+            (true, true) => Some(quote! { #[derive(Hash, PartialEq, Eq)] }),
+            (true, false) => Some(quote! { #[derive(PartialEq, Eq)] }),
+        }
+    }
+
+    /// Compares two string slices for equality
+    pub fn eq(
+        self,
+        a: proc_macro2::TokenStream,
+        b: proc_macro2::TokenStream,
+        std_lib: &StdLib,
+    ) -> proc_macro2::TokenStream {
+        match self.helper(std_lib) {
+            Some(helper) => quote! { #helper::eq(#a, #b) },
+            None => quote! { #a == #b },
+        }
+    }
+
+    /// Implements `PartialEq`, `Eq`, and, if it is generated, `Hash` for a type
+    /// in place of the derives
+    ///
+    /// `as_str` produces the string slice of the value it is given.
+    pub fn impls(
+        self,
+        ty: &impl ToTokens,
+        as_str: impl Fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream,
+        hash: bool,
+        std_lib: &StdLib,
+    ) -> Option<proc_macro2::TokenStream> {
+        let helper = self.helper(std_lib)?;
+        let core = std_lib.core();
+        let this = as_str(quote! { self });
+        let other = as_str(quote! { other });
+        let hash = hash.then(|| {
+            quote! {
+                #[automatically_derived]
+                impl ::#core::hash::Hash for #ty {
+                    #[inline]
+                    fn hash<H: ::#core::hash::Hasher>(&self, state: &mut H) {
+                        #helper::hash(#this, state)
+                    }
+                }
+            }
+        });
+
+        Some(quote! {
+            #[automatically_derived]
+            impl ::#core::cmp::PartialEq for #ty {
+                #[inline]
+                fn eq(&self, other: &Self) -> bool {
+                    #helper::eq(#this, #other)
+                }
+            }
+
+            #[automatically_derived]
+            impl ::#core::cmp::Eq for #ty {}
+
+            #hash
+        })
+    }
+
+    /// Implements `PartialOrd` and `Ord` for a type consistently with its
+    /// equality
+    pub fn ord_impls(
+        self,
+        ty: &impl ToTokens,
+        as_str: impl Fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream,
+        std_lib: &StdLib,
+    ) -> Option<proc_macro2::TokenStream> {
+        let helper = self.helper(std_lib)?;
+        let core = std_lib.core();
+        let this = as_str(quote! { self });
+        let other = as_str(quote! { other });
+
+        Some(quote! {
+            #[automatically_derived]
+            impl ::#core::cmp::Ord for #ty {
+                #[inline]
+                fn cmp(&self, other: &Self) -> ::#core::cmp::Ordering {
+                    #helper::cmp(#this, #other)
+                }
+            }
+
+            #[automatically_derived]
+            impl ::#core::cmp::PartialOrd for #ty {
+                #[inline]
+                fn partial_cmp(&self, other: &Self) -> ::#core::option::Option<::#core::cmp::Ordering> {
+                    ::#core::option::Option::Some(::#core::cmp::Ord::cmp(self, other))
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,123 @@
+use quote::ToTokens;
+
+use super::symbol::{self, parse_expr_as_lit, parse_lit_into_vis};
+
+/// How much of the wrapped value is exposed outside the declaring module
+///
+/// `no_expose` keeps the functions that expose the internal field type
+/// private. `no_expose = "opaque"` additionally keeps `as_str()` private and
+/// omits the impls through which the value could be read as a string slice:
+/// `AsRef<str>`, `Borrow<str>`, `From<Owned> for String`, and the `Braid` and
+/// `BraidRef` traits.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Expose {
+    #[default]
+    Inner,
+    Hidden,
+    Opaque,
+}
+
+impl std::str::FromStr for Expose {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "opaque" => Ok(Self::Opaque),
+            _ => Err("valid values are: `opaque`"),
+        }
+    }
+}
+
+impl Expose {
+    /// Whether functions exposing the internal field type are public
+    pub fn inner(self) -> bool {
+        self == Self::Inner
+    }
+
+    /// Whether the value can be read as a string slice outside the declaring
+    /// module
+    pub fn str(self) -> bool {
+        self != Self::Opaque
+    }
+
+    /// The visibility of functions exposing the internal field type
+    pub fn inner_vis(self) -> Option<proc_macro2::Ident> {
+        self.inner()
+            .then(|| proc_macro2::Ident::new("pub", proc_macro2::Span::call_site()))
+    }
+
+    /// The visibility of `as_str()`
+    pub fn str_vis(self) -> Option<proc_macro2::Ident> {
+        self.str()
+            .then(|| proc_macro2::Ident::new("pub", proc_macro2::Span::call_site()))
+    }
+}
+
+/// The visibility given to the generated inherent methods in place of `pub`
+///
+/// Methods that are already kept private, such as those hidden by
+/// `no_expose`, are left private. Hidden support methods named with a
+/// leading `__` remain public, as they are called from macros expanded in
+/// other crates.
+#[derive(Default)]
+pub struct MethodVis {
+    vis: Option<syn::Visibility>,
+}
+
+impl MethodVis {
+    pub fn try_set(&mut self, nv: &syn::MetaNameValue) -> Result<(), syn::Error> {
+        if self.vis.is_some() {
+            return Err(syn::Error::new_spanned(
+                nv,
+                format!("{} can only be specified once", symbol::EXPOSE),
+            ));
+        }
+
+        self.vis = Some(parse_lit_into_vis(
+            symbol::EXPOSE,
+            parse_expr_as_lit(&nv.value)?,
+        )?);
+        Ok(())
+    }
+
+    /// Replaces the `pub` visibility of each method in the generated inherent
+    /// impls
+    ///
+    /// Restricted methods may go unused within the crate, so they are allowed
+    /// to be dead code.
+    pub fn apply(&self, tokens: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let vis = match &self.vis {
+            Some(vis) => vis,
+            None => return tokens,
+        };
+
+        let mut file: syn::File = match syn::parse2(tokens) {
+            Ok(file) => file,
+            Err(err) => return err.to_compile_error(),
+        };
+
+        let methods = file
+            .items
+            .iter_mut()
+            .filter_map(|item| match item {
+                syn::Item::Impl(item) if item.trait_.is_none() => Some(&mut item.items),
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|item| match item {
+                syn::ImplItem::Fn(method) => Some(method),
+                _ => None,
+            })
+            .filter(|method| {
+                matches!(method.vis, syn::Visibility::Public(_))
+                    && !method.sig.ident.to_string().starts_with("__")
+            });
+
+        for method in methods {
+            method.vis = vis.clone();
+            method.attrs.push(syn::parse_quote!(#[allow(dead_code)]));
+        }
+
+        file.into_token_stream()
+    }
+}
@@ -0,0 +1,96 @@
+use quote::{quote, ToTokens};
+
+use super::symbol::{self, Symbol};
+
+/// The integrations whose generated impls can be gated behind a feature
+pub const GATEABLE: &[Symbol] = &[
+    symbol::SERDE,
+    symbol::CLAP,
+    symbol::VALUABLE,
+    symbol::DEFMT,
+    symbol::TS_RS,
+    symbol::REDIS,
+    symbol::BSON,
+    symbol::SEA_ORM,
+    symbol::QUICKCHECK,
+];
+
+/// The features named with `feature_gate(...)`, under which the impls of an integration
+/// are generated
+///
+/// The features are those of the crate declaring the braid, so that a library can offer
+/// its integrations as optional features.
+#[derive(Debug, Default)]
+pub struct FeatureGates {
+    gates: Vec<(Symbol, String)>,
+}
+
+impl FeatureGates {
+    pub fn try_extend(&mut self, list: &syn::MetaList) -> Result<(), syn::Error> {
+        let gates = list.parse_args_with(
+            syn::punctuated::Punctuated::<syn::MetaNameValue, syn::Token![,]>::parse_terminated,
+        )?;
+
+        for gate in gates {
+            let Some(&integration) = GATEABLE.iter().find(|s| gate.path == **s) else {
+                return Err(syn::Error::new_spanned(
+                    &gate.path,
+                    format!(
+                        "{} does not support `{}`; valid integrations are: {}",
+                        symbol::FEATURE_GATE,
+                        gate.path.to_token_stream(),
+                        GATEABLE
+                            .iter()
+                            .map(|s| format!("`{s}`"))
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    ),
+                ));
+            };
+
+            if self.gates.iter().any(|(gated, _)| *gated == integration) {
+                return Err(syn::Error::new_spanned(
+                    &gate.path,
+                    format!(
+                        "{} integration `{}` can only be specified once",
+                        symbol::FEATURE_GATE,
+                        gate.path.to_token_stream(),
+                    ),
+                ));
+            }
+
+            let feature = symbol::parse_lit_into_lit_str(
+                symbol::FEATURE_GATE,
+                symbol::parse_expr_as_lit(&gate.value)?,
+            )?;
+            self.gates.push((integration, feature.value()));
+        }
+
+        Ok(())
+    }
+
+    /// Places each item generated for an integration behind its feature, if it has one
+    pub fn gate(
+        &self,
+        integration: Symbol,
+        tokens: Option<proc_macro2::TokenStream>,
+    ) -> Option<proc_macro2::TokenStream> {
+        let tokens = tokens?;
+        let Some((_, feature)) = self.gates.iter().find(|(gated, _)| *gated == integration) else {
+            return Some(tokens);
+        };
+
+        let file: syn::File = match syn::parse2(tokens) {
+            Ok(file) => file,
+            Err(err) => return Some(err.to_compile_error()),
+        };
+        let items = file.items.iter();
+
+        Some(quote! {
+            #(
+                #[cfg(feature = #feature)]
+                #items
+            )*
+        })
+    }
+}
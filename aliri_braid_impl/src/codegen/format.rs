@@ -0,0 +1,151 @@
+use quote::quote;
+
+use super::{check_mode::IndefiniteCheckMode, constraints::Constraints, symbol, StdLib};
+
+/// A well-known textual format with a built-in validator or normalizer
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Uuid,
+    Hostname,
+    Email,
+    Uri,
+    Idna,
+}
+
+impl std::str::FromStr for Format {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "uuid" => Ok(Self::Uuid),
+            "hostname" => Ok(Self::Hostname),
+            "email" => Ok(Self::Email),
+            "uri" => Ok(Self::Uri),
+            "idna" => Ok(Self::Idna),
+            _ => Err("valid values are: `uuid`, `hostname`, `email`, `uri`, or `idna`"),
+        }
+    }
+}
+
+impl Format {
+    /// Rejects formats whose validator or normalizer requires a feature of
+    /// aliri_braid that is not enabled
+    pub fn ensure_enabled(self) -> Result<(), String> {
+        let feature = match self {
+            Self::Uuid if !cfg!(feature = "uuid") => "uuid",
+            Self::Uri if !cfg!(feature = "uri") => "uri",
+            Self::Idna if !cfg!(feature = "idna") => "idna",
+            _ => return Ok(()),
+        };
+
+        Err(format!(
+            "{} = \"{}\" requires the `{}` feature of aliri_braid",
+            symbol::FORMAT,
+            feature,
+            feature
+        ))
+    }
+
+    /// Sets the built-in validator or normalizer for the format as the check
+    /// mode of the braid
+    pub fn try_apply(
+        self,
+        check_mode: &mut IndefiniteCheckMode,
+        std_lib: &StdLib,
+    ) -> Result<(), String> {
+        let braid = std_lib.braid();
+        match self {
+            Self::Uuid => {
+                check_mode.try_set_normalizer(Some(syn::parse_quote!(#braid::UuidFormat)))
+            }
+            Self::Hostname => {
+                check_mode.try_set_validator(Some(syn::parse_quote!(#braid::HostnameFormat)))
+            }
+            Self::Email => {
+                check_mode.try_set_validator(Some(syn::parse_quote!(#braid::EmailFormat)))
+            }
+            Self::Uri => check_mode.try_set_validator(Some(syn::parse_quote!(#braid::UriFormat))),
+            Self::Idna => {
+                check_mode.try_set_normalizer(Some(syn::parse_quote!(#braid::IdnaFormat)))
+            }
+        }
+    }
+
+    /// Rejects declarative constraints on formats with conversions from a
+    /// structured type, as the constraints could reject the canonical form of
+    /// a value and so prevent those conversions from being infallible
+    pub fn ensure_unconstrained(
+        self,
+        span: proc_macro2::Span,
+        constraints: &Constraints,
+    ) -> Result<(), syn::Error> {
+        if self == Self::Uuid && !constraints.is_empty() {
+            return Err(syn::Error::new(
+                span,
+                format!(
+                    "{} = \"uuid\" cannot be combined with declarative constraints",
+                    symbol::FORMAT
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Generates conversions between the braid and the structured type, for
+    /// formats that have one
+    pub fn tokens(
+        self,
+        owned_ty: &syn::Ident,
+        ref_ty: &syn::Type,
+        std_lib: &StdLib,
+    ) -> Option<proc_macro2::TokenStream> {
+        let core = std_lib.core();
+        let braid = std_lib.braid();
+
+        match self {
+            Self::Hostname | Self::Email | Self::Uri | Self::Idna => None,
+            Self::Uuid => {
+                let uuid = quote! { #braid::__private::Uuid };
+                let error = quote! { #braid::__private::UuidError };
+
+                Some(quote! {
+                    #[automatically_derived]
+                    impl ::#core::convert::From<#uuid> for #owned_ty {
+                        #[inline]
+                        fn from(uuid: #uuid) -> Self {
+                            let mut buffer = #uuid::encode_buffer();
+                            let canonical: &str = uuid.hyphenated().encode_lower(&mut buffer);
+                            match ::#core::str::FromStr::from_str(canonical) {
+                                ::#core::result::Result::Ok(value) => value,
+                                ::#core::result::Result::Err(_) => {
+                                    ::#core::unreachable!("canonical UUID text is always valid")
+                                }
+                            }
+                        }
+                    }
+
+                    #[automatically_derived]
+                    impl ::#core::convert::TryFrom<&'_ #ref_ty> for #uuid {
+                        type Error = #error;
+
+                        #[inline]
+                        fn try_from(value: &#ref_ty) -> ::#core::result::Result<Self, Self::Error> {
+                            #uuid::try_parse(value.as_str())
+                        }
+                    }
+
+                    #[automatically_derived]
+                    impl ::#core::convert::TryFrom<&'_ #owned_ty> for #uuid {
+                        type Error = #error;
+
+                        #[inline]
+                        fn try_from(value: &#owned_ty) -> ::#core::result::Result<Self, Self::Error> {
+                            #uuid::try_parse(value.as_str())
+                        }
+                    }
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,311 @@
+use quote::{quote, ToTokens};
+use syn::visit_mut::{self, VisitMut};
+
+use super::StdLib;
+
+/// The standard traits that are derived on the owned and borrowed types
+const DERIVED_TRAITS: &[&str] = &["Clone", "Hash", "PartialEq", "Eq", "PartialOrd", "Ord"];
+
+/// Rejects generic parameters that cannot be threaded through the generated items
+///
+/// Lifetime parameters are not supported, as the generated impls introduce lifetimes of their
+/// own and a borrowed form could not outlive them.
+pub fn ensure_supported(generics: &syn::Generics) -> Result<(), syn::Error> {
+    match generics.lifetimes().next() {
+        Some(lifetime) => Err(syn::Error::new_spanned(
+            lifetime,
+            "lifetime parameters are not supported on braids",
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Threads the generic parameters of a braid through its generated items
+///
+/// The generated items name the owned and borrowed types without any arguments. For generic
+/// braids, every mention of those types is given the braid's parameters as arguments, and
+/// every impl that mentions them is made generic over those parameters. Derives of the
+/// standard comparison traits are replaced with impls that compare the fields directly, as a
+/// derive would require every type parameter to implement the trait, even though the
+/// parameters only appear in `PhantomData`.
+pub struct GenericBraid<'a> {
+    pub generics: &'a syn::Generics,
+    pub owned_ty: &'a syn::Ident,
+    pub ref_ty: &'a syn::Ident,
+    pub std_lib: &'a StdLib,
+}
+
+impl GenericBraid<'_> {
+    pub fn apply(&self, tokens: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let mut file: syn::File = match syn::parse2(tokens) {
+            Ok(file) => file,
+            Err(err) => return err.to_compile_error(),
+        };
+
+        let mut derived = Vec::new();
+        for item in &mut file.items {
+            if let syn::Item::Struct(item) = item {
+                if item.ident == *self.ref_ty {
+                    item.generics = self.generics.clone();
+                }
+
+                if item.ident == *self.owned_ty || item.ident == *self.ref_ty {
+                    derived.extend(self.replace_derives(item));
+                }
+            }
+        }
+
+        Mentions {
+            braid: self,
+            found: false,
+        }
+        .visit_file_mut(&mut file);
+
+        file.items.extend(derived);
+        file.into_token_stream()
+    }
+
+    fn is_braid_type(&self, ident: &syn::Ident) -> bool {
+        ident == self.owned_ty || ident == self.ref_ty
+    }
+
+    /// The braid's parameters as arguments, in turbofish form so that they are valid in both
+    /// type and expression position
+    fn arguments(&self) -> syn::AngleBracketedGenericArguments {
+        let args = self.generics.params.iter().map(|param| match param {
+            syn::GenericParam::Type(param) => {
+                syn::GenericArgument::Type(syn::Type::Verbatim(param.ident.to_token_stream()))
+            }
+            syn::GenericParam::Const(param) => {
+                syn::GenericArgument::Type(syn::Type::Verbatim(param.ident.to_token_stream()))
+            }
+            syn::GenericParam::Lifetime(param) => {
+                syn::GenericArgument::Lifetime(param.lifetime.clone())
+            }
+        });
+
+        syn::AngleBracketedGenericArguments {
+            colon2_token: Some(Default::default()),
+            lt_token: Default::default(),
+            args: args.collect(),
+            gt_token: Default::default(),
+        }
+    }
+
+    /// Adds the braid's parameters to those of an impl
+    fn merge_into(&self, generics: &mut syn::Generics) {
+        let mut braid_params = self.generics.params.clone();
+        for param in &mut braid_params {
+            match param {
+                syn::GenericParam::Type(param) => {
+                    param.eq_token = None;
+                    param.default = None;
+                }
+                syn::GenericParam::Const(param) => {
+                    param.eq_token = None;
+                    param.default = None;
+                }
+                syn::GenericParam::Lifetime(_) => {}
+            }
+        }
+
+        let (lifetimes, others): (Vec<_>, Vec<_>) = std::mem::take(&mut generics.params)
+            .into_iter()
+            .partition(|param| matches!(param, syn::GenericParam::Lifetime(_)));
+        generics.params = lifetimes
+            .into_iter()
+            .chain(others)
+            .chain(braid_params)
+            .collect();
+        if generics.lt_token.is_none() {
+            generics.lt_token = Some(Default::default());
+            generics.gt_token = Some(Default::default());
+        }
+
+        if let Some(where_clause) = &self.generics.where_clause {
+            generics
+                .make_where_clause()
+                .predicates
+                .extend(where_clause.predicates.iter().cloned());
+        }
+    }
+
+    /// Requires the braid's type parameters to be `'static` on inherent methods that deal in
+    /// `'static` references, such as `from_static()`, as such references can only be formed
+    /// when the parameters outlive them
+    fn bound_static_methods(&self, items: &mut [syn::ImplItem]) {
+        let params: Vec<_> = self.generics.type_params().map(|p| &p.ident).collect();
+        for item in items {
+            if let syn::ImplItem::Fn(item) = item {
+                if item.sig.to_token_stream().to_string().contains("'static") {
+                    let where_clause = item.sig.generics.make_where_clause();
+                    for param in &params {
+                        where_clause
+                            .predicates
+                            .push(syn::parse_quote! { #param: 'static });
+                    }
+                }
+            }
+        }
+    }
+
+    /// Removes the standard trait derives from a struct, returning equivalent impls
+    fn replace_derives(&self, item: &mut syn::ItemStruct) -> Vec<syn::Item> {
+        let mut traits = Vec::new();
+
+        item.attrs.retain_mut(|attr| {
+            if !attr.path().is_ident("derive") {
+                return true;
+            }
+
+            let Ok(paths) = attr.parse_args_with(
+                syn::punctuated::Punctuated::<syn::Path, syn::Token![,]>::parse_terminated,
+            ) else {
+                return true;
+            };
+
+            let (derived, kept): (Vec<_>, Vec<_>) = paths.into_iter().partition(|path| {
+                path.get_ident()
+                    .is_some_and(|ident| DERIVED_TRAITS.iter().any(|t| ident == t))
+            });
+            traits.extend(derived);
+
+            if kept.is_empty() {
+                false
+            } else {
+                *attr = syn::parse_quote! { #[derive(#(#kept),*)] };
+                true
+            }
+        });
+
+        traits
+            .iter()
+            .filter_map(|path| path.get_ident())
+            .map(|ident| self.derive(item, ident))
+            .collect()
+    }
+
+    fn derive(&self, item: &syn::ItemStruct, trait_name: &syn::Ident) -> syn::Item {
+        let core = self.std_lib.core();
+        let ident = &item.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let members: Vec<_> = item.fields.members().collect();
+
+        let body = match trait_name.to_string().as_str() {
+            "Clone" => {
+                let values = members
+                    .iter()
+                    .map(|m| quote! { #m: ::#core::clone::Clone::clone(&self.#m) });
+                quote! {
+                    #[inline]
+                    fn clone(&self) -> Self {
+                        Self { #(#values),* }
+                    }
+                }
+            }
+            "Hash" => quote! {
+                #[inline]
+                fn hash<H: ::#core::hash::Hasher>(&self, state: &mut H) {
+                    #(::#core::hash::Hash::hash(&self.#members, state);)*
+                }
+            },
+            "PartialEq" => quote! {
+                #[inline]
+                fn eq(&self, other: &Self) -> bool {
+                    true #(&& ::#core::cmp::PartialEq::eq(&self.#members, &other.#members))*
+                }
+            },
+            "Eq" => quote! {},
+            "PartialOrd" => quote! {
+                #[inline]
+                fn partial_cmp(&self, other: &Self) -> ::#core::option::Option<::#core::cmp::Ordering> {
+                    #(
+                        match ::#core::cmp::PartialOrd::partial_cmp(&self.#members, &other.#members) {
+                            ::#core::option::Option::Some(::#core::cmp::Ordering::Equal) => {}
+                            cmp => return cmp,
+                        }
+                    )*
+                    ::#core::option::Option::Some(::#core::cmp::Ordering::Equal)
+                }
+            },
+            _ => quote! {
+                #[inline]
+                fn cmp(&self, other: &Self) -> ::#core::cmp::Ordering {
+                    #(
+                        match ::#core::cmp::Ord::cmp(&self.#members, &other.#members) {
+                            ::#core::cmp::Ordering::Equal => {}
+                            cmp => return cmp,
+                        }
+                    )*
+                    ::#core::cmp::Ordering::Equal
+                }
+            },
+        };
+
+        let trait_path = match trait_name.to_string().as_str() {
+            "Clone" => quote! { ::#core::clone::Clone },
+            "Hash" => quote! { ::#core::hash::Hash },
+            _ => quote! { ::#core::cmp::#trait_name },
+        };
+
+        syn::parse_quote! {
+            #[automatically_derived]
+            impl #impl_generics #trait_path for #ident #ty_generics #where_clause {
+                #body
+            }
+        }
+    }
+}
+
+/// Adds the braid's parameters to mentions of the braid's types and to the impls containing
+/// them
+struct Mentions<'a> {
+    braid: &'a GenericBraid<'a>,
+    found: bool,
+}
+
+impl VisitMut for Mentions<'_> {
+    fn visit_item_impl_mut(&mut self, item: &mut syn::ItemImpl) {
+        let outer = std::mem::replace(&mut self.found, false);
+        visit_mut::visit_item_impl_mut(self, item);
+        if self.found {
+            self.braid.merge_into(&mut item.generics);
+            if item.trait_.is_none() {
+                self.braid.bound_static_methods(&mut item.items);
+            }
+        }
+        self.found = outer;
+    }
+
+    fn visit_path_mut(&mut self, path: &mut syn::Path) {
+        if path.leading_colon.is_none() {
+            if let Some(first) = path.segments.first_mut() {
+                if first.arguments.is_none() && self.braid.is_braid_type(&first.ident) {
+                    first.arguments = syn::PathArguments::AngleBracketed(self.braid.arguments());
+                    self.found = true;
+                }
+            }
+        }
+
+        visit_mut::visit_path_mut(self, path);
+    }
+
+    fn visit_macro_mut(&mut self, mac: &mut syn::Macro) {
+        if mac
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "stringify")
+        {
+            return;
+        }
+
+        let parser = syn::punctuated::Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated;
+        if let Ok(mut args) = mac.parse_body_with(parser) {
+            for arg in &mut args {
+                self.visit_expr_mut(arg);
+            }
+            mac.tokens = args.into_token_stream();
+        }
+    }
+}
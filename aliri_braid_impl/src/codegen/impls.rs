@@ -1,6 +1,6 @@
 use quote::{quote, ToTokens};
 
-use super::{check_mode::CheckMode, OwnedCodeGen, RefCodeGen};
+use super::{check_mode::CheckMode, feature_gate::FeatureGates, OwnedCodeGen, RefCodeGen, StdLib};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ImplOption {
@@ -89,7 +89,28 @@ pub struct Impls {
     pub debug: ImplDebug,
     pub display: ImplDisplay,
     pub ord: ImplOrd,
+    pub hash: ImplHash,
     pub serde: ImplSerde,
+    pub clap: ImplClap,
+    pub valuable: ImplValuable,
+    pub defmt: ImplDefmt,
+    pub ts_rs: ImplTsRs,
+    pub redis: ImplRedis,
+    pub bson: ImplBson,
+    pub sea_orm: ImplSeaOrm,
+    pub quickcheck: ImplQuickcheck,
+    pub gates: FeatureGates,
+}
+
+impl Impls {
+    /// Settles options that depend on one another once all parameters have been parsed
+    pub fn resolve(&mut self) {
+        // BSON distinguishes strings from binary data, so the owned type must not be
+        // serialized through a wrapped type that might serialize as bytes
+        if self.bson.0 == ImplOption::Implement {
+            self.serde.1 = SerializeVia::Str;
+        }
+    }
 }
 
 pub(crate) trait ToImpl {
@@ -163,9 +184,10 @@ impl ToImpl for ImplDisplay {
             quote! {
                 #[automatically_derived]
                 impl ::#core::fmt::Display for #ty {
+                    // Padded like `str`, so that width, fill, alignment, and precision are honored
                     #[inline]
                     fn fmt(&self, f: &mut ::#core::fmt::Formatter) -> ::#core::fmt::Result {
-                        <str as ::#core::fmt::Display>::fmt(&self.#field_name, f)
+                        f.pad(&self.#field_name)
                     }
                 }
             }
@@ -225,17 +247,110 @@ impl ToImpl for ImplDebug {
 }
 
 #[derive(Debug)]
-pub struct ImplOrd(DelegatingImplOption);
+pub struct ImplOrd(DelegatingImplOption, OrderBy);
+
+/// How values are ordered by the generated `Ord` implementations
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OrderBy {
+    /// Orders values by their exact contents
+    Exact,
+    /// Orders values ignoring case, falling back to their exact contents
+    CaseInsensitive,
+    /// Orders runs of digits by their numeric value, falling back to their
+    /// exact contents
+    Natural,
+}
+
+impl std::str::FromStr for OrderBy {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "case_insensitive" => Ok(Self::CaseInsensitive),
+            "natural" => Ok(Self::Natural),
+            _ => Err("valid values are: `case_insensitive` or `natural`"),
+        }
+    }
+}
+
+impl ImplOrd {
+    /// Whether values are ordered by something other than their exact contents
+    pub fn is_custom(&self) -> bool {
+        self.1 != OrderBy::Exact
+    }
+
+    /// Whether no ordering is generated for either form
+    pub fn is_omitted(&self) -> bool {
+        self.0 == DelegatingImplOption::Omit
+    }
+
+    /// The runtime comparison used for a custom ordering
+    fn comparison(&self, braid: &syn::Path) -> Option<proc_macro2::TokenStream> {
+        match self.1 {
+            OrderBy::Exact => None,
+            OrderBy::CaseInsensitive => Some(quote! { #braid::__private::case_insensitive_cmp }),
+            OrderBy::Natural => Some(quote! { #braid::__private::natural_cmp }),
+        }
+    }
+}
 
 impl Default for ImplOrd {
     fn default() -> Self {
-        Self(DelegatingImplOption::Implement)
+        Self(DelegatingImplOption::Implement, OrderBy::Exact)
     }
 }
 
 impl From<DelegatingImplOption> for ImplOrd {
     fn from(opt: DelegatingImplOption) -> Self {
-        Self(opt)
+        Self(opt, OrderBy::Exact)
+    }
+}
+
+impl From<OrderBy> for ImplOrd {
+    fn from(order: OrderBy) -> Self {
+        Self(DelegatingImplOption::Implement, order)
+    }
+}
+
+impl std::str::FromStr for ImplOrd {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<DelegatingImplOption>()
+            .map(Self::from)
+            .or_else(|_| s.parse::<OrderBy>().map(Self::from))
+            .map_err(|_| {
+                "valid values are: `impl`, `owned`, `omit`, `case_insensitive`, or `natural`"
+            })
+    }
+}
+
+/// Implements `Ord` and `PartialOrd` through a runtime comparison of string slices
+fn ord_by(
+    ty: &impl ToTokens,
+    comparison: &proc_macro2::TokenStream,
+    as_str: impl Fn(proc_macro2::TokenStream) -> proc_macro2::TokenStream,
+    core: &proc_macro2::Ident,
+) -> proc_macro2::TokenStream {
+    let this = as_str(quote! { self });
+    let other = as_str(quote! { other });
+
+    quote! {
+        #[automatically_derived]
+        impl ::#core::cmp::Ord for #ty {
+            #[inline]
+            fn cmp(&self, other: &Self) -> ::#core::cmp::Ordering {
+                #comparison(#this, #other)
+            }
+        }
+
+        #[automatically_derived]
+        impl ::#core::cmp::PartialOrd for #ty {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> ::#core::option::Option<::#core::cmp::Ordering> {
+                ::#core::option::Option::Some(::#core::cmp::Ord::cmp(self, other))
+            }
+        }
     }
 }
 
@@ -244,6 +359,20 @@ impl ToImpl for ImplOrd {
         let ty = &gen.ty;
         let field_name = &gen.field.name;
         let core = gen.std_lib.core();
+        if !gen.equality.is_exact() {
+            return self.0.map_owned(|| {
+                gen.equality
+                    .ord_impls(ty, |v| quote! { #v.as_str() }, gen.std_lib)
+                    .unwrap_or_default()
+            });
+        }
+
+        if let Some(comparison) = self.comparison(gen.std_lib.braid()) {
+            return self
+                .0
+                .map_owned(|| ord_by(ty, &comparison, |v| quote! { #v.as_str() }, core));
+        }
+
         self.0.map_owned(|| quote! {
             #[automatically_derived]
             impl ::#core::cmp::Ord for #ty {
@@ -263,23 +392,262 @@ impl ToImpl for ImplOrd {
         })
     }
 
-    fn to_borrowed_impl(&self, _gen: &RefCodeGen) -> Option<proc_macro2::TokenStream> {
+    fn to_borrowed_impl(&self, gen: &RefCodeGen) -> Option<proc_macro2::TokenStream> {
+        if !gen.equality.is_exact() {
+            let field_name = &gen.field.name;
+            return self.0.map_ref(|| {
+                gen.equality
+                    .ord_impls(&gen.ty, |v| quote! { &#v.#field_name }, gen.std_lib)
+                    .unwrap_or_default()
+            });
+        }
+
+        if let Some(comparison) = self.comparison(gen.std_lib.braid()) {
+            let field_name = &gen.field.name;
+            let core = gen.std_lib.core();
+            return self
+                .0
+                .map_ref(|| ord_by(&gen.ty, &comparison, |v| quote! { &#v.#field_name }, core));
+        }
+
         self.0.map_ref(|| quote! { #[derive(PartialOrd, Ord)] })
     }
 }
 
+/// How `Hash` is implemented for both forms
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ImplHash {
+    /// Hashes values by their contents, as their string slice would be hashed
+    #[default]
+    Implement,
+    /// Neither form implements `Hash`
+    Omit,
+    /// The user implements `Hash` for both forms
+    Custom,
+}
+
+impl ImplHash {
+    /// Whether `Hash` is generated alongside the equality implementations
+    pub fn is_generated(self) -> bool {
+        self == Self::Implement
+    }
+
+    /// Whether values are known to hash as their string slice would
+    ///
+    /// A user-provided `Hash` may hash a canonical form instead, so `Borrow<str>` is not
+    /// implemented for braids with a custom `Hash`.
+    pub fn matches_str(self) -> bool {
+        self != Self::Custom
+    }
+}
+
+impl std::str::FromStr for ImplHash {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "impl" => Ok(Self::Implement),
+            "omit" => Ok(Self::Omit),
+            "custom" => Ok(Self::Custom),
+            _ => Err("valid values are: `impl`, `omit`, or `custom`"),
+        }
+    }
+}
+
 #[derive(Debug)]
-pub struct ImplSerde(ImplOption);
+pub struct ImplSerde(ImplOption, SerializeVia);
+
+/// How the owned type is serialized
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SerializeVia {
+    /// Delegates to the `Serialize` implementation of the wrapped type
+    Wrapped,
+    /// Serializes the value as a string slice, regardless of the wrapped type
+    Str,
+    /// Serializes the value as a string slice in human-readable formats, and as bytes otherwise
+    Bytes,
+}
+
+impl ImplSerde {
+    /// Enables the implementations, keeping any choice of how the owned type is serialized
+    pub fn enable(&mut self) {
+        self.0 = ImplOption::Implement;
+    }
+
+    /// Serializes the value as a string slice in human-readable formats, and as bytes otherwise
+    fn serialize_bytes() -> proc_macro2::TokenStream {
+        quote! {
+            if ::serde::Serializer::is_human_readable(&serializer) {
+                ::serde::Serializer::serialize_str(serializer, self.as_str())
+            } else {
+                ::serde::Serializer::serialize_bytes(serializer, self.as_str().as_bytes())
+            }
+        }
+    }
+
+    /// Deserializes into an existing owned value, reusing its allocation where possible
+    ///
+    /// A field declared as a `String` is overwritten only once the incoming value has been checked,
+    /// so that a failure leaves the existing value in place. Other fields can only be
+    /// deserialized in place when there are no invariants to uphold, and otherwise fall back to
+    /// the default implementation, which replaces the value.
+    fn deserialize_in_place(&self, gen: &OwnedCodeGen) -> Option<proc_macro2::TokenStream> {
+        let core = gen.std_lib.core();
+        let alloc = gen.std_lib.alloc();
+        let field_name = &gen.field.name;
+        let wrapped_type = &gen.field.ty;
+
+        if !gen.field.is_string() {
+            return match (gen.check_mode, self.1) {
+                (CheckMode::None, SerializeVia::Wrapped | SerializeVia::Str) => Some(quote! {
+                    fn deserialize_in_place<D: ::serde::Deserializer<'de>>(deserializer: D, place: &mut Self) -> ::#core::result::Result<(), D::Error> {
+                        <#wrapped_type as ::serde::Deserialize<'de>>::deserialize_in_place(deserializer, &mut place.#field_name)
+                    }
+                }),
+                _ => None,
+            };
+        }
+
+        let ref_ty = gen.ref_ty;
+        let from_str = gen.constructor.borrowed();
+
+        // The check yields a replacement when the value must be normalized. It cannot name the
+        // braid types within the visitor, as those may be generic.
+        let check = match gen.check_mode {
+            CheckMode::None => quote! {
+                ::#core::result::Result::Ok(::#core::option::Option::None)
+            },
+            CheckMode::Validate(_) => quote! {
+                match #ref_ty::#from_str(raw) {
+                    ::#core::result::Result::Ok(_) => ::#core::result::Result::Ok(::#core::option::Option::None),
+                    ::#core::result::Result::Err(err) => ::#core::result::Result::Err(::#alloc::string::ToString::to_string(&err)),
+                }
+            },
+            CheckMode::Normalize(_) => quote! {
+                match #ref_ty::#from_str(raw) {
+                    ::#core::result::Result::Ok(::#alloc::borrow::Cow::Borrowed(_)) => ::#core::result::Result::Ok(::#core::option::Option::None),
+                    ::#core::result::Result::Ok(::#alloc::borrow::Cow::Owned(normalized)) => ::#core::result::Result::Ok(::#core::option::Option::Some(normalized.#field_name)),
+                    ::#core::result::Result::Err(err) => ::#core::result::Result::Err(::#alloc::string::ToString::to_string(&err)),
+                }
+            },
+        };
+
+        let read = quote! { ::serde::Deserializer::deserialize_string(deserializer, visitor) };
+        let read = if self.1 == SerializeVia::Bytes {
+            quote! {
+                if ::serde::Deserializer::is_human_readable(&deserializer) {
+                    #read
+                } else {
+                    ::serde::Deserializer::deserialize_byte_buf(deserializer, visitor)
+                }
+            }
+        } else {
+            read
+        };
+
+        Some(quote! {
+            fn deserialize_in_place<D: ::serde::Deserializer<'de>>(deserializer: D, place: &mut Self) -> ::#core::result::Result<(), D::Error> {
+                struct InPlaceVisitor<'a, F>(&'a mut #wrapped_type, F);
+
+                impl<'de, 'a, F> ::serde::de::Visitor<'de> for InPlaceVisitor<'a, F>
+                where
+                    F: ::#core::ops::FnOnce(&str) -> ::#core::result::Result<::#core::option::Option<#wrapped_type>, ::#alloc::string::String>,
+                {
+                    type Value = ();
+
+                    fn expecting(&self, f: &mut ::#core::fmt::Formatter) -> ::#core::fmt::Result {
+                        f.write_str("a string")
+                    }
+
+                    fn visit_str<E: ::serde::de::Error>(self, v: &str) -> ::#core::result::Result<Self::Value, E> {
+                        match (self.1)(v).map_err(E::custom)? {
+                            ::#core::option::Option::None => {
+                                self.0.clear();
+                                self.0.push_str(v);
+                            }
+                            ::#core::option::Option::Some(normalized) => *self.0 = normalized,
+                        }
+                        ::#core::result::Result::Ok(())
+                    }
+
+                    fn visit_string<E: ::serde::de::Error>(self, v: ::#alloc::string::String) -> ::#core::result::Result<Self::Value, E> {
+                        *self.0 = match (self.1)(&v).map_err(E::custom)? {
+                            ::#core::option::Option::None => ::#core::convert::From::from(v),
+                            ::#core::option::Option::Some(normalized) => normalized,
+                        };
+                        ::#core::result::Result::Ok(())
+                    }
+
+                    fn visit_bytes<E: ::serde::de::Error>(self, v: &[u8]) -> ::#core::result::Result<Self::Value, E> {
+                        match ::#core::str::from_utf8(v) {
+                            ::#core::result::Result::Ok(s) => self.visit_str(s),
+                            ::#core::result::Result::Err(_) => ::#core::result::Result::Err(E::invalid_value(::serde::de::Unexpected::Bytes(v), &self)),
+                        }
+                    }
+
+                    fn visit_byte_buf<E: ::serde::de::Error>(self, v: ::#alloc::vec::Vec<u8>) -> ::#core::result::Result<Self::Value, E> {
+                        match ::#alloc::string::String::from_utf8(v) {
+                            ::#core::result::Result::Ok(s) => self.visit_string(s),
+                            ::#core::result::Result::Err(e) => ::#core::result::Result::Err(E::invalid_value(::serde::de::Unexpected::Bytes(e.as_bytes()), &self)),
+                        }
+                    }
+                }
+
+                let visitor = InPlaceVisitor(&mut place.#field_name, |raw: &str| -> ::#core::result::Result<::#core::option::Option<#wrapped_type>, ::#alloc::string::String> {
+                    #check
+                });
+                #read
+            }
+        })
+    }
+
+    /// Reads a borrowed string slice, which is read from borrowed bytes in formats that are not
+    /// human-readable when serializing as bytes
+    fn deserialize_borrowed_str(&self, core: &proc_macro2::Ident) -> proc_macro2::TokenStream {
+        let deserialize =
+            quote! { <&str as ::serde::Deserialize<'de>>::deserialize(deserializer)? };
+
+        if self.1 != SerializeVia::Bytes {
+            return deserialize;
+        }
+
+        quote! {
+            if ::serde::Deserializer::is_human_readable(&deserializer) {
+                #deserialize
+            } else {
+                let bytes = <&[u8] as ::serde::Deserialize<'de>>::deserialize(deserializer)?;
+                ::#core::str::from_utf8(bytes).map_err(|_| {
+                    <D::Error as ::serde::de::Error>::invalid_value(::serde::de::Unexpected::Bytes(bytes), &"a UTF-8 byte string")
+                })?
+            }
+        }
+    }
+}
 
 impl Default for ImplSerde {
     fn default() -> Self {
-        Self(ImplOption::Omit)
+        Self(ImplOption::Omit, SerializeVia::Wrapped)
     }
 }
 
 impl From<ImplOption> for ImplSerde {
     fn from(opt: ImplOption) -> Self {
-        Self(opt)
+        Self(opt, SerializeVia::Wrapped)
+    }
+}
+
+impl std::str::FromStr for ImplSerde {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "str" => Ok(Self(ImplOption::Implement, SerializeVia::Str)),
+            "bytes" => Ok(Self(ImplOption::Implement, SerializeVia::Bytes)),
+            _ => s
+                .parse::<ImplOption>()
+                .map(Self::from)
+                .map_err(|_| "valid values are: `impl`, `str`, `bytes`, or `omit`"),
+        }
     }
 }
 
@@ -287,26 +655,99 @@ impl ToImpl for ImplSerde {
     fn to_owned_impl(&self, gen: &OwnedCodeGen) -> Option<proc_macro2::TokenStream> {
         self.0.map(|| {
             let handle_failure = gen.check_mode.serde_err_handler();
+            let core = gen.std_lib.core();
+            let new = gen.constructor.owned();
 
             let name = gen.ty;
             let field_name = &gen.field.name;
             let wrapped_type = &gen.field.ty;
 
+            let serialize = match self.1 {
+                SerializeVia::Wrapped => quote! {
+                    <#wrapped_type as ::serde::Serialize>::serialize(&self.#field_name, serializer)
+                },
+                SerializeVia::Str => quote! {
+                    ::serde::Serializer::serialize_str(serializer, self.as_str())
+                },
+                SerializeVia::Bytes => Self::serialize_bytes(),
+            };
+
+            let deserialize = quote! {
+                let raw = <#wrapped_type as ::serde::Deserialize<'de>>::deserialize(deserializer)?;
+                ::#core::result::Result::Ok(Self::#new(raw)#handle_failure)
+            };
+
+            let deserialize_in_place = self.deserialize_in_place(gen);
+
+            let deserialize = if self.1 == SerializeVia::Bytes {
+                let alloc = gen.std_lib.alloc();
+                let from_string = match gen.check_mode {
+                    CheckMode::None => quote! {
+                        <Self as ::#core::convert::From<::#alloc::string::String>>::from(raw)
+                    },
+                    _ => quote! {
+                        <Self as ::#core::convert::TryFrom<::#alloc::string::String>>::try_from(raw)#handle_failure
+                    },
+                };
+
+                quote! {
+                    if ::serde::Deserializer::is_human_readable(&deserializer) {
+                        #deserialize
+                    } else {
+                        struct BytesVisitor;
+
+                        impl<'de> ::serde::de::Visitor<'de> for BytesVisitor {
+                            type Value = ::#alloc::string::String;
+
+                            fn expecting(&self, f: &mut ::#core::fmt::Formatter) -> ::#core::fmt::Result {
+                                f.write_str("a UTF-8 byte string")
+                            }
+
+                            fn visit_bytes<E: ::serde::de::Error>(self, v: &[u8]) -> ::#core::result::Result<Self::Value, E> {
+                                match ::#core::str::from_utf8(v) {
+                                    ::#core::result::Result::Ok(s) => ::#core::result::Result::Ok(::#alloc::borrow::ToOwned::to_owned(s)),
+                                    ::#core::result::Result::Err(_) => ::#core::result::Result::Err(E::invalid_value(::serde::de::Unexpected::Bytes(v), &self)),
+                                }
+                            }
+
+                            fn visit_byte_buf<E: ::serde::de::Error>(self, v: ::#alloc::vec::Vec<u8>) -> ::#core::result::Result<Self::Value, E> {
+                                ::#alloc::string::String::from_utf8(v)
+                                    .map_err(|e| E::invalid_value(::serde::de::Unexpected::Bytes(e.as_bytes()), &self))
+                            }
+
+                            fn visit_str<E: ::serde::de::Error>(self, v: &str) -> ::#core::result::Result<Self::Value, E> {
+                                ::#core::result::Result::Ok(::#alloc::borrow::ToOwned::to_owned(v))
+                            }
+
+                            fn visit_string<E: ::serde::de::Error>(self, v: ::#alloc::string::String) -> ::#core::result::Result<Self::Value, E> {
+                                ::#core::result::Result::Ok(v)
+                            }
+                        }
+
+                        let raw = ::serde::Deserializer::deserialize_byte_buf(deserializer, BytesVisitor)?;
+                        ::#core::result::Result::Ok(#from_string)
+                    }
+                }
+            } else {
+                deserialize
+            };
+
             quote! {
                 #[automatically_derived]
                 impl ::serde::Serialize for #name {
-                    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-                        <#wrapped_type as ::serde::Serialize>::serialize(&self.#field_name, serializer)
+                    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> ::#core::result::Result<S::Ok, S::Error> {
+                        #serialize
                     }
                 }
 
                 #[allow(clippy::needless_question_mark, clippy::unsafe_derive_deserialize)]
                 #[automatically_derived]
                 impl<'de> ::serde::Deserialize<'de> for #name {
-                    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-                        let raw = <#wrapped_type as ::serde::Deserialize<'de>>::deserialize(deserializer)?;
-                        Ok(Self::new(raw)#handle_failure)
+                    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> ::#core::result::Result<Self, D::Error> {
+                        #deserialize
                     }
+
+                    #deserialize_in_place
                 }
             }
         })
@@ -318,21 +759,155 @@ impl ToImpl for ImplSerde {
             let check_mode = gen.check_mode;
             let core = gen.std_lib.core();
             let alloc = gen.std_lib.alloc();
+            let from_str = gen.constructor.borrowed();
 
             let handle_failure = check_mode.serde_err_handler();
 
-            let deserialize_boxed = gen.owned_ty.map(|owned_ty| {
-                quote! {
+            // An existing owned type named with `owned` cannot be relied upon to box up the borrowed
+            // form, so the boxed form is only deserialized through owned types generated alongside,
+            // or directly from a boxed string when requested with `alloc`
+            let deserialize_boxed = match (&gen.owned_ty, gen.owned_field) {
+                (Some(owned_ty), Some(_)) => Some(quote! {
                     #[automatically_derived]
                     impl<'de> ::serde::Deserialize<'de> for ::#alloc::boxed::Box<#ty> {
                         fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> ::#core::result::Result<Self, D::Error> {
                             let owned = <#owned_ty as ::serde::Deserialize<'de>>::deserialize(deserializer)?;
-                            ::#core::result::Result::Ok(owned.into_boxed_ref())
+                            ::#core::result::Result::Ok(::#core::convert::From::from(owned))
+                        }
+                    }
+                }),
+                (_, None) if gen.alloc => {
+                    let safety_comment = gen.pointer_reinterpret_safety_comment(true);
+                    let check = handle_failure.as_ref().map(|handle_failure| {
+                        quote! { #ty::#from_str(&boxed)#handle_failure; }
+                    });
+                    Some(quote! {
+                        #[automatically_derived]
+                        impl<'de> ::serde::Deserialize<'de> for ::#alloc::boxed::Box<#ty> {
+                            #[allow(unsafe_code)]
+                            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> ::#core::result::Result<Self, D::Error> {
+                                let boxed = <::#alloc::boxed::Box<str> as ::serde::Deserialize<'de>>::deserialize(deserializer)?;
+                                #check
+                                #safety_comment
+                                ::#core::result::Result::Ok(unsafe { ::#alloc::boxed::Box::from_raw(::#alloc::boxed::Box::into_raw(boxed) as *mut #ty) })
+                            }
+                        }
+                    })
+                }
+                _ => None,
+            };
+
+            let deserialize_cow = gen.owned_ty.as_ref().map(|owned_ty| {
+                let (borrowed, owned) = match check_mode {
+                    // An owned type reached through a conversion function can only be created
+                    // from a checked borrowed value
+                    CheckMode::None | CheckMode::Validate(_) if gen.to_owned_fn.is_some() => (
+                        quote! { ::#alloc::borrow::Cow::Borrowed(#ty::#from_str(raw)#handle_failure) },
+                        quote! { ::#alloc::borrow::Cow::Owned(::#alloc::borrow::ToOwned::to_owned(#ty::#from_str(&raw)#handle_failure)) },
+                    ),
+                    CheckMode::Normalize(_) if gen.to_owned_fn.is_some() => (
+                        quote! { #ty::#from_str(raw)#handle_failure },
+                        quote! { ::#alloc::borrow::Cow::Owned(::#alloc::borrow::Cow::into_owned(#ty::#from_str(&raw)#handle_failure)) },
+                    ),
+                    CheckMode::None => (
+                        quote! { ::#alloc::borrow::Cow::Borrowed(#ty::#from_str(raw)) },
+                        quote! { ::#alloc::borrow::Cow::Owned(<#owned_ty as ::#core::convert::From<_>>::from(raw)) },
+                    ),
+                    CheckMode::Validate(_) => (
+                        quote! { ::#alloc::borrow::Cow::Borrowed(#ty::#from_str(raw)#handle_failure) },
+                        quote! { ::#alloc::borrow::Cow::Owned(<#owned_ty as ::#core::convert::TryFrom<_>>::try_from(raw)#handle_failure) },
+                    ),
+                    CheckMode::Normalize(_) => (
+                        quote! { #ty::#from_str(raw)#handle_failure },
+                        quote! { ::#alloc::borrow::Cow::Owned(<#owned_ty as ::#core::convert::TryFrom<_>>::try_from(raw)#handle_failure) },
+                    ),
+                };
+
+                let (visit_bytes, read_cow) = if self.1 == SerializeVia::Bytes {
+                    let visit_bytes = quote! {
+                        fn visit_borrowed_bytes<E: ::serde::de::Error>(self, v: &'de [u8]) -> ::#core::result::Result<Self::Value, E> {
+                            match ::#core::str::from_utf8(v) {
+                                ::#core::result::Result::Ok(s) => ::#core::result::Result::Ok(::#alloc::borrow::Cow::Borrowed(s)),
+                                ::#core::result::Result::Err(_) => ::#core::result::Result::Err(E::invalid_value(::serde::de::Unexpected::Bytes(v), &self)),
+                            }
+                        }
+
+                        fn visit_bytes<E: ::serde::de::Error>(self, v: &[u8]) -> ::#core::result::Result<Self::Value, E> {
+                            match ::#core::str::from_utf8(v) {
+                                ::#core::result::Result::Ok(s) => ::#core::result::Result::Ok(::#alloc::borrow::Cow::Owned(::#alloc::borrow::ToOwned::to_owned(s))),
+                                ::#core::result::Result::Err(_) => ::#core::result::Result::Err(E::invalid_value(::serde::de::Unexpected::Bytes(v), &self)),
+                            }
+                        }
+
+                        fn visit_byte_buf<E: ::serde::de::Error>(self, v: ::#alloc::vec::Vec<u8>) -> ::#core::result::Result<Self::Value, E> {
+                            ::#alloc::string::String::from_utf8(v)
+                                .map(::#alloc::borrow::Cow::Owned)
+                                .map_err(|e| E::invalid_value(::serde::de::Unexpected::Bytes(e.as_bytes()), &self))
+                        }
+                    };
+                    let read_cow = quote! {
+                        if ::serde::Deserializer::is_human_readable(&deserializer) {
+                            ::serde::Deserializer::deserialize_str(deserializer, CowStrVisitor)?
+                        } else {
+                            ::serde::Deserializer::deserialize_bytes(deserializer, CowStrVisitor)?
+                        }
+                    };
+                    (Some(visit_bytes), read_cow)
+                } else {
+                    (None, quote! { ::serde::Deserializer::deserialize_str(deserializer, CowStrVisitor)? })
+                };
+
+                let doc = format!(
+                    "Deserializes a [`Cow<{ty}>`][{alloc}::borrow::Cow], borrowing from the input \
+                    where possible\n\
+                    \n\
+                    The `Deserialize` implementation for `Cow` provided by `serde` always produces an \
+                    owned value. This function can be used with `#[serde(deserialize_with = \"...\")]` \
+                    to avoid that allocation when the deserializer can lend out the string.",
+                    ty = ty.to_token_stream(),
+                );
+
+                quote! {
+                    #[automatically_derived]
+                    impl #ty {
+                        #[doc = #doc]
+                        #[allow(clippy::needless_question_mark)]
+                        pub fn deserialize_cow<'de: 'a, 'a, D: ::serde::Deserializer<'de>>(deserializer: D) -> ::#core::result::Result<::#alloc::borrow::Cow<'a, #ty>, D::Error> {
+                            struct CowStrVisitor;
+
+                            impl<'de> ::serde::de::Visitor<'de> for CowStrVisitor {
+                                type Value = ::#alloc::borrow::Cow<'de, str>;
+
+                                fn expecting(&self, f: &mut ::#core::fmt::Formatter) -> ::#core::fmt::Result {
+                                    f.write_str("a string")
+                                }
+
+                                fn visit_borrowed_str<E: ::serde::de::Error>(self, v: &'de str) -> ::#core::result::Result<Self::Value, E> {
+                                    ::#core::result::Result::Ok(::#alloc::borrow::Cow::Borrowed(v))
+                                }
+
+                                fn visit_str<E: ::serde::de::Error>(self, v: &str) -> ::#core::result::Result<Self::Value, E> {
+                                    ::#core::result::Result::Ok(::#alloc::borrow::Cow::Owned(::#alloc::borrow::ToOwned::to_owned(v)))
+                                }
+
+                                fn visit_string<E: ::serde::de::Error>(self, v: ::#alloc::string::String) -> ::#core::result::Result<Self::Value, E> {
+                                    ::#core::result::Result::Ok(::#alloc::borrow::Cow::Owned(v))
+                                }
+
+                                #visit_bytes
+                            }
+
+                            match #read_cow {
+                                ::#alloc::borrow::Cow::Borrowed(raw) => ::#core::result::Result::Ok(#borrowed),
+                                ::#alloc::borrow::Cow::Owned(raw) => ::#core::result::Result::Ok(#owned),
+                            }
                         }
                     }
                 }
             });
 
+            let read_str = self.deserialize_borrowed_str(core);
+
             let deserialize = if matches!(check_mode, CheckMode::Normalize(_)) {
                 let deserialize_doc = format!(
                     "Deserializes a `{ty}` in normalized form\n\
@@ -341,7 +916,7 @@ impl ToImpl for ImplSerde {
                     If values may require normalization, then deserialized as [`{owned}`] or \
                     [`Cow<{ty}>`][{alloc}::borrow::Cow] instead.",
                     ty = ty.to_token_stream(),
-                    owned = gen.owned_ty.expect("normalize not available if no owned").to_token_stream(),
+                    owned = gen.owned_ty.as_ref().expect("normalize not available if no owned").to_token_stream(),
                 );
 
                 quote! {
@@ -357,7 +932,7 @@ impl ToImpl for ImplSerde {
                     #[automatically_derived]
                     impl<'de: 'a, 'a> ::serde::Deserialize<'de> for &'a #ty {
                         fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> ::#core::result::Result<Self, D::Error> {
-                            let raw = <&str as ::serde::Deserialize<'de>>::deserialize(deserializer)?;
+                            let raw = #read_str;
                             ::#core::result::Result::Ok(#ty::from_normalized_str(raw)#handle_failure)
                         }
                     }
@@ -368,23 +943,600 @@ impl ToImpl for ImplSerde {
                     #[automatically_derived]
                     impl<'de: 'a, 'a> ::serde::Deserialize<'de> for &'a #ty {
                         fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> ::#core::result::Result<Self, D::Error> {
-                            let raw = <&str as ::serde::Deserialize<'de>>::deserialize(deserializer)?;
-                            ::#core::result::Result::Ok(#ty::from_str(raw)#handle_failure)
+                            let raw = #read_str;
+                            ::#core::result::Result::Ok(#ty::#from_str(raw)#handle_failure)
                         }
                     }
                 }
             };
 
+            let serialize = if self.1 == SerializeVia::Bytes {
+                Self::serialize_bytes()
+            } else {
+                quote! { <str as ::serde::Serialize>::serialize(self.as_str(), serializer) }
+            };
+
             quote! {
                 #[automatically_derived]
                 impl ::serde::Serialize for #ty {
                     fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> ::#core::result::Result<S::Ok, S::Error> {
-                        <str as ::serde::Serialize>::serialize(self.as_str(), serializer)
+                        #serialize
                     }
                 }
 
                 #deserialize
                 #deserialize_boxed
+                #deserialize_cow
+            }
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct ImplClap(ImplOption);
+
+impl Default for ImplClap {
+    fn default() -> Self {
+        Self(ImplOption::Omit)
+    }
+}
+
+impl From<ImplOption> for ImplClap {
+    fn from(opt: ImplOption) -> Self {
+        Self(opt)
+    }
+}
+
+impl ToImpl for ImplClap {
+    fn to_owned_impl(&self, gen: &OwnedCodeGen) -> Option<proc_macro2::TokenStream> {
+        self.0.map(|| {
+            let ty = gen.ty;
+            let core = gen.std_lib.core();
+
+            quote! {
+                #[automatically_derived]
+                impl ::clap::builder::ValueParserFactory for #ty {
+                    type Parser = ::clap::builder::ValueParser;
+
+                    fn value_parser() -> Self::Parser {
+                        ::clap::builder::ValueParser::new(|raw: &str| {
+                            <Self as ::#core::str::FromStr>::from_str(raw)
+                        })
+                    }
+                }
+            }
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct ImplValuable(ImplOption);
+
+impl Default for ImplValuable {
+    fn default() -> Self {
+        Self(ImplOption::Omit)
+    }
+}
+
+impl From<ImplOption> for ImplValuable {
+    fn from(opt: ImplOption) -> Self {
+        Self(opt)
+    }
+}
+
+impl ImplValuable {
+    fn tokens(&self, ty: &impl ToTokens) -> Option<proc_macro2::TokenStream> {
+        self.0.map(|| {
+            quote! {
+                #[automatically_derived]
+                impl ::valuable::Valuable for #ty {
+                    #[inline]
+                    fn as_value(&self) -> ::valuable::Value<'_> {
+                        ::valuable::Value::String(self.as_str())
+                    }
+
+                    #[inline]
+                    fn visit(&self, visit: &mut dyn ::valuable::Visit) {
+                        visit.visit_value(::valuable::Valuable::as_value(self));
+                    }
+                }
+            }
+        })
+    }
+}
+
+impl ToImpl for ImplValuable {
+    fn to_owned_impl(&self, gen: &OwnedCodeGen) -> Option<proc_macro2::TokenStream> {
+        self.tokens(gen.ty)
+    }
+
+    fn to_borrowed_impl(&self, gen: &RefCodeGen) -> Option<proc_macro2::TokenStream> {
+        self.tokens(&gen.ty)
+    }
+}
+
+#[derive(Debug)]
+pub struct ImplDefmt(ImplOption);
+
+impl Default for ImplDefmt {
+    fn default() -> Self {
+        Self(ImplOption::Omit)
+    }
+}
+
+impl From<ImplOption> for ImplDefmt {
+    fn from(opt: ImplOption) -> Self {
+        Self(opt)
+    }
+}
+
+impl ImplDefmt {
+    fn tokens(&self, ty: &impl ToTokens) -> Option<proc_macro2::TokenStream> {
+        self.0.map(|| {
+            quote! {
+                #[automatically_derived]
+                impl ::defmt::Format for #ty {
+                    #[inline]
+                    fn format(&self, f: ::defmt::Formatter<'_>) {
+                        ::defmt::write!(f, "{=str}", self.as_str())
+                    }
+                }
+            }
+        })
+    }
+}
+
+impl ToImpl for ImplDefmt {
+    fn to_owned_impl(&self, gen: &OwnedCodeGen) -> Option<proc_macro2::TokenStream> {
+        self.tokens(gen.ty)
+    }
+
+    fn to_borrowed_impl(&self, gen: &RefCodeGen) -> Option<proc_macro2::TokenStream> {
+        self.tokens(&gen.ty)
+    }
+}
+
+#[derive(Debug)]
+pub struct ImplTsRs(ImplOption);
+
+impl Default for ImplTsRs {
+    fn default() -> Self {
+        Self(ImplOption::Omit)
+    }
+}
+
+impl From<ImplOption> for ImplTsRs {
+    fn from(opt: ImplOption) -> Self {
+        Self(opt)
+    }
+}
+
+impl ImplTsRs {
+    /// Declares the braid as a TypeScript alias of `string`, named after the given type
+    fn declaration(
+        ty: &impl ToTokens,
+        name: &syn::Ident,
+        std_lib: &StdLib,
+    ) -> proc_macro2::TokenStream {
+        let core = std_lib.core();
+        let alloc = std_lib.alloc();
+        let docs = format!("/**\n * Rust type `{name}`\n */\n");
+        let decl = format!("type {name} = string;");
+        let output_path = format!("{name}.ts");
+        let name = name.to_string();
+
+        quote! {
+            #[automatically_derived]
+            impl ::ts_rs::TS for #ty {
+                type WithoutGenerics = Self;
+                type OptionInnerType = Self;
+
+                fn docs() -> ::#core::option::Option<::#alloc::string::String> {
+                    ::#core::option::Option::Some(::#alloc::borrow::ToOwned::to_owned(#docs))
+                }
+
+                fn name() -> ::#alloc::string::String {
+                    ::#alloc::borrow::ToOwned::to_owned(#name)
+                }
+
+                fn inline() -> ::#alloc::string::String {
+                    ::#alloc::borrow::ToOwned::to_owned("string")
+                }
+
+                fn inline_flattened() -> ::#alloc::string::String {
+                    ::#core::panic!("{} cannot be flattened", #name)
+                }
+
+                fn decl() -> ::#alloc::string::String {
+                    ::#alloc::borrow::ToOwned::to_owned(#decl)
+                }
+
+                fn decl_concrete() -> ::#alloc::string::String {
+                    ::#alloc::borrow::ToOwned::to_owned(#decl)
+                }
+
+                fn output_path() -> ::#core::option::Option<::std::path::PathBuf> {
+                    ::#core::option::Option::Some(::std::path::PathBuf::from(#output_path))
+                }
+            }
+        }
+    }
+}
+
+impl ToImpl for ImplTsRs {
+    fn to_owned_impl(&self, gen: &OwnedCodeGen) -> Option<proc_macro2::TokenStream> {
+        self.0
+            .map(|| Self::declaration(gen.ty, gen.ty, gen.std_lib))
+    }
+
+    fn to_borrowed_impl(&self, gen: &RefCodeGen) -> Option<proc_macro2::TokenStream> {
+        self.0.map(|| {
+            let ty = &gen.ty;
+            let owned_ty = match &gen.owned_ty {
+                Some(owned_ty) => owned_ty,
+                None => return Self::declaration(ty, &gen.ident, gen.std_lib),
+            };
+            let core = gen.std_lib.core();
+            let alloc = gen.std_lib.alloc();
+
+            quote! {
+                #[automatically_derived]
+                impl ::ts_rs::TS for #ty {
+                    type WithoutGenerics = #owned_ty;
+                    type OptionInnerType = Self;
+
+                    fn docs() -> ::#core::option::Option<::#alloc::string::String> {
+                        <#owned_ty as ::ts_rs::TS>::docs()
+                    }
+
+                    fn name() -> ::#alloc::string::String {
+                        <#owned_ty as ::ts_rs::TS>::name()
+                    }
+
+                    fn inline() -> ::#alloc::string::String {
+                        <#owned_ty as ::ts_rs::TS>::inline()
+                    }
+
+                    fn inline_flattened() -> ::#alloc::string::String {
+                        <#owned_ty as ::ts_rs::TS>::inline_flattened()
+                    }
+
+                    fn decl() -> ::#alloc::string::String {
+                        <#owned_ty as ::ts_rs::TS>::decl()
+                    }
+
+                    fn decl_concrete() -> ::#alloc::string::String {
+                        <#owned_ty as ::ts_rs::TS>::decl_concrete()
+                    }
+
+                    fn output_path() -> ::#core::option::Option<::std::path::PathBuf> {
+                        <#owned_ty as ::ts_rs::TS>::output_path()
+                    }
+                }
+            }
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct ImplRedis(ImplOption);
+
+impl Default for ImplRedis {
+    fn default() -> Self {
+        Self(ImplOption::Omit)
+    }
+}
+
+impl From<ImplOption> for ImplRedis {
+    fn from(opt: ImplOption) -> Self {
+        Self(opt)
+    }
+}
+
+impl ImplRedis {
+    fn to_redis_args(
+        generics: proc_macro2::TokenStream,
+        ty: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        quote! {
+            #[automatically_derived]
+            impl #generics ::redis::ToRedisArgs for #ty {
+                #[inline]
+                fn write_redis_args<W>(&self, out: &mut W)
+                where
+                    W: ?Sized + ::redis::RedisWrite,
+                {
+                    out.write_arg(self.as_str().as_bytes());
+                }
+            }
+
+            #[automatically_derived]
+            impl #generics ::redis::ToSingleRedisArg for #ty {}
+        }
+    }
+}
+
+impl ToImpl for ImplRedis {
+    fn to_owned_impl(&self, gen: &OwnedCodeGen) -> Option<proc_macro2::TokenStream> {
+        self.0.map(|| {
+            let ty = gen.ty;
+            let core = gen.std_lib.core();
+            let alloc = gen.std_lib.alloc();
+            let to_redis_args = Self::to_redis_args(quote! {}, quote! { #ty });
+
+            quote! {
+                #to_redis_args
+
+                #[automatically_derived]
+                impl ::redis::FromRedisValue for #ty {
+                    fn from_redis_value(v: ::redis::Value) -> ::#core::result::Result<Self, ::redis::ParsingError> {
+                        let raw = <::#alloc::string::String as ::redis::FromRedisValue>::from_redis_value(v)?;
+                        <Self as ::#core::str::FromStr>::from_str(&raw)
+                            .map_err(|err| ::redis::ParsingError::from(::#alloc::string::ToString::to_string(&err)))
+                    }
+                }
+            }
+        })
+    }
+
+    fn to_borrowed_impl(&self, gen: &RefCodeGen) -> Option<proc_macro2::TokenStream> {
+        self.0.map(|| {
+            let ty = &gen.ty;
+            Self::to_redis_args(quote! { <'a> }, quote! { &'a #ty })
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct ImplBson(ImplOption);
+
+impl Default for ImplBson {
+    fn default() -> Self {
+        Self(ImplOption::Omit)
+    }
+}
+
+impl From<ImplOption> for ImplBson {
+    fn from(opt: ImplOption) -> Self {
+        Self(opt)
+    }
+}
+
+impl ToImpl for ImplBson {
+    fn to_owned_impl(&self, gen: &OwnedCodeGen) -> Option<proc_macro2::TokenStream> {
+        self.0.map(|| {
+            let ty = gen.ty;
+            let field_name = &gen.field.name;
+            let core = gen.std_lib.core();
+            let braid = gen.std_lib.braid();
+
+            quote! {
+                #[automatically_derived]
+                impl ::#core::convert::From<#ty> for #braid::__private::Bson {
+                    #[inline]
+                    fn from(value: #ty) -> Self {
+                        #braid::__private::Bson::String(::#core::convert::From::from(value.#field_name))
+                    }
+                }
+
+                #[automatically_derived]
+                impl ::#core::convert::TryFrom<#braid::__private::Bson> for #ty {
+                    type Error = #braid::BsonError<<Self as ::#core::str::FromStr>::Err>;
+
+                    fn try_from(value: #braid::__private::Bson) -> ::#core::result::Result<Self, Self::Error> {
+                        match value {
+                            #braid::__private::Bson::String(raw) => {
+                                <Self as ::#core::str::FromStr>::from_str(&raw)
+                                    .map_err(#braid::BsonError::Invalid)
+                            }
+                            other => ::#core::result::Result::Err(
+                                #braid::BsonError::UnexpectedType(other.element_type()),
+                            ),
+                        }
+                    }
+                }
+            }
+        })
+    }
+
+    fn to_borrowed_impl(&self, gen: &RefCodeGen) -> Option<proc_macro2::TokenStream> {
+        self.0.map(|| {
+            let ty = &gen.ty;
+            let core = gen.std_lib.core();
+            let alloc = gen.std_lib.alloc();
+            let braid = gen.std_lib.braid();
+
+            quote! {
+                #[automatically_derived]
+                impl<'a> ::#core::convert::From<&'a #ty> for #braid::__private::Bson {
+                    #[inline]
+                    fn from(value: &'a #ty) -> Self {
+                        #braid::__private::Bson::String(::#alloc::borrow::ToOwned::to_owned(value.as_str()))
+                    }
+                }
+            }
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct ImplSeaOrm(ImplOption);
+
+impl Default for ImplSeaOrm {
+    fn default() -> Self {
+        Self(ImplOption::Omit)
+    }
+}
+
+impl From<ImplOption> for ImplSeaOrm {
+    fn from(opt: ImplOption) -> Self {
+        Self(opt)
+    }
+}
+
+impl ToImpl for ImplSeaOrm {
+    fn to_owned_impl(&self, gen: &OwnedCodeGen) -> Option<proc_macro2::TokenStream> {
+        self.0.map(|| {
+            let ty = gen.ty;
+            let field_name = &gen.field.name;
+            let core = gen.std_lib.core();
+            let alloc = gen.std_lib.alloc();
+            let type_name = ty.to_string();
+
+            quote! {
+                #[automatically_derived]
+                impl ::#core::convert::From<#ty> for ::sea_orm::Value {
+                    #[inline]
+                    fn from(value: #ty) -> Self {
+                        let raw: ::#alloc::string::String = ::#core::convert::From::from(value.#field_name);
+                        ::#core::convert::From::from(raw)
+                    }
+                }
+
+                #[automatically_derived]
+                impl ::sea_orm::sea_query::Nullable for #ty {
+                    #[inline]
+                    fn null() -> ::sea_orm::Value {
+                        <::#alloc::string::String as ::sea_orm::sea_query::Nullable>::null()
+                    }
+                }
+
+                #[automatically_derived]
+                impl ::sea_orm::sea_query::ValueType for #ty {
+                    fn try_from(v: ::sea_orm::Value) -> ::#core::result::Result<Self, ::sea_orm::sea_query::ValueTypeErr> {
+                        let raw = <::#alloc::string::String as ::sea_orm::sea_query::ValueType>::try_from(v)?;
+                        <Self as ::#core::str::FromStr>::from_str(&raw)
+                            .map_err(|_| ::sea_orm::sea_query::ValueTypeErr)
+                    }
+
+                    fn type_name() -> ::#alloc::string::String {
+                        ::#alloc::borrow::ToOwned::to_owned(#type_name)
+                    }
+
+                    fn array_type() -> ::sea_orm::sea_query::ArrayType {
+                        <::#alloc::string::String as ::sea_orm::sea_query::ValueType>::array_type()
+                    }
+
+                    fn column_type() -> ::sea_orm::sea_query::ColumnType {
+                        <::#alloc::string::String as ::sea_orm::sea_query::ValueType>::column_type()
+                    }
+                }
+
+                #[automatically_derived]
+                impl ::sea_orm::TryGetable for #ty {
+                    fn try_get_by<I: ::sea_orm::ColIdx>(
+                        res: &::sea_orm::QueryResult,
+                        index: I,
+                    ) -> ::#core::result::Result<Self, ::sea_orm::TryGetError> {
+                        let raw = <::#alloc::string::String as ::sea_orm::TryGetable>::try_get_by(res, index)?;
+                        <Self as ::#core::str::FromStr>::from_str(&raw).map_err(|err| {
+                            ::sea_orm::TryGetError::DbErr(::sea_orm::DbErr::Type(
+                                ::#alloc::string::ToString::to_string(&err),
+                            ))
+                        })
+                    }
+                }
+
+                #[automatically_derived]
+                impl ::sea_orm::TryFromU64 for #ty {
+                    fn try_from_u64(_: u64) -> ::#core::result::Result<Self, ::sea_orm::DbErr> {
+                        ::#core::result::Result::Err(::sea_orm::DbErr::ConvertFromU64(#type_name))
+                    }
+                }
+            }
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct ImplQuickcheck(ImplOption);
+
+impl Default for ImplQuickcheck {
+    fn default() -> Self {
+        Self(ImplOption::Omit)
+    }
+}
+
+impl From<ImplOption> for ImplQuickcheck {
+    fn from(opt: ImplOption) -> Self {
+        Self(opt)
+    }
+}
+
+impl ImplQuickcheck {
+    /// The number of arbitrary strings generated before giving up on finding a valid value
+    const ATTEMPTS: usize = 100;
+
+    /// The number of shrunken forms of each arbitrary string that are tried in turn, as simpler
+    /// strings are more likely to satisfy constraints such as a maximum length or a character set
+    const SHRINKS: usize = 100;
+}
+
+impl ToImpl for ImplQuickcheck {
+    fn to_owned_impl(&self, gen: &OwnedCodeGen) -> Option<proc_macro2::TokenStream> {
+        self.0.map(|| {
+            let ty = gen.ty;
+            let core = gen.std_lib.core();
+            let alloc = gen.std_lib.alloc();
+
+            let arbitrary = match gen.check_mode {
+                CheckMode::None => quote! {
+                    <Self as ::#core::convert::From<::#alloc::string::String>>::from(
+                        <::#alloc::string::String as ::quickcheck::Arbitrary>::arbitrary(g),
+                    )
+                },
+                _ => {
+                    let attempts = Self::ATTEMPTS;
+                    let shrinks = Self::SHRINKS;
+                    let message = format!(
+                        "unable to generate a valid `{}` from arbitrary strings; consider \
+                         implementing `quickcheck::Arbitrary` by hand",
+                        ty,
+                    );
+
+                    quote! {
+                        for _ in 0..#attempts {
+                            let raw = <::#alloc::string::String as ::quickcheck::Arbitrary>::arbitrary(g);
+                            let candidates = ::#core::iter::Iterator::take(
+                                ::quickcheck::Arbitrary::shrink(&raw),
+                                #shrinks,
+                            );
+                            for candidate in ::#core::iter::once(raw).chain(candidates) {
+                                if let ::#core::result::Result::Ok(value) =
+                                    <Self as ::#core::convert::TryFrom<::#alloc::string::String>>::try_from(candidate)
+                                {
+                                    return value;
+                                }
+                            }
+                        }
+
+                        ::#core::panic!(#message)
+                    }
+                }
+            };
+
+            quote! {
+                #[automatically_derived]
+                impl ::quickcheck::Arbitrary for #ty
+                where
+                    Self: 'static,
+                {
+                    fn arbitrary(g: &mut ::quickcheck::Gen) -> Self {
+                        #arbitrary
+                    }
+
+                    fn shrink(&self) -> ::#alloc::boxed::Box<dyn ::#core::iter::Iterator<Item = Self>> {
+                        let raw = ::#alloc::borrow::ToOwned::to_owned(self.as_str());
+                        let shrunk = ::quickcheck::Arbitrary::shrink(&raw);
+                        ::#alloc::boxed::Box::new(::#core::iter::Iterator::filter_map(shrunk, move |candidate| {
+                            match <Self as ::#core::convert::TryFrom<::#alloc::string::String>>::try_from(candidate) {
+                                // A normalized candidate may be the value being shrunk
+                                ::#core::result::Result::Ok(value) if value.as_str() != raw => ::#core::option::Option::Some(value),
+                                _ => ::#core::option::Option::None,
+                            }
+                        }))
+                    }
+                }
             }
         })
     }
@@ -0,0 +1,789 @@
+//! Braids wrapping a borrowed type other than `str`
+//!
+//! Most of the generated API of a braid assumes that its borrowed form wraps a `str`. When
+//! `ref_inner = "Type"` is given, a reduced set of items is generated instead, relying only on
+//! the standard traits that connect the inner type with the owned field's type. The `path` and
+//! `c_str` flags are shorthand for wrapping `std::path::Path` and `core::ffi::CStr`, with
+//! helpers and checks specific to each.
+
+use quote::{quote, ToTokens};
+use syn::spanned::Spanned;
+
+use super::{
+    check_mode::ident_to_type,
+    get_field_info, infer_ref_type_from_owned_name, parse_crate_path,
+    symbol::{self, parse_expr_as_lit, parse_lit_into_type},
+    AttrList, Field, FieldName, StdLib,
+};
+
+/// The parameters accepted by braids with a borrowed form wrapping a type other than `str`
+pub struct InnerParams {
+    inner: syn::Type,
+    ref_ty: Option<syn::Type>,
+    validator: Option<Option<syn::Type>>,
+    kind: InnerKind,
+    std_lib: StdLib,
+}
+
+/// The kind of borrowed type being wrapped, which determines the extra items generated
+#[derive(Clone, Copy)]
+enum InnerKind {
+    /// An arbitrary type given with `ref_inner`
+    Custom,
+    /// `std::path::Path`, with an optional synthesized check
+    Path(Option<PathCheck>),
+    /// `core::ffi::CStr`
+    CStr,
+}
+
+/// A check synthesized for braids wrapping `std::path::Path`
+#[derive(Clone, Copy)]
+enum PathCheck {
+    Relative,
+    Absolute,
+}
+
+impl InnerParams {
+    pub fn parse(args: AttrList) -> Result<Self, syn::Error> {
+        let mut inner = None;
+        let mut ref_ty = None;
+        let mut validator = None;
+        let mut modes = Vec::new();
+        let mut checks = Vec::new();
+        let mut no_std = None;
+        let mut std_lib = StdLib::default();
+        if let Some(braid) = parse_crate_path(&args)? {
+            std_lib.set_braid(braid);
+        }
+
+        for arg in &args {
+            match arg {
+                syn::Meta::NameValue(nv) if nv.path == symbol::REF_INNER => {
+                    inner = Some(parse_lit_into_type(
+                        symbol::REF_INNER,
+                        parse_expr_as_lit(&nv.value)?,
+                    )?);
+                    modes.push((symbol::REF_INNER, nv.path.span()));
+                }
+                syn::Meta::Path(p) if p == symbol::PATH => {
+                    modes.push((symbol::PATH, p.span()));
+                }
+                syn::Meta::Path(p) if p == symbol::C_STR => {
+                    modes.push((symbol::C_STR, p.span()));
+                }
+                syn::Meta::Path(p) if p == symbol::RELATIVE => {
+                    checks.push((PathCheck::Relative, p.span()));
+                }
+                syn::Meta::Path(p) if p == symbol::ABSOLUTE => {
+                    checks.push((PathCheck::Absolute, p.span()));
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::REF => {
+                    ref_ty = Some(parse_lit_into_type(
+                        symbol::REF,
+                        parse_expr_as_lit(&nv.value)?,
+                    )?);
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::VALIDATOR => {
+                    let ty = parse_lit_into_type(symbol::VALIDATOR, parse_expr_as_lit(&nv.value)?)?;
+                    Self::set_validator(&mut validator, nv, Some(ty))?;
+                }
+                syn::Meta::Path(p) if p == symbol::VALIDATOR => {
+                    Self::set_validator(&mut validator, p, None)?;
+                }
+                syn::Meta::Path(p) if p == symbol::NO_STD => {
+                    no_std = Some(p.span());
+                    std_lib.set_no_std(p.segments[0].ident.span());
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::CRATE => {}
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        arg,
+                        format!(
+                            "`{}` cannot be combined with `{}`, which only supports `{}`, `{}`, \
+                             `{}`, and `{}`",
+                            arg.path().to_token_stream(),
+                            modes.first().map_or(symbol::REF_INNER, |(mode, _)| *mode),
+                            symbol::REF,
+                            symbol::VALIDATOR,
+                            symbol::NO_STD,
+                            symbol::CRATE,
+                        ),
+                    ));
+                }
+            }
+        }
+
+        let mode = match modes.as_slice() {
+            [(mode, _)] => *mode,
+            [(first, _), (second, span), ..] => {
+                return Err(syn::Error::new(
+                    *span,
+                    format!("{} cannot be combined with {}", second, first),
+                ));
+            }
+            [] => unreachable!("inner params require `ref_inner`, `path`, or `c_str`"),
+        };
+
+        if mode != symbol::PATH {
+            if let Some((_, span)) = checks.first() {
+                return Err(syn::Error::new(
+                    *span,
+                    format!(
+                        "`{}` and `{}` can only be used with `{}`",
+                        symbol::RELATIVE,
+                        symbol::ABSOLUTE,
+                        symbol::PATH
+                    ),
+                ));
+            }
+        }
+
+        let core = std_lib.core();
+        let (inner, kind) = if mode == symbol::PATH {
+            if let Some(span) = no_std {
+                return Err(syn::Error::new(
+                    span,
+                    format!(
+                        "{} cannot be combined with {}",
+                        symbol::NO_STD,
+                        symbol::PATH
+                    ),
+                ));
+            }
+
+            let check = Self::path_check(checks, validator.is_some())?;
+            (syn::parse_quote!(::std::path::Path), InnerKind::Path(check))
+        } else if mode == symbol::C_STR {
+            (syn::parse_quote!(::#core::ffi::CStr), InnerKind::CStr)
+        } else {
+            (
+                inner.expect("`ref_inner` always carries a type"),
+                InnerKind::Custom,
+            )
+        };
+
+        Ok(Self {
+            inner,
+            ref_ty,
+            validator,
+            kind,
+            std_lib,
+        })
+    }
+
+    fn path_check(
+        checks: Vec<(PathCheck, proc_macro2::Span)>,
+        has_validator: bool,
+    ) -> Result<Option<PathCheck>, syn::Error> {
+        let mut checks = checks.into_iter();
+        let check = checks.next();
+        if let Some((_, span)) = checks.next() {
+            return Err(syn::Error::new(
+                span,
+                format!(
+                    "only one of `{}` and `{}` can be specified",
+                    symbol::RELATIVE,
+                    symbol::ABSOLUTE
+                ),
+            ));
+        }
+
+        if let (Some((_, span)), true) = (check, has_validator) {
+            return Err(syn::Error::new(
+                span,
+                format!(
+                    "path checks cannot be combined with `{}`; check the path in the validator \
+                     instead",
+                    symbol::VALIDATOR
+                ),
+            ));
+        }
+
+        Ok(check.map(|(check, _)| check))
+    }
+
+    fn set_validator(
+        validator: &mut Option<Option<syn::Type>>,
+        tokens: impl ToTokens,
+        value: Option<syn::Type>,
+    ) -> Result<(), syn::Error> {
+        if validator.is_some() {
+            return Err(syn::Error::new_spanned(
+                tokens,
+                format!("{} can only be specified once", symbol::VALIDATOR),
+            ));
+        }
+
+        *validator = Some(value);
+        Ok(())
+    }
+
+    pub fn build(self, mut body: syn::ItemStruct) -> Result<InnerCodeGen, syn::Error> {
+        if body.fields.is_empty() {
+            let alloc = self.std_lib.alloc();
+            match self.kind {
+                InnerKind::Path(_) => {
+                    body.fields = syn::Fields::Unnamed(syn::parse_quote!((::std::path::PathBuf)));
+                }
+                InnerKind::CStr => {
+                    body.fields = syn::Fields::Unnamed(syn::parse_quote!((::#alloc::ffi::CString)));
+                }
+                InnerKind::Custom => {}
+            }
+        }
+
+        if !body.generics.params.is_empty() {
+            return Err(syn::Error::new_spanned(
+                &body.generics,
+                format!(
+                    "generic braids cannot be combined with `{}`",
+                    symbol::REF_INNER
+                ),
+            ));
+        }
+
+        if body.fields.is_empty() {
+            return Err(syn::Error::new_spanned(
+                &body.ident,
+                format!(
+                    "`{}` requires the owned field to be declared, such as `struct {}(PathBuf)`",
+                    symbol::REF_INNER,
+                    body.ident,
+                ),
+            ));
+        }
+
+        let field = get_field_info(&body.fields)?;
+        if let Some(phantom) = field.phantoms.first() {
+            return Err(syn::Error::new_spanned(
+                &phantom.ty,
+                format!(
+                    "phantom fields cannot be combined with `{}`",
+                    symbol::REF_INNER
+                ),
+            ));
+        }
+
+        let ref_ty = self
+            .ref_ty
+            .unwrap_or_else(|| infer_ref_type_from_owned_name(&body.ident));
+        let has_check = matches!(self.kind, InnerKind::Path(Some(_)));
+        let validator = self
+            .validator
+            .map(|validator| validator.unwrap_or_else(|| ident_to_type(&body.ident)))
+            .or_else(|| has_check.then(|| ident_to_type(&body.ident)));
+
+        Ok(InnerCodeGen {
+            inner: self.inner,
+            ref_ty,
+            validator,
+            kind: self.kind,
+            std_lib: self.std_lib,
+            field,
+            body,
+        })
+    }
+}
+
+pub struct InnerCodeGen {
+    inner: syn::Type,
+    ref_ty: syn::Type,
+    validator: Option<syn::Type>,
+    kind: InnerKind,
+    std_lib: StdLib,
+    field: Field,
+    body: syn::ItemStruct,
+}
+
+impl InnerCodeGen {
+    pub fn generate(&self) -> proc_macro2::TokenStream {
+        let owned = self.owned();
+        let borrowed = self.borrowed();
+        let comparison = self.comparison();
+        let path = self.path_support();
+        let check = self.path_check();
+        let c_str = self.c_str_support();
+
+        super::enclose_impls(quote! {
+            #owned
+            #borrowed
+            #comparison
+            #path
+            #check
+            #c_str
+        })
+    }
+
+    /// Path helpers and conversions for braids wrapping `std::path::Path`
+    fn path_support(&self) -> Option<proc_macro2::TokenStream> {
+        if !matches!(self.kind, InnerKind::Path(_)) {
+            return None;
+        }
+
+        let owned_ty = &self.body.ident;
+        let ref_ty = &self.ref_ty;
+        let field_name = &self.field.name;
+
+        Some(quote! {
+            #[automatically_derived]
+            impl #ref_ty {
+                /// Creates an owned `PathBuf` with `path` adjoined to this path
+                #[inline]
+                pub fn join<P: ::std::convert::AsRef<::std::path::Path>>(&self, path: P) -> ::std::path::PathBuf {
+                    self.#field_name.join(path)
+                }
+
+                /// Returns an object that implements `Display` for safely printing this path,
+                /// which may contain non-Unicode data
+                #[inline]
+                pub fn display(&self) -> ::std::path::Display<'_> {
+                    self.#field_name.display()
+                }
+            }
+
+            #[automatically_derived]
+            impl ::std::convert::AsRef<::std::ffi::OsStr> for #ref_ty {
+                #[inline]
+                fn as_ref(&self) -> &::std::ffi::OsStr {
+                    self.#field_name.as_os_str()
+                }
+            }
+
+            #[automatically_derived]
+            impl ::std::convert::AsRef<::std::ffi::OsStr> for #owned_ty {
+                #[inline]
+                fn as_ref(&self) -> &::std::ffi::OsStr {
+                    #ref_ty::as_inner(self).as_os_str()
+                }
+            }
+        })
+    }
+
+    /// The validator synthesized for a path check
+    fn path_check(&self) -> Option<proc_macro2::TokenStream> {
+        let owned_ty = &self.body.ident;
+        let braid = self.std_lib.braid();
+        let (check, error) = match self.kind {
+            InnerKind::Path(Some(PathCheck::Relative)) => {
+                (quote! { is_relative }, quote! { NotRelative })
+            }
+            InnerKind::Path(Some(PathCheck::Absolute)) => {
+                (quote! { is_absolute }, quote! { NotAbsolute })
+            }
+            _ => return None,
+        };
+
+        Some(quote! {
+            #[automatically_derived]
+            impl #braid::InnerValidator for #owned_ty {
+                type Inner = ::std::path::Path;
+                type Error = #braid::PathError;
+
+                #[inline]
+                fn validate(inner: &::std::path::Path) -> ::std::result::Result<(), Self::Error> {
+                    if inner.#check() {
+                        ::std::result::Result::Ok(())
+                    } else {
+                        ::std::result::Result::Err(#braid::PathError::#error)
+                    }
+                }
+            }
+        })
+    }
+
+    /// FFI helpers and nul-checked conversions for braids wrapping `core::ffi::CStr`
+    fn c_str_support(&self) -> Option<proc_macro2::TokenStream> {
+        if !matches!(self.kind, InnerKind::CStr) {
+            return None;
+        }
+
+        let owned_ty = &self.body.ident;
+        let ref_ty = &self.ref_ty;
+        let field_name = &self.field.name;
+        let core = self.std_lib.core();
+        let alloc = self.std_lib.alloc();
+        let braid = self.std_lib.braid();
+
+        let (error, create) = match self.validator() {
+            Some((validator, _)) => (
+                quote! { #validator::Error },
+                quote! { Self::new(::#core::convert::From::from(inner)).map_err(#braid::CStringError::Invalid) },
+            ),
+            None => (
+                quote! { ::#core::convert::Infallible },
+                quote! { ::#core::result::Result::Ok(Self::new(::#core::convert::From::from(inner))) },
+            ),
+        };
+
+        Some(quote! {
+            #[automatically_derived]
+            impl #ref_ty {
+                /// Returns the inner pointer to this C string, for passing across an FFI boundary
+                ///
+                /// The pointer is valid only as long as this value is borrowed.
+                #[inline]
+                pub const fn as_ptr(&self) -> *const ::#core::ffi::c_char {
+                    self.#field_name.as_ptr()
+                }
+
+                /// Returns the bytes of this C string, without the trailing nul terminator
+                #[inline]
+                pub fn to_bytes(&self) -> &[u8] {
+                    self.#field_name.to_bytes()
+                }
+
+                /// Yields a `&str` if this C string contains valid UTF-8
+                #[inline]
+                pub fn to_str(&self) -> ::#core::result::Result<&str, ::#core::str::Utf8Error> {
+                    self.#field_name.to_str()
+                }
+            }
+
+            #[automatically_derived]
+            impl ::#core::str::FromStr for #owned_ty {
+                type Err = #braid::CStringError<#error>;
+
+                #[inline]
+                fn from_str(s: &str) -> ::#core::result::Result<Self, Self::Err> {
+                    let inner = ::#alloc::ffi::CString::new(s).map_err(#braid::CStringError::Nul)?;
+                    #create
+                }
+            }
+
+            #[automatically_derived]
+            impl ::#core::convert::TryFrom<&'_ str> for #owned_ty {
+                type Error = #braid::CStringError<#error>;
+
+                #[inline]
+                fn try_from(s: &str) -> ::#core::result::Result<Self, Self::Error> {
+                    ::#core::str::FromStr::from_str(s)
+                }
+            }
+        })
+    }
+
+    /// The validator as an `InnerValidator`, along with its name for doc comments
+    fn validator(&self) -> Option<(proc_macro2::TokenStream, String)> {
+        let braid = self.std_lib.braid();
+        self.validator.as_ref().map(|validator| {
+            (
+                quote! { <#validator as #braid::InnerValidator> },
+                validator.to_token_stream().to_string(),
+            )
+        })
+    }
+
+    fn owned(&self) -> proc_macro2::TokenStream {
+        let ty = &self.body.ident;
+        let body = &self.body;
+        let ref_ty = &self.ref_ty;
+        let inner = &self.inner;
+        let field_ty = &self.field.ty;
+        let field_name = &self.field.name;
+        let param = self.field.name.input_name();
+        let create = self.field.constructor(quote! { Self }, &param);
+        let core = self.std_lib.core();
+        let alloc = self.std_lib.alloc();
+
+        let (constructor, conversion) = match self.validator() {
+            Some((validator, name)) => {
+                let doc = format!("Constructs a new {ty} if it conforms to [`{name}`]");
+                (
+                    quote! {
+                        #[doc = #doc]
+                        #[inline]
+                        pub fn new(#param: #field_ty) -> ::#core::result::Result<Self, #validator::Error> {
+                            #validator::validate(::#core::borrow::Borrow::<#inner>::borrow(&#param))?;
+                            ::#core::result::Result::Ok(#create)
+                        }
+                    },
+                    quote! {
+                        #[automatically_derived]
+                        impl ::#core::convert::TryFrom<#field_ty> for #ty {
+                            type Error = #validator::Error;
+
+                            #[inline]
+                            fn try_from(#param: #field_ty) -> ::#core::result::Result<Self, Self::Error> {
+                                Self::new(#param)
+                            }
+                        }
+                    },
+                )
+            }
+            None => {
+                let doc = format!("Constructs a new {ty}");
+                (
+                    quote! {
+                        #[doc = #doc]
+                        #[inline]
+                        pub const fn new(#param: #field_ty) -> Self {
+                            #create
+                        }
+                    },
+                    quote! {
+                        #[automatically_derived]
+                        impl ::#core::convert::From<#field_ty> for #ty {
+                            #[inline]
+                            fn from(#param: #field_ty) -> Self {
+                                Self::new(#param)
+                            }
+                        }
+                    },
+                )
+            }
+        };
+
+        quote! {
+            #[derive(Clone)]
+            #[repr(transparent)]
+            #body
+
+            #[automatically_derived]
+            impl #ty {
+                #constructor
+
+                /// Unwraps the underlying value
+                #[inline]
+                pub fn into_inner(self) -> #field_ty {
+                    self.#field_name
+                }
+            }
+
+            #conversion
+
+            #[automatically_derived]
+            impl ::#core::ops::Deref for #ty {
+                type Target = #ref_ty;
+
+                #[inline]
+                fn deref(&self) -> &Self::Target {
+                    #ref_ty::from_inner_unchecked(::#core::borrow::Borrow::<#inner>::borrow(&self.#field_name))
+                }
+            }
+
+            #[automatically_derived]
+            impl ::#core::borrow::Borrow<#ref_ty> for #ty {
+                #[inline]
+                fn borrow(&self) -> &#ref_ty {
+                    self
+                }
+            }
+
+            #[automatically_derived]
+            impl ::#core::convert::AsRef<#ref_ty> for #ty {
+                #[inline]
+                fn as_ref(&self) -> &#ref_ty {
+                    self
+                }
+            }
+
+            #[automatically_derived]
+            impl ::#core::convert::AsRef<#inner> for #ty {
+                #[inline]
+                fn as_ref(&self) -> &#inner {
+                    #ref_ty::as_inner(self)
+                }
+            }
+
+            #[automatically_derived]
+            impl ::#core::convert::From<&'_ #ref_ty> for #ty {
+                #[inline]
+                fn from(r: &#ref_ty) -> Self {
+                    ::#alloc::borrow::ToOwned::to_owned(r)
+                }
+            }
+
+            #[automatically_derived]
+            impl ::#core::fmt::Debug for #ty {
+                #[inline]
+                fn fmt(&self, f: &mut ::#core::fmt::Formatter) -> ::#core::fmt::Result {
+                    ::#core::fmt::Debug::fmt(&**self, f)
+                }
+            }
+
+            #[automatically_derived]
+            impl ::#core::hash::Hash for #ty {
+                #[inline]
+                fn hash<H: ::#core::hash::Hasher>(&self, state: &mut H) {
+                    ::#core::hash::Hash::hash(&**self, state)
+                }
+            }
+
+            #[automatically_derived]
+            impl ::#core::cmp::PartialEq for #ty {
+                #[inline]
+                fn eq(&self, other: &Self) -> bool {
+                    **self == **other
+                }
+            }
+
+            #[automatically_derived]
+            impl ::#core::cmp::Eq for #ty {}
+
+            #[automatically_derived]
+            impl ::#core::cmp::PartialOrd for #ty {
+                #[inline]
+                fn partial_cmp(&self, other: &Self) -> ::#core::option::Option<::#core::cmp::Ordering> {
+                    ::#core::option::Option::Some(::#core::cmp::Ord::cmp(self, other))
+                }
+            }
+
+            #[automatically_derived]
+            impl ::#core::cmp::Ord for #ty {
+                #[inline]
+                fn cmp(&self, other: &Self) -> ::#core::cmp::Ordering {
+                    ::#core::cmp::Ord::cmp(&**self, &**other)
+                }
+            }
+        }
+    }
+
+    fn borrowed(&self) -> proc_macro2::TokenStream {
+        let ty = &self.ref_ty;
+        let ty_name = ty.to_token_stream().to_string();
+        let owned_ty = &self.body.ident;
+        let vis = &self.body.vis;
+        let attrs = &self.body.attrs;
+        let inner = &self.inner;
+        let field_name = &self.field.name;
+        let field_attrs = &self.field.attrs;
+        let core = self.std_lib.core();
+        let alloc = self.std_lib.alloc();
+        let body = match field_name {
+            FieldName::Named(name) => quote! { { #(#field_attrs)* #name: #inner } },
+            FieldName::Unnamed(_) => quote! { ( #(#field_attrs)* #inner ); },
+        };
+
+        let safety_comment = format!(
+            "SAFETY: `{ty_name}` is `#[repr(transparent)]` around a single `{inner}` field, so a \
+             `*const {inner}` can be safely reinterpreted as a `*const {ty_name}`",
+            inner = inner.to_token_stream(),
+        );
+
+        let constructor = match self.validator() {
+            Some((validator, name)) => {
+                let doc = format!(
+                    "Transparently reinterprets the borrowed value as a strongly-typed {ty_name} \
+                     if it conforms to [`{name}`]"
+                );
+                quote! {
+                    #[doc = #doc]
+                    #[inline]
+                    pub fn from_inner(inner: &#inner) -> ::#core::result::Result<&Self, #validator::Error> {
+                        #validator::validate(inner)?;
+                        ::#core::result::Result::Ok(Self::from_inner_unchecked(inner))
+                    }
+                }
+            }
+            None => {
+                let doc = format!(
+                    "Transparently reinterprets the borrowed value as a strongly-typed {ty_name}"
+                );
+                quote! {
+                    #[doc = #doc]
+                    #[inline]
+                    pub fn from_inner(inner: &#inner) -> &Self {
+                        Self::from_inner_unchecked(inner)
+                    }
+                }
+            }
+        };
+
+        let create = self.field.constructor(
+            owned_ty,
+            quote! { ::#core::convert::From::from(::#alloc::borrow::ToOwned::to_owned(&self.#field_name)) },
+        );
+
+        quote! {
+            #[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+            #[repr(transparent)]
+            #(#attrs)*
+            #vis struct #ty #body
+
+            #[automatically_derived]
+            impl #ty {
+                #constructor
+
+                #[allow(unsafe_code)]
+                #[inline]
+                fn from_inner_unchecked(inner: &#inner) -> &Self {
+                    #[doc = #safety_comment]
+                    fn ptr_safety_comment() {}
+                    unsafe { &*(inner as *const #inner as *const Self) }
+                }
+
+                /// Provides access to the underlying value
+                #[inline]
+                pub const fn as_inner(&self) -> &#inner {
+                    &self.#field_name
+                }
+            }
+
+            #[automatically_derived]
+            impl ::#alloc::borrow::ToOwned for #ty {
+                type Owned = #owned_ty;
+
+                #[inline]
+                fn to_owned(&self) -> Self::Owned {
+                    #create
+                }
+            }
+
+            #[automatically_derived]
+            impl ::#core::convert::AsRef<#inner> for #ty {
+                #[inline]
+                fn as_ref(&self) -> &#inner {
+                    &self.#field_name
+                }
+            }
+
+            #[automatically_derived]
+            impl ::#core::fmt::Debug for #ty {
+                #[inline]
+                fn fmt(&self, f: &mut ::#core::fmt::Formatter) -> ::#core::fmt::Result {
+                    ::#core::fmt::Debug::fmt(&self.#field_name, f)
+                }
+            }
+        }
+    }
+
+    fn comparison(&self) -> proc_macro2::TokenStream {
+        let owned_ty = &self.body.ident;
+        let ref_ty = &self.ref_ty;
+        let core = self.std_lib.core();
+
+        quote! {
+            #[automatically_derived]
+            impl ::#core::cmp::PartialEq<#ref_ty> for #owned_ty {
+                #[inline]
+                fn eq(&self, other: &#ref_ty) -> bool {
+                    **self == *other
+                }
+            }
+
+            #[automatically_derived]
+            impl ::#core::cmp::PartialEq<&'_ #ref_ty> for #owned_ty {
+                #[inline]
+                fn eq(&self, other: &&#ref_ty) -> bool {
+                    **self == **other
+                }
+            }
+
+            #[automatically_derived]
+            impl ::#core::cmp::PartialEq<#owned_ty> for #ref_ty {
+                #[inline]
+                fn eq(&self, other: &#owned_ty) -> bool {
+                    *self == **other
+                }
+            }
+
+            #[automatically_derived]
+            impl ::#core::cmp::PartialEq<#owned_ty> for &'_ #ref_ty {
+                #[inline]
+                fn eq(&self, other: &#owned_ty) -> bool {
+                    **self == **other
+                }
+            }
+        }
+    }
+}
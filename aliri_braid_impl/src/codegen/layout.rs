@@ -0,0 +1,75 @@
+use quote::{quote, ToTokens};
+
+use super::{symbol, StdLib};
+
+/// Asserts during constant evaluation that the braid is laid out like the types it wraps
+///
+/// The conversions between a braid and the types it wraps rely on both types being
+/// `repr(transparent)` over their field. Attributes given through `ref_attr`,
+/// `owned_attr`, or on the fields themselves can silently break that assumption,
+/// such as by adding a second non-zero-sized field, so the assertions catch any
+/// change in size or alignment at compile time.
+pub fn tokens(
+    owned_ty: &syn::Ident,
+    ref_ty: &syn::Type,
+    field_ty: &syn::Type,
+    std_lib: &StdLib,
+) -> proc_macro2::TokenStream {
+    let core = std_lib.core();
+    let ref_name = ref_ty.to_token_stream().to_string();
+    let field_name = field_ty.to_token_stream().to_string();
+    let assertions = [
+        (
+            quote! { ::#core::mem::size_of::<&#ref_ty>() },
+            quote! { ::#core::mem::size_of::<&str>() },
+            format!("`&{}` must have the same size as `&str`", ref_name),
+        ),
+        (
+            quote! { ::#core::mem::align_of::<&#ref_ty>() },
+            quote! { ::#core::mem::align_of::<&str>() },
+            format!("`&{}` must have the same alignment as `&str`", ref_name),
+        ),
+        (
+            quote! { ::#core::mem::size_of::<#owned_ty>() },
+            quote! { ::#core::mem::size_of::<#field_ty>() },
+            format!("`{}` must have the same size as `{}`", owned_ty, field_name),
+        ),
+        (
+            quote! { ::#core::mem::align_of::<#owned_ty>() },
+            quote! { ::#core::mem::align_of::<#field_ty>() },
+            format!(
+                "`{}` must have the same alignment as `{}`",
+                owned_ty, field_name
+            ),
+        ),
+    ];
+    let assertions = assertions.iter().map(|(actual, expected, message)| {
+        quote! {
+            ::#core::assert!(#actual == #expected, #message);
+        }
+    });
+
+    quote! {
+        const _: () = {
+            #(#assertions)*
+        };
+    }
+}
+
+/// Rejects layout assertions on generic braids, as the assertions are evaluated
+/// without any arguments for the braid's parameters
+pub fn ensure_supported(
+    assert_layout: Option<&syn::Path>,
+    generics: &syn::Generics,
+) -> Result<(), syn::Error> {
+    match assert_layout {
+        Some(path) if !generics.params.is_empty() => Err(syn::Error::new_spanned(
+            path,
+            format!(
+                "{} is not supported on generic braids",
+                symbol::ASSERT_LAYOUT
+            ),
+        )),
+        _ => Ok(()),
+    }
+}
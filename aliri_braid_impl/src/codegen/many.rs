@@ -0,0 +1,78 @@
+use super::BraidParams;
+
+/// A batch of braids declared with the same parameters by `braid_many!`
+///
+/// The input is the parameters, as given to `#[braid(...)]`, followed by a semicolon and the
+/// braids to declare, each with its own attributes and visibility.
+pub struct BraidMany {
+    args: proc_macro2::TokenStream,
+    braids: syn::punctuated::Punctuated<Declaration, syn::Token![,]>,
+}
+
+/// A single braid declared by `braid_many!`
+struct Declaration {
+    attrs: Vec<syn::Attribute>,
+    vis: syn::Visibility,
+    ident: syn::Ident,
+}
+
+impl syn::parse::Parse for Declaration {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self, syn::Error> {
+        Ok(Self {
+            attrs: input.call(syn::Attribute::parse_outer)?,
+            vis: input.parse()?,
+            ident: input.parse()?,
+        })
+    }
+}
+
+impl syn::parse::Parse for BraidMany {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self, syn::Error> {
+        let mut args = proc_macro2::TokenStream::new();
+        while !input.peek(syn::Token![;]) {
+            if input.is_empty() {
+                return Err(input.error("expected `;` after the braid parameters"));
+            }
+            args.extend(std::iter::once(input.parse::<proc_macro2::TokenTree>()?));
+        }
+        input.parse::<syn::Token![;]>()?;
+
+        // Checked once up front, so that invalid parameters are not reported for every braid
+        syn::parse2::<BraidParams>(args.clone())?;
+
+        let mut braids = syn::punctuated::Punctuated::new();
+        while !input.is_empty() && !input.peek(syn::Token![;]) {
+            braids.push_value(input.parse()?);
+            if input.is_empty() || input.peek(syn::Token![;]) {
+                break;
+            }
+            braids.push_punct(input.parse()?);
+        }
+        if !input.is_empty() {
+            input.parse::<syn::Token![;]>()?;
+        }
+
+        if braids.is_empty() {
+            return Err(input.error("expected at least one braid to declare"));
+        }
+
+        Ok(Self { args, braids })
+    }
+}
+
+impl BraidMany {
+    pub fn generate(self) -> proc_macro2::TokenStream {
+        let args = self.args;
+        self.braids
+            .into_iter()
+            .map(|Declaration { attrs, vis, ident }| {
+                let body: syn::ItemStruct = syn::parse_quote! {
+                    #(#attrs)*
+                    #vis struct #ident;
+                };
+                syn::parse2::<BraidParams>(args.clone())?.try_generate(body)
+            })
+            .collect::<Result<proc_macro2::TokenStream, _>>()
+            .unwrap_or_else(syn::Error::into_compile_error)
+    }
+}
@@ -1,33 +1,86 @@
-use quote::{format_ident, ToTokens, TokenStreamExt};
-use symbol::{parse_expr_as_lit, parse_lit_into_string, parse_lit_into_type};
+use quote::{format_ident, quote, ToTokens, TokenStreamExt};
+use symbol::{
+    parse_expr_as_lit, parse_lit_into_ident, parse_lit_into_int, parse_lit_into_lit_str,
+    parse_lit_into_path, parse_lit_into_string, parse_lit_into_type,
+};
 use syn::spanned::Spanned;
 
-pub use self::{borrowed::RefCodeGen, owned::OwnedCodeGen};
 use self::{
-    check_mode::{CheckMode, IndefiniteCheckMode},
-    impls::{DelegatingImplOption, ImplOption, Impls},
+    as_path::AsPath,
+    check_mode::{ident_to_type, CheckMode, IndefiniteCheckMode},
+    constraints::{Charset, Constraint, Constraints},
+    constructor::Constructor,
+    convert_from::ConvertFrom,
+    equality::Equality,
+    expose::{Expose, MethodVis},
+    format::Format,
+    impls::{DelegatingImplOption, ImplHash, ImplOption, ImplOrd, ImplSerde, Impls, OrderBy},
+    inner::InnerParams,
+    must_use::MustUse,
+    naming::Naming,
+    omit::Omit,
+    parse_as::ParseAs,
+    same_repr::SameRepr,
+    segments::Segments,
+    unchecked::Unchecked,
 };
+pub use self::{borrowed::RefCodeGen, many::BraidMany, owned::OwnedCodeGen};
 
+mod as_path;
 mod borrowed;
 mod check_mode;
+mod constraints;
+mod constructor;
+mod convert_from;
+mod diagnostics;
+mod equality;
+mod expose;
+mod feature_gate;
+mod format;
+mod generic;
 mod impls;
+mod inner;
+mod layout;
+mod many;
+mod must_use;
+mod naming;
+mod omit;
 mod owned;
+mod parse_as;
+mod registry;
+mod same_repr;
+mod segments;
 mod symbol;
+mod unchecked;
 
 pub type AttrList = syn::punctuated::Punctuated<syn::Meta, syn::Token![,]>;
 
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct StdLib {
     core: proc_macro2::Ident,
     alloc: proc_macro2::Ident,
+    braid: syn::Path,
 }
 
 impl StdLib {
-    pub fn no_std(span: proc_macro2::Span) -> Self {
-        Self {
-            core: proc_macro2::Ident::new("core", span),
-            alloc: proc_macro2::Ident::new("alloc", span),
-        }
+    pub fn set_no_std(&mut self, span: proc_macro2::Span) {
+        self.core = proc_macro2::Ident::new("core", span);
+        self.alloc = proc_macro2::Ident::new("alloc", span);
+    }
+
+    pub fn set_facade(&mut self, path: proc_macro2::Ident) {
+        self.core = path.clone();
+        self.alloc = path;
+    }
+
+    /// Redirects generated paths into `aliri_braid` through the given path
+    pub fn set_braid(&mut self, path: syn::Path) {
+        self.braid = path;
+    }
+
+    /// Whether the paths have been redirected through a `std` facade crate
+    pub fn is_facade(&self) -> bool {
+        self.core == self.alloc && self.core != "std"
     }
 
     pub fn core(&self) -> &proc_macro2::Ident {
@@ -37,6 +90,10 @@ impl StdLib {
     pub fn alloc(&self) -> &proc_macro2::Ident {
         &self.alloc
     }
+
+    pub fn braid(&self) -> &syn::Path {
+        &self.braid
+    }
 }
 
 impl Default for StdLib {
@@ -44,18 +101,90 @@ impl Default for StdLib {
         Self {
             core: proc_macro2::Ident::new("std", proc_macro2::Span::call_site()),
             alloc: proc_macro2::Ident::new("std", proc_macro2::Span::call_site()),
+            braid: syn::parse_quote!(::aliri_braid),
+        }
+    }
+}
+
+/// The value used for a generated `Default` implementation
+enum DefaultValue {
+    /// The bare `default` flag, which uses an empty string
+    Empty(proc_macro2::Span),
+    Value(syn::LitStr),
+}
+
+impl DefaultValue {
+    fn resolve(
+        self,
+        check_mode: &CheckMode,
+        constraints: &Constraints,
+    ) -> Result<syn::LitStr, syn::Error> {
+        match self {
+            Self::Empty(span) if matches!(check_mode, CheckMode::None) => {
+                Ok(syn::LitStr::new("", span))
+            }
+            Self::Empty(span) => Err(syn::Error::new(
+                span,
+                format!(
+                    "{} requires a value (`{} = \"value\"`) when a {} or {} is specified",
+                    symbol::DEFAULT,
+                    symbol::DEFAULT,
+                    symbol::VALIDATOR,
+                    symbol::NORMALIZER
+                ),
+            )),
+            Self::Value(value) => {
+                constraints.check_literal(&value)?;
+                Ok(value)
+            }
         }
     }
 }
 
 pub struct Params {
     ref_ty: Option<syn::Type>,
-    ref_doc: Vec<syn::Lit>,
-    ref_attrs: AttrList,
-    owned_attrs: AttrList,
+    error: Option<syn::Type>,
+    ref_doc: Vec<syn::LitStr>,
+    owned_doc: Vec<syn::LitStr>,
+    aliases: Vec<syn::LitStr>,
+    ref_attrs: Vec<proc_macro2::TokenStream>,
+    owned_attrs: Vec<proc_macro2::TokenStream>,
+    ref_field_attrs: Vec<proc_macro2::TokenStream>,
+    owned_field_attrs: Vec<proc_macro2::TokenStream>,
     std_lib: StdLib,
     check_mode: IndefiniteCheckMode,
-    expose_inner: bool,
+    constraints: Constraints,
+    parse_as: ParseAs,
+    segments: Segments,
+    convert_from: ConvertFrom,
+    as_path: AsPath,
+    format: Option<(Format, proc_macro2::Span)>,
+    same_repr: SameRepr,
+    assert_layout: Option<syn::Path>,
+    nested: Option<syn::Path>,
+    provenance: Option<syn::Path>,
+    unchecked: Unchecked,
+    constructor: Constructor,
+    naming: Naming,
+    omit: Omit,
+    method_vis: MethodVis,
+    must_use: MustUse,
+    from_bytes: bool,
+    unicode: bool,
+    arena: bool,
+    view_slices: bool,
+    vec: bool,
+    slice: bool,
+    intern: Option<syn::Path>,
+    test_ctor: bool,
+    mutable: Option<syn::Path>,
+    add: bool,
+    add_str: Option<syn::Path>,
+    build: bool,
+    iter: Option<syn::Path>,
+    default: Option<DefaultValue>,
+    expose: Expose,
+    equality: Equality,
     impls: Impls,
 }
 
@@ -63,22 +192,99 @@ impl Default for Params {
     fn default() -> Self {
         Self {
             ref_ty: None,
+            error: None,
             ref_doc: Vec::new(),
-            ref_attrs: AttrList::new(),
-            owned_attrs: AttrList::new(),
+            owned_doc: Vec::new(),
+            aliases: Vec::new(),
+            ref_attrs: Vec::new(),
+            owned_attrs: Vec::new(),
+            ref_field_attrs: Vec::new(),
+            owned_field_attrs: Vec::new(),
             std_lib: StdLib::default(),
             check_mode: IndefiniteCheckMode::None,
-            expose_inner: true,
+            constraints: Constraints::default(),
+            parse_as: ParseAs::default(),
+            segments: Segments::default(),
+            convert_from: ConvertFrom::default(),
+            as_path: AsPath::default(),
+            format: None,
+            same_repr: SameRepr::default(),
+            assert_layout: None,
+            nested: None,
+            provenance: None,
+            unchecked: Unchecked::default(),
+            constructor: Constructor::default(),
+            naming: Naming::default(),
+            omit: Omit::default(),
+            method_vis: MethodVis::default(),
+            must_use: MustUse::default(),
+            from_bytes: false,
+            unicode: false,
+            arena: false,
+            view_slices: false,
+            vec: false,
+            slice: false,
+            intern: None,
+            test_ctor: false,
+            mutable: None,
+            add: false,
+            add_str: None,
+            build: false,
+            iter: None,
+            default: None,
+            expose: Expose::default(),
+            equality: Equality::default(),
             impls: Impls::default(),
         }
     }
 }
 
-impl syn::parse::Parse for Params {
+/// The parameters of a braid
+///
+/// Braids given `ref_inner` wrap a borrowed type other than `str` and accept only a reduced set
+/// of parameters.
+// Parsed once per invocation, so the size difference between the variants is immaterial
+#[allow(clippy::large_enum_variant)]
+pub enum BraidParams {
+    Str(Params),
+    Inner(InnerParams),
+}
+
+impl syn::parse::Parse for BraidParams {
     fn parse(input: syn::parse::ParseStream) -> Result<Self, syn::Error> {
+        let args = diagnostics::parse_args(input, diagnostics::Macro::Braid)?;
+        if args.iter().any(|arg| {
+            [symbol::REF_INNER, symbol::PATH, symbol::C_STR]
+                .iter()
+                .any(|s| arg.path() == *s)
+        }) {
+            InnerParams::parse(args).map(Self::Inner)
+        } else {
+            Params::from_args(args).map(Self::Str)
+        }
+    }
+}
+
+impl BraidParams {
+    pub fn generate(self, body: syn::ItemStruct) -> proc_macro2::TokenStream {
+        self.try_generate(body)
+            .unwrap_or_else(syn::Error::into_compile_error)
+    }
+
+    fn try_generate(self, body: syn::ItemStruct) -> Result<proc_macro2::TokenStream, syn::Error> {
+        match self {
+            Self::Str(params) => params.build(body).map(|codegen| codegen.generate()),
+            Self::Inner(params) => params.build(body).map(|codegen| codegen.generate()),
+        }
+    }
+}
+
+impl Params {
+    fn from_args(args: AttrList) -> Result<Self, syn::Error> {
         let mut params = Self::default();
-        let args =
-            syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated(input)?;
+        if let Some(braid) = parse_crate_path(&args)? {
+            params.std_lib.set_braid(braid);
+        }
 
         for arg in args {
             match &arg {
@@ -88,6 +294,19 @@ impl syn::parse::Parse for Params {
                         parse_expr_as_lit(&nv.value)?,
                     )?);
                 }
+                syn::Meta::NameValue(nv) if nv.path == symbol::ERROR => {
+                    if params.error.is_some() {
+                        return Err(syn::Error::new_spanned(
+                            nv,
+                            format!("{} can only be specified once", symbol::ERROR),
+                        ));
+                    }
+
+                    params.error = Some(parse_lit_into_type(
+                        symbol::ERROR,
+                        parse_expr_as_lit(&nv.value)?,
+                    )?);
+                }
                 syn::Meta::NameValue(nv) if nv.path == symbol::VALIDATOR => {
                     let validator =
                         parse_lit_into_type(symbol::VALIDATOR, parse_expr_as_lit(&nv.value)?)?;
@@ -104,16 +323,98 @@ impl syn::parse::Parse for Params {
                         .try_set_normalizer(Some(normalizer))
                         .map_err(|s| syn::Error::new_spanned(nv, s))?;
                 }
+                syn::Meta::NameValue(nv) if nv.path == symbol::CONVERT_CASE => {
+                    let braid = params.std_lib.braid();
+                    let normalizer: syn::Type = match parse_lit_into_string(
+                        symbol::CONVERT_CASE,
+                        parse_expr_as_lit(&nv.value)?,
+                    )?
+                    .as_str()
+                    {
+                        "snake" => syn::parse_quote!(#braid::SnakeCase),
+                        "kebab" => syn::parse_quote!(#braid::KebabCase),
+                        "camel" => syn::parse_quote!(#braid::CamelCase),
+                        _ => {
+                            return Err(syn::Error::new_spanned(
+                                nv,
+                                "valid values are: `snake`, `kebab`, or `camel`",
+                            ))
+                        }
+                    };
+                    params
+                        .check_mode
+                        .try_set_normalizer(Some(normalizer))
+                        .map_err(|_| {
+                            syn::Error::new_spanned(
+                                nv,
+                                format!(
+                                    "{} cannot be combined with a {} or {}",
+                                    symbol::CONVERT_CASE,
+                                    symbol::VALIDATOR,
+                                    symbol::NORMALIZER
+                                ),
+                            )
+                        })?;
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::FORMAT => {
+                    let format =
+                        parse_lit_into_string(symbol::FORMAT, parse_expr_as_lit(&nv.value)?)?
+                            .parse::<Format>()
+                            .map_err(|e| syn::Error::new_spanned(nv, e.to_owned()))?;
+                    format
+                        .ensure_enabled()
+                        .map_err(|e| syn::Error::new_spanned(nv, e))?;
+                    format
+                        .try_apply(&mut params.check_mode, &params.std_lib)
+                        .map_err(|_| {
+                            syn::Error::new_spanned(
+                                nv,
+                                format!(
+                                    "{} cannot be combined with a {}, {}, or {}",
+                                    symbol::FORMAT,
+                                    symbol::VALIDATOR,
+                                    symbol::NORMALIZER,
+                                    symbol::CONVERT_CASE
+                                ),
+                            )
+                        })?;
+                    params.format = Some((format, nv.span()));
+                }
                 syn::Meta::NameValue(nv) if nv.path == symbol::REF_DOC => {
+                    params.ref_doc.push(parse_lit_into_lit_str(
+                        symbol::REF_DOC,
+                        parse_expr_as_lit(&nv.value)?,
+                    )?);
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::OWNED_DOC => {
+                    params.owned_doc.push(parse_lit_into_lit_str(
+                        symbol::OWNED_DOC,
+                        parse_expr_as_lit(&nv.value)?,
+                    )?);
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::ALIAS => {
+                    params.aliases.push(parse_lit_into_lit_str(
+                        symbol::ALIAS,
+                        parse_expr_as_lit(&nv.value)?,
+                    )?);
+                }
+                syn::Meta::List(list) if list.path == symbol::REF_ATTR => {
+                    params.ref_attrs.push(attr_tokens(symbol::REF_ATTR, list)?);
+                }
+                syn::Meta::List(list) if list.path == symbol::OWNED_ATTR => {
                     params
-                        .ref_doc
-                        .push(parse_expr_as_lit(&nv.value)?.to_owned());
+                        .owned_attrs
+                        .push(attr_tokens(symbol::OWNED_ATTR, list)?);
                 }
-                syn::Meta::List(nv) if nv.path == symbol::REF_ATTR => {
-                    params.ref_attrs.extend(nv.parse_args::<syn::Meta>());
+                syn::Meta::List(list) if list.path == symbol::REF_FIELD_ATTR => {
+                    params
+                        .ref_field_attrs
+                        .push(attr_tokens(symbol::REF_FIELD_ATTR, list)?);
                 }
-                syn::Meta::List(nv) if nv.path == symbol::OWNED_ATTR => {
-                    params.owned_attrs.extend(nv.parse_args::<syn::Meta>());
+                syn::Meta::List(list) if list.path == symbol::OWNED_FIELD_ATTR => {
+                    params
+                        .owned_field_attrs
+                        .push(attr_tokens(symbol::OWNED_FIELD_ATTR, list)?);
                 }
                 syn::Meta::NameValue(nv) if nv.path == symbol::DEBUG => {
                     params.impls.debug =
@@ -132,9 +433,14 @@ impl syn::parse::Parse for Params {
                 syn::Meta::NameValue(nv) if nv.path == symbol::ORD => {
                     params.impls.ord =
                         parse_lit_into_string(symbol::ORD, parse_expr_as_lit(&nv.value)?)?
-                            .parse::<DelegatingImplOption>()
-                            .map_err(|e| syn::Error::new_spanned(&arg, e.to_owned()))?
-                            .into();
+                            .parse::<ImplOrd>()
+                            .map_err(|e| syn::Error::new_spanned(&arg, e.to_owned()))?;
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::HASH => {
+                    params.impls.hash =
+                        parse_lit_into_string(symbol::HASH, parse_expr_as_lit(&nv.value)?)?
+                            .parse::<ImplHash>()
+                            .map_err(|e| syn::Error::new_spanned(&arg, e.to_owned()))?;
                 }
                 syn::Meta::NameValue(nv) if nv.path == symbol::CLONE => {
                     params.impls.clone =
@@ -146,13 +452,110 @@ impl syn::parse::Parse for Params {
                 syn::Meta::NameValue(nv) if nv.path == symbol::SERDE => {
                     params.impls.serde =
                         parse_lit_into_string(symbol::SERDE, parse_expr_as_lit(&nv.value)?)?
-                            .parse::<ImplOption>()
-                            .map_err(|e| syn::Error::new_spanned(&arg, e.to_owned()))?
-                            .into();
+                            .parse::<ImplSerde>()
+                            .map_err(|e| syn::Error::new_spanned(&arg, e.to_owned()))?;
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::UNCHECKED => {
+                    params.unchecked =
+                        parse_lit_into_string(symbol::UNCHECKED, parse_expr_as_lit(&nv.value)?)?
+                            .parse::<Unchecked>()
+                            .map_err(|e| syn::Error::new_spanned(&arg, e.to_owned()))?;
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::CONSTRUCTOR => {
+                    params.constructor =
+                        parse_lit_into_string(symbol::CONSTRUCTOR, parse_expr_as_lit(&nv.value)?)?
+                            .parse::<Constructor>()
+                            .map_err(|e| syn::Error::new_spanned(&arg, e.to_owned()))?;
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::NAMING => {
+                    params.naming =
+                        parse_lit_into_string(symbol::NAMING, parse_expr_as_lit(&nv.value)?)?
+                            .parse::<Naming>()
+                            .map_err(|e| syn::Error::new_spanned(&arg, e.to_owned()))?;
                 }
                 syn::Meta::Path(p) if p == symbol::SERDE => {
                     params.impls.serde = ImplOption::Implement.into();
                 }
+                syn::Meta::NameValue(nv) if nv.path == symbol::CLAP => {
+                    params.impls.clap =
+                        parse_lit_into_string(symbol::CLAP, parse_expr_as_lit(&nv.value)?)?
+                            .parse::<ImplOption>()
+                            .map_err(|e| syn::Error::new_spanned(nv, e.to_owned()))?
+                            .into();
+                }
+                syn::Meta::Path(p) if p == symbol::CLAP => {
+                    params.impls.clap = ImplOption::Implement.into();
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::VALUABLE => {
+                    params.impls.valuable =
+                        parse_lit_into_string(symbol::VALUABLE, parse_expr_as_lit(&nv.value)?)?
+                            .parse::<ImplOption>()
+                            .map_err(|e| syn::Error::new_spanned(nv, e.to_owned()))?
+                            .into();
+                }
+                syn::Meta::Path(p) if p == symbol::VALUABLE => {
+                    params.impls.valuable = ImplOption::Implement.into();
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::DEFMT => {
+                    params.impls.defmt =
+                        parse_lit_into_string(symbol::DEFMT, parse_expr_as_lit(&nv.value)?)?
+                            .parse::<ImplOption>()
+                            .map_err(|e| syn::Error::new_spanned(nv, e.to_owned()))?
+                            .into();
+                }
+                syn::Meta::Path(p) if p == symbol::DEFMT => {
+                    params.impls.defmt = ImplOption::Implement.into();
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::TS_RS => {
+                    params.impls.ts_rs =
+                        parse_lit_into_string(symbol::TS_RS, parse_expr_as_lit(&nv.value)?)?
+                            .parse::<ImplOption>()
+                            .map_err(|e| syn::Error::new_spanned(nv, e.to_owned()))?
+                            .into();
+                }
+                syn::Meta::Path(p) if p == symbol::TS_RS => {
+                    params.impls.ts_rs = ImplOption::Implement.into();
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::REDIS => {
+                    params.impls.redis =
+                        parse_lit_into_string(symbol::REDIS, parse_expr_as_lit(&nv.value)?)?
+                            .parse::<ImplOption>()
+                            .map_err(|e| syn::Error::new_spanned(nv, e.to_owned()))?
+                            .into();
+                }
+                syn::Meta::Path(p) if p == symbol::REDIS => {
+                    params.impls.redis = ImplOption::Implement.into();
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::BSON => {
+                    params.impls.bson =
+                        parse_lit_into_string(symbol::BSON, parse_expr_as_lit(&nv.value)?)?
+                            .parse::<ImplOption>()
+                            .map_err(|e| syn::Error::new_spanned(nv, e.to_owned()))?
+                            .into();
+                }
+                syn::Meta::Path(p) if p == symbol::BSON => {
+                    params.impls.bson = ImplOption::Implement.into();
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::SEA_ORM => {
+                    params.impls.sea_orm =
+                        parse_lit_into_string(symbol::SEA_ORM, parse_expr_as_lit(&nv.value)?)?
+                            .parse::<ImplOption>()
+                            .map_err(|e| syn::Error::new_spanned(nv, e.to_owned()))?
+                            .into();
+                }
+                syn::Meta::Path(p) if p == symbol::SEA_ORM => {
+                    params.impls.sea_orm = ImplOption::Implement.into();
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::QUICKCHECK => {
+                    params.impls.quickcheck =
+                        parse_lit_into_string(symbol::QUICKCHECK, parse_expr_as_lit(&nv.value)?)?
+                            .parse::<ImplOption>()
+                            .map_err(|e| syn::Error::new_spanned(nv, e.to_owned()))?
+                            .into();
+                }
+                syn::Meta::Path(p) if p == symbol::QUICKCHECK => {
+                    params.impls.quickcheck = ImplOption::Implement.into();
+                }
                 syn::Meta::Path(p) if p == symbol::VALIDATOR => {
                     params
                         .check_mode
@@ -166,22 +569,238 @@ impl syn::parse::Parse for Params {
                         .map_err(|s| syn::Error::new_spanned(p, s))?;
                 }
                 syn::Meta::Path(p) if p == symbol::NO_STD => {
-                    params.std_lib = StdLib::no_std(p.span());
+                    if params.std_lib.is_facade() {
+                        return Err(syn::Error::new_spanned(
+                            p,
+                            format!(
+                                "{} cannot be combined with {}",
+                                symbol::NO_STD,
+                                symbol::STD_PATH
+                            ),
+                        ));
+                    }
+                    params.std_lib.set_no_std(p.span());
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::CRATE => {
+                    // Already applied by `parse_crate_path()`
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::STD_PATH => {
+                    if params.std_lib.is_facade() {
+                        return Err(syn::Error::new_spanned(
+                            nv,
+                            format!("{} can only be specified once", symbol::STD_PATH),
+                        ));
+                    } else if params.std_lib.core() != "std" {
+                        return Err(syn::Error::new_spanned(
+                            nv,
+                            format!(
+                                "{} cannot be combined with {}",
+                                symbol::STD_PATH,
+                                symbol::NO_STD
+                            ),
+                        ));
+                    }
+                    params.std_lib.set_facade(parse_lit_into_ident(
+                        symbol::STD_PATH,
+                        parse_expr_as_lit(&nv.value)?,
+                    )?);
+                }
+                syn::Meta::Path(p) if p == symbol::FROM_BYTES => {
+                    params.from_bytes = true;
+                }
+                syn::Meta::Path(p) if p == symbol::UNICODE => {
+                    params.unicode = true;
+                }
+                syn::Meta::Path(p) if p == symbol::AS_PATH => {
+                    params.as_path.set(p);
+                }
+                syn::Meta::Path(p) if p == symbol::ARENA => {
+                    params.arena = true;
+                }
+                syn::Meta::Path(p) if p == symbol::VIEW_SLICES => {
+                    params.view_slices = true;
+                }
+                syn::Meta::Path(p) if p == symbol::VEC => {
+                    params.vec = true;
+                }
+                syn::Meta::Path(p) if p == symbol::SLICE => {
+                    params.slice = true;
+                }
+                syn::Meta::Path(p) if p == symbol::INTERN => {
+                    params.intern = Some(p.clone());
+                }
+                syn::Meta::Path(p) if p == symbol::ASSERT_LAYOUT => {
+                    params.assert_layout = Some(p.clone());
+                }
+                syn::Meta::Path(p) if p == symbol::NESTED => {
+                    params.nested = Some(p.clone());
+                }
+                syn::Meta::Path(p) if p == symbol::PROVENANCE => {
+                    params.provenance = Some(p.clone());
+                }
+                syn::Meta::Path(p) if p == symbol::TEST_CTOR => {
+                    params.test_ctor = true;
+                }
+                syn::Meta::Path(p) if p == symbol::MUTABLE => {
+                    params.mutable = Some(p.clone());
+                }
+                syn::Meta::Path(p) if p == symbol::ADD => {
+                    params.add = true;
+                }
+                syn::Meta::Path(p) if p == symbol::ADD_STR => {
+                    params.add_str = Some(p.clone());
+                }
+                syn::Meta::Path(p) if p == symbol::BUILD => {
+                    params.build = true;
+                }
+                syn::Meta::Path(p) if p == symbol::ITER => {
+                    params.iter = Some(p.clone());
+                }
+                syn::Meta::Path(p) if p == symbol::AXUM => {
+                    // `Path<T>` and `Query<T>` extract through `Deserialize`
+                    params.impls.serde.enable();
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::DEFAULT => {
+                    let value =
+                        parse_lit_into_lit_str(symbol::DEFAULT, parse_expr_as_lit(&nv.value)?)?;
+                    params.try_set_default(nv, DefaultValue::Value(value))?;
+                }
+                syn::Meta::Path(p) if p == symbol::DEFAULT => {
+                    let value = DefaultValue::Empty(p.span());
+                    params.try_set_default(p, value)?;
+                }
+                syn::Meta::Path(p) if p == symbol::NO_MUST_USE => {
+                    params.must_use.disable();
                 }
                 syn::Meta::Path(p) if p == symbol::NO_EXPOSE => {
-                    params.expose_inner = false;
+                    params.expose = Expose::Hidden;
                 }
-                syn::Meta::Path(ref path)
-                | syn::Meta::NameValue(syn::MetaNameValue { ref path, .. }) => {
-                    return Err(syn::Error::new_spanned(
-                        &arg,
-                        format!("unsupported argument `{}`", path.to_token_stream()),
-                    ));
+                syn::Meta::NameValue(nv) if nv.path == symbol::NO_EXPOSE => {
+                    params.expose =
+                        parse_lit_into_string(symbol::NO_EXPOSE, parse_expr_as_lit(&nv.value)?)?
+                            .parse::<Expose>()
+                            .map_err(|e| syn::Error::new_spanned(&arg, e.to_owned()))?;
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::EQ => {
+                    params.equality =
+                        parse_lit_into_string(symbol::EQ, parse_expr_as_lit(&nv.value)?)?
+                            .parse::<Equality>()
+                            .map_err(|e| syn::Error::new_spanned(&arg, e.to_owned()))?;
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::CHARSET => {
+                    let charset =
+                        parse_lit_into_string(symbol::CHARSET, parse_expr_as_lit(&nv.value)?)?
+                            .parse::<Charset>()
+                            .map_err(|e| syn::Error::new_spanned(&arg, e.to_owned()))?;
+                    params
+                        .constraints
+                        .try_push(Constraint::Charset(charset, nv.span()))?;
+                }
+                syn::Meta::Path(p) if p == symbol::NON_EMPTY => {
+                    params
+                        .constraints
+                        .try_push(Constraint::NonEmpty(p.span()))?;
+                }
+                syn::Meta::NameValue(nv)
+                    if nv.path == symbol::MIN_LEN || nv.path == symbol::MIN_BYTES =>
+                {
+                    let name = if nv.path == symbol::MIN_BYTES {
+                        symbol::MIN_BYTES
+                    } else {
+                        symbol::MIN_LEN
+                    };
+                    params.constraints.try_push(Constraint::MinLen(
+                        parse_lit_into_int(name, parse_expr_as_lit(&nv.value)?)?,
+                        name,
+                    ))?;
+                }
+                syn::Meta::NameValue(nv)
+                    if nv.path == symbol::MAX_LEN || nv.path == symbol::MAX_BYTES =>
+                {
+                    let name = if nv.path == symbol::MAX_BYTES {
+                        symbol::MAX_BYTES
+                    } else {
+                        symbol::MAX_LEN
+                    };
+                    params.constraints.try_push(Constraint::MaxLen(
+                        parse_lit_into_int(name, parse_expr_as_lit(&nv.value)?)?,
+                        name,
+                    ))?;
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::MIN_CHARS => {
+                    params
+                        .constraints
+                        .try_push(Constraint::MinChars(parse_lit_into_int(
+                            symbol::MIN_CHARS,
+                            parse_expr_as_lit(&nv.value)?,
+                        )?))?;
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::MAX_CHARS => {
+                    params
+                        .constraints
+                        .try_push(Constraint::MaxChars(parse_lit_into_int(
+                            symbol::MAX_CHARS,
+                            parse_expr_as_lit(&nv.value)?,
+                        )?))?;
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::PREFIX => {
+                    params
+                        .constraints
+                        .try_push(Constraint::Prefix(parse_lit_into_lit_str(
+                            symbol::PREFIX,
+                            parse_expr_as_lit(&nv.value)?,
+                        )?))?;
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::SUFFIX => {
+                    params
+                        .constraints
+                        .try_push(Constraint::Suffix(parse_lit_into_lit_str(
+                            symbol::SUFFIX,
+                            parse_expr_as_lit(&nv.value)?,
+                        )?))?;
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::PATTERN => {
+                    params
+                        .constraints
+                        .try_push(Constraint::pattern(parse_lit_into_lit_str(
+                            symbol::PATTERN,
+                            parse_expr_as_lit(&nv.value)?,
+                        )?)?)?;
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::REST => {
+                    params.constraints.try_set_rest(parse_lit_into_type(
+                        symbol::REST,
+                        parse_expr_as_lit(&nv.value)?,
+                    )?)?;
+                }
+                syn::Meta::List(list) if list.path == symbol::PARSE_AS => {
+                    params.parse_as.try_extend(list)?;
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::SEGMENTS => {
+                    params.segments.try_set_delimiter(nv)?;
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::SEGMENT => {
+                    params.segments.try_set_item(nv)?;
+                }
+                syn::Meta::List(list) if list.path == symbol::OMIT => {
+                    params.omit.try_extend(list, omit::OWNED_METHODS)?;
+                }
+                syn::Meta::List(list) if list.path == symbol::FEATURE_GATE => {
+                    params.impls.gates.try_extend(list)?;
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::EXPOSE => {
+                    params.method_vis.try_set(nv)?;
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::CONVERT_FROM => {
+                    params.convert_from.try_push(nv)?;
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::SAME_REPR_AS => {
+                    params.same_repr.try_push(nv)?;
                 }
                 _ => {
-                    return Err(syn::Error::new_spanned(
+                    return Err(diagnostics::unsupported_argument(
                         &arg,
-                        "unsupported argument".to_string(),
+                        diagnostics::Macro::Braid,
                     ));
                 }
             }
@@ -192,33 +811,165 @@ impl syn::parse::Parse for Params {
 }
 
 impl Params {
+    fn try_set_default(
+        &mut self,
+        tokens: impl ToTokens,
+        value: DefaultValue,
+    ) -> Result<(), syn::Error> {
+        if self.default.is_some() {
+            return Err(syn::Error::new_spanned(
+                tokens,
+                format!("{} can only be specified once", symbol::DEFAULT),
+            ));
+        }
+
+        self.default = Some(value);
+        Ok(())
+    }
+
     pub fn build(self, mut body: syn::ItemStruct) -> Result<CodeGen, syn::Error> {
         let Params {
             ref_ty,
+            error,
             ref_doc,
-            ref_attrs,
-            owned_attrs,
+            owned_doc,
+            aliases,
+            mut ref_attrs,
+            mut owned_attrs,
+            ref_field_attrs,
+            owned_field_attrs,
             std_lib,
             check_mode,
-            expose_inner,
-            impls,
+            constraints,
+            parse_as,
+            segments,
+            convert_from,
+            as_path,
+            format,
+            same_repr,
+            assert_layout,
+            nested,
+            provenance,
+            unchecked,
+            constructor,
+            naming,
+            omit,
+            method_vis,
+            must_use,
+            from_bytes,
+            unicode,
+            arena,
+            view_slices,
+            vec,
+            slice,
+            intern,
+            test_ctor,
+            mutable,
+            add,
+            add_str,
+            build,
+            iter,
+            default,
+            expose,
+            equality,
+            mut impls,
         } = self;
 
+        impls.resolve();
         create_field_if_none(&mut body.fields);
-        let (wrapped_type, field_ident, field_attrs) = get_field_info(&body.fields)?;
+        let mut field = get_field_info(&body.fields)?;
+        // The attributes of the field info are only placed on the borrowed type's field
+        field.attrs.extend(parse_attrs(ref_field_attrs)?);
+        add_value_field_attrs(&mut body.fields, parse_attrs(owned_field_attrs)?);
+        generic::ensure_supported(&body.generics)?;
+        same_repr.ensure_supported(&body)?;
+        layout::ensure_supported(assert_layout.as_ref(), &body.generics)?;
         let owned_ty = &body.ident;
         let ref_ty = ref_ty.unwrap_or_else(|| infer_ref_type_from_owned_name(owned_ty));
-        let check_mode = check_mode.infer_validator_if_missing(owned_ty);
-        let field = Field {
-            attrs: field_attrs.to_owned(),
-            name: field_ident
-                .cloned()
-                .map_or(FieldName::Unnamed, FieldName::Named),
-            ty: wrapped_type.to_owned(),
-        };
+        let ref_doc = expand_docs(&mut body, &owned_doc, &ref_doc, &ref_ty);
+        // Aliases are placed on each type separately so that they are kept
+        // regardless of which type the declared doc comments are applied to
+        owned_attrs.extend(aliases.iter().map(alias_attr));
+        ref_attrs.extend(aliases.iter().map(alias_attr));
+        let owned_ty = &body.ident;
+        let repr_impls = same_repr.tokens(
+            owned_ty,
+            &ref_ty,
+            &field,
+            &check_mode,
+            &constraints,
+            &std_lib,
+        );
+        let literal_impls =
+            constraints.literal_impls(&check_mode, &[&ident_to_type(owned_ty), &ref_ty], &std_lib);
+        let (check_mode, constraint_impls) =
+            constraints.resolve(check_mode, owned_ty, &ref_ty, &std_lib)?;
+        let check_mode = nest_check_mode(nested.as_ref(), check_mode, &field.ty, &std_lib)?;
+        field.nested = nested.is_some();
+        if let Some(provenance) = &provenance {
+            ensure_provenance_supported(provenance, &check_mode, assert_layout.is_some(), &body)?;
+            field.provenance = Some(add_provenance_field(&mut body.fields, &std_lib));
+        }
+        ensure_error_has_check_mode(error.as_ref(), &check_mode)?;
+        ensure_unchecked(mutable.as_ref(), &check_mode)?;
+        ensure_unchecked(add_str.as_ref(), &check_mode)?;
+        ensure_unchecked(iter.as_ref(), &check_mode)?;
+        ensure_ord_consistent_with_eq(&impls, equality)?;
+        as_path.ensure_supported(&std_lib, expose)?;
+        if let (Some(intern), false) = (&intern, expose.str()) {
+            return Err(syn::Error::new_spanned(
+                intern,
+                format!(
+                    "{} cannot be combined with {} = \"opaque\"",
+                    symbol::INTERN,
+                    symbol::NO_EXPOSE
+                ),
+            ));
+        }
+        if let Some((format, span)) = format {
+            format.ensure_unconstrained(span, &constraints)?;
+        }
+        let mutable = mutable.is_some();
+        // Concatenating string slices also concatenates the borrowed form
+        let add = add || add_str.is_some();
+        let add_str = add_str.is_some();
+        let iter = iter.is_some();
+        ensure_intern_has_arc(intern.as_ref(), &omit)?;
+        let intern = intern.is_some();
+        let constructor = constructor.with_naming(naming, &check_mode);
+        if let (Some(_), Some(from_static)) = (&default, omit.find("from_static")) {
+            return Err(syn::Error::new_spanned(
+                from_static,
+                format!("{} requires `from_static`", symbol::DEFAULT),
+            ));
+        }
+        let default = default
+            .map(|default| default.resolve(&check_mode, &constraints))
+            .transpose()?;
+        let adapter_impls = parse_as.tokens(&ref_ty, &std_lib);
+        let segment_impls = segments.tokens(&ref_ty, &std_lib)?;
+        let conversion_impls = convert_from.tokens(owned_ty, &check_mode, error.as_ref(), &std_lib);
+        let path_impls = as_path.tokens(owned_ty, &ref_ty, &std_lib);
+        let format_impls =
+            format.and_then(|(format, _)| format.tokens(owned_ty, &ref_ty, &std_lib));
+        let registration =
+            registry::tokens(Some(owned_ty), &ref_ty, &check_mode, &constraints, &std_lib);
+        let layout_assertions =
+            assert_layout.map(|_| layout::tokens(owned_ty, &ref_ty, &field.ty, &std_lib));
 
         Ok(CodeGen {
             check_mode,
+            error,
+            constraint_impls,
+            literal_impls,
+            adapter_impls,
+            segment_impls,
+            conversion_impls,
+            path_impls,
+            format_impls,
+            repr_impls,
+            layout_assertions,
+            registration,
             body,
             field,
 
@@ -229,23 +980,88 @@ impl Params {
             ref_ty,
 
             std_lib,
-            expose_inner,
+            unchecked,
+            constructor,
+            naming,
+            omit,
+            method_vis,
+            must_use,
+            from_bytes,
+            unicode,
+            arena,
+            view_slices,
+            vec,
+            slice,
+            intern,
+            test_ctor,
+            mutable,
+            add,
+            add_str,
+            build,
+            iter,
+            default,
+            expose,
+            equality,
             impls,
         })
     }
 }
 
 pub struct ParamsRef {
+    owned: Option<syn::Type>,
+    to_owned: Option<syn::Path>,
+    as_str: Option<syn::Path>,
+    alloc: bool,
+    error: Option<syn::Type>,
+    aliases: Vec<syn::LitStr>,
     std_lib: StdLib,
     check_mode: IndefiniteCheckMode,
+    constraints: Constraints,
+    parse_as: ParseAs,
+    segments: Segments,
+    unchecked: Unchecked,
+    constructor: Constructor,
+    naming: Naming,
+    omit: Omit,
+    method_vis: MethodVis,
+    must_use: MustUse,
+    from_bytes: bool,
+    arena: bool,
+    view_slices: bool,
+    vec: bool,
+    slice: bool,
+    intern: Option<syn::Path>,
+    equality: Equality,
     impls: Impls,
 }
 
 impl Default for ParamsRef {
     fn default() -> Self {
         Self {
+            owned: None,
+            to_owned: None,
+            as_str: None,
+            alloc: false,
+            error: None,
+            aliases: Vec::new(),
             std_lib: StdLib::default(),
             check_mode: IndefiniteCheckMode::None,
+            constraints: Constraints::default(),
+            parse_as: ParseAs::default(),
+            segments: Segments::default(),
+            unchecked: Unchecked::default(),
+            constructor: Constructor::default(),
+            naming: Naming::default(),
+            omit: Omit::default(),
+            method_vis: MethodVis::default(),
+            must_use: MustUse::default(),
+            from_bytes: false,
+            arena: false,
+            view_slices: false,
+            vec: false,
+            slice: false,
+            intern: None,
+            equality: Equality::default(),
             impls: Impls::default(),
         }
     }
@@ -254,11 +1070,65 @@ impl Default for ParamsRef {
 impl syn::parse::Parse for ParamsRef {
     fn parse(input: syn::parse::ParseStream) -> Result<Self, syn::Error> {
         let mut params = Self::default();
-        let args =
-            syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated(input)?;
+        let args = diagnostics::parse_args(input, diagnostics::Macro::BraidRef)?;
+        if let Some(braid) = parse_crate_path(&args)? {
+            params.std_lib.set_braid(braid);
+        }
 
         for arg in args {
             match arg {
+                syn::Meta::NameValue(nv) if nv.path == symbol::OWNED => {
+                    if params.owned.is_some() {
+                        return Err(syn::Error::new_spanned(
+                            nv,
+                            format!("{} can only be specified once", symbol::OWNED),
+                        ));
+                    }
+
+                    params.owned = Some(parse_lit_into_type(
+                        symbol::OWNED,
+                        parse_expr_as_lit(&nv.value)?,
+                    )?);
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::TO_OWNED => {
+                    if params.to_owned.is_some() {
+                        return Err(syn::Error::new_spanned(
+                            nv,
+                            format!("{} can only be specified once", symbol::TO_OWNED),
+                        ));
+                    }
+
+                    params.to_owned = Some(parse_lit_into_path(
+                        symbol::TO_OWNED,
+                        parse_expr_as_lit(&nv.value)?,
+                    )?);
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::AS_STR => {
+                    if params.as_str.is_some() {
+                        return Err(syn::Error::new_spanned(
+                            nv,
+                            format!("{} can only be specified once", symbol::AS_STR),
+                        ));
+                    }
+
+                    params.as_str = Some(parse_lit_into_path(
+                        symbol::AS_STR,
+                        parse_expr_as_lit(&nv.value)?,
+                    )?);
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::ERROR => {
+                    if params.error.is_some() {
+                        return Err(syn::Error::new_spanned(
+                            nv,
+                            format!("{} can only be specified once", symbol::ERROR),
+                        ));
+                    }
+
+                    params.error = Some(parse_lit_into_type(
+                        symbol::ERROR,
+                        parse_expr_as_lit(&nv.value)?,
+                    )?);
+                }
                 syn::Meta::NameValue(nv) if nv.path == symbol::VALIDATOR => {
                     let validator =
                         parse_lit_into_type(symbol::VALIDATOR, parse_expr_as_lit(&nv.value)?)?;
@@ -284,12 +1154,23 @@ impl syn::parse::Parse for ParamsRef {
                             .into();
                 }
                 syn::Meta::NameValue(nv) if nv.path == symbol::ORD => {
-                    params.impls.ord =
-                        parse_lit_into_string(symbol::ORD, parse_expr_as_lit(&nv.value)?)?
-                            .parse::<ImplOption>()
-                            .map_err(|e| syn::Error::new_spanned(nv, e.to_owned()))
-                            .map(DelegatingImplOption::from)?
-                            .into();
+                    let value = parse_lit_into_string(symbol::ORD, parse_expr_as_lit(&nv.value)?)?;
+                    params.impls.ord = match value.parse::<ImplOption>() {
+                        Ok(opt) => DelegatingImplOption::from(opt).into(),
+                        Err(_) => value.parse::<OrderBy>().map(ImplOrd::from).map_err(|_| {
+                            syn::Error::new_spanned(
+                                nv,
+                                "valid values are: `impl`, `omit`, `case_insensitive`, or \
+                                 `natural`",
+                            )
+                        })?,
+                    };
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::HASH => {
+                    params.impls.hash =
+                        parse_lit_into_string(symbol::HASH, parse_expr_as_lit(&nv.value)?)?
+                            .parse::<ImplHash>()
+                            .map_err(|e| syn::Error::new_spanned(nv, e.to_owned()))?;
                 }
                 syn::Meta::NameValue(nv) if nv.path == symbol::SERDE => {
                     params.impls.serde =
@@ -298,9 +1179,77 @@ impl syn::parse::Parse for ParamsRef {
                             .map_err(|e| syn::Error::new_spanned(nv, e.to_owned()))?
                             .into();
                 }
+                syn::Meta::NameValue(nv) if nv.path == symbol::UNCHECKED => {
+                    params.unchecked =
+                        parse_lit_into_string(symbol::UNCHECKED, parse_expr_as_lit(&nv.value)?)?
+                            .parse::<Unchecked>()
+                            .map_err(|e| syn::Error::new_spanned(nv, e.to_owned()))?;
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::CONSTRUCTOR => {
+                    params.constructor =
+                        parse_lit_into_string(symbol::CONSTRUCTOR, parse_expr_as_lit(&nv.value)?)?
+                            .parse::<Constructor>()
+                            .map_err(|e| syn::Error::new_spanned(nv, e.to_owned()))?;
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::NAMING => {
+                    params.naming =
+                        parse_lit_into_string(symbol::NAMING, parse_expr_as_lit(&nv.value)?)?
+                            .parse::<Naming>()
+                            .map_err(|e| syn::Error::new_spanned(nv, e.to_owned()))?;
+                }
                 syn::Meta::Path(p) if p == symbol::SERDE => {
                     params.impls.serde = ImplOption::Implement.into();
                 }
+                syn::Meta::NameValue(nv) if nv.path == symbol::VALUABLE => {
+                    params.impls.valuable =
+                        parse_lit_into_string(symbol::VALUABLE, parse_expr_as_lit(&nv.value)?)?
+                            .parse::<ImplOption>()
+                            .map_err(|e| syn::Error::new_spanned(nv, e.to_owned()))?
+                            .into();
+                }
+                syn::Meta::Path(p) if p == symbol::VALUABLE => {
+                    params.impls.valuable = ImplOption::Implement.into();
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::DEFMT => {
+                    params.impls.defmt =
+                        parse_lit_into_string(symbol::DEFMT, parse_expr_as_lit(&nv.value)?)?
+                            .parse::<ImplOption>()
+                            .map_err(|e| syn::Error::new_spanned(nv, e.to_owned()))?
+                            .into();
+                }
+                syn::Meta::Path(p) if p == symbol::DEFMT => {
+                    params.impls.defmt = ImplOption::Implement.into();
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::TS_RS => {
+                    params.impls.ts_rs =
+                        parse_lit_into_string(symbol::TS_RS, parse_expr_as_lit(&nv.value)?)?
+                            .parse::<ImplOption>()
+                            .map_err(|e| syn::Error::new_spanned(nv, e.to_owned()))?
+                            .into();
+                }
+                syn::Meta::Path(p) if p == symbol::TS_RS => {
+                    params.impls.ts_rs = ImplOption::Implement.into();
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::REDIS => {
+                    params.impls.redis =
+                        parse_lit_into_string(symbol::REDIS, parse_expr_as_lit(&nv.value)?)?
+                            .parse::<ImplOption>()
+                            .map_err(|e| syn::Error::new_spanned(nv, e.to_owned()))?
+                            .into();
+                }
+                syn::Meta::Path(p) if p == symbol::REDIS => {
+                    params.impls.redis = ImplOption::Implement.into();
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::BSON => {
+                    params.impls.bson =
+                        parse_lit_into_string(symbol::BSON, parse_expr_as_lit(&nv.value)?)?
+                            .parse::<ImplOption>()
+                            .map_err(|e| syn::Error::new_spanned(nv, e.to_owned()))?
+                            .into();
+                }
+                syn::Meta::Path(p) if p == symbol::BSON => {
+                    params.impls.bson = ImplOption::Implement.into();
+                }
                 syn::Meta::Path(p) if p == symbol::VALIDATOR => {
                     params
                         .check_mode
@@ -308,19 +1257,186 @@ impl syn::parse::Parse for ParamsRef {
                         .map_err(|s| syn::Error::new_spanned(p, s))?;
                 }
                 syn::Meta::Path(p) if p == symbol::NO_STD => {
-                    params.std_lib = StdLib::no_std(p.span());
+                    if params.std_lib.is_facade() {
+                        return Err(syn::Error::new_spanned(
+                            p,
+                            format!(
+                                "{} cannot be combined with {}",
+                                symbol::NO_STD,
+                                symbol::STD_PATH
+                            ),
+                        ));
+                    }
+                    params.std_lib.set_no_std(p.span());
                 }
-                syn::Meta::Path(ref path)
-                | syn::Meta::NameValue(syn::MetaNameValue { ref path, .. }) => {
-                    return Err(syn::Error::new_spanned(
-                        &arg,
-                        format!("unsupported argument `{}`", path.to_token_stream()),
-                    ));
+                syn::Meta::NameValue(nv) if nv.path == symbol::CRATE => {
+                    // Already applied by `parse_crate_path()`
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::STD_PATH => {
+                    if params.std_lib.is_facade() {
+                        return Err(syn::Error::new_spanned(
+                            nv,
+                            format!("{} can only be specified once", symbol::STD_PATH),
+                        ));
+                    } else if params.std_lib.core() != "std" {
+                        return Err(syn::Error::new_spanned(
+                            nv,
+                            format!(
+                                "{} cannot be combined with {}",
+                                symbol::STD_PATH,
+                                symbol::NO_STD
+                            ),
+                        ));
+                    }
+                    params.std_lib.set_facade(parse_lit_into_ident(
+                        symbol::STD_PATH,
+                        parse_expr_as_lit(&nv.value)?,
+                    )?);
+                }
+                syn::Meta::Path(p) if p == symbol::FROM_BYTES => {
+                    params.from_bytes = true;
+                }
+                syn::Meta::Path(p) if p == symbol::ARENA => {
+                    params.arena = true;
+                }
+                syn::Meta::Path(p) if p == symbol::VIEW_SLICES => {
+                    params.view_slices = true;
+                }
+                syn::Meta::Path(p) if p == symbol::VEC => {
+                    params.vec = true;
+                }
+                syn::Meta::Path(p) if p == symbol::SLICE => {
+                    params.slice = true;
+                }
+                syn::Meta::Path(p) if p == symbol::INTERN => {
+                    params.intern = Some(p.clone());
+                }
+                syn::Meta::Path(p) if p == symbol::NO_MUST_USE => {
+                    params.must_use.disable();
+                }
+                syn::Meta::Path(p) if p == symbol::ALLOC => {
+                    params.alloc = true;
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::CHARSET => {
+                    let charset =
+                        parse_lit_into_string(symbol::CHARSET, parse_expr_as_lit(&nv.value)?)?
+                            .parse::<Charset>()
+                            .map_err(|e| syn::Error::new_spanned(&nv, e.to_owned()))?;
+                    params
+                        .constraints
+                        .try_push(Constraint::Charset(charset, nv.span()))?;
+                }
+                syn::Meta::Path(p) if p == symbol::NON_EMPTY => {
+                    params
+                        .constraints
+                        .try_push(Constraint::NonEmpty(p.span()))?;
+                }
+                syn::Meta::NameValue(nv)
+                    if nv.path == symbol::MIN_LEN || nv.path == symbol::MIN_BYTES =>
+                {
+                    let name = if nv.path == symbol::MIN_BYTES {
+                        symbol::MIN_BYTES
+                    } else {
+                        symbol::MIN_LEN
+                    };
+                    params.constraints.try_push(Constraint::MinLen(
+                        parse_lit_into_int(name, parse_expr_as_lit(&nv.value)?)?,
+                        name,
+                    ))?;
+                }
+                syn::Meta::NameValue(nv)
+                    if nv.path == symbol::MAX_LEN || nv.path == symbol::MAX_BYTES =>
+                {
+                    let name = if nv.path == symbol::MAX_BYTES {
+                        symbol::MAX_BYTES
+                    } else {
+                        symbol::MAX_LEN
+                    };
+                    params.constraints.try_push(Constraint::MaxLen(
+                        parse_lit_into_int(name, parse_expr_as_lit(&nv.value)?)?,
+                        name,
+                    ))?;
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::MIN_CHARS => {
+                    params
+                        .constraints
+                        .try_push(Constraint::MinChars(parse_lit_into_int(
+                            symbol::MIN_CHARS,
+                            parse_expr_as_lit(&nv.value)?,
+                        )?))?;
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::MAX_CHARS => {
+                    params
+                        .constraints
+                        .try_push(Constraint::MaxChars(parse_lit_into_int(
+                            symbol::MAX_CHARS,
+                            parse_expr_as_lit(&nv.value)?,
+                        )?))?;
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::PREFIX => {
+                    params
+                        .constraints
+                        .try_push(Constraint::Prefix(parse_lit_into_lit_str(
+                            symbol::PREFIX,
+                            parse_expr_as_lit(&nv.value)?,
+                        )?))?;
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::SUFFIX => {
+                    params
+                        .constraints
+                        .try_push(Constraint::Suffix(parse_lit_into_lit_str(
+                            symbol::SUFFIX,
+                            parse_expr_as_lit(&nv.value)?,
+                        )?))?;
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::PATTERN => {
+                    params
+                        .constraints
+                        .try_push(Constraint::pattern(parse_lit_into_lit_str(
+                            symbol::PATTERN,
+                            parse_expr_as_lit(&nv.value)?,
+                        )?)?)?;
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::REST => {
+                    params.constraints.try_set_rest(parse_lit_into_type(
+                        symbol::REST,
+                        parse_expr_as_lit(&nv.value)?,
+                    )?)?;
+                }
+                syn::Meta::List(list) if list.path == symbol::PARSE_AS => {
+                    params.parse_as.try_extend(&list)?;
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::SEGMENTS => {
+                    params.segments.try_set_delimiter(&nv)?;
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::SEGMENT => {
+                    params.segments.try_set_item(&nv)?;
+                }
+                syn::Meta::List(list) if list.path == symbol::OMIT => {
+                    params.omit.try_extend(&list, omit::REF_METHODS)?;
+                }
+                syn::Meta::List(list) if list.path == symbol::FEATURE_GATE => {
+                    params.impls.gates.try_extend(&list)?;
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::EXPOSE => {
+                    params.method_vis.try_set(&nv)?;
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::EQ => {
+                    params.equality =
+                        parse_lit_into_string(symbol::EQ, parse_expr_as_lit(&nv.value)?)?
+                            .parse::<Equality>()
+                            .map_err(|e| syn::Error::new_spanned(&nv, e.to_owned()))?;
+                }
+                syn::Meta::NameValue(nv) if nv.path == symbol::ALIAS => {
+                    params.aliases.push(parse_lit_into_lit_str(
+                        symbol::ALIAS,
+                        parse_expr_as_lit(&nv.value)?,
+                    )?);
                 }
                 _ => {
-                    return Err(syn::Error::new_spanned(
+                    return Err(diagnostics::unsupported_argument(
                         &arg,
-                        "unsupported argument".to_string(),
+                        diagnostics::Macro::BraidRef,
                     ));
                 }
             }
@@ -333,55 +1449,176 @@ impl syn::parse::Parse for ParamsRef {
 impl ParamsRef {
     pub fn build(self, body: &mut syn::ItemStruct) -> Result<proc_macro2::TokenStream, syn::Error> {
         let ParamsRef {
+            owned,
+            to_owned,
+            as_str,
+            alloc,
+            error,
+            aliases,
             std_lib,
             check_mode,
+            constraints,
+            parse_as,
+            segments,
+            unchecked,
+            constructor,
+            naming,
+            omit,
+            method_vis,
+            must_use,
+            from_bytes,
+            arena,
+            view_slices,
+            vec,
+            slice,
+            intern,
+            equality,
             impls,
         } = self;
 
+        if !body.generics.params.is_empty() {
+            return Err(syn::Error::new_spanned(
+                &body.generics,
+                "generic parameters are only supported on owned braids",
+            ));
+        }
+
+        if owned.is_none() {
+            for (path, name) in [(&to_owned, symbol::TO_OWNED), (&as_str, symbol::AS_STR)] {
+                if let Some(path) = path {
+                    return Err(syn::Error::new_spanned(
+                        path,
+                        format!("{} requires `{}`", name, symbol::OWNED),
+                    ));
+                }
+            }
+        }
+
+        ensure_intern_has_arc(intern.as_ref(), &omit)?;
+        if let Some(intern) = &intern {
+            if owned.is_none() && !alloc {
+                return Err(syn::Error::new_spanned(
+                    intern,
+                    format!(
+                        "{} requires `{}` or `{}`",
+                        symbol::INTERN,
+                        symbol::ALLOC,
+                        symbol::OWNED
+                    ),
+                ));
+            }
+        }
+
         create_ref_field_if_none(&mut body.fields);
-        let (wrapped_type, field_ident, field_attrs) = get_field_info(&body.fields)?;
+        let field = get_field_info(&body.fields)?;
+        body.attrs.extend(
+            aliases
+                .iter()
+                .map(|alias| syn::parse_quote!(#[doc(alias = #alias)])),
+        );
         let ref_ty = &body.ident;
-        let check_mode = check_mode.infer_validator_if_missing(ref_ty);
-        let field = Field {
-            attrs: field_attrs.to_owned(),
-            name: field_ident
-                .cloned()
-                .map_or(FieldName::Unnamed, FieldName::Named),
-            ty: wrapped_type.to_owned(),
-        };
+        let ref_ty_path = syn::Type::Verbatim(ref_ty.to_token_stream());
+        let literal_impls = constraints.literal_impls(&check_mode, &[&ref_ty_path], &std_lib);
+        let (check_mode, constraint_impls) =
+            constraints.resolve(check_mode, ref_ty, &ref_ty_path, &std_lib)?;
+        ensure_error_has_check_mode(error.as_ref(), &check_mode)?;
+        ensure_ord_consistent_with_eq(&impls, equality)?;
+        let constructor = constructor.with_naming(naming, &check_mode);
+        let adapter_impls = parse_as.tokens(&ref_ty_path, &std_lib);
+        let segment_impls = segments.tokens(&ref_ty_path, &std_lib)?;
+        let registration =
+            registry::tokens(None, &ref_ty_path, &check_mode, &constraints, &std_lib);
 
         let code_gen = RefCodeGen {
             doc: &[],
             common_attrs: &body.attrs,
-            attrs: &syn::punctuated::Punctuated::default(),
+            attrs: &[],
             vis: &body.vis,
             ty: &syn::Type::Verbatim(body.ident.to_token_stream()),
             ident: body.ident.clone(),
             field,
             check_mode: &check_mode,
-            owned_ty: None,
+            error: error.as_ref(),
+            owned_ty: owned,
+            owned_field: None,
+            to_owned_fn: to_owned.as_ref(),
+            as_str_fn: as_str.as_ref(),
+            alloc,
             std_lib: &std_lib,
+            unchecked: &unchecked,
+            constructor: &constructor,
+            omit: &omit,
+            expose: Expose::default(),
+            from_bytes,
+            unicode: false,
+            arena,
+            view_slices,
+            vec,
+            slice,
+            intern: intern.is_some(),
+            equality,
             impls: &impls,
         }
         .tokens();
 
-        Ok(code_gen)
+        Ok(enclose_impls(method_vis.apply(must_use.apply(
+            quote::quote! {
+                #code_gen
+                #constraint_impls
+                #literal_impls
+                #adapter_impls
+                #segment_impls
+                #registration
+            },
+        ))))
     }
 }
 
 pub struct CodeGen {
     check_mode: CheckMode,
+    error: Option<syn::Type>,
+    constraint_impls: Option<proc_macro2::TokenStream>,
+    literal_impls: Option<proc_macro2::TokenStream>,
+    adapter_impls: Option<proc_macro2::TokenStream>,
+    segment_impls: Option<proc_macro2::TokenStream>,
+    conversion_impls: Option<proc_macro2::TokenStream>,
+    path_impls: Option<proc_macro2::TokenStream>,
+    format_impls: Option<proc_macro2::TokenStream>,
+    repr_impls: Option<proc_macro2::TokenStream>,
+    layout_assertions: Option<proc_macro2::TokenStream>,
+    registration: proc_macro2::TokenStream,
     body: syn::ItemStruct,
     field: Field,
 
-    owned_attrs: AttrList,
+    owned_attrs: Vec<proc_macro2::TokenStream>,
 
-    ref_doc: Vec<syn::Lit>,
-    ref_attrs: AttrList,
+    ref_doc: Vec<syn::Attribute>,
+    ref_attrs: Vec<proc_macro2::TokenStream>,
     ref_ty: syn::Type,
 
     std_lib: StdLib,
-    expose_inner: bool,
+    unchecked: Unchecked,
+    constructor: Constructor,
+    naming: Naming,
+    omit: Omit,
+    method_vis: MethodVis,
+    must_use: MustUse,
+    from_bytes: bool,
+    unicode: bool,
+    arena: bool,
+    view_slices: bool,
+    vec: bool,
+    slice: bool,
+    intern: bool,
+    test_ctor: bool,
+    mutable: bool,
+    add: bool,
+    add_str: bool,
+    build: bool,
+    iter: bool,
+    default: Option<syn::LitStr>,
+    expose: Expose,
+    equality: Equality,
     impls: Impls,
 }
 
@@ -389,59 +1626,463 @@ impl CodeGen {
     pub fn generate(&self) -> proc_macro2::TokenStream {
         let owned = self.owned().tokens();
         let ref_ = self.borrowed().tokens();
+        let constraint_impls = &self.constraint_impls;
+        let literal_impls = &self.literal_impls;
+        let adapter_impls = &self.adapter_impls;
+        let segment_impls = &self.segment_impls;
+        let conversion_impls = &self.conversion_impls;
+        let path_impls = &self.path_impls;
+        let format_impls = &self.format_impls;
+        let repr_impls = &self.repr_impls;
+        let layout_assertions = &self.layout_assertions;
+        let registration = &self.registration;
 
-        quote::quote! {
+        let tokens = quote::quote! {
             #owned
             #ref_
-        }
+            #constraint_impls
+            #literal_impls
+            #adapter_impls
+            #segment_impls
+            #conversion_impls
+            #path_impls
+            #format_impls
+            #repr_impls
+            #layout_assertions
+            #registration
+        };
+
+        let tokens = if self.body.generics.params.is_empty() {
+            tokens
+        } else {
+            generic::GenericBraid {
+                generics: &self.body.generics,
+                owned_ty: &self.body.ident,
+                ref_ty: &self.borrowed().ident,
+                std_lib: &self.std_lib,
+            }
+            .apply(tokens)
+        };
+
+        enclose_impls(self.method_vis.apply(self.must_use.apply(tokens)))
     }
 
-    pub fn owned(&self) -> OwnedCodeGen {
+    pub fn owned(&self) -> OwnedCodeGen<'_> {
         OwnedCodeGen {
-            common_attrs: &self.body.attrs,
             check_mode: &self.check_mode,
+            error: self.error.as_ref(),
             body: &self.body,
             field: &self.field,
             attrs: &self.owned_attrs,
             ty: &self.body.ident,
             ref_ty: &self.ref_ty,
             std_lib: &self.std_lib,
-            expose_inner: self.expose_inner,
+            unchecked: &self.unchecked,
+            constructor: &self.constructor,
+            omit: &self.omit,
+            naming: self.naming,
+            from_bytes: self.from_bytes,
+            test_ctor: self.test_ctor,
+            mutable: self.mutable,
+            add: self.add,
+            add_str: self.add_str,
+            build: self.build,
+            vec: self.vec,
+            view_slices: self.view_slices,
+            iter: self.iter,
+            default: self.default.as_ref(),
+            expose: self.expose,
+            equality: self.equality,
             impls: &self.impls,
         }
     }
 
-    pub fn borrowed(&self) -> RefCodeGen {
+    pub fn borrowed(&self) -> RefCodeGen<'_> {
         RefCodeGen {
             doc: &self.ref_doc,
             common_attrs: &self.body.attrs,
             check_mode: &self.check_mode,
+            error: self.error.as_ref(),
             vis: &self.body.vis,
-            field: self.field.clone(),
+            field: self.field.ref_layout(),
             attrs: &self.ref_attrs,
             ty: &self.ref_ty,
             ident: syn::Ident::new(
                 &self.ref_ty.to_token_stream().to_string(),
                 self.ref_ty.span(),
             ),
-            owned_ty: Some(&self.body.ident),
+            owned_ty: Some(ident_to_type(&self.body.ident)),
+            owned_field: Some(&self.field),
+            to_owned_fn: None,
+            as_str_fn: None,
+            alloc: false,
             std_lib: &self.std_lib,
+            unchecked: &self.unchecked,
+            constructor: &self.constructor,
+            omit: &self.omit,
+            expose: self.expose,
+            from_bytes: self.from_bytes,
+            unicode: self.unicode,
+            arena: self.arena,
+            view_slices: self.view_slices,
+            vec: self.vec,
+            slice: self.slice,
+            intern: self.intern,
+            equality: self.equality,
             impls: &self.impls,
         }
     }
 }
 
+/// Takes the tokens of an attribute to be forwarded verbatim to one of the generated types
+///
+/// The tokens are not interpreted, so that attributes such as `cfg_attr(...)` and attribute
+/// macros taking arbitrary tokens can be forwarded.
+fn attr_tokens(
+    attr_name: symbol::Symbol,
+    list: &syn::MetaList,
+) -> Result<proc_macro2::TokenStream, syn::Error> {
+    if list.tokens.is_empty() {
+        return Err(syn::Error::new_spanned(
+            list,
+            format!(
+                "expected an attribute, such as `{}(derive(...))`",
+                attr_name
+            ),
+        ));
+    }
+
+    Ok(list.tokens.clone())
+}
+
+/// Applies the `owned_doc` and `ref_doc` overrides, returning the doc attributes for the
+/// borrowed type
+///
+/// The `{owned}` and `{ref}` placeholders in each override are replaced with the names of the
+/// owned and borrowed types. If only the owned type's docs are overridden, the borrowed type
+/// keeps the docs from the declaration. No doc attributes are returned if the borrowed type
+/// should share the declaration's docs.
+fn expand_docs(
+    body: &mut syn::ItemStruct,
+    owned_doc: &[syn::LitStr],
+    ref_doc: &[syn::LitStr],
+    ref_ty: &syn::Type,
+) -> Vec<syn::Attribute> {
+    let owned_name = body.ident.to_string();
+    let ref_name = ref_ty.to_token_stream().to_string();
+    let expand = |docs: &[syn::LitStr]| -> Vec<syn::Attribute> {
+        docs.iter()
+            .map(|doc| {
+                let value = doc
+                    .value()
+                    .replace("{owned}", &owned_name)
+                    .replace("{ref}", &ref_name);
+                let doc = syn::LitStr::new(&value, doc.span());
+                syn::parse_quote!(#[doc = #doc])
+            })
+            .collect()
+    };
+
+    let ref_doc = expand(ref_doc);
+    if owned_doc.is_empty() {
+        return ref_doc;
+    }
+
+    let (declared_doc, attrs) = std::mem::take(&mut body.attrs)
+        .into_iter()
+        .partition(|attr| attr.path().is_ident("doc"));
+    body.attrs = expand(owned_doc);
+    body.attrs.extend(attrs);
+
+    if ref_doc.is_empty() {
+        declared_doc
+    } else {
+        ref_doc
+    }
+}
+
+/// Creates the tokens of a `doc(alias)` attribute
+fn alias_attr(alias: &syn::LitStr) -> proc_macro2::TokenStream {
+    quote! { doc(alias = #alias) }
+}
+
+/// Parses attributes forwarded verbatim onto one of the generated fields
+fn parse_attrs(attrs: Vec<proc_macro2::TokenStream>) -> Result<Vec<syn::Attribute>, syn::Error> {
+    attrs
+        .into_iter()
+        .map(|attr| {
+            syn::parse::Parser::parse2(syn::Attribute::parse_outer, quote::quote!(#[#attr]))
+        })
+        .try_fold(Vec::new(), |mut parsed, attrs| {
+            parsed.extend(attrs?);
+            Ok(parsed)
+        })
+}
+
+/// Finds the path to the `aliri_braid` crate named with `crate = "..."`
+///
+/// The path is found ahead of the other arguments, as some of them, such as
+/// `convert_case`, name types within the crate as they are parsed.
+fn parse_crate_path(args: &AttrList) -> Result<Option<syn::Path>, syn::Error> {
+    let mut braid = None;
+    for arg in args {
+        if let syn::Meta::NameValue(nv) = arg {
+            if nv.path == symbol::CRATE {
+                if braid.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        nv,
+                        format!("{} can only be specified once", symbol::CRATE),
+                    ));
+                }
+
+                braid = Some(parse_lit_into_path(
+                    symbol::CRATE,
+                    parse_expr_as_lit(&nv.value)?,
+                )?);
+            }
+        }
+    }
+
+    Ok(braid)
+}
+
+fn ensure_error_has_check_mode(
+    error: Option<&syn::Type>,
+    check_mode: &CheckMode,
+) -> Result<(), syn::Error> {
+    match (error, check_mode) {
+        (Some(error), CheckMode::None) => Err(syn::Error::new_spanned(
+            error,
+            format!(
+                "{} requires a {} or {} to be specified",
+                symbol::ERROR,
+                symbol::VALIDATOR,
+                symbol::NORMALIZER
+            ),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Checks the values of a braid wrapping another braid with the inner braid first, followed by
+/// the braid's own validator, if any
+fn nest_check_mode(
+    nested: Option<&syn::Path>,
+    check_mode: CheckMode,
+    field_ty: &syn::Type,
+    std_lib: &StdLib,
+) -> Result<CheckMode, syn::Error> {
+    let nested = match nested {
+        Some(nested) => nested,
+        None => return Ok(check_mode),
+    };
+
+    let braid = std_lib.braid();
+    match check_mode {
+        CheckMode::None => Ok(CheckMode::Validate(syn::parse_quote! {
+            #braid::__private::Nested<#field_ty>
+        })),
+        CheckMode::Validate(validator) => Ok(CheckMode::Validate(syn::parse_quote! {
+            #braid::__private::Nested<#field_ty, #validator>
+        })),
+        CheckMode::Normalize(_) => Err(syn::Error::new_spanned(
+            nested,
+            format!(
+                "{} cannot be combined with a {}",
+                symbol::NESTED,
+                symbol::NORMALIZER
+            ),
+        )),
+    }
+}
+
+/// Rejects a parameter that is only supported on braids that are neither
+/// validated nor normalized
+fn ensure_unchecked(param: Option<&syn::Path>, check_mode: &CheckMode) -> Result<(), syn::Error> {
+    match (param, check_mode) {
+        (Some(param), CheckMode::Validate(_) | CheckMode::Normalize(_)) => {
+            Err(syn::Error::new_spanned(
+                param,
+                format!(
+                    "{} cannot be combined with a {}, {}, or constraints",
+                    param.to_token_stream(),
+                    symbol::VALIDATOR,
+                    symbol::NORMALIZER
+                ),
+            ))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Rejects interning on braids that omit the conversion into `Arc`, which the interner uses to
+/// store values
+fn ensure_intern_has_arc(intern: Option<&syn::Path>, omit: &Omit) -> Result<(), syn::Error> {
+    match (intern, omit.find("arc")) {
+        (Some(_), Some(arc)) => Err(syn::Error::new_spanned(
+            arc,
+            format!("{} requires the conversion into `Arc`", symbol::INTERN),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Rejects provenance on braids whose values are never checked, as well as on braids that
+/// rely on the owned type being laid out like its field
+///
+/// Generic braids compare their fields directly, which cannot account for a field that only
+/// exists in debug builds.
+fn ensure_provenance_supported(
+    provenance: &syn::Path,
+    check_mode: &CheckMode,
+    assert_layout: bool,
+    body: &syn::ItemStruct,
+) -> Result<(), syn::Error> {
+    let conflict = if matches!(check_mode, CheckMode::None) {
+        format!(
+            "{} requires a {}, {}, or constraints",
+            symbol::PROVENANCE,
+            symbol::VALIDATOR,
+            symbol::NORMALIZER
+        )
+    } else if assert_layout {
+        format!(
+            "{} cannot be combined with {}",
+            symbol::PROVENANCE,
+            symbol::ASSERT_LAYOUT
+        )
+    } else if !body.generics.params.is_empty() {
+        format!("{} is not supported on generic braids", symbol::PROVENANCE)
+    } else {
+        return Ok(());
+    };
+
+    Err(syn::Error::new_spanned(provenance, conflict))
+}
+
+/// Rejects a custom ordering on a case-insensitive braid, as values that are
+/// equal ignoring case must also be ordered as equal
+///
+/// When equality is omitted, the consumer's implementation may compare only part of the
+/// value, so `Hash` and `Ord` can only be generated if they do not rely on equality of the
+/// whole value.
+fn ensure_ord_consistent_with_eq(impls: &Impls, equality: Equality) -> Result<(), syn::Error> {
+    if equality.is_omitted() {
+        if impls.hash.is_generated() {
+            return Err(syn::Error::new(
+                proc_macro2::Span::call_site(),
+                format!(
+                    "an omitted {} requires {} to be `omit` or `custom`",
+                    symbol::EQ,
+                    symbol::HASH
+                ),
+            ));
+        }
+
+        if !impls.ord.is_omitted() {
+            return Err(syn::Error::new(
+                proc_macro2::Span::call_site(),
+                format!(
+                    "an omitted {} requires {} to be `omit`",
+                    symbol::EQ,
+                    symbol::ORD
+                ),
+            ));
+        }
+    }
+
+    if impls.ord.is_custom() && !equality.is_exact() {
+        return Err(syn::Error::new(
+            proc_macro2::Span::call_site(),
+            format!(
+                "a custom {} cannot be combined with a case-insensitive {}",
+                symbol::ORD,
+                symbol::EQ
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+/// The error type produced by fallible constructors, which is the validator's
+/// error unless overridden with the `error` parameter
+fn error_type(
+    error: Option<&syn::Type>,
+    validator: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    error.map_or_else(
+        || quote::quote! { #validator::Error },
+        ToTokens::to_token_stream,
+    )
+}
+
+/// Moves every generated item other than the braid's type declarations into an anonymous
+/// `const _: () = { ... };` block
+///
+/// Helper items inside the block cannot collide with items in the declaring module. As the
+/// generated code names standard and `aliri_braid` items by absolute path, it is unaffected
+/// by the imports in scope at the declaration and leaves them all in use or unused as before.
+fn enclose_impls(tokens: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let file: syn::File = match syn::parse2(tokens) {
+        Ok(file) => file,
+        Err(err) => return err.to_compile_error(),
+    };
+
+    let (decls, items): (Vec<_>, Vec<_>) = file
+        .items
+        .into_iter()
+        .partition(|item| matches!(item, syn::Item::Struct(_)));
+
+    quote::quote! {
+        #(#decls)*
+
+        const _: () = {
+            #(#items)*
+        };
+    }
+}
+
+/// Spans generated constructors to the braid declaration
+///
+/// Diagnostics that refer to these items, such as conflicting definitions, and IDE
+/// go-to-definition then land on the declaring struct's name rather than on the macro
+/// invocation as a whole. Only the attributes, signatures, and the braces delimiting function
+/// bodies are respanned, so lints on the bodies themselves remain attributed to the macro.
+fn respan_to_declaration(
+    tokens: proc_macro2::TokenStream,
+    ident: &syn::Ident,
+) -> proc_macro2::TokenStream {
+    let span = ident.span();
+    tokens
+        .into_iter()
+        .map(|token| match token {
+            proc_macro2::TokenTree::Group(mut g)
+                if g.delimiter() == proc_macro2::Delimiter::Brace =>
+            {
+                g.set_span(span);
+                proc_macro2::TokenTree::Group(g)
+            }
+            token => symbol::respan_token_tree(token, span),
+        })
+        .collect()
+}
+
 fn infer_ref_type_from_owned_name(name: &syn::Ident) -> syn::Type {
     let name_str = name.to_string();
     if name_str.ends_with("Buf") || name_str.ends_with("String") {
         syn::Type::Path(syn::TypePath {
             qself: None,
-            path: syn::Path::from(format_ident!("{}", name_str[..name_str.len() - 3])),
+            path: syn::Path::from(format_ident!(
+                "{}",
+                name_str[..name_str.len() - 3],
+                span = name.span()
+            )),
         })
     } else {
         syn::Type::Path(syn::TypePath {
             qself: None,
-            path: syn::Path::from(format_ident!("{}Ref", name_str)),
+            path: syn::Path::from(format_ident!("{}Ref", name_str, span = name.span())),
         })
     }
 }
@@ -466,6 +2107,12 @@ fn create_field_if_none(fields: &mut syn::Fields) {
     }
 }
 
+fn add_value_field_attrs(fields: &mut syn::Fields, attrs: Vec<syn::Attribute>) {
+    if let Some(field) = fields.iter_mut().find(|f| !is_phantom_data(&f.ty)) {
+        field.attrs.extend(attrs);
+    }
+}
+
 fn create_ref_field_if_none(fields: &mut syn::Fields) {
     if fields.is_empty() {
         let field = syn::Field {
@@ -486,20 +2133,86 @@ fn create_ref_field_if_none(fields: &mut syn::Fields) {
     }
 }
 
-fn get_field_info(
-    fields: &syn::Fields,
-) -> Result<(&syn::Type, Option<&syn::Ident>, &[syn::Attribute]), syn::Error> {
-    let mut iter = fields.iter();
-    let field = iter.next().unwrap();
+fn get_field_info(fields: &syn::Fields) -> Result<Field, syn::Error> {
+    let mut value = None;
+    let mut phantoms = Vec::new();
 
-    if iter.next().is_some() {
-        return Err(syn::Error::new_spanned(
-            fields,
-            "typed string can only have one field",
-        ));
+    for (index, field) in fields.iter().enumerate() {
+        let name = field
+            .ident
+            .clone()
+            .map_or(FieldName::Unnamed(index), FieldName::Named);
+
+        if is_phantom_data(&field.ty) {
+            phantoms.push(PhantomField {
+                attrs: field.attrs.clone(),
+                name,
+                ty: field.ty.clone(),
+            });
+        } else if value.is_some() {
+            return Err(syn::Error::new_spanned(
+                fields,
+                "typed string can only have one field",
+            ));
+        } else {
+            value = Some((field, name));
+        }
     }
 
-    Ok((&field.ty, field.ident.as_ref(), &field.attrs))
+    let (field, name) = value.ok_or_else(|| {
+        syn::Error::new_spanned(fields, "typed string must have a non-phantom field")
+    })?;
+
+    Ok(Field {
+        attrs: field.attrs.clone(),
+        name,
+        ty: field.ty.clone(),
+        phantoms,
+        nested: false,
+        provenance: None,
+    })
+}
+
+/// Adds a field recording how the value was created to the owned type, in debug builds only
+fn add_provenance_field(fields: &mut syn::Fields, std_lib: &StdLib) -> ProvenanceField {
+    let braid = std_lib.braid().clone();
+    let mut field = syn::Field {
+        vis: syn::Visibility::Inherited,
+        attrs: vec![syn::parse_quote! { #[cfg(debug_assertions)] }],
+        colon_token: None,
+        ident: None,
+        ty: syn::parse_quote! { #braid::__private::Tracked },
+        mutability: syn::FieldMutability::None,
+    };
+
+    let name = match fields {
+        syn::Fields::Named(named) => {
+            let name = format_ident!("provenance");
+            field.ident = Some(name.clone());
+            field.colon_token = Some(Default::default());
+            named.named.push(field);
+            FieldName::Named(name)
+        }
+        syn::Fields::Unnamed(unnamed) => {
+            let index = unnamed.unnamed.len();
+            unnamed.unnamed.push(field);
+            FieldName::Unnamed(index)
+        }
+        syn::Fields::Unit => unreachable!("a field is always created for the value"),
+    };
+
+    ProvenanceField { name, braid }
+}
+
+fn is_phantom_data(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(ty) => ty
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "PhantomData"),
+        _ => false,
+    }
 }
 
 #[derive(Clone)]
@@ -507,32 +2220,213 @@ pub struct Field {
     pub attrs: Vec<syn::Attribute>,
     pub name: FieldName,
     pub ty: syn::Type,
+    pub phantoms: Vec<PhantomField>,
+    /// Whether the field is itself a braid, declared with `nested`
+    pub nested: bool,
+    /// The field recording how the value was created, declared with `provenance`
+    pub provenance: Option<ProvenanceField>,
+}
+
+/// A field of the owned type recording how the value was created
+///
+/// The field only exists in debug builds, so every mention of it is gated on
+/// `debug_assertions`.
+#[derive(Clone)]
+pub struct ProvenanceField {
+    pub name: FieldName,
+    braid: syn::Path,
+}
+
+/// A `PhantomData` field carrying the type parameters of a generic braid
+#[derive(Clone)]
+pub struct PhantomField {
+    pub attrs: Vec<syn::Attribute>,
+    pub name: FieldName,
+    pub ty: syn::Type,
+}
+
+impl PhantomField {
+    /// The `PhantomData` value, spelled with the path used in the field's type
+    fn value(&self) -> proc_macro2::TokenStream {
+        match &self.ty {
+            syn::Type::Path(ty) => {
+                let mut path = ty.path.clone();
+                if let Some(segment) = path.segments.last_mut() {
+                    segment.arguments = syn::PathArguments::None;
+                }
+                path.into_token_stream()
+            }
+            ty => ty.into_token_stream(),
+        }
+    }
 }
 
 impl Field {
-    fn self_constructor(&self) -> SelfConstructorImpl {
+    fn self_constructor(&self) -> SelfConstructorImpl<'_> {
         SelfConstructorImpl(self)
     }
+
+    /// Constructs `Self` from a value that was not checked, recording the caller as its
+    /// provenance
+    fn unchecked_self_constructor(&self) -> proc_macro2::TokenStream {
+        self.construct(quote::quote! { Self }, self.name.input_name(), |braid| {
+            quote::quote! { #braid::__private::Tracked::unchecked() }
+        })
+    }
+
+    /// Constructs the struct named by `path` from `value`, filling in any phantom fields
+    fn constructor(&self, path: impl ToTokens, value: impl ToTokens) -> proc_macro2::TokenStream {
+        self.construct(path, value, |braid| {
+            quote::quote! { #braid::__private::Tracked(#braid::Provenance::Checked) }
+        })
+    }
+
+    fn construct(
+        &self,
+        path: impl ToTokens,
+        value: impl ToTokens,
+        tracked: impl FnOnce(&syn::Path) -> proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        let value = value.into_token_stream();
+        let phantoms = self.phantoms.iter().map(|p| (&p.name, p.value()));
+        let mut fields: Vec<_> = std::iter::once((&self.name, value))
+            .chain(phantoms)
+            .collect();
+        let provenance = self.provenance.as_ref().map(|provenance| {
+            let name = &provenance.name;
+            let tracked = tracked(&provenance.braid);
+            quote::quote! { #[cfg(debug_assertions)] #name: #tracked }
+        });
+
+        match (&self.name, provenance) {
+            // Fields of tuple structs can be named by their index, which allows the
+            // provenance to be gated on `debug_assertions`
+            (FieldName::Named(_), provenance) | (FieldName::Unnamed(_), provenance @ Some(_)) => {
+                let fields = fields
+                    .iter()
+                    .map(|(name, value)| quote::quote! { #name: #value });
+                quote::quote! { #path { #(#fields,)* #provenance } }
+            }
+            (FieldName::Unnamed(_), None) => {
+                fields.sort_by_key(|(name, _)| name.index());
+                let values = fields.iter().map(|(_, value)| value);
+                quote::quote! { #path ( #(#values),* ) }
+            }
+        }
+    }
+
+    /// Whether the field is declared as a `String`, such that it can be
+    /// normalized without being copied, and slices of strings can be viewed as
+    /// slices of the braid
+    fn is_string(&self) -> bool {
+        self.is_declared_as("String")
+    }
+
+    /// Whether the field is declared as a `bytestring::ByteString`, such that it
+    /// can be converted to and from `bytes::Bytes` without being copied
+    fn is_byte_string(&self) -> bool {
+        self.is_declared_as("ByteString")
+    }
+
+    /// Whether the field is declared as a `Cow<'static, str>`, such that a static string slice
+    /// can be wrapped during constant evaluation
+    fn is_static_cow(&self) -> bool {
+        match syn::parse2(self.ty.to_token_stream()) {
+            Ok(syn::Type::Path(path)) if path.qself.is_none() => {
+                path.path.segments.last().is_some_and(|s| {
+                    s.ident == "Cow"
+                        && matches!(
+                            &s.arguments,
+                            syn::PathArguments::AngleBracketed(args)
+                                if matches!(
+                                    args.args.first(),
+                                    Some(syn::GenericArgument::Lifetime(l)) if l.ident == "static"
+                                )
+                        )
+                })
+            }
+            _ => false,
+        }
+    }
+
+    /// Converts a `&str`, `Box<str>`, or `Cow<str>` into the field's type
+    ///
+    /// A `Cow<'static, str>` can only borrow static string slices, so other values are copied
+    /// into an owned `String` first. A nested braid is constructed through its own checked
+    /// conversion, which cannot fail once the value has been validated.
+    fn convert_str(&self, value: impl ToTokens, std_lib: &StdLib) -> proc_macro2::TokenStream {
+        let core = std_lib.core();
+        let alloc = std_lib.alloc();
+        if self.nested {
+            let braid = std_lib.braid();
+            quote::quote! { #braid::__private::nested_field(#value) }
+        } else if self.is_static_cow() {
+            quote::quote! {
+                ::#alloc::borrow::Cow::Owned(::#alloc::string::String::from(#value))
+            }
+        } else {
+            quote::quote! { ::#core::convert::From::from(#value) }
+        }
+    }
+
+    fn is_declared_as(&self, name: &str) -> bool {
+        // The field created for braids declared without one is verbatim
+        match syn::parse2(self.ty.to_token_stream()) {
+            Ok(syn::Type::Path(path)) if path.qself.is_none() => path
+                .path
+                .segments
+                .last()
+                .is_some_and(|s| s.ident == name && s.arguments.is_empty()),
+            _ => false,
+        }
+    }
+
+    /// The layout of the borrowed form, in which the unsized value must come after any
+    /// phantom fields
+    fn ref_layout(&self) -> Self {
+        let reindex = |name: &FieldName, index| match name {
+            FieldName::Named(name) => FieldName::Named(name.clone()),
+            FieldName::Unnamed(_) => FieldName::Unnamed(index),
+        };
+
+        Self {
+            attrs: self.attrs.clone(),
+            name: reindex(&self.name, self.phantoms.len()),
+            ty: self.ty.clone(),
+            phantoms: self
+                .phantoms
+                .iter()
+                .enumerate()
+                .map(|(index, p)| PhantomField {
+                    attrs: p.attrs.clone(),
+                    name: reindex(&p.name, index),
+                    ty: p.ty.clone(),
+                })
+                .collect(),
+            nested: false,
+            provenance: None,
+        }
+    }
 }
 
 #[derive(Clone)]
 pub enum FieldName {
     Named(syn::Ident),
-    Unnamed,
+    Unnamed(usize),
 }
 
 impl FieldName {
-    fn constructor_delimiter(&self) -> proc_macro2::Delimiter {
+    fn index(&self) -> usize {
         match self {
-            FieldName::Named(_) => proc_macro2::Delimiter::Brace,
-            FieldName::Unnamed => proc_macro2::Delimiter::Parenthesis,
+            FieldName::Named(_) => 0,
+            FieldName::Unnamed(index) => *index,
         }
     }
 
     fn input_name(&self) -> proc_macro2::Ident {
         match self {
             FieldName::Named(name) => name.clone(),
-            FieldName::Unnamed => proc_macro2::Ident::new("raw", proc_macro2::Span::call_site()),
+            FieldName::Unnamed(_) => proc_macro2::Ident::new("raw", proc_macro2::Span::call_site()),
         }
     }
 }
@@ -541,7 +2435,7 @@ impl ToTokens for FieldName {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         match self {
             Self::Named(ident) => ident.to_tokens(tokens),
-            Self::Unnamed => tokens.append(proc_macro2::Literal::u8_unsuffixed(0)),
+            Self::Unnamed(index) => tokens.append(proc_macro2::Literal::usize_unsuffixed(*index)),
         }
     }
 }
@@ -551,13 +2445,7 @@ struct SelfConstructorImpl<'a>(&'a Field);
 impl<'a> ToTokens for SelfConstructorImpl<'a> {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         let Self(field) = self;
-        tokens.append(proc_macro2::Ident::new(
-            "Self",
-            proc_macro2::Span::call_site(),
-        ));
-        tokens.append(proc_macro2::Group::new(
-            field.name.constructor_delimiter(),
-            field.name.input_name().into_token_stream(),
-        ));
+        let path = proc_macro2::Ident::new("Self", proc_macro2::Span::call_site());
+        tokens.extend(field.constructor(path, field.name.input_name()));
     }
 }
@@ -0,0 +1,76 @@
+use quote::ToTokens;
+
+/// Whether `#[must_use]` is placed on the generated inherent methods
+///
+/// Every generated inherent method returns a value derived from its
+/// arguments, so discarding it is almost certainly a mistake. Methods
+/// returning a `Result` are left alone, as `Result` is already `#[must_use]`.
+/// A message is always given, so that annotating the braid itself with
+/// `#[must_use]` does not trip `clippy::double_must_use`.
+/// Trait implementations, such as `From` and `TryFrom`, cannot be annotated.
+pub struct MustUse {
+    enabled: bool,
+}
+
+impl Default for MustUse {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl MustUse {
+    pub fn disable(&mut self) {
+        self.enabled = false;
+    }
+
+    /// Adds `#[must_use]` to each method in the generated inherent impls
+    pub fn apply(&self, tokens: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        if !self.enabled {
+            return tokens;
+        }
+
+        let mut file: syn::File = match syn::parse2(tokens) {
+            Ok(file) => file,
+            Err(err) => return err.to_compile_error(),
+        };
+
+        let methods = file
+            .items
+            .iter_mut()
+            .filter_map(|item| match item {
+                syn::Item::Impl(item) if item.trait_.is_none() => Some(&mut item.items),
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|item| match item {
+                syn::ImplItem::Fn(method) => Some(method),
+                _ => None,
+            })
+            .filter(|method| {
+                !method.sig.ident.to_string().starts_with("__")
+                    && returns_plain_value(&method.sig.output)
+                    && !method.attrs.iter().any(|a| a.path().is_ident("must_use"))
+            });
+
+        for method in methods {
+            method.attrs.push(syn::parse_quote!(
+                #[must_use = "this returns a value and has no other effect"]
+            ));
+        }
+
+        file.into_token_stream()
+    }
+}
+
+/// Determines whether a method returns a value that is not already `#[must_use]`
+fn returns_plain_value(output: &syn::ReturnType) -> bool {
+    match output {
+        syn::ReturnType::Default => false,
+        syn::ReturnType::Type(_, ty) => match &**ty {
+            syn::Type::Path(path) => {
+                !matches!(path.path.segments.last(), Some(segment) if segment.ident == "Result")
+            }
+            _ => true,
+        },
+    }
+}
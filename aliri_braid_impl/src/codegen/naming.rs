@@ -0,0 +1,40 @@
+use quote::format_ident;
+
+/// The generation of names given to the generated inherent methods
+///
+/// `v2` gives methods names that do not shadow standard trait methods and
+/// that describe what they do, keeping the `v1` names as deprecated aliases
+/// so that existing callers continue to compile.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Naming {
+    #[default]
+    V1,
+    V2,
+}
+
+impl std::str::FromStr for Naming {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "v1" => Ok(Self::V1),
+            "v2" => Ok(Self::V2),
+            _ => Err("valid values are: `v1` or `v2`"),
+        }
+    }
+}
+
+impl Naming {
+    /// The name of the method unwrapping the owned type into its field
+    pub fn into_inner(self) -> syn::Ident {
+        match self {
+            Self::V1 => format_ident!("take"),
+            Self::V2 => format_ident!("into_inner"),
+        }
+    }
+
+    /// The former name of the method unwrapping the owned type, if renamed
+    pub fn deprecated_into_inner(self) -> Option<syn::Ident> {
+        (self == Self::V2).then(|| format_ident!("take"))
+    }
+}
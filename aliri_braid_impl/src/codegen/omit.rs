@@ -0,0 +1,114 @@
+use super::symbol;
+
+/// The inherent methods of owned braids that can be omitted
+pub const OWNED_METHODS: &[&str] = &[
+    "from_static",
+    "try_from_static",
+    "take",
+    "into_inner",
+    "into_boxed_ref",
+    "into_owned",
+    "normalize",
+    "is_normalized",
+    "normalized",
+    "build",
+    "slice",
+    "split_at",
+    "from_vec",
+    "try_from_vec",
+    "into_inner_vec",
+    "from_str_vec",
+    "from_slice",
+    "as_inner_slice",
+    "from_str_slice",
+    // Not a method, but all conversions that require the field to implement `From<Box<str>>`
+    "box_conversions",
+    // Not methods, but the conversions into `Rc` and `Arc` of the borrowed type
+    "rc",
+    "arc",
+];
+
+/// The inherent methods of borrowed-only braids that can be omitted
+pub const REF_METHODS: &[&str] = &[
+    "from_static",
+    "try_from_static",
+    "into_owned",
+    "is_normalized",
+    "normalized",
+    "slice",
+    "split_at",
+    "from_str_vec",
+    "from_str_slice",
+    // Not methods, but the conversions into `Rc` and `Arc` of the borrowed type
+    "rc",
+    "arc",
+];
+
+/// The generated inherent methods suppressed with `omit(...)`
+///
+/// A method is omitted from both the owned and borrowed types wherever it is
+/// generated. Other generated items never call an omitted method, so that
+/// users are free to define their own methods under those names.
+#[derive(Default)]
+pub struct Omit {
+    methods: Vec<syn::Ident>,
+}
+
+impl Omit {
+    pub fn try_extend(
+        &mut self,
+        list: &syn::MetaList,
+        omittable: &[&str],
+    ) -> Result<(), syn::Error> {
+        let methods = list.parse_args_with(
+            syn::punctuated::Punctuated::<syn::Ident, syn::Token![,]>::parse_terminated,
+        )?;
+
+        for method in methods {
+            if !omittable.iter().any(|m| method == m) {
+                return Err(syn::Error::new_spanned(
+                    &method,
+                    format!(
+                        "{} does not support `{}`; valid methods are: {}",
+                        symbol::OMIT,
+                        method,
+                        omittable
+                            .iter()
+                            .map(|m| format!("`{m}`"))
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    ),
+                ));
+            }
+
+            if self.methods.contains(&method) {
+                return Err(syn::Error::new_spanned(
+                    &method,
+                    format!(
+                        "{} method `{}` can only be specified once",
+                        symbol::OMIT,
+                        method
+                    ),
+                ));
+            }
+
+            self.methods.push(method);
+        }
+
+        Ok(())
+    }
+
+    /// Finds the named method if it is omitted
+    pub fn find(&self, method: &str) -> Option<&syn::Ident> {
+        self.methods.iter().find(|m| *m == method)
+    }
+
+    /// Keeps the tokens of a method unless it is omitted
+    pub fn retain(
+        &self,
+        method: impl AsRef<str>,
+        tokens: proc_macro2::TokenStream,
+    ) -> Option<proc_macro2::TokenStream> {
+        self.find(method.as_ref()).is_none().then_some(tokens)
+    }
+}
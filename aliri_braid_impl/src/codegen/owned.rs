@@ -1,17 +1,35 @@
 use quote::{quote, ToTokens};
 
-use super::{impls::ToImpl, AttrList, CheckMode, Field, Impls, StdLib};
+use super::{
+    equality::Equality, impls::ToImpl, CheckMode, Constructor, Expose, Field, Impls, Naming, Omit,
+    StdLib, Unchecked,
+};
 
 pub struct OwnedCodeGen<'a> {
-    pub common_attrs: &'a [syn::Attribute],
-    pub attrs: &'a AttrList,
+    pub attrs: &'a [proc_macro2::TokenStream],
     pub body: &'a syn::ItemStruct,
     pub ty: &'a syn::Ident,
     pub field: &'a Field,
     pub check_mode: &'a CheckMode,
+    pub error: Option<&'a syn::Type>,
     pub ref_ty: &'a syn::Type,
     pub std_lib: &'a StdLib,
-    pub expose_inner: bool,
+    pub unchecked: &'a Unchecked,
+    pub constructor: &'a Constructor,
+    pub naming: Naming,
+    pub omit: &'a Omit,
+    pub from_bytes: bool,
+    pub test_ctor: bool,
+    pub mutable: bool,
+    pub add: bool,
+    pub add_str: bool,
+    pub build: bool,
+    pub vec: bool,
+    pub view_slices: bool,
+    pub iter: bool,
+    pub default: Option<&'a syn::LitStr>,
+    pub expose: Expose,
+    pub equality: Equality,
     pub impls: &'a Impls,
 }
 
@@ -33,24 +51,42 @@ impl<'a> OwnedCodeGen<'a> {
         let ref_ty = self.ref_ty;
         let field_ty = &self.field.ty;
         let alloc = self.std_lib.alloc();
+        let new = self.constructor.owned();
 
-        let vis = self
-            .expose_inner
-            .then(|| proc_macro2::Ident::new("pub", proc_macro2::Span::call_site()));
+        let vis = self.expose.inner_vis();
+
+        // A static string slice can be borrowed without allocating, so that constants can be
+        // declared with `from_static()`
+        let from_static = if self.field.is_static_cow() {
+            quote! {
+                #[inline]
+                #[doc = #static_doc_comment]
+                #[doc = ""]
+                #[doc = "The value is borrowed rather than copied, so this function can be used to declare constants."]
+                pub const fn from_static(raw: &'static str) -> Self {
+                    Self::#new(::#alloc::borrow::Cow::Borrowed(raw))
+                }
+            }
+        } else {
+            quote! {
+                #[inline]
+                #[doc = #static_doc_comment]
+                #[track_caller]
+                pub fn from_static(raw: &'static str) -> Self {
+                    ::#alloc::borrow::ToOwned::to_owned(#ref_ty::from_static(raw))
+                }
+            }
+        };
+        let from_static = self.omit.retain("from_static", from_static);
 
         quote! {
             #[doc = #doc_comment]
             #[inline]
-            #vis const fn new(#param: #field_ty) -> Self {
+            #vis const fn #new(#param: #field_ty) -> Self {
                 #create
             }
 
-            #[inline]
-            #[doc = #static_doc_comment]
-            #[track_caller]
-            pub fn from_static(raw: &'static str) -> Self {
-                ::#alloc::borrow::ToOwned::to_owned(#ref_ty::from_static(raw))
-            }
+            #from_static
         }
     }
 
@@ -66,50 +102,131 @@ impl<'a> OwnedCodeGen<'a> {
             self.ty, validator_tokens
         );
 
+        let try_static_doc_comment = format!(
+            "Constructs a new {} from a static reference if it conforms to [`{}`], returning an \
+             error instead of panicking if it does not",
+            self.ty, validator_tokens
+        );
+
         let doc_comment_unsafe = format!(
             "Constructs a new {} without validation\n\n# Safety\n\nConsumers of this function \
              must ensure that values conform to [`{}`]. Failure to maintain this invariant may \
-             lead to undefined behavior.",
-            self.ty, validator_tokens
+             lead to undefined behavior.\n\nTo check this invariant in debug builds, use \
+             [`{}::{}`] instead.",
+            self.ty,
+            validator_tokens,
+            self.ty,
+            self.unchecked.owned_checked_constructor(),
+        );
+        let doc_comment_checked = format!(
+            "Constructs a new {} without validation in release builds\n\n# Safety\n\nConsumers of \
+             this function must ensure that values conform to [`{}`]. Failure to maintain this \
+             invariant may lead to undefined behavior.\n\nUnlike [`{}::{}`], this function is not \
+             `const`, and checks this invariant with a debug assertion in debug builds.",
+            self.ty,
+            validator_tokens,
+            self.ty,
+            self.unchecked.owned_constructor(),
         );
 
-        let validator = crate::as_validator(validator);
+        let validator = crate::as_validator(validator, self.std_lib);
+        let error = super::error_type(self.error, &validator);
         let param = self.field.name.input_name();
+        let unchecked = self.unchecked.owned_constructor();
+        let unchecked_vis = self.unchecked.vis(self.expose.inner());
+        let unchecked_attrs = self.unchecked.attrs();
+        let unsafety = self.unchecked.unsafety();
+        let unchecked_checked = self.unchecked.owned_checked_constructor();
+        let call_unchecked = self.unchecked.call(quote! { Self::#unchecked(#param) });
+        let debug_assert_msg =
+            format!("invalid value passed to {}::{}", self.ty, unchecked_checked);
         let create = self.field.self_constructor();
+        let create_unchecked = self.field.unchecked_self_constructor();
+        let track_caller = self.track_provenance_caller();
         let ref_ty = self.ref_ty;
         let field_ty = &self.field.ty;
         let core = self.std_lib.core();
         let alloc = self.std_lib.alloc();
+        let new = self.constructor.owned();
+
+        let vis = self.expose.inner_vis();
+
+        let (from_static_body, try_from_static_body) = if self.field.is_static_cow() {
+            (
+                quote! {
+                    Self::#new(::#alloc::borrow::Cow::Borrowed(raw))
+                        .expect(::#core::concat!("invalid ", ::#core::stringify!(#ref_ty)))
+                },
+                quote! { Self::#new(::#alloc::borrow::Cow::Borrowed(raw)) },
+            )
+        } else {
+            (
+                quote! { ::#alloc::borrow::ToOwned::to_owned(#ref_ty::from_static(raw)) },
+                quote! {
+                    ::#core::result::Result::Ok(::#alloc::borrow::ToOwned::to_owned(#ref_ty::try_from_static(raw)?))
+                },
+            )
+        };
 
-        let vis = self
-            .expose_inner
-            .then(|| proc_macro2::Ident::new("pub", proc_macro2::Span::call_site()));
+        let from_static = self.omit.retain(
+            "from_static",
+            quote! {
+                #[inline]
+                #[doc = #static_doc_comment]
+                #[doc = ""]
+                #[doc = "# Panics"]
+                #[doc = ""]
+                #[doc = "This function will panic if the provided raw string is not valid."]
+                #[track_caller]
+                pub fn from_static(raw: &'static str) -> Self {
+                    #from_static_body
+                }
+            },
+        );
+
+        let try_from_static = self.omit.retain(
+            "try_from_static",
+            quote! {
+                #[inline]
+                #[doc = #try_static_doc_comment]
+                pub fn try_from_static(raw: &'static str) -> ::#core::result::Result<Self, #error> {
+                    #try_from_static_body
+                }
+            },
+        );
 
         quote! {
             #[doc = #doc_comment]
             #[inline]
-            #vis fn new(#param: #field_ty) -> ::#core::result::Result<Self, #validator::Error> {
+            #vis fn #new(#param: #field_ty) -> ::#core::result::Result<Self, #error> {
                 #validator::validate(#param.as_ref())?;
                 ::#core::result::Result::Ok(#create)
             }
 
             #[doc = #doc_comment_unsafe]
+            #unchecked_attrs
             #[allow(unsafe_code)]
             #[inline]
-            #vis const unsafe fn new_unchecked(#param: #field_ty) -> Self {
-                #create
+            #track_caller
+            #unchecked_vis const #unsafety fn #unchecked(#param: #field_ty) -> Self {
+                #create_unchecked
             }
 
+            #[doc = #doc_comment_checked]
+            #unchecked_attrs
+            #[allow(unsafe_code)]
             #[inline]
-            #[doc = #static_doc_comment]
-            #[doc = ""]
-            #[doc = "# Panics"]
-            #[doc = ""]
-            #[doc = "This function will panic if the provided raw string is not valid."]
             #[track_caller]
-            pub fn from_static(raw: &'static str) -> Self {
-                ::#alloc::borrow::ToOwned::to_owned(#ref_ty::from_static(raw))
+            #unchecked_vis #unsafety fn #unchecked_checked(#param: #field_ty) -> Self {
+                ::#core::debug_assert!(
+                    #validator::validate(::#core::convert::AsRef::<str>::as_ref(&#param)).is_ok(),
+                    #debug_assert_msg,
+                );
+                #call_unchecked
             }
+
+            #from_static
+            #try_from_static
         }
     }
 
@@ -126,55 +243,205 @@ impl<'a> OwnedCodeGen<'a> {
             self.ty, normalizer_tokens
         );
 
+        let try_static_doc_comment = format!(
+            "Constructs a new {} from a static reference if it conforms to [`{}`], normalizing \
+             the input and returning an error instead of panicking if it does not conform",
+            self.ty, normalizer_tokens
+        );
+
         let doc_comment_unsafe = format!(
             "Constructs a new {} without validation or normalization\n\n# Safety\n\nConsumers of \
              this function must ensure that values conform to [`{}`] and are in normalized form. \
-             Failure to maintain this invariant may lead to undefined behavior.",
-            self.ty, normalizer_tokens
+             Failure to maintain this invariant may lead to undefined behavior.\n\nTo check this \
+             invariant in debug builds, use [`{}::{}`] instead.",
+            self.ty,
+            normalizer_tokens,
+            self.ty,
+            self.unchecked.owned_checked_constructor(),
+        );
+        let doc_comment_checked = format!(
+            "Constructs a new {} without validation or normalization in release builds\n\n# \
+             Safety\n\nConsumers of this function must ensure that values conform to [`{}`] and \
+             are in normalized form. Failure to maintain this invariant may lead to undefined \
+             behavior.\n\nUnlike [`{}::{}`], this function is not `const`, and checks this \
+             invariant with a debug assertion in debug builds.",
+            self.ty,
+            normalizer_tokens,
+            self.ty,
+            self.unchecked.owned_constructor(),
         );
 
         let ty = self.ty;
-        let validator = crate::as_validator(normalizer);
-        let normalizer = crate::as_normalizer(normalizer);
+        let validator = crate::as_validator(normalizer, self.std_lib);
+        let error = super::error_type(self.error, &validator);
+        let unchecked = self.unchecked.owned_constructor();
+        let unchecked_vis = self.unchecked.vis(self.expose.inner());
+        let unchecked_attrs = self.unchecked.attrs();
+        let unsafety = self.unchecked.unsafety();
+        let unchecked_checked = self.unchecked.owned_checked_constructor();
+        let debug_assert_msg = format!(
+            "invalid or non-normalized value passed to {}::{}",
+            self.ty, unchecked_checked
+        );
+        let normalizer = crate::as_normalizer(normalizer, self.std_lib);
         let param = self.field.name.input_name();
+        let call_unchecked = self.unchecked.call(quote! { Self::#unchecked(#param) });
         let create = self.field.self_constructor();
+        let create_unchecked = self.field.unchecked_self_constructor();
+        let track_caller = self.track_provenance_caller();
         let ref_ty = self.ref_ty;
         let field_ty = &self.field.ty;
         let core = self.std_lib.core();
+        let new = self.constructor.owned();
+        let from_str = self.constructor.borrowed();
+
+        let vis = self.expose.inner_vis();
+        let alloc = self.std_lib.alloc();
+
+        let (from_static_body, try_from_static_body) = if self.field.is_static_cow() {
+            (
+                quote! {
+                    Self::#new(::#alloc::borrow::Cow::Borrowed(raw))
+                        .expect(::#core::concat!("invalid ", ::#core::stringify!(#ty)))
+                },
+                quote! { Self::#new(::#alloc::borrow::Cow::Borrowed(raw)) },
+            )
+        } else {
+            (
+                quote! {
+                    #ref_ty::#from_str(raw).expect(::#core::concat!("invalid ", ::#core::stringify!(#ty))).into_owned()
+                },
+                quote! { ::#core::result::Result::Ok(#ref_ty::#from_str(raw)?.into_owned()) },
+            )
+        };
+
+        let from_static = self.omit.retain(
+            "from_static",
+            quote! {
+                #[inline]
+                #[doc = #static_doc_comment]
+                #[doc = ""]
+                #[doc = "# Panics"]
+                #[doc = ""]
+                #[doc = "This function will panic if the provided raw string is not valid."]
+                #[track_caller]
+                pub fn from_static(raw: &'static str) -> Self {
+                    #from_static_body
+                }
+            },
+        );
+
+        let try_from_static = self.omit.retain(
+            "try_from_static",
+            quote! {
+                #[inline]
+                #[doc = #try_static_doc_comment]
+                pub fn try_from_static(raw: &'static str) -> ::#core::result::Result<Self, #error> {
+                    #try_from_static_body
+                }
+            },
+        );
+
+        let normalize_param = if self.field.is_string() {
+            quote! { let #param = #normalizer::normalize_owned(#param)?; }
+        } else if self.field.is_static_cow() {
+            // A value that is already normalized is kept as is, so that borrowed static values
+            // are not copied
+            quote! {
+                let normalized = match #normalizer::normalize(&#param)? {
+                    ::#alloc::borrow::Cow::Borrowed(_) => ::#core::option::Option::None,
+                    ::#alloc::borrow::Cow::Owned(normalized) => ::#core::option::Option::Some(normalized),
+                };
+                let #param = match normalized {
+                    ::#core::option::Option::Some(normalized) => ::#alloc::borrow::Cow::Owned(normalized),
+                    ::#core::option::Option::None => #param,
+                };
+            }
+        } else {
+            let normalized = self.field.convert_str(
+                quote! { #normalizer::normalize(#param.as_ref())? },
+                self.std_lib,
+            );
+            quote! { let #param = #normalized; }
+        };
 
-        let vis = self
-            .expose_inner
-            .then(|| proc_macro2::Ident::new("pub", proc_macro2::Span::call_site()));
+        let field_name = &self.field.name;
+        let normalize = self.omit.retain(
+            "normalize",
+            quote! {
+                /// Normalizes a value that may have been created without normalization, such as
+                /// through an unchecked constructor
+                ///
+                /// Values that are already normalized, or that cannot be normalized, are returned
+                /// unchanged.
+                #[inline]
+                pub fn normalize(self) -> Self {
+                    let normalized = match #normalizer::normalize(::#core::convert::AsRef::<str>::as_ref(&self.#field_name)) {
+                        ::#core::result::Result::Ok(::#alloc::borrow::Cow::Owned(normalized)) => ::#core::option::Option::Some(normalized),
+                        _ => ::#core::option::Option::None,
+                    };
+
+                    match normalized {
+                        ::#core::option::Option::Some(normalized) => {
+                            let #param = ::#core::convert::From::from(normalized);
+                            #create
+                        }
+                        ::#core::option::Option::None => self,
+                    }
+                }
+            },
+        );
 
         quote! {
             #[doc = #doc_comment]
             #[inline]
-            #vis fn new(#param: #field_ty) -> ::#core::result::Result<Self, #validator::Error> {
-                let #param = ::#core::convert::From::from(#normalizer::normalize(#param.as_ref())?);
+            #vis fn #new(#param: #field_ty) -> ::#core::result::Result<Self, #error> {
+                #normalize_param
                 ::#core::result::Result::Ok(#create)
             }
 
             #[doc = #doc_comment_unsafe]
+            #unchecked_attrs
             #[allow(unsafe_code)]
             #[inline]
-            #vis const unsafe fn new_unchecked(#param: #field_ty) -> Self {
-                #create
+            #track_caller
+            #unchecked_vis const #unsafety fn #unchecked(#param: #field_ty) -> Self {
+                #create_unchecked
             }
 
+            #[doc = #doc_comment_checked]
+            #unchecked_attrs
+            #[allow(unsafe_code)]
             #[inline]
-            #[doc = #static_doc_comment]
-            #[doc = ""]
-            #[doc = "# Panics"]
-            #[doc = ""]
-            #[doc = "This function will panic if the provided raw string is not valid."]
             #[track_caller]
-            pub fn from_static(raw: &'static str) -> Self {
-                #ref_ty::from_str(raw).expect(concat!("invalid ", stringify!(#ty))).into_owned()
+            #unchecked_vis #unsafety fn #unchecked_checked(#param: #field_ty) -> Self {
+                ::#core::debug_assert!(
+                    #validator::validate(::#core::convert::AsRef::<str>::as_ref(&#param)).is_ok(),
+                    #debug_assert_msg,
+                );
+                #call_unchecked
             }
+
+            #from_static
+            #try_from_static
+            #normalize
         }
     }
 
-    fn make_into_boxed_ref(&self) -> proc_macro2::TokenStream {
+    fn box_pointer_reinterpret_safety_comment(&self) -> proc_macro2::TokenStream {
+        let doc = format!(
+            "SAFETY: `{ty}` is `#[repr(transparent)]` around a single `str` field, so a `*mut \
+             str` and a `*mut {ty}` can be safely reinterpreted as one another",
+            ty = self.ref_ty.to_token_stream(),
+        );
+
+        quote! {
+            #[doc = #doc]
+            fn ptr_safety_comment() {}
+        }
+    }
+
+    fn make_into_boxed_ref(&self) -> Option<proc_macro2::TokenStream> {
         let doc = format!(
             "Converts this `{}` into a [`Box<{}>`]\n\nThis will drop any excess capacity.",
             self.ty,
@@ -182,34 +449,22 @@ impl<'a> OwnedCodeGen<'a> {
         );
 
         let ref_type = self.ref_ty;
-        let field = &self.field.name;
+        let core = self.std_lib.core();
         let alloc = self.std_lib.alloc();
-        let box_pointer_reinterpret_safety_comment = {
-            let doc = format!(
-                "SAFETY: `{ty}` is `#[repr(transparent)]` around a single `str` field, so a `*mut \
-                 str` can be safely reinterpreted as a `*mut {ty}`",
-                ty = self.ref_ty.to_token_stream(),
-            );
 
+        self.omit.retain(
+            "into_boxed_ref",
             quote! {
                 #[doc = #doc]
-                fn ptr_safety_comment() {}
-            }
-        };
-
-        quote! {
-            #[doc = #doc]
-            #[allow(unsafe_code)]
-            #[inline]
-            pub fn into_boxed_ref(self) -> ::#alloc::boxed::Box<#ref_type> {
-                #box_pointer_reinterpret_safety_comment
-                let box_str = ::#alloc::string::String::from(self.#field).into_boxed_str();
-                unsafe { ::#alloc::boxed::Box::from_raw(::#alloc::boxed::Box::into_raw(box_str) as *mut #ref_type) }
-            }
-        }
+                #[inline]
+                pub fn into_boxed_ref(self) -> ::#alloc::boxed::Box<#ref_type> {
+                    ::#core::convert::From::from(self)
+                }
+            },
+        )
     }
 
-    fn make_take(&self) -> proc_macro2::TokenStream {
+    fn make_into_inner(&self) -> proc_macro2::TokenStream {
         let field = &self.field.name;
         let field_ty = &self.field.ty;
         let doc = format!(
@@ -217,41 +472,757 @@ impl<'a> OwnedCodeGen<'a> {
             field_ty.to_token_stream()
         );
 
-        let vis = self
-            .expose_inner
-            .then(|| proc_macro2::Ident::new("pub", proc_macro2::Span::call_site()));
+        let into_inner = self.naming.into_inner();
+
+        let vis = self.expose.inner_vis();
+
+        let deprecated = self.naming.deprecated_into_inner().and_then(|take| {
+            let note = format!("renamed to `{into_inner}`");
+            self.omit.retain(
+                take.to_string(),
+                quote! {
+                    #[doc = #doc]
+                    #[deprecated(note = #note)]
+                    #[allow(dead_code)]
+                    #[inline]
+                    #vis fn #take(self) -> #field_ty {
+                        self.#field
+                    }
+                },
+            )
+        });
+
+        let into_inner = self.omit.retain(
+            into_inner.to_string(),
+            quote! {
+                #[doc = #doc]
+                #[inline]
+                #vis fn #into_inner(self) -> #field_ty {
+                    self.#field
+                }
+            },
+        );
 
         quote! {
-            #[doc = #doc]
+            #into_inner
+            #deprecated
+        }
+    }
+
+    /// Attributes the provenance of values created without checks to the caller
+    fn track_provenance_caller(&self) -> Option<proc_macro2::TokenStream> {
+        self.field
+            .provenance
+            .is_some()
+            .then(|| quote! { #[track_caller] })
+    }
+
+    fn make_provenance(&self) -> Option<proc_macro2::TokenStream> {
+        let provenance = self.field.provenance.as_ref()?;
+        let name = &provenance.name;
+        let core = self.std_lib.core();
+        let braid = self.std_lib.braid();
+
+        Some(quote! {
+            /// Reports whether the value was created through a checked or an unchecked
+            /// constructor
+            ///
+            /// The provenance is only recorded in debug builds, and is `None` otherwise.
             #[inline]
-            #vis fn take(self) -> #field_ty {
-                self.#field
+            pub fn provenance(&self) -> ::#core::option::Option<#braid::Provenance> {
+                #[cfg(debug_assertions)]
+                {
+                    ::#core::option::Option::Some(self.#name.0)
+                }
+
+                #[cfg(not(debug_assertions))]
+                {
+                    ::#core::option::Option::None
+                }
             }
-        }
+        })
     }
 
     fn inherent(&self) -> proc_macro2::TokenStream {
         let name = self.ty;
-        let constructor = self.constructor();
+        let constructor = super::respan_to_declaration(self.constructor(), self.ty);
         let into_boxed_ref = self.make_into_boxed_ref();
-        let into_string = self.make_take();
-
+        let into_inner = self.make_into_inner();
+        let test_ctor = self.make_test_ctor();
+        let mutation = self.make_mutation();
+        let build = self.make_build();
+        let try_concat = self.make_try_concat();
+        let try_from_iter = self.make_try_from_iter();
+        let from_utf8 = self.make_from_utf8();
+        let byte_string = self.make_byte_string();
+        let provenance = self.make_provenance();
+        let vec_conversions = self.make_vec_conversions();
+        let slice_conversions = self.make_slice_conversions();
+
+        // The constructors are spanned to the declaration, so unused ones would otherwise be
+        // reported as dead code in braids that are private to their crate
         quote! {
             #[automatically_derived]
+            #[allow(dead_code)]
             impl #name {
                 #constructor
                 #into_boxed_ref
-                #into_string
+                #into_inner
+                #test_ctor
+                #mutation
+                #build
+                #try_concat
+                #try_from_iter
+                #vec_conversions
+                #slice_conversions
+                #from_utf8
+                #byte_string
+                #provenance
+            }
+        }
+    }
+
+    fn make_build(&self) -> Option<proc_macro2::TokenStream> {
+        if !self.build {
+            return None;
+        }
+
+        let core = self.std_lib.core();
+        let alloc = self.std_lib.alloc();
+        let from_string = self.construct_from_string();
+        let (doc_comment, output) = match self.check_mode {
+            CheckMode::None => (
+                format!(
+                    "Constructs a new {} from a string written by the provided function",
+                    self.ty,
+                ),
+                quote! { Self },
+            ),
+            CheckMode::Validate(check) | CheckMode::Normalize(check) => {
+                let validator = crate::as_validator(check, self.std_lib);
+                let error = super::error_type(self.error, &validator);
+                (
+                    format!(
+                        "Constructs a new {} from a string written by the provided function, if \
+                         the result conforms to [`{}`]\n\nThe value is only checked once the \
+                         function returns.",
+                        self.ty,
+                        check.to_token_stream(),
+                    ),
+                    quote! { ::#core::result::Result<Self, #error> },
+                )
+            }
+        };
+
+        self.omit.retain(
+            "build",
+            quote! {
+                #[doc = #doc_comment]
+                #[inline]
+                pub fn build<F: ::#core::ops::FnOnce(&mut ::#alloc::string::String)>(f: F) -> #output {
+                    let mut s = ::#alloc::string::String::new();
+                    f(&mut s);
+                    #from_string
+                }
+            },
+        )
+    }
+
+    fn make_try_concat(&self) -> Option<proc_macro2::TokenStream> {
+        let check = match self.check_mode {
+            CheckMode::Validate(check) | CheckMode::Normalize(check) if self.add => check,
+            _ => return None,
+        };
+
+        let doc_comment = format!(
+            "Concatenates another [`{}`] onto this value, constructing a new {} if the result \
+             conforms to [`{}`]",
+            self.ref_ty.to_token_stream(),
+            self.ty,
+            check.to_token_stream(),
+        );
+
+        let validator = crate::as_validator(check, self.std_lib);
+        let error = super::error_type(self.error, &validator);
+        let ref_ty = self.ref_ty;
+        let core = self.std_lib.core();
+        let alloc = self.std_lib.alloc();
+        let from_string = self.construct_from_string();
+
+        Some(quote! {
+            #[doc = #doc_comment]
+            #[inline]
+            pub fn try_concat(&self, other: &#ref_ty) -> ::#core::result::Result<Self, #error> {
+                let mut s = ::#alloc::string::String::with_capacity(self.as_str().len() + other.as_str().len());
+                s.push_str(self.as_str());
+                s.push_str(other.as_str());
+                #from_string
+            }
+        })
+    }
+
+    fn vec_reinterpret_safety_comment(&self) -> proc_macro2::TokenStream {
+        let doc = format!(
+            "SAFETY: `{ty}` is `#[repr(transparent)]` around a single `{field_ty}` field, so the \
+             allocation of a `Vec<{field_ty}>` can be safely reinterpreted as that of a \
+             `Vec<{ty}>`, and vice versa",
+            ty = self.ty,
+            field_ty = self.field.ty.to_token_stream(),
+        );
+
+        quote! {
+            #[doc = #doc]
+            fn vec_safety_comment() {}
+        }
+    }
+
+    /// Reinterprets a vector of one transparent form of the braid as a vector of the other,
+    /// without reallocating
+    fn reinterpret_vec(
+        &self,
+        values: proc_macro2::TokenStream,
+        target: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        let core = self.std_lib.core();
+        let alloc = self.std_lib.alloc();
+        let safety_comment = self.vec_reinterpret_safety_comment();
+
+        quote! {
+            let mut values = ::#core::mem::ManuallyDrop::new(#values);
+            #safety_comment
+            unsafe {
+                ::#alloc::vec::Vec::from_raw_parts(
+                    values.as_mut_ptr() as *mut #target,
+                    values.len(),
+                    values.capacity(),
+                )
+            }
+        }
+    }
+
+    /// Views slices of strings as slices of the braid, and vice versa
+    ///
+    /// Only unvalidated braids wrapping a `String` can be viewed this way, as any string is a
+    /// valid value.
+    fn make_slice_conversions(&self) -> Option<proc_macro2::TokenStream> {
+        if !self.view_slices
+            || !matches!(self.check_mode, CheckMode::None)
+            || !self.field.is_string()
+        {
+            return None;
+        }
+
+        let ty = self.ty;
+        let field_ty = &self.field.ty;
+        let vis = self.expose.inner_vis();
+        let safety_doc = format!(
+            "SAFETY: `{ty}` is `#[repr(transparent)]` around a single `{field}` field, so a \
+             `*const [{field}]` and a `*const [{ty}]` can be safely reinterpreted as one another",
+            field = field_ty.to_token_stream(),
+        );
+        let from_doc = format!(
+            "Views each of the provided [`{}`] values as a {ty}, without copying",
+            field_ty.to_token_stream(),
+        );
+        let as_doc = format!(
+            "Views the underlying [`{}`] value of each of the provided {ty} values, without \
+             copying",
+            field_ty.to_token_stream(),
+        );
+
+        let from_slice = self.omit.retain(
+            "from_slice",
+            quote! {
+                #[doc = #from_doc]
+                #[allow(unsafe_code)]
+                #[inline]
+                #vis fn from_slice(values: &[#field_ty]) -> &[Self] {
+                    #[doc = #safety_doc]
+                    fn slice_safety_comment() {}
+                    unsafe { &*(values as *const [#field_ty] as *const [Self]) }
+                }
+            },
+        );
+        let as_inner_slice = self.omit.retain(
+            "as_inner_slice",
+            quote! {
+                #[doc = #as_doc]
+                #[allow(unsafe_code)]
+                #[inline]
+                #vis fn as_inner_slice(values: &[Self]) -> &[#field_ty] {
+                    #[doc = #safety_doc]
+                    fn slice_safety_comment() {}
+                    unsafe { &*(values as *const [Self] as *const [#field_ty]) }
+                }
+            },
+        );
+
+        Some(quote! {
+            #from_slice
+            #as_inner_slice
+        })
+    }
+
+    /// Converts vectors of the wrapped type without copying each element
+    ///
+    /// Values carrying their provenance are not transparent in debug builds, and are
+    /// converted element by element instead.
+    fn make_vec_conversions(&self) -> Option<proc_macro2::TokenStream> {
+        if !self.vec {
+            return None;
+        }
+
+        let ty = self.ty;
+        let field_ty = &self.field.ty;
+        let field = &self.field.name;
+        let core = self.std_lib.core();
+        let alloc = self.std_lib.alloc();
+        let new = self.constructor.owned();
+        let vis = self.expose.inner_vis();
+
+        let from_vec = match self.check_mode {
+            CheckMode::None => {
+                let doc_comment = format!(
+                    "Constructs a {ty} from each of the provided [`{}`] values\n\nThe allocation \
+                     of the vector is reused, without copying the values.",
+                    field_ty.to_token_stream(),
+                );
+                let reinterpret = self.reinterpret_vec(quote! { values }, quote! { Self });
+
+                self.omit.retain(
+                    "from_vec",
+                    quote! {
+                        #[doc = #doc_comment]
+                        #[allow(unsafe_code)]
+                        #[inline]
+                        #vis fn from_vec(values: ::#alloc::vec::Vec<#field_ty>) -> ::#alloc::vec::Vec<Self> {
+                            #reinterpret
+                        }
+                    },
+                )
+            }
+            CheckMode::Validate(check) | CheckMode::Normalize(check) => {
+                let doc_comment = format!(
+                    "Constructs a {ty} from each of the provided [`{}`] values if they all \
+                     conform to [`{}`]\n\nThe allocation of the vector is reused where \
+                     possible.\n\n# Errors\n\nStops at the first value that is not valid, \
+                     returning its index alongside the error.",
+                    field_ty.to_token_stream(),
+                    check.to_token_stream(),
+                );
+                let validator = crate::as_validator(check, self.std_lib);
+                let error = super::error_type(self.error, &validator);
+
+                self.omit.retain(
+                    "try_from_vec",
+                    quote! {
+                        #[doc = #doc_comment]
+                        #[inline]
+                        #vis fn try_from_vec(
+                            values: ::#alloc::vec::Vec<#field_ty>,
+                        ) -> ::#core::result::Result<::#alloc::vec::Vec<Self>, (usize, #error)> {
+                            ::#core::iter::Iterator::collect(::#core::iter::Iterator::map(
+                                ::#core::iter::Iterator::enumerate(::#core::iter::IntoIterator::into_iter(values)),
+                                |(idx, value)| Self::#new(value).map_err(|err| (idx, err)),
+                            ))
+                        }
+                    },
+                )
+            }
+        };
+
+        let into_inner_vec = if self.field.provenance.is_some() {
+            quote! {
+                ::#core::iter::Iterator::collect(::#core::iter::Iterator::map(
+                    ::#core::iter::IntoIterator::into_iter(values),
+                    |value| value.#field,
+                ))
+            }
+        } else {
+            self.reinterpret_vec(quote! { values }, quote! { #field_ty })
+        };
+        let doc_comment = format!(
+            "Unwraps the underlying [`{}`] value of each of the provided {ty} values\n\nThe \
+             allocation of the vector is reused{}.",
+            field_ty.to_token_stream(),
+            if self.field.provenance.is_some() {
+                " where possible"
+            } else {
+                ", without copying the values"
+            },
+        );
+        let into_inner_vec = self.omit.retain(
+            "into_inner_vec",
+            quote! {
+                #[doc = #doc_comment]
+                #[allow(unsafe_code)]
+                #[inline]
+                #vis fn into_inner_vec(values: ::#alloc::vec::Vec<Self>) -> ::#alloc::vec::Vec<#field_ty> {
+                    #into_inner_vec
+                }
+            },
+        );
+
+        Some(quote! {
+            #from_vec
+            #into_inner_vec
+        })
+    }
+
+    fn make_try_from_iter(&self) -> Option<proc_macro2::TokenStream> {
+        let check = match self.check_mode {
+            CheckMode::None => return None,
+            CheckMode::Validate(check) | CheckMode::Normalize(check) => check,
+        };
+
+        let doc_comment = format!(
+            "Constructs a {} from each of the provided strings if they all conform to [`{}`]",
+            self.ty,
+            check.to_token_stream(),
+        );
+
+        let validator = crate::as_validator(check, self.std_lib);
+        let error = super::error_type(self.error, &validator);
+        let core = self.std_lib.core();
+        let alloc = self.std_lib.alloc();
+        let from_string = self.construct_from_string();
+
+        Some(quote! {
+            #[doc = #doc_comment]
+            #[doc = ""]
+            #[doc = "# Errors"]
+            #[doc = ""]
+            #[doc = "Stops at the first string that is not valid, returning its index alongside the error."]
+            #[inline]
+            pub fn try_from_iter<I>(iter: I) -> ::#core::result::Result<::#alloc::vec::Vec<Self>, (usize, #error)>
+            where
+                I: ::#core::iter::IntoIterator<Item = ::#alloc::string::String>,
+            {
+                ::#core::iter::Iterator::collect(::#core::iter::Iterator::map(
+                    ::#core::iter::Iterator::enumerate(::#core::iter::IntoIterator::into_iter(iter)),
+                    |(idx, s)| #from_string.map_err(|err| (idx, err)),
+                ))
+            }
+        })
+    }
+
+    /// The error rejecting a value that is valid UTF-8, for the conversions from bytes
+    fn bytes_error(&self) -> proc_macro2::TokenStream {
+        match self.check_mode {
+            CheckMode::None => {
+                let core = self.std_lib.core();
+                quote! { ::#core::convert::Infallible }
+            }
+            CheckMode::Validate(check) | CheckMode::Normalize(check) => {
+                super::error_type(self.error, &crate::as_validator(check, self.std_lib))
+            }
+        }
+    }
+
+    /// Wraps the construction of a value from a valid UTF-8 string in the result of the
+    /// conversions from bytes
+    fn bytes_result(&self, construct: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let core = self.std_lib.core();
+        let braid = self.std_lib.braid();
+        match self.check_mode {
+            CheckMode::None => quote! { ::#core::result::Result::Ok(#construct) },
+            CheckMode::Validate(_) | CheckMode::Normalize(_) => {
+                quote! { #construct.map_err(#braid::FromBytesError::Invalid) }
+            }
+        }
+    }
+
+    fn make_from_utf8(&self) -> Option<proc_macro2::TokenStream> {
+        if !self.from_bytes {
+            return None;
+        }
+
+        let core = self.std_lib.core();
+        let alloc = self.std_lib.alloc();
+        let braid = self.std_lib.braid();
+        let new = self.constructor.owned();
+        let error = self.bytes_error();
+        let result = self.bytes_result(self.construct_from_string());
+
+        let doc_comment = format!(
+            "Constructs a new {ty} from a byte vector, reusing its allocation\n\nThe bytes are \
+             first checked to be valid UTF-8 and then checked as if passed to \
+             [`{new}`][Self::{new}].",
+            ty = self.ty,
+        );
+
+        let lossy = match self.check_mode {
+            CheckMode::Validate(_) => None,
+            CheckMode::None => Some(quote! {
+                /// Constructs a new value from a byte slice, replacing any invalid UTF-8
+                /// sequences with `U+FFFD REPLACEMENT CHARACTER`
+                #[inline]
+                pub fn from_utf8_lossy(raw: &[u8]) -> Self {
+                    let s = ::#alloc::borrow::Cow::into_owned(::#alloc::string::String::from_utf8_lossy(raw));
+                    Self::#new(::#core::convert::From::from(s))
+                }
+            }),
+            CheckMode::Normalize(_) => Some(quote! {
+                /// Normalizes a byte slice into a new value, after replacing any invalid UTF-8
+                /// sequences with `U+FFFD REPLACEMENT CHARACTER`
+                #[inline]
+                pub fn from_utf8_lossy(raw: &[u8]) -> ::#core::result::Result<Self, #error> {
+                    let s = ::#alloc::borrow::Cow::into_owned(::#alloc::string::String::from_utf8_lossy(raw));
+                    Self::#new(::#core::convert::From::from(s))
+                }
+            }),
+        };
+
+        Some(quote! {
+            #[inline]
+            #[doc = #doc_comment]
+            pub fn from_utf8(
+                raw: ::#alloc::vec::Vec<u8>,
+            ) -> ::#core::result::Result<Self, #braid::FromBytesError<#error>> {
+                let s = ::#alloc::string::String::from_utf8(raw)
+                    .map_err(|err| #braid::FromBytesError::Utf8(err.utf8_error()))?;
+                #result
+            }
+
+            #lossy
+        })
+    }
+
+    /// Whether the zero-copy conversions to and from `bytes::Bytes` are generated
+    fn wraps_byte_string(&self) -> bool {
+        cfg!(feature = "bytes") && self.field.is_byte_string()
+    }
+
+    fn make_byte_string(&self) -> Option<proc_macro2::TokenStream> {
+        if !self.wraps_byte_string() {
+            return None;
+        }
+
+        let core = self.std_lib.core();
+        let braid = self.std_lib.braid();
+        let field_ty = &self.field.ty;
+        let field_name = &self.field.name;
+        let param = self.field.name.input_name();
+        let new = self.constructor.owned();
+        let error = self.bytes_error();
+
+        let construct = self.bytes_result(quote! { Self::#new(#param) });
+
+        let doc_comment = format!(
+            "Constructs a new {ty} from [`Bytes`], without copying\n\n\
+             The bytes are first checked to be valid UTF-8 and then checked as if passed to \
+             [`{new}`][Self::{new}]. The value shares the buffer of `raw`.\n\n\
+             [`Bytes`]: https://docs.rs/bytes/*/bytes/struct.Bytes.html",
+            ty = self.ty,
+        );
+
+        Some(quote! {
+            #[inline]
+            #[doc = #doc_comment]
+            pub fn from_bytes(
+                raw: #braid::__private::Bytes,
+            ) -> ::#core::result::Result<Self, #braid::FromBytesError<#error>> {
+                let #param = <#field_ty as ::#core::convert::TryFrom<#braid::__private::Bytes>>::try_from(raw)
+                    .map_err(#braid::FromBytesError::Utf8)?;
+                #construct
+            }
+
+            /// Unwraps the underlying [`Bytes`] buffer, without copying
+            ///
+            /// [`Bytes`]: https://docs.rs/bytes/*/bytes/struct.Bytes.html
+            #[inline]
+            pub fn into_bytes(self) -> #braid::__private::Bytes {
+                <#field_ty>::into_bytes(self.#field_name)
+            }
+        })
+    }
+
+    fn bytes_conversion(&self) -> Option<proc_macro2::TokenStream> {
+        if !self.from_bytes {
+            return None;
+        }
+
+        let ty = self.ty;
+        let core = self.std_lib.core();
+        let braid = self.std_lib.braid();
+        let error = self.bytes_error();
+        let result = self.bytes_result(match self.check_mode {
+            CheckMode::None => quote! { <Self as ::#core::convert::From<&str>>::from(s) },
+            CheckMode::Validate(_) | CheckMode::Normalize(_) => {
+                quote! { <Self as ::#core::convert::TryFrom<&str>>::try_from(s) }
+            }
+        });
+
+        Some(quote! {
+            #[automatically_derived]
+            impl ::#core::convert::TryFrom<&[u8]> for #ty {
+                type Error = #braid::FromBytesError<#error>;
+
+                #[inline]
+                fn try_from(raw: &[u8]) -> ::#core::result::Result<Self, Self::Error> {
+                    let s = ::#core::str::from_utf8(raw).map_err(#braid::FromBytesError::Utf8)?;
+                    #result
+                }
+            }
+        })
+    }
+
+    fn byte_string_conversion(&self) -> Option<proc_macro2::TokenStream> {
+        if !self.wraps_byte_string() {
+            return None;
+        }
+
+        let ty = self.ty;
+        let core = self.std_lib.core();
+        let braid = self.std_lib.braid();
+        let error = self.bytes_error();
+
+        Some(quote! {
+            #[automatically_derived]
+            impl ::#core::convert::TryFrom<#braid::__private::Bytes> for #ty {
+                type Error = #braid::FromBytesError<#error>;
+
+                #[inline]
+                fn try_from(raw: #braid::__private::Bytes) -> ::#core::result::Result<Self, Self::Error> {
+                    Self::from_bytes(raw)
+                }
+            }
+
+            #[automatically_derived]
+            impl ::#core::convert::From<#ty> for #braid::__private::Bytes {
+                #[inline]
+                fn from(owned: #ty) -> Self {
+                    owned.into_bytes()
+                }
+            }
+        })
+    }
+
+    fn make_mutation(&self) -> Option<proc_macro2::TokenStream> {
+        if !self.mutable {
+            return None;
+        }
+
+        let field = &self.field.name;
+
+        Some(quote! {
+            /// Appends a string slice onto the end of this value
+            #[inline]
+            pub fn push_str(&mut self, s: &str) {
+                self.#field.push_str(s)
+            }
+
+            /// Appends a `char` onto the end of this value
+            #[inline]
+            pub fn push(&mut self, ch: char) {
+                self.#field.push(ch)
+            }
+
+            /// Shortens this value to the specified length in bytes
+            ///
+            /// # Panics
+            ///
+            /// Panics if `new_len` does not lie on a `char` boundary.
+            #[inline]
+            pub fn truncate(&mut self, new_len: usize) {
+                self.#field.truncate(new_len)
+            }
+
+            /// Truncates this value, removing all contents
+            #[inline]
+            pub fn clear(&mut self) {
+                self.#field.clear()
+            }
+
+            /// Reserves capacity for at least `additional` more bytes
+            #[inline]
+            pub fn reserve(&mut self, additional: usize) {
+                self.#field.reserve(additional)
             }
+        })
+    }
+
+    fn make_test_ctor(&self) -> Option<proc_macro2::TokenStream> {
+        if !self.test_ctor {
+            return None;
         }
+
+        let doc_comment = format!(
+            "Constructs a new {} without validation or normalization\n\nThis constructor is only \
+             available in test builds. Values must still conform to the type's invariants, which \
+             are checked with a debug assertion when the value is borrowed.",
+            self.ty
+        );
+
+        let param = self.field.name.input_name();
+        let create = self.field.unchecked_self_constructor();
+        let field_ty = &self.field.ty;
+        let core = self.std_lib.core();
+        let track_caller = self.track_provenance_caller();
+
+        Some(quote! {
+            #[cfg(test)]
+            #[doc = #doc_comment]
+            #[allow(dead_code)]
+            #[inline]
+            #track_caller
+            pub fn test(value: &str) -> Self {
+                let #param: #field_ty = ::#core::convert::From::from(value);
+                #create
+            }
+        })
     }
 
     fn common_conversion(&self) -> proc_macro2::TokenStream {
         let ty = self.ty;
         let field_name = &self.field.name;
+        let field_ty = &self.field.ty;
+        let param = self.field.name.input_name();
+        let create = self.field.self_constructor();
         let ref_ty = self.ref_ty;
         let core = self.std_lib.core();
         let alloc = self.std_lib.alloc();
+        let box_pointer_reinterpret_safety_comment = self.box_pointer_reinterpret_safety_comment();
+        let into_string = self.expose.str().then(|| {
+            quote! {
+                #[automatically_derived]
+                impl ::#core::convert::From<#ty> for ::#alloc::string::String {
+                    #[inline]
+                    fn from(s: #ty) -> Self {
+                        ::#core::convert::From::from(s.#field_name)
+                    }
+                }
+            }
+        });
+        let as_ref_str = self.expose.str().then(|| {
+            quote! {
+                #[automatically_derived]
+                impl ::#core::convert::AsRef<str> for #ty {
+                    #[inline]
+                    fn as_ref(&self) -> &str {
+                        self.as_str()
+                    }
+                }
+            }
+        });
+        let from_boxed = self.field.convert_str(quote! { boxed }, self.std_lib);
+        let from_boxed_ref = self.omit.retain(
+            "box_conversions",
+            quote! {
+                #[automatically_derived]
+                impl ::#core::convert::From<::#alloc::boxed::Box<#ref_ty>> for #ty {
+                    #[allow(unsafe_code)]
+                    #[inline]
+                    fn from(r: ::#alloc::boxed::Box<#ref_ty>) -> Self {
+                        #box_pointer_reinterpret_safety_comment
+                        let raw = ::#alloc::boxed::Box::into_raw(r);
+                        let boxed = unsafe { ::#alloc::boxed::Box::from_raw(raw as *mut str) };
+                        let #param: #field_ty = #from_boxed;
+                        #create
+                    }
+                }
+            },
+        );
 
         quote! {
             #[automatically_derived]
@@ -262,13 +1233,7 @@ impl<'a> OwnedCodeGen<'a> {
                 }
             }
 
-            #[automatically_derived]
-            impl ::#core::convert::From<#ty> for ::#alloc::string::String {
-                #[inline]
-                fn from(s: #ty) -> Self {
-                    ::#core::convert::From::from(s.#field_name)
-                }
-            }
+            #into_string
 
             #[automatically_derived]
             impl ::#core::borrow::Borrow<#ref_ty> for #ty {
@@ -286,30 +1251,21 @@ impl<'a> OwnedCodeGen<'a> {
                 }
             }
 
-            #[automatically_derived]
-            impl ::#core::convert::AsRef<str> for #ty {
-                #[inline]
-                fn as_ref(&self) -> &str {
-                    self.as_str()
-                }
-            }
+            #as_ref_str
 
 
             #[automatically_derived]
             impl ::#core::convert::From<#ty> for ::#alloc::boxed::Box<#ref_ty> {
+                #[allow(unsafe_code)]
                 #[inline]
                 fn from(r: #ty) -> Self {
-                    r.into_boxed_ref()
+                    #box_pointer_reinterpret_safety_comment
+                    let box_str = ::#alloc::string::String::from(r.#field_name).into_boxed_str();
+                    unsafe { ::#alloc::boxed::Box::from_raw(::#alloc::boxed::Box::into_raw(box_str) as *mut #ref_ty) }
                 }
             }
 
-            #[automatically_derived]
-            impl ::#core::convert::From<::#alloc::boxed::Box<#ref_ty>> for #ty {
-                #[inline]
-                fn from(r: ::#alloc::boxed::Box<#ref_ty>) -> Self {
-                    r.into_owned()
-                }
-            }
+            #from_boxed_ref
 
             #[automatically_derived]
             impl<'a> ::#core::convert::From<::#alloc::borrow::Cow<'a, #ref_ty>> for #ty {
@@ -338,13 +1294,31 @@ impl<'a> OwnedCodeGen<'a> {
         let field_name = &self.field.name;
         let core = self.std_lib.core();
         let alloc = self.std_lib.alloc();
+        let new = self.constructor.owned();
+        let from_str = self.constructor.borrowed();
+        let borrow_str = self.borrow_str();
+        let iteration = self.infallible_iteration();
+        let concatenation = self.infallible_concatenation();
+        let convert_str = self.field.convert_str(quote! { s }, self.std_lib);
+        let from_boxed_str = self.omit.retain(
+            "box_conversions",
+            quote! {
+                #[automatically_derived]
+                impl ::#core::convert::From<::#alloc::boxed::Box<str>> for #ty {
+                    #[inline]
+                    fn from(s: ::#alloc::boxed::Box<str>) -> Self {
+                        Self::#new(#convert_str)
+                    }
+                }
+            },
+        );
 
         quote! {
             #[automatically_derived]
             impl ::#core::convert::From<::#alloc::string::String> for #ty {
                 #[inline]
                 fn from(s: ::#alloc::string::String) -> Self {
-                    Self::new(From::from(s))
+                    Self::#new(::#core::convert::From::from(s))
                 }
             }
 
@@ -352,17 +1326,11 @@ impl<'a> OwnedCodeGen<'a> {
             impl ::#core::convert::From<&'_ str> for #ty {
                 #[inline]
                 fn from(s: &str) -> Self {
-                    Self::new(::#core::convert::From::from(s))
+                    Self::#new(#convert_str)
                 }
             }
 
-            #[automatically_derived]
-            impl ::#core::convert::From<::#alloc::boxed::Box<str>> for #ty {
-                #[inline]
-                fn from(s: ::#alloc::boxed::Box<str>) -> Self {
-                    Self::new(::#core::convert::From::from(s))
-                }
-            }
+            #from_boxed_str
 
             #[automatically_derived]
             impl ::#core::str::FromStr for #ty {
@@ -374,29 +1342,160 @@ impl<'a> OwnedCodeGen<'a> {
                 }
             }
 
+            #borrow_str
+
             #[automatically_derived]
-            impl ::#core::borrow::Borrow<str> for #ty {
+            impl ::#core::ops::Deref for #ty {
+                type Target = #ref_ty;
+
                 #[inline]
-                fn borrow(&self) -> &str {
-                    self.as_str()
+                fn deref(&self) -> &Self::Target {
+                    #ref_ty::#from_str(::#core::convert::AsRef::as_ref(&self.#field_name))
+                }
+            }
+
+            #iteration
+            #concatenation
+        }
+    }
+
+    /// Concatenates onto infallible braids by appending to the value as a `String`
+    fn infallible_concatenation(&self) -> Option<proc_macro2::TokenStream> {
+        if !self.add {
+            return None;
+        }
+
+        let ty = self.ty;
+        let ref_ty = self.ref_ty;
+        let core = self.std_lib.core();
+        let push_str = self.modify_as_string(quote! { s.push_str(rhs) });
+        let push_ref = self.modify_as_string(quote! { s.push_str(rhs.as_str()) });
+
+        let add_str = self.add_str.then(|| {
+            quote! {
+                #[automatically_derived]
+                impl ::#core::ops::Add<&'_ str> for #ty {
+                    type Output = Self;
+
+                    #[inline]
+                    fn add(mut self, rhs: &str) -> Self {
+                        ::#core::ops::AddAssign::add_assign(&mut self, rhs);
+                        self
+                    }
+                }
+
+                #[automatically_derived]
+                impl ::#core::ops::AddAssign<&'_ str> for #ty {
+                    #[inline]
+                    fn add_assign(&mut self, rhs: &str) {
+                        #push_str
+                    }
                 }
             }
+        });
 
+        Some(quote! {
             #[automatically_derived]
-            impl ::#core::ops::Deref for #ty {
-                type Target = #ref_ty;
+            impl ::#core::ops::Add<&'_ #ref_ty> for #ty {
+                type Output = Self;
 
                 #[inline]
-                fn deref(&self) -> &Self::Target {
-                    #ref_ty::from_str(::#core::convert::AsRef::as_ref(&self.#field_name))
+                fn add(mut self, rhs: &#ref_ty) -> Self {
+                    ::#core::ops::AddAssign::add_assign(&mut self, rhs);
+                    self
                 }
             }
+
+            #[automatically_derived]
+            impl ::#core::ops::AddAssign<&'_ #ref_ty> for #ty {
+                #[inline]
+                fn add_assign(&mut self, rhs: &#ref_ty) {
+                    #push_ref
+                }
+            }
+
+            #add_str
+        })
+    }
+
+    /// Modifies the value of an infallible braid as a `String` named `s`
+    ///
+    /// The field type is only required to convert to and from `String`, so the field is
+    /// temporarily moved out, leaving an empty value in its place.
+    fn modify_as_string(&self, modify: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let field_name = &self.field.name;
+        let field_ty = &self.field.ty;
+        let core = self.std_lib.core();
+        let alloc = self.std_lib.alloc();
+
+        quote! {
+            let empty: #field_ty = ::#core::convert::From::from("");
+            let value = ::#core::mem::replace(&mut self.#field_name, empty);
+            let mut s: ::#alloc::string::String = ::#core::convert::From::from(value);
+            #modify;
+            self.#field_name = ::#core::convert::From::from(s);
+        }
+    }
+
+    /// Builds infallible braids from iterators by collecting into a `String`
+    fn infallible_iteration(&self) -> Option<proc_macro2::TokenStream> {
+        if !self.iter {
+            return None;
+        }
+
+        let ty = self.ty;
+        let core = self.std_lib.core();
+        let alloc = self.std_lib.alloc();
+        let new = self.constructor.owned();
+        let extend = self.modify_as_string(quote! { ::#core::iter::Extend::extend(&mut s, iter) });
+
+        Some(quote! {
+            #[automatically_derived]
+            impl ::#core::iter::FromIterator<char> for #ty {
+                #[inline]
+                fn from_iter<I: ::#core::iter::IntoIterator<Item = char>>(iter: I) -> Self {
+                    let s: ::#alloc::string::String = ::#core::iter::FromIterator::from_iter(iter);
+                    Self::#new(::#core::convert::From::from(s))
+                }
+            }
+
+            #[automatically_derived]
+            impl<'a> ::#core::iter::FromIterator<&'a str> for #ty {
+                #[inline]
+                fn from_iter<I: ::#core::iter::IntoIterator<Item = &'a str>>(iter: I) -> Self {
+                    let s: ::#alloc::string::String = ::#core::iter::FromIterator::from_iter(iter);
+                    Self::#new(::#core::convert::From::from(s))
+                }
+            }
+
+            #[automatically_derived]
+            impl<'a> ::#core::iter::Extend<&'a str> for #ty {
+                #[inline]
+                fn extend<I: ::#core::iter::IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+                    #extend
+                }
+            }
+        })
+    }
+
+    /// Constructs the braid from a `String` named `s`
+    ///
+    /// A nested braid is constructed through its own checked conversion, so that the value is
+    /// checked by the inner braid before being validated again as a whole.
+    fn construct_from_string(&self) -> proc_macro2::TokenStream {
+        let core = self.std_lib.core();
+        if self.field.nested {
+            let alloc = self.std_lib.alloc();
+            quote! { <Self as ::#core::convert::TryFrom<::#alloc::string::String>>::try_from(s) }
+        } else {
+            let new = self.constructor.owned();
+            quote! { Self::#new(::#core::convert::From::from(s)) }
         }
     }
 
     fn unchecked_safety_comment(is_normalized: bool) -> proc_macro2::TokenStream {
         let doc = format!(
-            "SAFETY: The value was satisfies the type's invariant and conforms to the required \
+            "SAFETY: The value satisfies the type's invariant and conforms to the required \
              implicit contracts of the {}.",
             if is_normalized {
                 "normalizer"
@@ -416,54 +1515,59 @@ impl<'a> OwnedCodeGen<'a> {
         let ref_ty = self.ref_ty;
         let field_name = &self.field.name;
         let field_ty = &self.field.ty;
-        let validator = crate::as_validator(validator);
+        let validator = crate::as_validator(validator, self.std_lib);
+        let error = super::error_type(self.error, &validator);
         let core = self.std_lib.core();
         let alloc = self.std_lib.alloc();
+        let new = self.constructor.owned();
+        let param = self.field.name.input_name();
+        let create = self.field.self_constructor();
         let unchecked_safety_comment = Self::unchecked_safety_comment(false);
+        let from_str_unchecked_checked = self.unchecked.ref_checked_constructor();
+        let borrow_str = self.borrow_str();
+        let deref = self.unchecked.call(quote! {
+            #ref_ty::#from_str_unchecked_checked(::#core::convert::AsRef::as_ref(&self.#field_name))
+        });
+        let convert_str = self.field.convert_str(quote! { s }, self.std_lib);
 
         quote! {
             #[automatically_derived]
             impl ::#core::convert::TryFrom<::#alloc::string::String> for #ty {
-                type Error = #validator::Error;
+                type Error = #error;
 
                 #[inline]
                 fn try_from(s: ::#alloc::string::String) -> ::#core::result::Result<Self, Self::Error> {
-                    const fn ensure_try_from_string_error_converts_to_validator_error<T: ?Sized + From<<#field_ty as ::#core::convert::TryFrom<::#alloc::string::String>>::Error>>() {}
+                    const fn ensure_try_from_string_error_converts_to_validator_error<T: ?::#core::marker::Sized + ::#core::convert::From<<#field_ty as ::#core::convert::TryFrom<::#alloc::string::String>>::Error>>() {}
                     ensure_try_from_string_error_converts_to_validator_error::<Self::Error>();
 
-                    Self::new(::#core::convert::TryFrom::try_from(s)?)
+                    Self::#new(::#core::convert::TryFrom::try_from(s)?)
                 }
             }
 
             #[automatically_derived]
             impl ::#core::convert::TryFrom<&'_ str> for #ty {
-                type Error = #validator::Error;
+                type Error = #error;
 
                 #[inline]
                 fn try_from(s: &str) -> ::#core::result::Result<Self, Self::Error> {
-                    let ref_ty = #ref_ty::from_str(s)?;
-                    ::#core::result::Result::Ok(::#alloc::borrow::ToOwned::to_owned(ref_ty))
+                    // Validated once, and constructed directly from the input
+                    #validator::validate(s)?;
+                    let #param: #field_ty = #convert_str;
+                    ::#core::result::Result::Ok(#create)
                 }
             }
 
             #[automatically_derived]
             impl ::#core::str::FromStr for #ty {
-                type Err = #validator::Error;
+                type Err = #error;
 
                 #[inline]
                 fn from_str(s: &str) -> ::#core::result::Result<Self, Self::Err> {
-                    let ref_ty = #ref_ty::from_str(s)?;
-                    ::#core::result::Result::Ok(::#alloc::borrow::ToOwned::to_owned(ref_ty))
+                    <Self as ::#core::convert::TryFrom<&'_ str>>::try_from(s)
                 }
             }
 
-            #[automatically_derived]
-            impl ::#core::borrow::Borrow<str> for #ty {
-                #[inline]
-                fn borrow(&self) -> &str {
-                    self.as_str()
-                }
-            }
+            #borrow_str
 
             #[automatically_derived]
             impl ::#core::ops::Deref for #ty {
@@ -473,7 +1577,7 @@ impl<'a> OwnedCodeGen<'a> {
                 #[inline]
                 fn deref(&self) -> &Self::Target {
                     #unchecked_safety_comment
-                    unsafe { #ref_ty::from_str_unchecked(::#core::convert::AsRef::as_ref(&self.#field_name)) }
+                    #deref
                 }
             }
         }
@@ -484,44 +1588,56 @@ impl<'a> OwnedCodeGen<'a> {
         let ref_ty = self.ref_ty;
         let field_name = &self.field.name;
         let field_ty = &self.field.ty;
-        let validator = crate::as_validator(normalizer);
+        let validator = crate::as_validator(normalizer, self.std_lib);
+        let error = super::error_type(self.error, &validator);
         let core = self.std_lib.core();
         let alloc = self.std_lib.alloc();
+        let normalizer = crate::as_normalizer(normalizer, self.std_lib);
+        let new = self.constructor.owned();
+        let param = self.field.name.input_name();
+        let create = self.field.self_constructor();
         let unchecked_safety_comment = Self::unchecked_safety_comment(true);
+        let from_str_unchecked_checked = self.unchecked.ref_checked_constructor();
+        let deref = self
+            .unchecked
+            .call(quote! { #ref_ty::#from_str_unchecked_checked(&self.#field_name) });
+        let normalized = self
+            .field
+            .convert_str(quote! { #normalizer::normalize(s)? }, self.std_lib);
 
         quote! {
             #[automatically_derived]
             impl ::#core::convert::TryFrom<::#alloc::string::String> for #ty {
-                type Error = #validator::Error;
+                type Error = #error;
 
                 #[inline]
                 fn try_from(s: ::#alloc::string::String) -> ::#core::result::Result<Self, Self::Error> {
-                    const fn ensure_try_from_string_error_converts_to_validator_error<T: ?Sized + From<<#field_ty as ::#core::convert::TryFrom<::#alloc::string::String>>::Error>>() {}
+                    const fn ensure_try_from_string_error_converts_to_validator_error<T: ?::#core::marker::Sized + ::#core::convert::From<<#field_ty as ::#core::convert::TryFrom<::#alloc::string::String>>::Error>>() {}
                     ensure_try_from_string_error_converts_to_validator_error::<Self::Error>();
 
-                    Self::new(::#core::convert::TryFrom::try_from(s)?)
+                    Self::#new(::#core::convert::TryFrom::try_from(s)?)
                 }
             }
 
             #[automatically_derived]
             impl ::#core::convert::TryFrom<&'_ str> for #ty {
-                type Error = #validator::Error;
+                type Error = #error;
 
                 #[inline]
                 fn try_from(s: &str) -> ::#core::result::Result<Self, Self::Error> {
-                    let ref_ty = #ref_ty::from_str(s)?;
-                    ::#core::result::Result::Ok(ref_ty.into_owned())
+                    // Normalized once, and constructed directly from the result
+                    let #param: #field_ty = #normalized;
+                    ::#core::result::Result::Ok(#create)
                 }
             }
 
             #[automatically_derived]
             impl ::#core::str::FromStr for #ty {
-                type Err = #validator::Error;
+                type Err = #error;
 
                 #[inline]
                 fn from_str(s: &str) -> ::#core::result::Result<Self, Self::Err> {
-                    let ref_ty = #ref_ty::from_str(s)?;
-                    ::#core::result::Result::Ok(ref_ty.into_owned())
+                    <Self as ::#core::convert::TryFrom<&'_ str>>::try_from(s)
                 }
             }
 
@@ -533,7 +1649,7 @@ impl<'a> OwnedCodeGen<'a> {
                 #[inline]
                 fn deref(&self) -> &Self::Target {
                     #unchecked_safety_comment
-                    unsafe { #ref_ty::from_str_unchecked(&self.#field_name) }
+                    #deref
                 }
             }
         }
@@ -546,39 +1662,162 @@ impl<'a> OwnedCodeGen<'a> {
             CheckMode::Validate(validator) => self.fallible_conversion(validator),
             CheckMode::Normalize(normalizer) => self.normalized_conversion(normalizer),
         };
+        let bytes = self.bytes_conversion();
+        let byte_string = self.byte_string_conversion();
 
         quote! {
             #common
             #convert
+            #bytes
+            #byte_string
         }
     }
 
+    fn default(&self) -> Option<proc_macro2::TokenStream> {
+        self.default.map(|value| {
+            let ty = &self.ty;
+            let core = self.std_lib.core();
+
+            quote! {
+                #[automatically_derived]
+                impl ::#core::default::Default for #ty {
+                    #[inline]
+                    fn default() -> Self {
+                        Self::from_static(#value)
+                    }
+                }
+            }
+        })
+    }
+
     pub fn tokens(&self) -> proc_macro2::TokenStream {
         let clone = self.impls.clone.to_owned_impl(self);
         let display = self.impls.display.to_owned_impl(self);
         let debug = self.impls.debug.to_owned_impl(self);
         let ord = self.impls.ord.to_owned_impl(self);
-        let serde = self.impls.serde.to_owned_impl(self);
+        let serde = self
+            .impls
+            .gates
+            .gate(super::symbol::SERDE, self.impls.serde.to_owned_impl(self));
+        let clap = self
+            .impls
+            .gates
+            .gate(super::symbol::CLAP, self.impls.clap.to_owned_impl(self));
+        let valuable = self.impls.gates.gate(
+            super::symbol::VALUABLE,
+            self.impls.valuable.to_owned_impl(self),
+        );
+        let defmt = self
+            .impls
+            .gates
+            .gate(super::symbol::DEFMT, self.impls.defmt.to_owned_impl(self));
+        let ts_rs = self
+            .impls
+            .gates
+            .gate(super::symbol::TS_RS, self.impls.ts_rs.to_owned_impl(self));
+        let redis = self
+            .impls
+            .gates
+            .gate(super::symbol::REDIS, self.impls.redis.to_owned_impl(self));
+        let bson = self
+            .impls
+            .gates
+            .gate(super::symbol::BSON, self.impls.bson.to_owned_impl(self));
+        let sea_orm = self.impls.gates.gate(
+            super::symbol::SEA_ORM,
+            self.impls.sea_orm.to_owned_impl(self),
+        );
+        let quickcheck = self.impls.gates.gate(
+            super::symbol::QUICKCHECK,
+            self.impls.quickcheck.to_owned_impl(self),
+        );
 
         let owned_attrs: proc_macro2::TokenStream =
             self.attrs.iter().map(|a| quote! {#[#a]}).collect();
         let body = &self.body;
         let inherent = self.inherent();
         let conversion = self.conversion();
+        let default = self.default();
+        let support = self.support();
+        let hash = self.impls.hash.is_generated();
+        let derives = self.equality.derives(hash);
+        let equality =
+            self.equality
+                .impls(&self.ty, |v| quote! { #v.as_str() }, hash, self.std_lib);
+
+        // The provenance is only carried in debug builds, in which the owned type cannot be
+        // transparent
+        let repr = match self.field.provenance {
+            Some(_) => quote! { #[cfg_attr(not(debug_assertions), repr(transparent))] },
+            None => quote! { #[repr(transparent)] },
+        };
 
         quote! {
             #clone
-            #[derive(Hash, PartialEq, Eq)]
-            #[repr(transparent)]
+            #derives
+            #repr
             #owned_attrs
             #body
 
             #inherent
             #conversion
+            #default
+            #support
+            #equality
             #debug
             #display
             #ord
             #serde
+            #clap
+            #valuable
+            #defmt
+            #ts_rs
+            #redis
+            #bson
+            #sea_orm
+            #quickcheck
         }
     }
+
+    fn borrow_str(&self) -> Option<proc_macro2::TokenStream> {
+        let ty = self.ty;
+        let core = self.std_lib.core();
+
+        (self.expose.str() && self.equality.is_exact() && self.impls.hash.matches_str()).then(
+            || {
+                quote! {
+                    #[automatically_derived]
+                    impl ::#core::borrow::Borrow<str> for #ty {
+                        #[inline]
+                        fn borrow(&self) -> &str {
+                            self.as_str()
+                        }
+                    }
+                }
+            },
+        )
+    }
+
+    fn support(&self) -> Option<proc_macro2::TokenStream> {
+        let ty = &self.ty;
+        let ref_ty = &self.ref_ty;
+        let braid = self.std_lib.braid();
+
+        self.expose.str().then(|| {
+            quote! {
+                #[automatically_derived]
+                impl #braid::__private::Sealed for #ty {}
+
+                #[automatically_derived]
+                impl #braid::Braid for #ty {
+                    type Ref = #ref_ty;
+
+                    #[inline]
+                    fn as_braid_ref(&self) -> &#ref_ty {
+                        self
+                    }
+                }
+            }
+        })
+    }
 }
@@ -0,0 +1,121 @@
+use quote::{quote, ToTokens};
+
+use super::{
+    symbol::{self, parse_expr_as_lit, parse_lit_into_type},
+    StdLib,
+};
+
+/// A structured interpretation of a braid value, parsed on demand via
+/// [`FromStr`][std::str::FromStr]
+struct Adapter {
+    name: syn::Ident,
+    ty: syn::Type,
+}
+
+impl Adapter {
+    fn method_name(&self) -> syn::Ident {
+        let name = self.name.to_string();
+        let mut snake = String::with_capacity(name.len() + 4);
+        let mut prev_lower = false;
+        for c in name.chars() {
+            if c.is_uppercase() {
+                if prev_lower {
+                    snake.push('_');
+                }
+                snake.extend(c.to_lowercase());
+                prev_lower = false;
+            } else {
+                snake.push(c);
+                prev_lower = c.is_lowercase() || c.is_ascii_digit();
+            }
+        }
+
+        quote::format_ident!("as_{}", snake, span = self.name.span())
+    }
+
+    fn tokens(&self, ref_ty: &syn::Type, std_lib: &StdLib) -> proc_macro2::TokenStream {
+        let core = std_lib.core();
+        let ty = &self.ty;
+        let method = self.method_name();
+        let error = quote! { <#ty as ::#core::str::FromStr>::Err };
+        let doc = format!("Parses the value as a [`{}`]", ty.to_token_stream());
+
+        quote! {
+            #[automatically_derived]
+            impl #ref_ty {
+                #[doc = #doc]
+                #[inline]
+                pub fn #method(&self) -> ::#core::result::Result<#ty, #error> {
+                    ::#core::str::FromStr::from_str(self.as_str())
+                }
+            }
+
+            #[automatically_derived]
+            impl<'a> ::#core::convert::TryFrom<&'a #ref_ty> for #ty {
+                type Error = #error;
+
+                #[inline]
+                fn try_from(s: &'a #ref_ty) -> ::#core::result::Result<Self, Self::Error> {
+                    s.#method()
+                }
+            }
+        }
+    }
+}
+
+/// The set of `parse_as` adapters declared on a braid
+#[derive(Default)]
+pub struct ParseAs {
+    adapters: Vec<Adapter>,
+}
+
+impl ParseAs {
+    pub fn try_extend(&mut self, list: &syn::MetaList) -> Result<(), syn::Error> {
+        let args = list.parse_args_with(
+            syn::punctuated::Punctuated::<syn::MetaNameValue, syn::Token![,]>::parse_terminated,
+        )?;
+
+        for nv in args {
+            let name = nv.path.get_ident().cloned().ok_or_else(|| {
+                syn::Error::new_spanned(
+                    &nv.path,
+                    format!(
+                        "{} adapter names must be a simple identifier",
+                        symbol::PARSE_AS
+                    ),
+                )
+            })?;
+
+            if self.adapters.iter().any(|a| a.name == name) {
+                return Err(syn::Error::new_spanned(
+                    &name,
+                    format!(
+                        "{} adapter `{}` can only be specified once",
+                        symbol::PARSE_AS,
+                        name
+                    ),
+                ));
+            }
+
+            let ty = parse_lit_into_type(symbol::PARSE_AS, parse_expr_as_lit(&nv.value)?)?;
+            self.adapters.push(Adapter { name, ty });
+        }
+
+        Ok(())
+    }
+
+    /// Generates an `as_*` method on the borrowed type and a `TryFrom`
+    /// conversion from the borrowed type for each declared adapter
+    pub fn tokens(&self, ref_ty: &syn::Type, std_lib: &StdLib) -> Option<proc_macro2::TokenStream> {
+        if self.adapters.is_empty() {
+            return None;
+        }
+
+        Some(
+            self.adapters
+                .iter()
+                .map(|a| a.tokens(ref_ty, std_lib))
+                .collect(),
+        )
+    }
+}
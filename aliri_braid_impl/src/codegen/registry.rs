@@ -0,0 +1,42 @@
+use quote::{quote, ToTokens};
+
+use super::{check_mode::CheckMode, constraints::Constraints, StdLib};
+
+/// Registers the braid's metadata in the global braid registry
+///
+/// The registration expands to nothing unless the `registry` feature of
+/// `aliri_braid` is enabled.
+pub fn tokens(
+    owned_ty: Option<&syn::Ident>,
+    ref_ty: &syn::Type,
+    check_mode: &CheckMode,
+    constraints: &Constraints,
+    std_lib: &StdLib,
+) -> proc_macro2::TokenStream {
+    let core = std_lib.core();
+    let braid = std_lib.braid();
+    let owned_name = match owned_ty {
+        Some(owned_ty) => {
+            let name = owned_ty.to_string();
+            quote! { ::#core::option::Option::Some(#name) }
+        }
+        None => quote! { ::#core::option::Option::None },
+    };
+    let ref_name = ref_ty.to_token_stream().to_string();
+    let check_mode = match check_mode {
+        CheckMode::None => quote! { None },
+        CheckMode::Validate(_) => quote! { Validate },
+        CheckMode::Normalize(_) => quote! { Normalize },
+    };
+    let constraints = constraints.descriptors(std_lib);
+
+    quote! {
+        #braid::__register_braid!(#braid::BraidInfo::new(
+            #owned_name,
+            #ref_name,
+            ::#core::module_path!(),
+            #braid::CheckMode::#check_mode,
+            #constraints,
+        ));
+    }
+}
@@ -0,0 +1,161 @@
+use quote::{quote, ToTokens};
+
+use super::{
+    check_mode::IndefiniteCheckMode,
+    symbol::{self, parse_expr_as_lit, parse_lit_into_type},
+    Constraints, Field, StdLib,
+};
+
+/// The set of other braids that share a representation with a braid
+///
+/// Both braids must declare `same_repr_as` naming the other. Each declaration
+/// generates conversions into the declaring braid that reuse the existing
+/// value without checking it again, along with a compile-time assertion that
+/// the two braids wrap the same field type, are checked by the same validator
+/// or normalizer, and declare the same constraints.
+#[derive(Default)]
+pub struct SameRepr {
+    targets: Vec<syn::Type>,
+}
+
+impl SameRepr {
+    pub fn try_push(&mut self, nv: &syn::MetaNameValue) -> Result<(), syn::Error> {
+        let target = parse_lit_into_type(symbol::SAME_REPR_AS, parse_expr_as_lit(&nv.value)?)?;
+        let name = target.to_token_stream().to_string();
+        if self
+            .targets
+            .iter()
+            .any(|t| t.to_token_stream().to_string() == name)
+        {
+            return Err(syn::Error::new_spanned(
+                nv,
+                format!(
+                    "{} `{}` can only be specified once",
+                    symbol::SAME_REPR_AS,
+                    name
+                ),
+            ));
+        }
+
+        self.targets.push(target);
+        Ok(())
+    }
+
+    /// Rejects declarations that name the braid itself or that are placed on
+    /// a generic braid
+    pub fn ensure_supported(&self, body: &syn::ItemStruct) -> Result<(), syn::Error> {
+        let name = body.ident.to_string();
+        if let Some(target) = self
+            .targets
+            .iter()
+            .find(|t| t.to_token_stream().to_string() == name)
+        {
+            return Err(syn::Error::new_spanned(
+                target,
+                format!("{} cannot name the braid itself", symbol::SAME_REPR_AS),
+            ));
+        }
+
+        match self.targets.first() {
+            Some(target) if !body.generics.params.is_empty() => Err(syn::Error::new_spanned(
+                target,
+                format!(
+                    "{} is not supported on generic braids",
+                    symbol::SAME_REPR_AS
+                ),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Generates the representation of the owned braid, and conversions into
+    /// the owned and borrowed braids from each braid that shares it
+    pub fn tokens(
+        &self,
+        ty: &syn::Ident,
+        ref_ty: &syn::Type,
+        field: &Field,
+        check_mode: &IndefiniteCheckMode,
+        constraints: &Constraints,
+        std_lib: &StdLib,
+    ) -> Option<proc_macro2::TokenStream> {
+        if self.targets.is_empty() {
+            return None;
+        }
+
+        let core = std_lib.core();
+        let braid = std_lib.braid();
+        let field_name = &field.name;
+        let field_ty = &field.ty;
+        let param = field.name.input_name();
+        let create = field.self_constructor();
+        let descriptors = constraints.descriptors(std_lib);
+        let check = match check_mode {
+            IndefiniteCheckMode::None => quote! { () },
+            IndefiniteCheckMode::Validate(Some(check))
+            | IndefiniteCheckMode::Normalize(Some(check)) => check.to_token_stream(),
+            IndefiniteCheckMode::Validate(None) | IndefiniteCheckMode::Normalize(None) => {
+                ty.to_token_stream()
+            }
+        };
+        let safety_comment = {
+            let doc = format!(
+                "SAFETY: `{ty}` and the borrowed form of the source braid are both \
+                 `#[repr(transparent)]` around a single `str` field, so a pointer to one can be \
+                 safely reinterpreted as a pointer to the other. The value already conforms to \
+                 the implicit contracts of `{ty}`, as the source braid has been asserted to share \
+                 its representation.",
+                ty = ref_ty.to_token_stream(),
+            );
+
+            quote! {
+                #[doc = #doc]
+                fn ptr_safety_comment() {}
+            }
+        };
+
+        let conversions = self.targets.iter().map(|target| {
+            let target_ref = quote! { <#target as ::#core::ops::Deref>::Target };
+
+            quote! {
+                const _: () = #braid::__private::assert_same_repr::<#ty, #target>();
+
+                #[automatically_derived]
+                impl ::#core::convert::From<#target> for #ty {
+                    #[inline]
+                    fn from(s: #target) -> Self {
+                        let #param: #field_ty = #braid::__private::Repr::into_repr(s);
+                        #create
+                    }
+                }
+
+                #[automatically_derived]
+                impl<'a> ::#core::convert::From<&'a #target_ref> for &'a #ref_ty {
+                    #[allow(unsafe_code)]
+                    #[inline]
+                    fn from(s: &'a #target_ref) -> Self {
+                        #safety_comment
+                        unsafe { &*(s as *const #target_ref as *const #ref_ty) }
+                    }
+                }
+            }
+        });
+
+        Some(quote! {
+            #[automatically_derived]
+            impl #braid::__private::Repr for #ty {
+                type Field = #field_ty;
+                type Check = #check;
+
+                const CONSTRAINTS: &'static [#braid::Constraint] = #descriptors;
+
+                #[inline]
+                fn into_repr(self) -> #field_ty {
+                    self.#field_name
+                }
+            }
+
+            #(#conversions)*
+        })
+    }
+}
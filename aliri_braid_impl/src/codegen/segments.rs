@@ -0,0 +1,108 @@
+use quote::{quote, ToTokens};
+
+use super::{
+    symbol::{self, parse_expr_as_lit, parse_lit_into_char, parse_lit_into_type},
+    StdLib,
+};
+
+/// Accessors for the delimited segments of a braid value
+///
+/// Segments are produced as string slices, or as references to another
+/// borrowed braid if a `segment` type is named. Each typed segment is checked
+/// as it is produced, so the items are the result of the conversion.
+#[derive(Default)]
+pub struct Segments {
+    delimiter: Option<syn::LitChar>,
+    item: Option<syn::Type>,
+}
+
+impl Segments {
+    pub fn try_set_delimiter(&mut self, nv: &syn::MetaNameValue) -> Result<(), syn::Error> {
+        if self.delimiter.is_some() {
+            return Err(syn::Error::new_spanned(
+                nv,
+                format!("{} can only be specified once", symbol::SEGMENTS),
+            ));
+        }
+
+        self.delimiter = Some(parse_lit_into_char(
+            symbol::SEGMENTS,
+            parse_expr_as_lit(&nv.value)?,
+        )?);
+        Ok(())
+    }
+
+    pub fn try_set_item(&mut self, nv: &syn::MetaNameValue) -> Result<(), syn::Error> {
+        if self.item.is_some() {
+            return Err(syn::Error::new_spanned(
+                nv,
+                format!("{} can only be specified once", symbol::SEGMENT),
+            ));
+        }
+
+        self.item = Some(parse_lit_into_type(
+            symbol::SEGMENT,
+            parse_expr_as_lit(&nv.value)?,
+        )?);
+        Ok(())
+    }
+
+    /// Generates the `segments()` and `nth_segment()` accessors on the
+    /// borrowed type
+    pub fn tokens(
+        &self,
+        ref_ty: &syn::Type,
+        std_lib: &StdLib,
+    ) -> Result<Option<proc_macro2::TokenStream>, syn::Error> {
+        let delimiter = match (&self.delimiter, &self.item) {
+            (Some(delimiter), _) => delimiter,
+            (None, Some(item)) => {
+                return Err(syn::Error::new_spanned(
+                    item,
+                    format!("{} requires {}", symbol::SEGMENT, symbol::SEGMENTS),
+                ))
+            }
+            (None, None) => return Ok(None),
+        };
+
+        let core = std_lib.core();
+        let (item, convert) = match &self.item {
+            Some(ty) => {
+                let conversion = quote! { <&'a #ty as ::#core::convert::TryFrom<&'a str>> };
+                (
+                    quote! { ::#core::result::Result<&'a #ty, #conversion::Error> },
+                    Some(quote! { .map(#conversion::try_from) }),
+                )
+            }
+            None => (quote! { &'a str }, None),
+        };
+
+        let segments_doc = format!(
+            "Iterates over the segments of the value separated by `{:?}`",
+            delimiter.value()
+        );
+        let nth_doc = format!(
+            "Returns the segment at index `n` of the value separated by `{:?}`, or `None` if \
+             there are not enough segments",
+            delimiter.value()
+        );
+        let ref_name = ref_ty.to_token_stream();
+
+        Ok(Some(quote! {
+            #[automatically_derived]
+            impl #ref_name {
+                #[doc = #segments_doc]
+                #[inline]
+                pub fn segments<'a>(&'a self) -> impl ::#core::iter::DoubleEndedIterator<Item = #item> + 'a {
+                    self.as_str().split(#delimiter) #convert
+                }
+
+                #[doc = #nth_doc]
+                #[inline]
+                pub fn nth_segment<'a>(&'a self, n: usize) -> ::#core::option::Option<#item> {
+                    self.segments().nth(n)
+                }
+            }
+        }))
+    }
+}
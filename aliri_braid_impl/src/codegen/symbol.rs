@@ -6,18 +6,88 @@ use syn::{Ident, Path};
 pub struct Symbol(&'static str);
 
 // pub const NO_AUTO_REF: Symbol = Symbol("no_auto_ref");
-// pub const OWNED: Symbol = Symbol("owned");
 pub const CLONE: Symbol = Symbol("clone");
 pub const DEBUG: Symbol = Symbol("debug");
 pub const DISPLAY: Symbol = Symbol("display");
 pub const ORD: Symbol = Symbol("ord");
+pub const HASH: Symbol = Symbol("hash");
 pub const SERDE: Symbol = Symbol("serde");
+pub const AXUM: Symbol = Symbol("axum");
+pub const CLAP: Symbol = Symbol("clap");
+pub const VALUABLE: Symbol = Symbol("valuable");
+pub const DEFMT: Symbol = Symbol("defmt");
+pub const TS_RS: Symbol = Symbol("ts_rs");
+pub const REDIS: Symbol = Symbol("redis");
+pub const BSON: Symbol = Symbol("bson");
+pub const SEA_ORM: Symbol = Symbol("sea_orm");
+pub const QUICKCHECK: Symbol = Symbol("quickcheck");
 pub const REF: Symbol = Symbol("ref_name");
+pub const REF_INNER: Symbol = Symbol("ref_inner");
+pub const PATH: Symbol = Symbol("path");
+pub const RELATIVE: Symbol = Symbol("relative");
+pub const ABSOLUTE: Symbol = Symbol("absolute");
+pub const C_STR: Symbol = Symbol("c_str");
+pub const OWNED: Symbol = Symbol("owned");
+pub const TO_OWNED: Symbol = Symbol("to_owned");
+pub const AS_STR: Symbol = Symbol("as_str");
+pub const ALLOC: Symbol = Symbol("alloc");
+pub const ERROR: Symbol = Symbol("error");
 pub const REF_DOC: Symbol = Symbol("ref_doc");
+pub const OWNED_DOC: Symbol = Symbol("owned_doc");
+pub const ALIAS: Symbol = Symbol("alias");
 pub const REF_ATTR: Symbol = Symbol("ref_attr");
 pub const OWNED_ATTR: Symbol = Symbol("owned_attr");
+pub const REF_FIELD_ATTR: Symbol = Symbol("ref_field_attr");
+pub const OWNED_FIELD_ATTR: Symbol = Symbol("owned_field_attr");
 pub const NO_STD: Symbol = Symbol("no_std");
+pub const STD_PATH: Symbol = Symbol("std_path");
+pub const CRATE: Symbol = Symbol("crate");
 pub const NO_EXPOSE: Symbol = Symbol("no_expose");
+pub const NO_MUST_USE: Symbol = Symbol("no_must_use");
+pub const EXPOSE: Symbol = Symbol("expose");
+pub const EQ: Symbol = Symbol("eq");
+pub const FROM_BYTES: Symbol = Symbol("from_bytes");
+pub const UNICODE: Symbol = Symbol("unicode");
+pub const AS_PATH: Symbol = Symbol("as_path");
+pub const ASSERT_LAYOUT: Symbol = Symbol("assert_layout");
+pub const NESTED: Symbol = Symbol("nested");
+pub const PROVENANCE: Symbol = Symbol("provenance");
+pub const FORMAT: Symbol = Symbol("format");
+pub const ARENA: Symbol = Symbol("arena");
+pub const VIEW_SLICES: Symbol = Symbol("view_slices");
+pub const VEC: Symbol = Symbol("vec");
+pub const SLICE: Symbol = Symbol("slice");
+pub const INTERN: Symbol = Symbol("intern");
+pub const TEST_CTOR: Symbol = Symbol("test_ctor");
+pub const MUTABLE: Symbol = Symbol("mutable");
+pub const ADD: Symbol = Symbol("add");
+pub const ADD_STR: Symbol = Symbol("add_str");
+pub const BUILD: Symbol = Symbol("build");
+pub const ITER: Symbol = Symbol("iter");
+pub const SEGMENTS: Symbol = Symbol("segments");
+pub const SEGMENT: Symbol = Symbol("segment");
+pub const NON_EMPTY: Symbol = Symbol("non_empty");
+pub const CHARSET: Symbol = Symbol("charset");
+pub const MIN_LEN: Symbol = Symbol("min_len");
+pub const MIN_BYTES: Symbol = Symbol("min_bytes");
+pub const MIN_CHARS: Symbol = Symbol("min_chars");
+pub const MAX_LEN: Symbol = Symbol("max_len");
+pub const MAX_BYTES: Symbol = Symbol("max_bytes");
+pub const MAX_CHARS: Symbol = Symbol("max_chars");
+pub const PREFIX: Symbol = Symbol("prefix");
+pub const SUFFIX: Symbol = Symbol("suffix");
+pub const PATTERN: Symbol = Symbol("pattern");
+pub const REST: Symbol = Symbol("rest");
+pub const PARSE_AS: Symbol = Symbol("parse_as");
+pub const UNCHECKED: Symbol = Symbol("unchecked");
+pub const CONSTRUCTOR: Symbol = Symbol("constructor");
+pub const NAMING: Symbol = Symbol("naming");
+pub const OMIT: Symbol = Symbol("omit");
+pub const FEATURE_GATE: Symbol = Symbol("feature_gate");
+pub const CONVERT_CASE: Symbol = Symbol("convert_case");
+pub const DEFAULT: Symbol = Symbol("default");
+pub const CONVERT_FROM: Symbol = Symbol("convert_from");
+pub const SAME_REPR_AS: Symbol = Symbol("same_repr_as");
 pub const VALIDATOR: Symbol = Symbol(super::check_mode::VALIDATOR);
 pub const NORMALIZER: Symbol = Symbol(super::check_mode::NORMALIZER);
 
@@ -27,7 +97,7 @@ impl PartialEq<Symbol> for Ident {
     }
 }
 
-impl<'a> PartialEq<Symbol> for &'a Ident {
+impl PartialEq<Symbol> for &Ident {
     fn eq(&self, word: &Symbol) -> bool {
         *self == word.0
     }
@@ -39,7 +109,7 @@ impl PartialEq<Symbol> for Path {
     }
 }
 
-impl<'a> PartialEq<Symbol> for &'a Path {
+impl PartialEq<Symbol> for &Path {
     fn eq(&self, word: &Symbol) -> bool {
         self.is_ident(word.0)
     }
@@ -65,12 +135,12 @@ fn get_lit_str(attr_name: Symbol, lit: &syn::Lit) -> Result<&syn::LitStr, syn::E
     }
 }
 
-// fn parse_lit_into_path(attr_name: Symbol, lit: &syn::Lit) -> Result<syn::Path, ()> {
-//     let string = get_lit_str( attr_name, lit)?;
-//     parse_lit_str(string).map_err(|_| {
-//         syn::Error::new_spanned(lit, format!("failed to parse path: {:?}", string.value()))
-//     })
-// }
+pub(super) fn parse_lit_into_path(attr_name: Symbol, lit: &syn::Lit) -> Result<Path, syn::Error> {
+    let string = get_lit_str(attr_name, lit)?;
+    parse_lit_str(string).map_err(|_| {
+        syn::Error::new_spanned(lit, format!("failed to parse path: {:?}", string.value()))
+    })
+}
 
 pub(super) fn parse_expr_as_lit(expr: &syn::Expr) -> Result<&syn::Lit, syn::Error> {
     if let syn::Expr::Lit(l) = expr {
@@ -93,6 +163,70 @@ pub(super) fn parse_lit_into_type(
     })
 }
 
+pub(super) fn parse_lit_into_ident(attr_name: Symbol, lit: &syn::Lit) -> Result<Ident, syn::Error> {
+    let string = get_lit_str(attr_name, lit)?;
+    parse_lit_str(string).map_err(|_| {
+        syn::Error::new_spanned(
+            lit,
+            format!("failed to parse identifier: {:?}", string.value()),
+        )
+    })
+}
+
+pub(super) fn parse_lit_into_vis(
+    attr_name: Symbol,
+    lit: &syn::Lit,
+) -> Result<syn::Visibility, syn::Error> {
+    let string = get_lit_str(attr_name, lit)?;
+    parse_lit_str(string).map_err(|_| {
+        syn::Error::new_spanned(
+            lit,
+            format!("failed to parse visibility: {:?}", string.value()),
+        )
+    })
+}
+
+pub(super) fn parse_lit_into_lit_str(
+    attr_name: Symbol,
+    lit: &syn::Lit,
+) -> Result<syn::LitStr, syn::Error> {
+    get_lit_str(attr_name, lit).cloned()
+}
+
+pub(super) fn parse_lit_into_int(
+    attr_name: Symbol,
+    lit: &syn::Lit,
+) -> Result<syn::LitInt, syn::Error> {
+    if let syn::Lit::Int(lit) = lit {
+        Ok(lit.clone())
+    } else {
+        Err(syn::Error::new_spanned(
+            lit,
+            format!(
+                "expected attribute `{}` to have an integer value (`{} = 42`)",
+                attr_name, attr_name
+            ),
+        ))
+    }
+}
+
+pub(super) fn parse_lit_into_char(
+    attr_name: Symbol,
+    lit: &syn::Lit,
+) -> Result<syn::LitChar, syn::Error> {
+    if let syn::Lit::Char(lit) = lit {
+        Ok(lit.clone())
+    } else {
+        Err(syn::Error::new_spanned(
+            lit,
+            format!(
+                "expected attribute `{}` to have a character value (`{} = ':'`)",
+                attr_name, attr_name
+            ),
+        ))
+    }
+}
+
 pub(super) fn parse_lit_into_string(
     attr_name: Symbol,
     lit: &syn::Lit,
@@ -124,7 +258,7 @@ fn respan_token_stream(
         .collect()
 }
 
-fn respan_token_tree(
+pub fn respan_token_tree(
     mut token: proc_macro2::TokenTree,
     span: proc_macro2::Span,
 ) -> proc_macro2::TokenTree {
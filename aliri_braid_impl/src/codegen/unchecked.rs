@@ -0,0 +1,96 @@
+use quote::{format_ident, quote};
+
+const DEFAULT_SUFFIX: &str = "unchecked";
+
+/// How the unchecked constructors of validated and normalized braids are
+/// provided
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum Unchecked {
+    #[default]
+    Implement,
+    Omit,
+    Rename(String),
+    SafeHidden,
+}
+
+impl std::str::FromStr for Unchecked {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "impl" => Ok(Self::Implement),
+            "omit" => Ok(Self::Omit),
+            "safe_hidden" => Ok(Self::SafeHidden),
+            _ => match s.strip_prefix("rename:") {
+                Some(suffix) if syn::parse_str::<syn::Ident>(suffix).is_ok() => {
+                    Ok(Self::Rename(suffix.to_owned()))
+                }
+                _ => Err("valid values are: `impl`, `omit`, `safe_hidden`, or `rename:suffix`"),
+            },
+        }
+    }
+}
+
+impl Unchecked {
+    fn suffix(&self) -> &str {
+        match self {
+            Self::Rename(suffix) => suffix,
+            Self::Implement | Self::Omit | Self::SafeHidden => DEFAULT_SUFFIX,
+        }
+    }
+
+    /// The name of the unchecked constructor on the owned type
+    pub fn owned_constructor(&self) -> syn::Ident {
+        format_ident!("new_{}", self.suffix())
+    }
+
+    /// The name of the unchecked constructor on the borrowed type
+    pub fn ref_constructor(&self) -> syn::Ident {
+        format_ident!("from_str_{}", self.suffix())
+    }
+
+    /// The name of the non-`const` counterpart of the unchecked constructor on the owned type,
+    /// which checks the value with a debug assertion
+    pub fn owned_checked_constructor(&self) -> syn::Ident {
+        format_ident!("new_{}_checked", self.suffix())
+    }
+
+    /// The name of the non-`const` counterpart of the unchecked constructor on the borrowed
+    /// type, which checks the value with a debug assertion
+    pub fn ref_checked_constructor(&self) -> syn::Ident {
+        format_ident!("from_str_{}_checked", self.suffix())
+    }
+
+    /// The visibility of the unchecked constructors, which are kept private
+    /// to the declaring module when omitted
+    pub fn vis(&self, expose: bool) -> Option<proc_macro2::Ident> {
+        (expose && *self != Self::Omit)
+            .then(|| proc_macro2::Ident::new("pub", proc_macro2::Span::call_site()))
+    }
+
+    /// The `unsafe` qualifier of the unchecked constructors, which is dropped
+    /// when they are made safe but hidden
+    pub fn unsafety(&self) -> Option<syn::Token![unsafe]> {
+        (*self != Self::SafeHidden).then(Default::default)
+    }
+
+    /// Attributes placed on the unchecked constructors
+    ///
+    /// Omitted constructors are private, and need not be used by the other generated items.
+    pub fn attrs(&self) -> Option<proc_macro2::TokenStream> {
+        match self {
+            Self::SafeHidden => Some(quote! { #[doc(hidden)] }),
+            Self::Omit => Some(quote! { #[allow(dead_code)] }),
+            _ => None,
+        }
+    }
+
+    /// Wraps a call to an unchecked constructor in an `unsafe` block if the
+    /// constructor is unsafe
+    pub fn call(&self, call: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        match self.unsafety() {
+            Some(unsafety) => quote! { #unsafety { #call } },
+            None => call,
+        }
+    }
+}
@@ -21,7 +21,7 @@ extern crate proc_macro;
 
 mod codegen;
 
-use codegen::{Params, ParamsRef};
+use codegen::{BraidMany, BraidParams, ParamsRef};
 use proc_macro::TokenStream;
 use syn::parse_macro_input;
 
@@ -34,15 +34,130 @@ use syn::parse_macro_input;
 /// Available options:
 /// * `ref_name = "RefName"`
 ///   * Sets the name of the borrowed type
-/// * `ref_doc = "Alternate doc comment"`
+/// * `ref_doc = "Alternate doc comment"` (may be repeated)
 ///   * Overrides the default doc comment for the borrowed type
-/// * `ref_attr = "#[derive(...)]"`
-///   * Provides an attribute to be placed only on the borrowed type
-/// * `owned_attr = "#[derive(...)]"`
-///   * Provides an attribute to be placed only on the owned type
+/// * `owned_doc = "Alternate doc comment"` (may be repeated)
+///   * Overrides the doc comment for the owned type. If `ref_doc` is not also specified, the
+///     borrowed type keeps the doc comment from the declaration. In both `owned_doc` and `ref_doc`,
+///     `{owned}` and `{ref}` are replaced with the names of the owned and borrowed types.
+/// * `alias = "name"` (may be repeated)
+///   * Places `#[doc(alias = "name")]` on both the owned and borrowed types, so that they can be
+///     found in the documentation by searching for `name`
+/// * `ref_attr(...)` (may be repeated)
+///   * Provides an attribute to be placed only on the borrowed type. The tokens are forwarded
+///     verbatim, so `ref_attr(cfg_attr(feature = "x", derive(Foo)))` places `#[cfg_attr(feature =
+///     "x", derive(Foo))]` on the borrowed type.
+/// * `owned_attr(...)` (may be repeated)
+///   * Provides an attribute to be placed only on the owned type, forwarded verbatim
+/// * `ref_field_attr(...)` and `owned_field_attr(...)` (may be repeated)
+///   * Provides an attribute to be placed only on the field of the borrowed or owned type,
+///     forwarded verbatim. Attributes placed on the wrapped field itself are applied to the fields
+///     of both types.
+/// * `assert_layout`
+///   * Asserts at compile time that references to the borrowed type have the same size and
+///     alignment as `&str`, and that the owned type has the same size and alignment as the type it
+///     wraps, catching attributes that break the layout the conversions rely on. Not supported on
+///     generic braids.
+/// * `nested`
+///   * Indicates that the wrapped field is itself a braid. Values are checked by the inner braid
+///     before any validator or constraints of the outer braid, whose error must implement `From`
+///     the inner braid's error. Cannot be combined with a normalizer.
 /// * either `validator [ = "Type" ]` or `normalizer [ = "Type" ]`
 ///   * Indicates the type is validated or normalized. If not specified, it is assumed that the
 ///     braid implements the relevant trait itself.
+/// * `convert_case = "snake|kebab|camel"`
+///   * Normalizes values into the given case convention. Cannot be combined with a `validator` or
+///     `normalizer`.
+/// * `format = "uuid|hostname|email|uri|idna"`
+///   * Checks values with a built-in validator for the given format, failing with a
+///     `ConstraintError`. Cannot be combined with a `validator`, `normalizer`, or `convert_case`.
+///   * `uuid` normalizes values into canonical UUID text, and generates `From<uuid::Uuid>` for the
+///     owned type and `TryFrom` conversions into `uuid::Uuid` from references to either type.
+///     Requires the `uuid` feature of `aliri_braid`, and cannot be combined with declarative
+///     constraints.
+///   * `uri` requires the `uri` feature of `aliri_braid`.
+///   * `idna` normalizes domain names into their lowercase, Punycode-encoded ASCII form. Requires
+///     the `idna` feature of `aliri_braid`.
+/// * `error = "Type"`
+///   * Overrides the error type returned by fallible constructors and conversions. The validator's
+///     error must be convertible into this type with `From`.
+/// * `non_empty`
+///   * Synthesizes a validator that rejects empty values, with `ConstraintError` as its error type,
+///     so that no handwritten validator is needed
+/// * `charset = "ascii|ascii_alphanumeric|no_control|printable"`
+///   * Synthesizes a validator that rejects values containing characters outside the named set.
+///     `printable` allows ASCII characters from `' '` through `'~'`.
+/// * `min_len = N` and `max_len = N`, or `min_bytes = N` and `max_bytes = N`
+///   * Synthesizes a validator that constrains the length of the value in bytes. If a validator or
+///     normalizer type is also named, the constraints are checked before delegating to it.
+/// * `min_chars = N` and `max_chars = N`
+///   * Synthesizes a validator that constrains the length of the value in `char`s, rather than
+///     bytes. Can be combined with the byte length constraints.
+/// * `prefix = "..."` and `suffix = "..."`
+///   * Synthesizes a validator requiring the value to start or end with the given affix, and
+///     generates `without_prefix()` or `without_suffix()` accessors on the borrowed type.
+/// * `pattern = "..."`
+///   * Synthesizes a validator requiring the entire value to match the given regular expression.
+///     Requires the `regex` feature of `aliri_braid`. Unicode word boundaries (`\b`) are not
+///     supported, though ASCII word boundaries (`(?-u:\b)`) are. Braids with a pattern cannot be
+///     used with `braid_lit!`.
+/// * `rest = "RefType"`
+///   * Makes the affix accessors return the remainder as a reference to another braid.
+/// * `parse_as(Name = "Type", ...)`
+///   * Generates an `as_name()` method on the borrowed type and a `TryFrom` conversion from the
+///     borrowed type that parse the value into `Type` via its `FromStr` implementation.
+/// * `segments = ':'`
+///   * Generates `segments()` and `nth_segment(n)` methods on the borrowed type that split the
+///     value on the given delimiter
+/// * `segment = "RefType"`
+///   * Makes the segment accessors produce references to another braid, converted with `TryFrom`.
+///     Requires `segments`.
+/// * `convert_from = "OtherBraid"` (may be repeated)
+///   * Generates conversions from `OtherBraid` and from references to its borrowed form. The
+///     conversions are `From` for braids without a validator or normalizer, and `TryFrom`
+///     otherwise.
+/// * `same_repr_as = "OtherBraid"` (may be repeated)
+///   * Generates infallible conversions from `OtherBraid` and from references to its borrowed form
+///     that reuse the value without checking it again. `OtherBraid` must also declare
+///     `same_repr_as` naming this braid, and both braids must wrap the same type, be checked by the
+///     same validator or normalizer, and declare the same constraints, which is asserted at compile
+///     time. Not supported on generic braids.
+/// * `unchecked = "impl|omit|safe_hidden|rename:suffix"` (default: `impl`)
+///   * Changes how the unchecked constructors of validated and normalized braids are provided. If
+///     `omit`, they are kept private to the declaring module. If `safe_hidden`, they are safe
+///     functions hidden from the documentation. If `rename:suffix`, they are named `new_suffix()`
+///     and `from_str_suffix()` instead, with debug-checked counterparts named
+///     `new_suffix_checked()` and `from_str_suffix_checked()`.
+/// * `constructor = "name"`
+///   * Renames the checked constructors, `new()` on the owned type and `from_str()` on the borrowed
+///     type, to `name()`. Generated trait implementations call the renamed constructors.
+/// * `naming = "v1|v2"` (default: `v1`)
+///   * Changes the names of generated inherent methods. If `v2`, `take()` is named `into_inner()`,
+///     and `from_str()` is named `try_from_str()` on validated and normalized braids and
+///     `parse_ref()` otherwise. The `v1` names remain as deprecated aliases.
+/// * `omit(method, ...)`
+///   * Omits the named inherent methods from both the owned and borrowed types, so that they can be
+///     replaced with user-defined methods of the same name. The methods that can be omitted are
+///     `from_static`, `try_from_static`, `take`, `into_inner`, `into_boxed_ref`, `into_owned`,
+///     `normalize`, `is_normalized`, `normalized`, `build`, `slice`, `split_at`, `from_vec`,
+///     `try_from_vec`, `into_inner_vec`, `from_str_vec`, `from_slice`, `as_inner_slice`, and
+///     `from_str_slice`. Generated trait implementations do not rely on them, though `default`
+///     requires `from_static`.
+///   * `box_conversions` can also be given to omit `into_owned()` and the conversions from
+///     `Box<str>` and boxed borrowed values, so that the owned type can wrap a string type that
+///     does not implement `From<Box<str>>`.
+///   * `rc` and `arc` can also be given to omit the conversions into `Rc` and `Arc` of the borrowed
+///     type, for targets that provide `alloc` without atomics. `arc` cannot be omitted from braids
+///     that are interned.
+/// * `feature_gate(integration = "feature", ...)`
+///   * Places the impls generated for each named integration behind `#[cfg(feature = "feature")]`,
+///     so that a library can offer them as optional features. The integrations that can be gated
+///     are `serde`, `clap`, `valuable`, `defmt`, `ts_rs`, `redis`, `bson`, `sea_orm`, and
+///     `quickcheck`.
+/// * `default [ = "value" ]`
+///   * Implements `Default` for the owned type using `from_static()` with the given value, which is
+///     checked against any declarative constraints during expansion. The bare flag uses an empty
+///     string and is only allowed for braids without a validator or normalizer.
 /// * `clone = "impl|omit"` (default: `impl`)
 ///   * Changes the automatic derivation of a `Clone` implementation on the owned type.
 /// * `debug = "impl|owned|omit"` (default `impl`)
@@ -53,45 +168,299 @@ use syn::parse_macro_input;
 ///   * Changes how automatic implementations of the `Display` trait are provided. If `owned`, then
 ///     the owned type will generate a `Display` implementation that will just delegate to the
 ///     borrowed implementation. If `omit`, then no implementations of `Display` will be provided.
-/// * `ord = "impl|owned|omit"` (default `impl`)
+/// * `ord = "impl|owned|omit|case_insensitive|natural"` (default `impl`)
 ///   * Changes how automatic implementations of the `PartialOrd` and `Ord` traits are provided. If
 ///     `owned`, then the owned type will generate implementations that will just delegate to the
-///     borrowed implementations. If `omit`, then no implementations will be provided.
-/// * `serde = "impl|omit"` (default `omit`)
-///   * Adds serialize and deserialize implementations
-/// * `no_expose`
-///   * Functions that expose the internal field type will not be exposed publicly.
+///     borrowed implementations. If `omit`, then no implementations will be provided. If
+///     `case_insensitive`, then values are ordered ignoring case. If `natural`, then runs of digits
+///     are ordered by their numeric value, so that `item2` sorts before `item10`. Either way, ties
+///     are broken by the exact contents. Cannot be combined with a case-insensitive `eq`.
+/// * `hash = "impl|omit|custom"` (default `impl`)
+///   * Changes how the `Hash` trait is implemented. If `omit`, then neither type implements `Hash`.
+///     If `custom`, then the consumer must implement `Hash` for both types, hashing an owned value
+///     and its borrowed form identically, and `Borrow<str>` is not implemented, as the values may
+///     no longer hash as a `str` would.
+/// * `serde = "impl|str|bytes|omit"` (default `omit`)
+///   * Adds serialize and deserialize implementations. If `str`, then the owned type is serialized
+///     as a string slice rather than through the `Serialize` implementation of the wrapped type. If
+///     `bytes`, then both types are serialized as a string slice in human-readable formats and as
+///     bytes in other formats, such as `bincode`.
+/// * `axum`
+///   * Implies `serde`, making the owned type usable as a typed `axum` route parameter through
+///     `Path<T>` or `Query<T>`.
+/// * `clap = "impl|omit"` (default `omit`)
+///   * Implements `clap`'s `ValueParserFactory` for the owned type
+/// * `valuable = "impl|omit"` (default `omit`)
+///   * Implements `valuable::Valuable` for both the owned and borrowed types, recording the value
+///     as a string
+/// * `defmt = "impl|omit"` (default `omit`)
+///   * Implements `defmt::Format` for the borrowed type, and for the owned type when one is
+///     generated, so that braids can be logged without going through `core::fmt`
+/// * `ts_rs = "impl|omit"` (default `omit`)
+///   * Implements `ts_rs::TS` for both the owned and borrowed types, exporting the braid as an
+///     alias of `string` named after the owned type
+/// * `redis = "impl|omit"` (default `omit`)
+///   * Implements `redis`'s `ToRedisArgs` for the owned type and for references to the borrowed
+///     type, and `FromRedisValue` for the owned type, validating values read from the server
+/// * `bson = "impl|omit"` (default `omit`)
+///   * Implements conversions between the braid and `bson::Bson`, validating values converted from
+///     BSON. When `serde` is also given, the owned type is always serialized as a string. Requires
+///     the `bson` feature of `aliri_braid`.
+/// * `sea_orm = "impl|omit"` (default `omit`)
+///   * Implements the `sea_orm` value traits for the owned type, so that it can be used as the type
+///     of an entity's column. Values read from the database are validated.
+/// * `quickcheck = "impl|omit"` (default `omit`)
+///   * Implements `quickcheck::Arbitrary` for the owned type, generating and shrinking only values
+///     that pass the braid's checks
+/// * `from_bytes`
+///   * Generates a `from_bytes_validated()` constructor on the borrowed type that checks a byte
+///     slice for UTF-8 and the braid's invariants and then reinterprets it in place.
+///   * Also generates a `from_utf8()` constructor taking a `Vec<u8>` and a `TryFrom<&[u8]>`
+///     implementation on the owned type. Unvalidated and normalized braids additionally get a
+///     `from_utf8_lossy()` constructor.
+/// * `slice`
+///   * Generates `slice(range)` and `split_at(mid)` on the borrowed type, which borrow parts of the
+///     value as the same braid. Validated braids check each part again and return a `Result`.
+/// * `vec`
+///   * Generates `from_vec()` and `into_inner_vec()` on the owned type, which convert vectors of
+///     the wrapped type without copying each element, and `from_str_vec()` on the borrowed type,
+///     which borrows a vector of string slices in place. Validated and normalized braids get a
+///     `try_from_vec()` that checks each value in place of `from_vec()`, and no `from_str_vec()`.
+/// * `view_slices`
+///   * Generates `from_slice()` and `as_inner_slice()` on the owned type, which view a `&[String]`
+///     as a slice of the owned type and back, and `from_str_slice()` on the borrowed type, which
+///     views a `&[&str]` as a slice of borrowed values. Only braids without a validator or
+///     normalizer get these, and the owned conversions also require the field to be a `String`.
+/// * `arena`
+///   * Generates an `alloc_in()` constructor on the borrowed type that checks a value and copies it
+///     into an `aliri_braid::Arena`, returning a reference that lives as long as the arena.
+/// * `intern`
+///   * Implements `aliri_braid::Intern` for the borrowed type, so that values can be checked and
+///     shared through an `aliri_braid::Interner`. Cannot be combined with `no_expose = "opaque"`.
+/// * `unicode`
+///   * Generates `grapheme_len()` and `truncate_graphemes()` helpers on the borrowed type that
+///     count and cut the value by extended grapheme clusters, checking the truncated value again
+///     for validated and normalized braids. Requires the `unicode` feature of `aliri_braid`.
+/// * `as_path`
+///   * Implements `AsRef<Path>` and `AsRef<OsStr>` for both types, for braids holding file names or
+///     paths. Cannot be combined with `no_std` or `no_expose = "opaque"`.
+/// * `test_ctor`
+///   * Generates a `#[cfg(test)]` `test()` constructor on the owned type that bypasses validation
+///     and normalization for use in unit tests.
+/// * `provenance`
+///   * In debug builds, records on each owned value whether it was created through a checked or an
+///     unchecked constructor, along with the caller of the unchecked constructor, exposed through
+///     `provenance()`. The owned type is not `repr(transparent)` in debug builds, and nothing is
+///     recorded in release builds. Requires a validator, normalizer, or constraints. Cannot be
+///     combined with `assert_layout` or generic parameters.
+/// * `mutable`
+///   * Generates `push_str()`, `push()`, `truncate()`, `clear()`, and `reserve()` on the owned
+///     type, delegating to the methods of the same name on the wrapped field. Cannot be combined
+///     with a `validator`, `normalizer`, or constraints.
+/// * `iter`
+///   * Implements `FromIterator<char>`, `FromIterator<&str>`, and `Extend<&str>` for the owned
+///     type. Cannot be combined with a `validator`, `normalizer`, or constraints.
+/// * `build`
+///   * Generates a `build()` constructor on the owned type that passes a new `String` to the given
+///     function and then constructs the braid from it. Validated and normalized braids check the
+///     value once the function returns, and so return a `Result`.
+/// * `add`
+///   * Generates `Add<&Ref>` and `AddAssign<&Ref>` on the owned type. Validated and normalized
+///     braids instead get a `try_concat()` method, which checks the concatenated value.
+/// * `add_str`
+///   * Implies `add`, additionally generating `Add<&str>` and `AddAssign<&str>` on the owned type.
+///     Cannot be combined with a `validator`, `normalizer`, or constraints.
+/// * `no_expose` or `no_expose = "opaque"`
+///   * Functions that expose the internal field type will not be exposed publicly. If `opaque`,
+///     `as_str()` is also kept private to the declaring module, and the `AsRef<str>`,
+///     `Borrow<str>`, `From<Owned> for String`, `Braid`, and `BraidRef` impls are omitted, so that
+///     the value cannot be read as a string slice outside the declaring module.
+/// * `expose = "pub(crate)"`
+///   * Gives the generated inherent methods, such as the constructors and `as_str()`, the named
+///     visibility in place of `pub`. Generated trait impls, such as `From` and `FromStr`, are
+///     unaffected.
+/// * `no_must_use`
+///   * Omits the `#[must_use]` attribute otherwise placed on generated inherent methods that return
+///     a value other than a `Result`, such as `from_static()`, `take()`, and `into_boxed_ref()`
+/// * `eq = "exact|ascii_case_insensitive|unicode_case_insensitive|omit"` (default `exact`)
+///   * Changes how values are compared for equality, hashed, and ordered. If
+///     `ascii_case_insensitive`, then differences in the case of ASCII letters are ignored. If
+///     `unicode_case_insensitive`, then values are compared after Unicode lowercasing, which
+///     requires the `unicode` feature. Values keep their original case. As a `str` would hash
+///     differently, `Borrow<str>` is not implemented for case-insensitive braids.
+///   * If `omit`, then no implementations of `PartialEq` and `Eq` are provided, including the
+///     comparisons between the owned and borrowed forms, and `Borrow<str>` is not implemented.
+///     Requires `hash` to be `omit` or `custom` and `ord` to be `omit`.
 /// * `no_std`
 ///   * Generates `no_std`-compatible braid (still requires `alloc`)
+/// * `std_path = "my_std"`
+///   * Redirects all generated `std`, `core`, and `alloc` paths through the named facade crate.
+///     Cannot be combined with `no_std`.
+/// * `crate = "path::to::aliri_braid"`
+///   * Overrides the path used to refer to `aliri_braid` in generated code, for when the crate has
+///     been renamed or is re-exported through another crate.
+/// * `ref_inner = "Type"`
+///   * Wraps `Type` in the borrowed form instead of `str`, such as `[u8]` or `Path`, generating a
+///     reduced set of items. The owned field must be declared, implement `Borrow<Type>`, and be
+///     convertible from `<Type as ToOwned>::Owned`. A `validator` must implement
+///     `aliri_braid::InnerValidator` rather than `Validator`. Only `ref_name`, `validator`,
+///     `no_std`, and `crate` can be combined with it.
+/// * `path`
+///   * Shorthand for `ref_inner = "std::path::Path"`, defaulting the owned field to `PathBuf` when
+///     none is declared. Also generates `join()` and `display()` on the borrowed type and
+///     `AsRef<OsStr>` on both types. Cannot be combined with `ref_inner` or `no_std`.
+/// * `relative` or `absolute`
+///   * With `path`, rejects paths that are not relative or not absolute, respectively, with
+///     `aliri_braid::PathError`. Cannot be combined with `validator`.
+/// * `c_str`
+///   * Shorthand for `ref_inner = "core::ffi::CStr"`, defaulting the owned field to `CString` when
+///     none is declared. Also generates `as_ptr()`, `to_bytes()`, and `to_str()` on the borrowed
+///     type, and `FromStr` and `TryFrom<&str>` on the owned type, which reject interior nul bytes
+///     with `aliri_braid::CStringError`. Cannot be combined with `ref_inner` or `path`.
 #[proc_macro_attribute]
 pub fn braid(args: TokenStream, input: TokenStream) -> TokenStream {
-    let args = parse_macro_input!(args as Params);
+    let args = parse_macro_input!(args as BraidParams);
     let body = parse_macro_input!(input as syn::ItemStruct);
 
-    args.build(body)
-        .map_or_else(syn::Error::into_compile_error, |codegen| codegen.generate())
-        .into()
+    args.generate(body).into()
 }
 
 /// Constructs a ref-only braid
 ///
 /// Available options:
+/// * `owned = "Type"`
+///   * Names a separately defined owned type, generating `ToOwned`, comparisons, and `serde` glue
+///     for it. The owned type must provide `new()` (or the unchecked constructor, for validated
+///     braids) and `as_str()`.
+/// * `to_owned = "path"` and `as_str = "path"`
+///   * Names functions converting `&Ref` into the owned type and borrowing `&str` from the owned
+///     type, for owned types that cannot be given `new()` or `as_str()`, such as those defined in
+///     other crates. Borrowing the braid from such a type checks the value, panicking if it is
+///     invalid, and `into_owned()` is not generated when `to_owned` is given. Both require `owned`.
+/// * `alloc`
+///   * Generates conversions into `Box`, `Rc`, and `Arc` of the borrowed type, along with a
+///     `Deserialize` implementation for `Box` when `serde` is enabled, without an owned type.
+///     Unvalidated braids given `vec` also get `from_str_vec()`, as when an owned type is given.
+/// * `alias = "name"` (may be repeated)
+///   * Places `#[doc(alias = "name")]` on the borrowed type
 /// * either `validator [ = "Type" ]`
 ///   * Indicates the type is validated. If not specified, it is assumed that the braid implements
 ///     the relevant trait itself.
+/// * `error = "Type"`
+///   * Overrides the error type returned by fallible constructors and conversions. The validator's
+///     error must be convertible into this type with `From`.
+/// * `non_empty`
+///   * Synthesizes a validator that rejects empty values, with `ConstraintError` as its error type,
+///     so that no handwritten validator is needed
+/// * `charset = "ascii|ascii_alphanumeric|no_control|printable"`
+///   * Synthesizes a validator that rejects values containing characters outside the named set.
+///     `printable` allows ASCII characters from `' '` through `'~'`.
+/// * `min_len = N` and `max_len = N`, or `min_bytes = N` and `max_bytes = N`
+///   * Synthesizes a validator that constrains the length of the value in bytes. If a validator
+///     type is also named, the constraints are checked before delegating to it.
+/// * `min_chars = N` and `max_chars = N`
+///   * Synthesizes a validator that constrains the length of the value in `char`s, rather than
+///     bytes. Can be combined with the byte length constraints.
+/// * `prefix = "..."` and `suffix = "..."`
+///   * Synthesizes a validator requiring the value to start or end with the given affix, and
+///     generates `without_prefix()` or `without_suffix()` accessors on the borrowed type.
+/// * `pattern = "..."`
+///   * Synthesizes a validator requiring the entire value to match the given regular expression.
+///     Requires the `regex` feature of `aliri_braid`. Unicode word boundaries (`\b`) are not
+///     supported, though ASCII word boundaries (`(?-u:\b)`) are. Braids with a pattern cannot be
+///     used with `braid_lit!`.
+/// * `rest = "RefType"`
+///   * Makes the affix accessors return the remainder as a reference to another braid.
+/// * `parse_as(Name = "Type", ...)`
+///   * Generates an `as_name()` method on the borrowed type and a `TryFrom` conversion from the
+///     borrowed type that parse the value into `Type` via its `FromStr` implementation.
+/// * `segments = ':'`
+///   * Generates `segments()` and `nth_segment(n)` methods on the borrowed type that split the
+///     value on the given delimiter
+/// * `segment = "RefType"`
+///   * Makes the segment accessors produce references to another braid, converted with `TryFrom`.
+///     Requires `segments`.
+/// * `unchecked = "impl|omit|safe_hidden|rename:suffix"` (default: `impl`)
+///   * Changes how the unchecked constructor of a validated braid is provided. If `omit`, it is
+///     kept private to the declaring module. If `safe_hidden`, it is a safe function hidden from
+///     the documentation. If `rename:suffix`, it is named `from_str_suffix()` instead, with a
+///     debug-checked counterpart named `from_str_suffix_checked()`.
+/// * `constructor = "name"`
+///   * Renames the checked `from_str()` constructor to `name()`. If `owned` is given, the existing
+///     owned type is expected to provide its constructor under the same name.
+/// * `naming = "v1|v2"` (default: `v1`)
+///   * Changes the names of generated inherent methods. If `v2`, `from_str()` is named
+///     `try_from_str()` on validated braids and `parse_ref()` otherwise, with `from_str()`
+///     remaining as a deprecated alias.
+/// * `omit(method, ...)`
+///   * Omits the named inherent methods, so that they can be replaced with user-defined methods of
+///     the same name. The methods that can be omitted are `from_static`, `try_from_static`,
+///     `into_owned`, `is_normalized`, `normalized`, `slice`, `split_at`, `from_str_vec`, and
+///     `from_str_slice`.
+///   * `rc` and `arc` can also be given to omit the conversions into `Rc` and `Arc` of the borrowed
+///     type, for targets that provide `alloc` without atomics. `arc` cannot be omitted from braids
+///     that are interned.
+/// * `feature_gate(integration = "feature", ...)`
+///   * Places the impls generated for each named integration behind `#[cfg(feature = "feature")]`,
+///     so that a library can offer them as optional features. The integrations that can be gated
+///     are `serde`, `valuable`, `defmt`, `ts_rs`, `redis`, and `bson`.
+/// * `expose = "pub(crate)"`
+///   * Gives the generated inherent methods the named visibility in place of `pub`.
+/// * `no_must_use`
+///   * Omits the `#[must_use]` attribute otherwise placed on generated inherent methods that return
+///     a value other than a `Result`
+/// * `eq = "exact|ascii_case_insensitive|unicode_case_insensitive|omit"` (default `exact`)
+///   * Changes how values are compared for equality, hashed, and ordered. If
+///     `ascii_case_insensitive`, then differences in the case of ASCII letters are ignored. If
+///     `unicode_case_insensitive`, then values are compared after Unicode lowercasing, which
+///     requires the `unicode` feature. Values keep their original case. As a `str` would hash
+///     differently, `Borrow<str>` is not implemented for case-insensitive braids.
+///   * If `omit`, then no implementations of `PartialEq` and `Eq` are provided, including the
+///     comparisons between the owned and borrowed forms, and `Borrow<str>` is not implemented.
+///     Requires `hash` to be `omit` or `custom` and `ord` to be `omit`.
 /// * `debug = "impl|omit"` (default `impl`)
 ///   * Changes how automatic implementations of the `Debug` trait are provided. If `omit`, then no
 ///     implementations of `Debug` will be provided.
 /// * `display = "impl|omit"` (default `impl`)
 ///   * Changes how automatic implementations of the `Display` trait are provided. If `omit`, then
 ///     no implementations of `Display` will be provided.
-/// * `ord = "impl|omit"` (default `impl`)
+/// * `ord = "impl|omit|case_insensitive|natural"` (default `impl`)
 ///   * Changes how automatic implementations of the `PartialOrd` and `Ord` traits are provided. If
-///     `omit`, then no implementations will be provided.
+///     `omit`, then no implementations will be provided. If `case_insensitive` or `natural`, then
+///     values are ordered ignoring case or with runs of digits ordered numerically, breaking ties
+///     by the exact contents.
+/// * `hash = "impl|omit|custom"` (default `impl`)
+///   * Changes how the `Hash` trait is implemented. If `omit`, then no implementation will be
+///     provided. If `custom`, then the consumer must implement `Hash`, and `Borrow<str>` is not
+///     implemented.
 /// * `serde = "impl|omit"` (default `omit`)
 ///   * Adds serialize and deserialize implementations
+/// * `from_bytes`
+///   * Generates a `from_bytes_validated()` constructor on the borrowed type that checks a byte
+///     slice for UTF-8 and the braid's invariants and then reinterprets it in place.
+/// * `slice`
+///   * Generates `slice(range)` and `split_at(mid)` on the borrowed type, which borrow parts of the
+///     value as the same braid. Validated braids check each part again and return a `Result`.
+/// * `vec`
+///   * Generates `from_str_vec()`, which borrows a vector of string slices in place, on unvalidated
+///     braids that are given `owned` or `alloc`
+/// * `view_slices`
+///   * Generates `from_str_slice()`, which views a `&[&str]` as a slice of borrowed values, on
+///     unvalidated braids
+/// * `arena`
+///   * Generates an `alloc_in()` constructor on the borrowed type that checks a value and copies it
+///     into an `aliri_braid::Arena`, returning a reference that lives as long as the arena.
+/// * `intern`
+///   * Implements `aliri_braid::Intern` for the borrowed type, so that values can be checked and
+///     shared through an `aliri_braid::Interner`. Requires `alloc` or `owned`.
 /// * `no_std`
 ///   * Generates a `no_std`-compatible braid that doesn't require `alloc`
+/// * `std_path = "my_std"`
+///   * Redirects all generated `std` and `core` paths through the named facade crate. Cannot be
+///     combined with `no_std`.
+/// * `crate = "path::to::aliri_braid"`
+///   * Overrides the path used to refer to `aliri_braid` in generated code, for when the crate has
+///     been renamed or is re-exported through another crate.
 #[proc_macro_attribute]
 pub fn braid_ref(args: TokenStream, input: TokenStream) -> TokenStream {
     let args = parse_macro_input!(args as ParamsRef);
@@ -102,10 +471,23 @@ pub fn braid_ref(args: TokenStream, input: TokenStream) -> TokenStream {
         .into()
 }
 
-fn as_validator(validator: &syn::Type) -> proc_macro2::TokenStream {
-    quote::quote! { <#validator as ::aliri_braid::Validator> }
+/// Declares several braids sharing the same parameters
+///
+/// The parameters accepted by [`braid`](macro@braid) come first, followed by a semicolon and a
+/// comma-separated list of the braids to declare. Each braid can be given its own attributes,
+/// including doc comments, and visibility, and expands to the same code as if it had been
+/// declared as a unit struct with `#[braid(...)]` and the shared parameters.
+#[proc_macro]
+pub fn braid_many(input: TokenStream) -> TokenStream {
+    parse_macro_input!(input as BraidMany).generate().into()
+}
+
+fn as_validator(validator: &syn::Type, std_lib: &codegen::StdLib) -> proc_macro2::TokenStream {
+    let braid = std_lib.braid();
+    quote::quote! { <#validator as #braid::Validator> }
 }
 
-fn as_normalizer(normalizer: &syn::Type) -> proc_macro2::TokenStream {
-    quote::quote! { <#normalizer as ::aliri_braid::Normalizer> }
+fn as_normalizer(normalizer: &syn::Type, std_lib: &codegen::StdLib) -> proc_macro2::TokenStream {
+    let braid = std_lib.braid();
+    quote::quote! { <#normalizer as #braid::Normalizer> }
 }